@@ -74,7 +74,7 @@ fn check_ecdsa_signature(
             return false;
         },
         IsZeroResult::NonZero(pt) => {
-            let (x, y) = ec_point_unwrap(pt);
+            let (x, _y) = ec_point_unwrap(pt);
             x
         },
     };
@@ -84,7 +84,7 @@ fn check_ecdsa_signature(
     match ec_point_is_zero(zG + rQ) {
         IsZeroResult::Zero(()) => {},
         IsZeroResult::NonZero(pt) => {
-            let (x, y) = ec_point_unwrap(pt);
+            let (x, _y) = ec_point_unwrap(pt);
             if (x == sR_x) {
                 return true;
             }
@@ -94,7 +94,7 @@ fn check_ecdsa_signature(
     match ec_point_is_zero(zG - rQ) {
         IsZeroResult::Zero(()) => {},
         IsZeroResult::NonZero(pt) => {
-            let (x, y) = ec_point_unwrap(pt);
+            let (x, _y) = ec_point_unwrap(pt);
             if (x == sR_x) {
                 return true;
             }
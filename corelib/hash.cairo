@@ -12,6 +12,12 @@ impl LegacyHashFelt of LegacyHash::<felt> {
     }
 }
 
+impl LegacyHashUnit of LegacyHash::<()> {
+    fn hash(state: felt, value: ()) -> felt {
+        state
+    }
+}
+
 impl LegacyHashBool of LegacyHash::<bool> {
     fn hash(state: felt, value: bool) -> felt {
         LegacyHash::<felt>::hash(state, if value {
@@ -17,7 +17,7 @@ extern fn u128s_from_felt(a: felt) -> U128sFromFeltResult implicits(RangeCheck)
 fn u128_try_from_felt(a: felt) -> Option::<u128> implicits(RangeCheck) nopanic {
     match u128s_from_felt(a) {
         U128sFromFeltResult::Narrow(x) => Option::<u128>::Some(x),
-        U128sFromFeltResult::Wide(x) => Option::<u128>::None(()),
+        U128sFromFeltResult::Wide(_x) => Option::<u128>::None(()),
     }
 }
 
@@ -52,7 +52,7 @@ fn u128_overflowing_mul(a: u128, b: u128) -> (u128, bool) implicits(RangeCheck)
 fn u128_checked_add(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
     match u128_overflowing_add(a, b) {
         Result::Ok(r) => Option::<u128>::Some(r),
-        Result::Err(r) => Option::<u128>::None(()),
+        Result::Err(_r) => Option::<u128>::None(()),
     }
 }
 
@@ -66,7 +66,7 @@ impl U128Add of Add::<u128> {
 fn u128_checked_sub(a: u128, b: u128) -> Option::<u128> implicits(RangeCheck) nopanic {
     match u128_overflowing_sub(a, b) {
         Result::Ok(r) => Option::<u128>::Some(r),
-        Result::Err(r) => Option::<u128>::None(()),
+        Result::Err(_r) => Option::<u128>::None(()),
     }
 }
 
@@ -103,14 +103,14 @@ fn u128_try_as_non_zero(a: u128) -> Option::<NonZero::<u128>> implicits() nopani
 
 impl U128Div of Div::<u128> {
     fn div(a: u128, b: u128) -> u128 {
-        let (q, r) = u128_safe_divmod(a, u128_as_non_zero(b));
+        let (q, _r) = u128_safe_divmod(a, u128_as_non_zero(b));
         q
     }
 }
 
 impl U128Rem of Rem::<u128> {
     fn rem(a: u128, b: u128) -> u128 {
-        let (q, r) = u128_safe_divmod(a, u128_as_non_zero(b));
+        let (_q, r) = u128_safe_divmod(a, u128_as_non_zero(b));
         r
     }
 }
@@ -134,6 +134,12 @@ impl U128PartialEq of PartialEq::<u128> {
     }
 }
 
+impl U128Clone of Clone::<u128> {
+    fn clone(ref self: u128) -> u128 {
+        self
+    }
+}
+
 impl U128PartialOrd of PartialOrd::<u128> {
     #[inline(always)]
     fn le(a: u128, b: u128) -> bool {
@@ -202,6 +208,12 @@ impl U8PartialEq of PartialEq::<u8> {
     }
 }
 
+impl U8Clone of Clone::<u8> {
+    fn clone(ref self: u8) -> u8 {
+        self
+    }
+}
+
 impl U8PartialOrd of PartialOrd::<u8> {
     #[inline(always)]
     fn le(a: u8, b: u8) -> bool {
@@ -241,7 +253,7 @@ fn u8_wrapping_sub(a: u8, b: u8) -> u8 implicits(RangeCheck) nopanic {
 fn u8_checked_add(a: u8, b: u8) -> Option::<u8> implicits(RangeCheck) nopanic {
     match u8_overflowing_add(a, b) {
         Result::Ok(r) => Option::<u8>::Some(r),
-        Result::Err(r) => Option::<u8>::None(()),
+        Result::Err(_r) => Option::<u8>::None(()),
     }
 }
 
@@ -254,7 +266,7 @@ impl U8Add of Add::<u8> {
 fn u8_checked_sub(a: u8, b: u8) -> Option::<u8> implicits(RangeCheck) nopanic {
     match u8_overflowing_sub(a, b) {
         Result::Ok(r) => Option::<u8>::Some(r),
-        Result::Err(r) => Option::<u8>::None(()),
+        Result::Err(_r) => Option::<u8>::None(()),
     }
 }
 
@@ -285,14 +297,14 @@ fn u8_try_as_non_zero(a: u8) -> Option::<NonZero::<u8>> implicits() nopanic {
 
 impl U8Div of Div::<u8> {
     fn div(a: u8, b: u8) -> u8 {
-        let (q, r) = u8_safe_divmod(a, u8_as_non_zero(b));
+        let (q, _r) = u8_safe_divmod(a, u8_as_non_zero(b));
         q
     }
 }
 
 impl U8Rem of Rem::<u8> {
     fn rem(a: u8, b: u8) -> u8 {
-        let (q, r) = u8_safe_divmod(a, u8_as_non_zero(b));
+        let (_q, r) = u8_safe_divmod(a, u8_as_non_zero(b));
         r
     }
 }
@@ -320,6 +332,12 @@ impl U16PartialEq of PartialEq::<u16> {
     }
 }
 
+impl U16Clone of Clone::<u16> {
+    fn clone(ref self: u16) -> u16 {
+        self
+    }
+}
+
 impl U16PartialOrd of PartialOrd::<u16> {
     #[inline(always)]
     fn le(a: u16, b: u16) -> bool {
@@ -359,7 +377,7 @@ fn u16_wrapping_sub(a: u16, b: u16) -> u16 implicits(RangeCheck) nopanic {
 fn u16_checked_add(a: u16, b: u16) -> Option::<u16> implicits(RangeCheck) nopanic {
     match u16_overflowing_add(a, b) {
         Result::Ok(r) => Option::<u16>::Some(r),
-        Result::Err(r) => Option::<u16>::None(()),
+        Result::Err(_r) => Option::<u16>::None(()),
     }
 }
 
@@ -372,7 +390,7 @@ impl U16Add of Add::<u16> {
 fn u16_checked_sub(a: u16, b: u16) -> Option::<u16> implicits(RangeCheck) nopanic {
     match u16_overflowing_sub(a, b) {
         Result::Ok(r) => Option::<u16>::Some(r),
-        Result::Err(r) => Option::<u16>::None(()),
+        Result::Err(_r) => Option::<u16>::None(()),
     }
 }
 
@@ -403,14 +421,14 @@ fn u16_try_as_non_zero(a: u16) -> Option::<NonZero::<u16>> implicits() nopanic {
 
 impl U16Div of Div::<u16> {
     fn div(a: u16, b: u16) -> u16 {
-        let (q, r) = u16_safe_divmod(a, u16_as_non_zero(b));
+        let (q, _r) = u16_safe_divmod(a, u16_as_non_zero(b));
         q
     }
 }
 
 impl U16Rem of Rem::<u16> {
     fn rem(a: u16, b: u16) -> u16 {
-        let (q, r) = u16_safe_divmod(a, u16_as_non_zero(b));
+        let (_q, r) = u16_safe_divmod(a, u16_as_non_zero(b));
         r
     }
 }
@@ -438,6 +456,12 @@ impl U32PartialEq of PartialEq::<u32> {
     }
 }
 
+impl U32Clone of Clone::<u32> {
+    fn clone(ref self: u32) -> u32 {
+        self
+    }
+}
+
 impl U32PartialOrd of PartialOrd::<u32> {
     #[inline(always)]
     fn le(a: u32, b: u32) -> bool {
@@ -477,7 +501,7 @@ fn u32_wrapping_sub(a: u32, b: u32) -> u32 implicits(RangeCheck) nopanic {
 fn u32_checked_add(a: u32, b: u32) -> Option::<u32> implicits(RangeCheck) nopanic {
     match u32_overflowing_add(a, b) {
         Result::Ok(r) => Option::<u32>::Some(r),
-        Result::Err(r) => Option::<u32>::None(()),
+        Result::Err(_r) => Option::<u32>::None(()),
     }
 }
 
@@ -490,7 +514,7 @@ impl U32Add of Add::<u32> {
 fn u32_checked_sub(a: u32, b: u32) -> Option::<u32> implicits(RangeCheck) nopanic {
     match u32_overflowing_sub(a, b) {
         Result::Ok(r) => Option::<u32>::Some(r),
-        Result::Err(r) => Option::<u32>::None(()),
+        Result::Err(_r) => Option::<u32>::None(()),
     }
 }
 
@@ -521,14 +545,14 @@ fn u32_try_as_non_zero(a: u32) -> Option::<NonZero::<u32>> implicits() nopanic {
 
 impl U32Div of Div::<u32> {
     fn div(a: u32, b: u32) -> u32 {
-        let (q, r) = u32_safe_divmod(a, u32_as_non_zero(b));
+        let (q, _r) = u32_safe_divmod(a, u32_as_non_zero(b));
         q
     }
 }
 
 impl U32Rem of Rem::<u32> {
     fn rem(a: u32, b: u32) -> u32 {
-        let (q, r) = u32_safe_divmod(a, u32_as_non_zero(b));
+        let (_q, r) = u32_safe_divmod(a, u32_as_non_zero(b));
         r
     }
 }
@@ -556,6 +580,12 @@ impl U64PartialEq of PartialEq::<u64> {
     }
 }
 
+impl U64Clone of Clone::<u64> {
+    fn clone(ref self: u64) -> u64 {
+        self
+    }
+}
+
 impl U64PartialOrd of PartialOrd::<u64> {
     #[inline(always)]
     fn le(a: u64, b: u64) -> bool {
@@ -595,7 +625,7 @@ fn u64_wrapping_sub(a: u64, b: u64) -> u64 implicits(RangeCheck) nopanic {
 fn u64_checked_add(a: u64, b: u64) -> Option::<u64> implicits(RangeCheck) nopanic {
     match u64_overflowing_add(a, b) {
         Result::Ok(r) => Option::<u64>::Some(r),
-        Result::Err(r) => Option::<u64>::None(()),
+        Result::Err(_r) => Option::<u64>::None(()),
     }
 }
 
@@ -608,7 +638,7 @@ impl U64Add of Add::<u64> {
 fn u64_checked_sub(a: u64, b: u64) -> Option::<u64> implicits(RangeCheck) nopanic {
     match u64_overflowing_sub(a, b) {
         Result::Ok(r) => Option::<u64>::Some(r),
-        Result::Err(r) => Option::<u64>::None(()),
+        Result::Err(_r) => Option::<u64>::None(()),
     }
 }
 
@@ -639,19 +669,19 @@ fn u64_try_as_non_zero(a: u64) -> Option::<NonZero::<u64>> implicits() nopanic {
 
 impl U64Div of Div::<u64> {
     fn div(a: u64, b: u64) -> u64 {
-        let (q, r) = u64_safe_divmod(a, u64_as_non_zero(b));
+        let (q, _r) = u64_safe_divmod(a, u64_as_non_zero(b));
         q
     }
 }
 
 impl U64Rem of Rem::<u64> {
     fn rem(a: u64, b: u64) -> u64 {
-        let (q, r) = u64_safe_divmod(a, u64_as_non_zero(b));
+        let (_q, r) = u64_safe_divmod(a, u64_as_non_zero(b));
         r
     }
 }
 
-#[derive(Copy, Drop)]
+#[derive(Copy, Drop, Clone, StorageAccess)]
 struct u256 {
     low: u128,
     high: u128,
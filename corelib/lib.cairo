@@ -3,6 +3,7 @@ use traits::Add;
 use traits::BitAnd;
 use traits::BitOr;
 use traits::BitXor;
+use traits::Clone;
 use traits::Copy;
 use traits::Div;
 use traits::Drop;
@@ -66,6 +67,30 @@ impl BoolPartialEq of PartialEq::<bool> {
     }
 }
 
+impl BoolClone of Clone::<bool> {
+    fn clone(ref self: bool) -> bool {
+        self
+    }
+}
+
+// Unit.
+impl UnitPartialEq of PartialEq::<()> {
+    #[inline(always)]
+    fn eq(a: (), b: ()) -> bool {
+        true
+    }
+    #[inline(always)]
+    fn ne(a: (), b: ()) -> bool {
+        false
+    }
+}
+
+impl UnitClone of Clone::<()> {
+    fn clone(ref self: ()) -> () {
+        self
+    }
+}
+
 // Felt.
 extern type RangeCheck;
 
@@ -138,6 +163,12 @@ impl FeltPartialEq of PartialEq::<felt> {
     }
 }
 
+impl FeltClone of Clone::<felt> {
+    fn clone(ref self: felt) -> felt {
+        self
+    }
+}
+
 impl PartialOrdFelt of PartialOrd::<felt> {
     #[inline(always)]
     fn le(a: felt, b: felt) -> bool {
@@ -257,6 +288,7 @@ use integer::U128Div;
 use integer::U128Rem;
 use integer::U128PartialOrd;
 use integer::U128PartialEq;
+use integer::U128Clone;
 use integer::U128BitAnd;
 use integer::U128BitOr;
 use integer::U128BitXor;
@@ -310,6 +342,8 @@ use integer::U64Rem;
 use integer::U64Sub;
 use integer::U64Mul;
 use integer::u256;
+use integer::u256Clone;
+use integer::u256StorageAccess;
 use integer::U256Add;
 use integer::U256Sub;
 use integer::U256Mul;
@@ -13,6 +13,13 @@ impl FeltSerde of Serde::<felt> {
     }
 }
 
+impl UnitSerde of Serde::<()> {
+    fn serialize(ref serialized: Array::<felt>, input: ()) {}
+    fn deserialize(ref serialized: Array::<felt>) -> Option::<()> {
+        Option::Some(())
+    }
+}
+
 impl BoolSerde of Serde::<bool> {
     fn serialize(ref serialized: Array::<felt>, input: bool) {
         Serde::<felt>::serialize(ref serialized, if input {
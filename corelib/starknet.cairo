@@ -32,30 +32,141 @@ extern fn storage_write_syscall(
 // Interoperability.
 extern fn contract_address_const<const address>() -> ContractAddress nopanic;
 extern fn call_contract_syscall(
-    address: ContractAddress, calldata: Array::<felt>
+    address: ContractAddress, entry_point_selector: felt, calldata: Array::<felt>
 ) -> SyscallResult::<Array::<felt>> implicits(GasBuiltin, System) nopanic;
 
 extern fn contract_address_try_from_felt(
     address: felt
 ) -> Option::<ContractAddress> implicits(RangeCheck) nopanic;
+extern fn contract_address_to_felt(address: ContractAddress) -> felt nopanic;
+
+extern fn library_call_syscall(
+    class_hash: felt, function_selector: felt, calldata: Array::<felt>
+) -> SyscallResult::<Array::<felt>> implicits(GasBuiltin, System) nopanic;
+
+// Deploys a new instance of the class `class_hash`, and returns the address of the deployed
+// contract together with the result of its constructor.
+// If `deploy_from_zero` is true, the deployer address is 0 when computing the address of the new
+// contract, instead of the address of the deploying contract.
+extern fn deploy_syscall(
+    class_hash: felt, contract_address_salt: felt, calldata: Array::<felt>, deploy_from_zero: bool
+) -> SyscallResult::<(ContractAddress, Array::<felt>)> implicits(GasBuiltin, System) nopanic;
+
+// Replaces the class of the calling contract with `class_hash`.
+extern fn replace_class_syscall(
+    class_hash: felt
+) -> SyscallResult::<()> implicits(GasBuiltin, System) nopanic;
 
 // Events.
 extern fn emit_event_syscall(
     keys: Array::<felt>, data: Array::<felt>
 ) -> SyscallResult::<()> implicits(GasBuiltin, System) nopanic;
 
+// Messaging.
+extern fn send_message_to_l1_syscall(
+    to_address: felt, payload: Array::<felt>
+) -> SyscallResult::<()> implicits(GasBuiltin, System) nopanic;
+
 // Getters.
 extern fn get_caller_address_syscall() -> SyscallResult::<felt> implicits(
     GasBuiltin, System
 ) nopanic;
+extern fn get_contract_address_syscall() -> SyscallResult::<ContractAddress> implicits(
+    GasBuiltin, System
+) nopanic;
+extern fn get_block_number_syscall() -> SyscallResult::<u64> implicits(GasBuiltin, System) nopanic;
+extern fn get_block_timestamp_syscall() -> SyscallResult::<u64> implicits(
+    GasBuiltin, System
+) nopanic;
+extern fn get_sequencer_address_syscall() -> SyscallResult::<ContractAddress> implicits(
+    GasBuiltin, System
+) nopanic;
+extern fn get_tx_info_syscall() -> SyscallResult::<Box::<TxInfo>> implicits(
+    GasBuiltin, System
+) nopanic;
+
+#[derive(Drop)]
+struct TxInfo {
+    // The version of the transaction.
+    version: felt,
+    // The account contract from which this transaction originates.
+    account_contract_address: ContractAddress,
+    // The max_fee field of the transaction.
+    max_fee: u128,
+    // The signature of the transaction.
+    signature: Array::<felt>,
+    // The hash of the transaction.
+    transaction_hash: felt,
+    // The identifier of the chain.
+    chain_id: felt,
+    // The transaction's nonce.
+    nonce: felt,
+}
 
 fn get_caller_address() -> felt {
     get_caller_address_syscall().unwrap_syscall()
 }
 
+fn get_contract_address() -> ContractAddress {
+    get_contract_address_syscall().unwrap_syscall()
+}
+
+fn get_block_number() -> u64 {
+    get_block_number_syscall().unwrap_syscall()
+}
+
+fn get_block_timestamp() -> u64 {
+    get_block_timestamp_syscall().unwrap_syscall()
+}
+
+fn get_sequencer_address() -> ContractAddress {
+    get_sequencer_address_syscall().unwrap_syscall()
+}
+
+fn get_tx_info() -> Box::<TxInfo> {
+    get_tx_info_syscall().unwrap_syscall()
+}
+
+fn library_call(
+    class_hash: felt, function_selector: felt, calldata: Array::<felt>
+) -> Array::<felt> {
+    library_call_syscall(class_hash, function_selector, calldata).unwrap_syscall()
+}
+
+fn deploy(
+    class_hash: felt, contract_address_salt: felt, calldata: Array::<felt>, deploy_from_zero: bool
+) -> (ContractAddress, Array::<felt>) {
+    deploy_syscall(class_hash, contract_address_salt, calldata, deploy_from_zero).unwrap_syscall()
+}
+
+fn send_message_to_l1(to_address: felt, payload: Array::<felt>) {
+    send_message_to_l1_syscall(to_address, payload).unwrap_syscall()
+}
+
+fn replace_class(class_hash: felt) {
+    replace_class_syscall(class_hash).unwrap_syscall()
+}
+
+/// Reading and writing values of type `T` in the storage of the contract.
+/// Implementations must provide the `*_internal` functions as well, as trait functions have no
+/// default implementations. Impls written before these functions were added need to implement them,
+/// usually in terms of the impls of the members of `T`.
+/// In impls whose `read` is not inlined, `read_at_offset_internal` is not inlined either, as the
+/// compiler does not support inlining it yet.
 trait StorageAccess<T> {
     fn read(address_domain: felt, base: StorageBaseAddress) -> SyscallResult::<T>;
     fn write(address_domain: felt, base: StorageBaseAddress, value: T) -> SyscallResult::<()>;
+    /// Reads a value stored `offset` slots after `base`. Used for laying out values, such as
+    /// structs, across consecutive storage slots.
+    fn read_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult::<T>;
+    /// Writes a value `offset` slots after `base`.
+    fn write_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8, value: T
+    ) -> SyscallResult::<()>;
+    /// Returns the number of storage slots a value occupies.
+    fn size_internal() -> u8;
 }
 
 impl StorageAccessFelt of StorageAccess::<felt> {
@@ -67,6 +178,51 @@ impl StorageAccessFelt of StorageAccess::<felt> {
     fn write(address_domain: felt, base: StorageBaseAddress, value: felt) -> SyscallResult::<()> {
         storage_write_syscall(address_domain, storage_address_from_base(base), value)
     }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult::<felt> {
+        storage_read_syscall(address_domain, storage_address_from_base_and_offset(base, offset))
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8, value: felt
+    ) -> SyscallResult::<()> {
+        storage_write_syscall(
+            address_domain, storage_address_from_base_and_offset(base, offset), value
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
+}
+
+impl StorageAccessUnit of StorageAccess::<()> {
+    #[inline(always)]
+    fn read(address_domain: felt, base: StorageBaseAddress) -> SyscallResult::<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn write(address_domain: felt, base: StorageBaseAddress, value: ()) -> SyscallResult::<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn read_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult::<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8, value: ()
+    ) -> SyscallResult::<()> {
+        Result::Ok(())
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        0_u8
+    }
 }
 
 impl StorageAccessBool of StorageAccess::<bool> {
@@ -81,6 +237,29 @@ impl StorageAccessBool of StorageAccess::<bool> {
             0
         })
     }
+    fn read_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult::<bool> {
+        Result::Ok(
+            StorageAccess::<felt>::read_at_offset_internal(address_domain, base, offset)? != 0
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8, value: bool
+    ) -> SyscallResult::<()> {
+        StorageAccess::<felt>::write_at_offset_internal(
+            address_domain, base, offset, if value {
+                1
+            } else {
+                0
+            }
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU8 of StorageAccess::<u8> {
@@ -93,6 +272,27 @@ impl StorageAccessU8 of StorageAccess::<u8> {
     ) -> Result::<(), Array::<felt>> {
         StorageAccess::<felt>::write(address_domain, base, u8_to_felt(value))
     }
+    fn read_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult::<u8> {
+        Result::Ok(
+            u8_from_felt(
+                StorageAccess::<felt>::read_at_offset_internal(address_domain, base, offset)?
+            )
+        )
+    }
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8, value: u8
+    ) -> SyscallResult::<()> {
+        StorageAccess::<felt>::write_at_offset_internal(
+            address_domain, base, offset, u8_to_felt(value)
+        )
+    }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
 impl StorageAccessU128 of StorageAccess::<u128> {
@@ -103,31 +303,33 @@ impl StorageAccessU128 of StorageAccess::<u128> {
     fn write(address_domain: felt, base: StorageBaseAddress, value: u128) -> SyscallResult::<()> {
         StorageAccess::<felt>::write(address_domain, base, u128_to_felt(value))
     }
-}
-
-impl StorageAccessU256 of StorageAccess::<u256> {
-    fn read(address_domain: felt, base: StorageBaseAddress) -> SyscallResult::<u256> {
+    fn read_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8
+    ) -> SyscallResult::<u128> {
         Result::Ok(
-            u256 {
-                low: StorageAccess::<u128>::read(address_domain, base)?,
-                high: u128_from_felt(
-                    storage_read_syscall(
-                        address_domain, storage_address_from_base_and_offset(base, 1_u8)
-                    )?
-                )
-            }
+            u128_from_felt(
+                StorageAccess::<felt>::read_at_offset_internal(address_domain, base, offset)?
+            )
         )
     }
-    fn write(address_domain: felt, base: StorageBaseAddress, value: u256) -> SyscallResult::<()> {
-        StorageAccess::<u128>::write(address_domain, base, value.low)?;
-        storage_write_syscall(
-            address_domain,
-            storage_address_from_base_and_offset(base, 1_u8),
-            u128_to_felt(value.high)
+    #[inline(always)]
+    fn write_at_offset_internal(
+        address_domain: felt, base: StorageBaseAddress, offset: u8, value: u128
+    ) -> SyscallResult::<()> {
+        StorageAccess::<felt>::write_at_offset_internal(
+            address_domain, base, offset, u128_to_felt(value)
         )
     }
+    #[inline(always)]
+    fn size_internal() -> u8 {
+        1_u8
+    }
 }
 
+// Testing utilities, available when compiling tests.
+#[cfg(testing)]
+mod testing;
+
 /// The result type for a syscall.
 type SyscallResult<T> = Result::<T, Array::<felt>>;
 
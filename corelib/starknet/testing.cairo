@@ -0,0 +1,55 @@
+// Cheatcodes for manipulating the simulated StarkNet state when running tests.
+// These are only supported by the test runner, and may not be used in contracts.
+use array::ArrayTrait;
+use option::OptionTrait;
+use serde::Serde;
+use starknet::ContractAddress;
+use starknet::contract_address_to_felt;
+
+// The cheatcode selectors are the short strings of the cheatcode names, e.g. 'set_storage'.
+extern fn cheatcode<const selector>(input: Array::<felt>) -> Array::<felt> nopanic;
+
+// Sets the address returned by `get_caller_address` in the following calls.
+fn set_caller_address(address: felt) {
+    let mut input = ArrayTrait::new();
+    input.append(address);
+    cheatcode::<10052436086942832998170947883001859293934451>(input);
+}
+
+// Sets the address of the running contract, which is returned by `get_contract_address` and used
+// for the storage accesses and emitted events of the following calls.
+fn set_contract_address(address: ContractAddress) {
+    let mut input = ArrayTrait::new();
+    input.append(contract_address_to_felt(address));
+    cheatcode::<658796451393958236726798008093001784562670728051>(input);
+}
+
+// Sets the timestamp returned by `get_block_timestamp` in the following calls.
+fn set_block_timestamp(block_timestamp: u64) {
+    let mut input = ArrayTrait::new();
+    input.append(u64_to_felt(block_timestamp));
+    cheatcode::<2573423638252396292085154671110649995631619440>(input);
+}
+
+// Sets the value of `key` in the storage of the contract at `address`.
+fn set_storage(address: ContractAddress, key: felt, value: felt) {
+    let mut input = ArrayTrait::new();
+    input.append(contract_address_to_felt(address));
+    input.append(key);
+    input.append(value);
+    cheatcode::<139505574970733817499838309>(input);
+}
+
+// Removes the earliest event emitted by the contract at `address` that was not popped yet, and
+// returns its keys and data. Returns `None` if there is no such event.
+fn pop_event(address: ContractAddress) -> Option::<(Array::<felt>, Array::<felt>)> {
+    let mut input = ArrayTrait::new();
+    input.append(contract_address_to_felt(address));
+    let mut output = cheatcode::<2074065359280454069876>(input);
+    if output.len() == 0_usize {
+        return Option::None(());
+    }
+    let keys = Serde::<Array::<felt>>::deserialize(ref output).expect('Invalid event keys');
+    let data = Serde::<Array::<felt>>::deserialize(ref output).expect('Invalid event data');
+    Option::Some((keys, data))
+}
@@ -2,6 +2,7 @@ use array::ArrayTrait;
 use dict::DictFeltToTrait;
 use option::OptionTrait;
 use option::OptionTraitImpl;
+use traits::Clone;
 
 #[test]
 #[should_panic]
@@ -574,6 +575,22 @@ fn test_u128_operators() {
     assert(u128_sqrt(0_u128) == 0_u128, 'u128_sqrt(0) == 0');
 }
 
+#[test]
+fn test_u128_div_mod_property(a: u128, b: u128) {
+    if b != 0_u128 {
+        assert((a / b) * b + a % b == a, 'a / b * b + a % b == a');
+        assert(a % b < b, 'a % b < b');
+    }
+}
+
+#[test_case(255, 1)]
+#[test_case(128, 128)]
+#[test_case(1, 255)]
+#[should_panic]
+fn test_u8_add_overflow_cases(a: u8, b: u8) {
+    a + b;
+}
+
 fn pow_2_127() -> u128 {
     0x80000000000000000000000000000000_u128
 }
@@ -870,3 +887,153 @@ fn test_box_unbox_u256() {
     assert(unbox::<u256>(boxed_x) == as_u256(1_u128, 0_u128), 'unbox u256 x');
     assert(unbox::<u256>(boxed_y) == as_u256(1_u128, 1_u128), 'unbox u256 y');
 }
+
+fn match_u8_and_options(x: u8, a: Option::<felt>, b: Option::<felt>) -> felt {
+    match x {
+        0_u8 => 0,
+        3_u8 => match (a, b) {
+            (Option::Some(x), Option::Some(y)) => x + y,
+            (Option::Some(x), Option::None(_)) => x,
+            (Option::None(_), _) => 1,
+        },
+        _ => 2,
+    }
+}
+
+#[test]
+fn test_match_decision_tree() {
+    assert(match_u8_and_options(0_u8, Option::None(()), Option::None(())) == 0, 'match 0');
+    assert(match_u8_and_options(3_u8, Option::Some(5), Option::Some(6)) == 11, 'match some some');
+    assert(match_u8_and_options(3_u8, Option::Some(5), Option::None(())) == 5, 'match some none');
+    assert(match_u8_and_options(3_u8, Option::None(()), Option::Some(6)) == 1, 'match none');
+    assert(match_u8_and_options(7_u8, Option::None(()), Option::None(())) == 2, 'match wildcard');
+}
+
+fn sum_until_with_loop(n: felt) -> felt {
+    let mut i = 0;
+    let mut sum = 0;
+    loop {
+        match get_gas() {
+            Option::Some(_) => {},
+            Option::None(_) => {
+                let mut data = ArrayTrait::new();
+                data.append('Out of gas');
+                panic(data);
+            },
+        }
+        if i == n {
+            break sum;
+        }
+        i = i + 1;
+        if i == 3 {
+            continue;
+        }
+        sum = sum + i;
+    }
+}
+
+fn append_with_while(ref arr: Array::<felt>, n: felt) {
+    let mut i = 0;
+    while i != n {
+        match get_gas() {
+            Option::Some(_) => {},
+            Option::None(_) => {
+                let mut data = ArrayTrait::new();
+                data.append('Out of gas');
+                panic(data);
+            },
+        }
+        arr.append(i);
+        i = i + 1;
+    }
+}
+
+#[test]
+#[available_gas(100000)]
+fn test_loops() {
+    assert(sum_until_with_loop(5) == 12, 'loop sum');
+    let mut arr = ArrayTrait::new();
+    append_with_while(ref arr, 4);
+    assert(arr.len() == 4_u32, 'while len');
+    assert(arr.at(3_u32) == 3, 'while last');
+}
+
+#[derive(Copy, Drop, Clone, LegacyHash, PartialEq, Serde)]
+struct DerivedPoint {
+    x: felt,
+    y: u256,
+}
+
+#[derive(Copy, Drop, Clone, LegacyHash, PartialEq, Serde)]
+enum DerivedShape {
+    Empty: (),
+    Point: DerivedPoint,
+    Radius: u128,
+}
+
+#[test]
+fn test_derived_traits() {
+    let point = DerivedPoint { x: 1, y: u256 { low: 2_u128, high: 3_u128 } };
+    let mut shape = DerivedShape::Point(point);
+    assert(shape.clone() == shape, 'derived clone');
+    assert(shape != DerivedShape::Empty(()), 'derived ne variant');
+    assert(DerivedShape::Radius(1_u128) != DerivedShape::Radius(2_u128), 'derived ne value');
+    let mut serialized = ArrayTrait::new();
+    serde::Serde::<DerivedShape>::serialize(ref serialized, shape);
+    serde::Serde::<DerivedShape>::serialize(ref serialized, DerivedShape::Empty(()));
+    assert(serialized.len() == 5_u32, 'derived serialize');
+    assert(
+        serde::Serde::<DerivedShape>::deserialize(ref serialized).unwrap() == shape,
+        'derived deserialize'
+    );
+    assert(
+        serde::Serde::<DerivedShape>::deserialize(
+            ref serialized
+        ).unwrap() == DerivedShape::Empty(()),
+        'derived deserialize unit'
+    );
+    assert(serde::Serde::<DerivedShape>::deserialize(ref serialized).is_none(), 'derived no data');
+    let other_point = DerivedPoint { x: 1, y: u256 { low: 2_u128, high: 4_u128 } };
+    assert(
+        hash::LegacyHash::<DerivedShape>::hash(
+            0, shape
+        ) != hash::LegacyHash::<DerivedShape>::hash(0, DerivedShape::Point(other_point)),
+        'derived hash'
+    );
+}
+
+#[derive(Copy, Drop, Clone, LegacyHash, PartialEq, Serde)]
+struct DerivedCollisions {
+    a: felt,
+    b: felt,
+    state: felt,
+    self: felt,
+}
+
+#[derive(Copy, Drop, Clone, PartialEq)]
+struct DerivedPrefixedNames {
+    x: felt,
+    clone_x: felt,
+}
+
+#[test]
+fn test_derived_traits_member_names() {
+    let mut value = DerivedCollisions { a: 1, b: 2, state: 3, self: 4 };
+    assert(value.clone() == value, 'collisions clone');
+    assert(value != DerivedCollisions { a: 1, b: 2, state: 3, self: 5 }, 'collisions ne');
+    assert(
+        hash::LegacyHash::<DerivedCollisions>::hash(
+            5, value
+        ) == pedersen(pedersen(pedersen(pedersen(5, 1), 2), 3), 4),
+        'collisions hash'
+    );
+    let mut serialized = ArrayTrait::new();
+    serde::Serde::<DerivedCollisions>::serialize(ref serialized, value);
+    assert(
+        serde::Serde::<DerivedCollisions>::deserialize(ref serialized).unwrap() == value,
+        'collisions serde'
+    );
+    let mut prefixed = DerivedPrefixedNames { x: 1, clone_x: 2 };
+    assert(prefixed.clone() == prefixed, 'prefixed clone');
+    assert(prefixed != DerivedPrefixedNames { x: 2, clone_x: 1 }, 'prefixed ne');
+}
@@ -57,3 +57,8 @@ trait PartialOrd<T> {
 trait ToBool<T> {
     fn to_bool(self: T) -> bool;
 }
+
+/// Represents a type whose value can be duplicated using `Clone::clone()`.
+trait Clone<T> {
+    fn clone(ref self: T) -> T;
+}
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 
 use indoc::writedoc;
+use num_bigint::BigInt;
 
 use crate::operand::{CellRef, DerefOrImmediate, ResOperand};
 
@@ -158,6 +159,16 @@ pub enum Hint {
         size: ResOperand,
         dst: CellRef,
     },
+    /// Runs the cheatcode identified by `selector` on the values from `input_start` to
+    /// `input_end`, and writes the bounds of its output to `output_start` and `output_end`.
+    /// Only supported when running tests.
+    Cheatcode {
+        selector: BigInt,
+        input_start: ResOperand,
+        input_end: ResOperand,
+        output_start: CellRef,
+        output_end: CellRef,
+    },
 }
 
 struct DerefOrImmediateFormatter<'a>(&'a DerefOrImmediate);
@@ -495,6 +506,19 @@ impl Display for Hint {
                     ResOperandFormatter(size)
                 )
             }
+            Hint::Cheatcode { selector, input_start, input_end, output_start, output_end } => {
+                writedoc!(
+                    f,
+                    "
+
+                        r = cheatcode({selector}, {}, {})
+                        memory{output_start} = r.start
+                        memory{output_end} = r.end
+                    ",
+                    ResOperandFormatter(input_start),
+                    ResOperandFormatter(input_end),
+                )
+            }
         }
     }
 }
@@ -110,3 +110,23 @@ fn test_debug_hint_format() {
         "}
     );
 }
+
+#[test]
+fn test_cheatcode_hint_format() {
+    assert_eq!(
+        Hint::Cheatcode {
+            selector: 7.into(),
+            input_start: res!([fp - 4]),
+            input_end: res!([fp - 3]),
+            output_start: CellRef { register: Register::AP, offset: 0 },
+            output_end: CellRef { register: Register::AP, offset: 1 },
+        }
+        .to_string(),
+        indoc! {"
+
+            r = cheatcode(7, memory[fp + -4], memory[fp + -3])
+            memory[ap + 0] = r.start
+            memory[ap + 1] = r.end
+        "}
+    );
+}
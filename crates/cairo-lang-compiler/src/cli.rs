@@ -2,10 +2,18 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::{
+    apply_diagnostic_fixes, DiagnosticsReporter, MessageFormat,
+};
+use cairo_lang_compiler::project::setup_project;
 use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
+/// The maximal number of times fixes are applied with `--fix`.
+const MAX_FIX_ROUNDS: usize = 10;
+
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
 #[derive(Parser, Debug)]
@@ -18,6 +26,12 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// Applies the suggested fixes of the diagnostics to the source files before compiling.
+    #[arg(long, default_value_t = false)]
+    fix: bool,
+    /// The format in which diagnostics are reported.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -26,9 +40,29 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
+    if args.fix {
+        // Fixing some diagnostics may reveal others (e.g. lowering diagnostics are only reported
+        // once the semantic diagnostics are fixed), so fixes are applied until none are left.
+        let mut n_applied = 0;
+        for _ in 0..MAX_FIX_ROUNDS {
+            let mut db = RootDatabase::builder().detect_corelib().build()?;
+            let main_crate_ids = setup_project(&mut db, &args.path)?;
+            let n_round_applied = apply_diagnostic_fixes(&db, &main_crate_ids)?;
+            if n_round_applied == 0 {
+                break;
+            }
+            n_applied += n_round_applied;
+        }
+        eprintln!("Applied {n_applied} edits.");
+    }
+
     let sierra_program = compile_cairo_project_at_path(
         &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+        CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::stderr()
+                .with_message_format(args.message_format),
+            replace_ids: args.replace_ids,
+        },
     )?;
 
     match args.output {
@@ -8,17 +8,20 @@ use cairo_lang_filesystem::db::{
     CORELIB_CRATE_NAME,
 };
 use cairo_lang_filesystem::detect::detect_corelib;
-use cairo_lang_filesystem::ids::CrateLongId;
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId};
 use cairo_lang_lowering::db::{init_lowering_group, LoweringDatabase, LoweringGroup};
 use cairo_lang_parser::db::ParserDatabase;
-use cairo_lang_plugins::get_default_plugins;
+use cairo_lang_plugins::{get_default_plugins, get_default_plugins_with_configs};
 use cairo_lang_project::ProjectConfig;
 use cairo_lang_semantic::corelib::get_core_ty_by_name;
 use cairo_lang_semantic::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
 use cairo_lang_semantic::plugin::SemanticPlugin;
 use cairo_lang_sierra_generator::db::SierraGenDatabase;
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
+use itertools::chain;
+use smol_str::SmolStr;
 
 use crate::project::update_crate_roots_from_project_config;
 
@@ -35,12 +38,18 @@ pub struct RootDatabase {
     storage: salsa::Storage<RootDatabase>,
 }
 impl salsa::Database for RootDatabase {}
+impl salsa::ParallelDatabase for RootDatabase {
+    fn snapshot(&self) -> salsa::Snapshot<RootDatabase> {
+        salsa::Snapshot::new(RootDatabase { storage: self.storage.snapshot() })
+    }
+}
 impl RootDatabase {
     pub fn new(plugins: Vec<Arc<dyn SemanticPlugin>>) -> Self {
         let mut res = Self { storage: Default::default() };
         init_files_group(&mut res);
         init_lowering_group(&mut res);
         res.set_semantic_plugins(plugins);
+        res.set_named_semantic_plugins(Arc::new(OrderedHashMap::default()));
         res
     }
 
@@ -63,6 +72,7 @@ impl Default for RootDatabase {
 #[derive(Clone, Debug, Default)]
 pub struct RootDatabaseBuilder {
     plugins: Option<Vec<Arc<dyn SemanticPlugin>>>,
+    named_plugins: Vec<(SmolStr, Arc<dyn SemanticPlugin>)>,
     detect_corelib: bool,
     project_config: Option<Box<ProjectConfig>>,
     implicit_precedence: Option<Vec<String>>,
@@ -78,6 +88,16 @@ impl RootDatabaseBuilder {
         self
     }
 
+    /// Makes a plugin available to the crates enabling it by name in their project settings.
+    pub fn with_named_plugin(
+        &mut self,
+        name: impl Into<SmolStr>,
+        plugin: Arc<dyn SemanticPlugin>,
+    ) -> &mut Self {
+        self.named_plugins.push((name.into(), plugin));
+        self
+    }
+
     pub fn detect_corelib(&mut self) -> &mut Self {
         self.detect_corelib = true;
         self
@@ -100,6 +120,9 @@ impl RootDatabaseBuilder {
 
         let mut db = RootDatabase::default();
 
+        // The named plugins are needed to load the crate settings of the project config.
+        db.set_named_semantic_plugins(Arc::new(self.named_plugins.iter().cloned().collect()));
+
         if self.detect_corelib {
             let path =
                 detect_corelib().ok_or_else(|| anyhow!("Failed to find development corelib."))?;
@@ -107,7 +130,7 @@ impl RootDatabaseBuilder {
         }
 
         if let Some(config) = self.project_config.clone() {
-            update_crate_roots_from_project_config(&mut db, *config.clone());
+            update_crate_roots_from_project_config(&mut db, *config.clone())?;
 
             if let Some(corelib) = config.corelib {
                 let core_crate = db.intern_crate(CrateLongId(CORELIB_CRATE_NAME.into()));
@@ -166,4 +189,17 @@ impl HasMacroPlugins for RootDatabase {
     fn macro_plugins(&self) -> Vec<Arc<dyn MacroPlugin>> {
         self.get_macro_plugins()
     }
+
+    /// Crates with settings use the default plugins, with their configs, followed by the named
+    /// plugins they enable. Other crates use the plugins of the database.
+    fn crate_macro_plugins(&self, crate_id: CrateId) -> Vec<Arc<dyn MacroPlugin>> {
+        let Some(settings) = self.crate_settings(crate_id) else { return self.macro_plugins(); };
+        let named_plugins = self.named_semantic_plugins();
+        chain!(
+            get_default_plugins_with_configs(settings.cfg_set),
+            settings.plugins.iter().filter_map(|name| named_plugins.get(name).cloned())
+        )
+        .map(|plugin| plugin.as_dyn_macro_plugin())
+        .collect()
+    }
 }
@@ -1,10 +1,18 @@
+use std::fs;
+
+use anyhow::Context;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{
+    apply_edits, DiagnosticEntry, DiagnosticFix, Diagnostics, JsonDiagnostic,
+};
 use cairo_lang_filesystem::db::FilesGroup;
-use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_filesystem::ids::{CrateId, FileLongId};
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use clap::ValueEnum;
 use thiserror::Error;
 
 use crate::db::RootDatabase;
@@ -29,15 +37,26 @@ impl<'a> DiagnosticCallback for Option<Box<dyn DiagnosticCallback + 'a>> {
     }
 }
 
+/// The format in which diagnostics are reported.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum MessageFormat {
+    /// Human-readable text, marking the location of each diagnostic in its source line.
+    #[default]
+    Human,
+    /// One JSON object per line for each diagnostic. See [JsonDiagnostic].
+    Json,
+}
+
 /// Collects compilation diagnostics and presents them in preconfigured way.
 pub struct DiagnosticsReporter<'a> {
     callback: Option<Box<dyn DiagnosticCallback + 'a>>,
+    message_format: MessageFormat,
 }
 
 impl DiagnosticsReporter<'static> {
     /// Create a reporter which does not print or collect diagnostics at all.
     pub fn ignoring() -> Self {
-        Self { callback: None }
+        Self { callback: None, message_format: MessageFormat::default() }
     }
 
     /// Create a reporter which prints all diagnostics to [`std::io::Stderr`].
@@ -77,59 +96,85 @@ impl<'a> DiagnosticsReporter<'a> {
 
     /// Create a reporter which calls [`DiagnosticCallback::on_diagnostic`].
     fn new(callback: impl DiagnosticCallback + 'a) -> Self {
-        Self { callback: Some(Box::new(callback)) }
+        Self { callback: Some(Box::new(callback)), message_format: MessageFormat::default() }
+    }
+
+    /// Sets the format in which the diagnostics are reported.
+    pub fn with_message_format(mut self, message_format: MessageFormat) -> Self {
+        self.message_format = message_format;
+        self
+    }
+
+    /// Reports a diagnostic that is not related to a location in the code.
+    fn report_message(&mut self, message: String) {
+        let diagnostic = match self.message_format {
+            MessageFormat::Human => message,
+            MessageFormat::Json => {
+                json_lines(&[JsonDiagnostic::message_only(message.trim_end().to_string())])
+            }
+        };
+        self.callback.on_diagnostic(diagnostic);
+    }
+
+    /// Reports a set of diagnostics, if it is not empty. Returns `true` if it has errors.
+    fn report<TEntry: DiagnosticEntry>(
+        &mut self,
+        db: &TEntry::DbType,
+        diagnostics: Diagnostics<TEntry>,
+    ) -> bool {
+        if diagnostics.get_all().is_empty() {
+            return false;
+        }
+        let diagnostic = match self.message_format {
+            MessageFormat::Human => diagnostics.format(db),
+            MessageFormat::Json => json_lines(&diagnostics.to_json(db)),
+        };
+        self.callback.on_diagnostic(diagnostic);
+        diagnostics.has_errors()
     }
 
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
-    /// Returns `true` if diagnostics were found.
+    /// Returns `true` if errors were found. Warnings are reported, but do not fail the check.
     pub fn check(&mut self, db: &mut RootDatabase) -> bool {
-        let mut found_diagnostics = false;
+        let files_db: &dyn FilesGroup = db;
+        let semantic_db: &dyn SemanticGroup = db;
+        let mut found_errors = false;
         for crate_id in db.crates() {
             let Ok(module_file) = db.module_main_file(ModuleId::CrateRoot(crate_id)) else {
-                found_diagnostics = true;
-                self.callback.on_diagnostic("Failed to get main module file".to_string());
+                found_errors = true;
+                self.report_message("Failed to get main module file".to_string());
                 continue;
             };
 
             if db.file_content(module_file).is_none() {
                 match db.lookup_intern_file(module_file) {
                     FileLongId::OnDisk(path) => {
-                        self.callback.on_diagnostic(format!("{} not found\n", path.display()))
+                        self.report_message(format!("{} not found\n", path.display()))
                     }
                     FileLongId::Virtual(_) => panic!("Missing virtual file."),
                 }
-                found_diagnostics = true;
+                found_errors = true;
             }
 
             for module_id in &*db.crate_modules(crate_id) {
                 for file_id in db.module_files(*module_id).unwrap_or_default() {
-                    let diag = db.file_syntax_diagnostics(file_id);
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_errors |= self.report(files_db, db.file_syntax_diagnostics(file_id));
                 }
 
                 if let Ok(diag) = db.module_semantic_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_errors |= self.report(semantic_db, diag);
                 }
 
                 if let Ok(diag) = db.module_lowering_diagnostics(*module_id) {
-                    if !diag.get_all().is_empty() {
-                        found_diagnostics = true;
-                        self.callback.on_diagnostic(diag.format(db));
-                    }
+                    found_errors |= self.report(semantic_db, diag);
                 }
             }
         }
-        found_diagnostics
+        found_errors
     }
 
     /// Checks if there are diagnostics and reports them to the provided callback as strings.
-    /// Returns `Err` if diagnostics were found.
+    /// Returns `Err` if errors were found.
     pub fn ensure(&mut self, db: &mut RootDatabase) -> Result<(), DiagnosticsError> {
         if self.check(db) { Err(DiagnosticsError) } else { Ok(()) }
     }
@@ -149,3 +194,76 @@ pub fn get_diagnostics_as_string(db: &mut RootDatabase) -> String {
     DiagnosticsReporter::write_to_string(&mut diagnostics).check(db);
     diagnostics
 }
+
+/// Serializes diagnostics as JSON, one object per line.
+fn json_lines(diagnostics: &[JsonDiagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let line = serde_json::to_string(diagnostic).expect("Failed to serialize diagnostic.");
+            format!("{line}\n")
+        })
+        .collect()
+}
+
+/// Returns the machine-applicable fixes of the diagnostics in the given crates, i.e. the fixes of
+/// the diagnostics that have exactly one suggested fix.
+pub fn get_diagnostic_fixes(db: &RootDatabase, crate_ids: &[CrateId]) -> Vec<DiagnosticFix> {
+    fn add_fixes<TEntry: DiagnosticEntry<DbType = dyn SemanticGroup>>(
+        db: &(dyn SemanticGroup + 'static),
+        diagnostics: Diagnostics<TEntry>,
+        fixes: &mut Vec<DiagnosticFix>,
+    ) {
+        for entry in diagnostics.get_all() {
+            if let [fix] = &entry.fixes(db)[..] {
+                fixes.push(fix.clone());
+            }
+        }
+    }
+
+    let semantic_db: &(dyn SemanticGroup + 'static) = db;
+    let mut fixes = vec![];
+    for crate_id in crate_ids {
+        for module_id in &*db.crate_modules(*crate_id) {
+            if let Ok(diag) = db.module_semantic_diagnostics(*module_id) {
+                add_fixes(semantic_db, diag, &mut fixes);
+            }
+            if let Ok(diag) = db.module_lowering_diagnostics(*module_id) {
+                add_fixes(semantic_db, diag, &mut fixes);
+            }
+        }
+    }
+    fixes
+}
+
+/// Applies the machine-applicable fixes of the diagnostics in the given crates to the files on
+/// disk. Fixes editing other files, such as plugin generated files, are skipped.
+/// Returns the number of applied edits.
+pub fn apply_diagnostic_fixes(db: &RootDatabase, crate_ids: &[CrateId]) -> anyhow::Result<usize> {
+    let mut file_edits = OrderedHashMap::default();
+    for fix in get_diagnostic_fixes(db, crate_ids) {
+        let on_disk = fix
+            .edits
+            .iter()
+            .all(|edit| matches!(db.lookup_intern_file(edit.file_id), FileLongId::OnDisk(_)));
+        if !on_disk {
+            continue;
+        }
+        for edit in fix.edits {
+            file_edits.entry(edit.file_id).or_insert_with(Vec::new).push(edit);
+        }
+    }
+
+    let mut n_applied = 0;
+    for (file_id, edits) in file_edits.iter() {
+        let FileLongId::OnDisk(path) = db.lookup_intern_file(*file_id) else {
+            unreachable!("Only edits of files on disk are collected.");
+        };
+        let content = db.file_content(*file_id).context("Failed to read file.")?;
+        let (new_content, n_file_applied) = apply_edits(&content, edits);
+        fs::write(&path, new_content)
+            .with_context(|| format!("Failed to write {}.", path.display()))?;
+        n_applied += n_file_applied;
+    }
+    Ok(n_applied)
+}
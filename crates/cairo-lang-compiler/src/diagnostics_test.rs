@@ -2,7 +2,7 @@ use cairo_lang_filesystem::db::{FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory};
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{get_diagnostics_as_string, DiagnosticsReporter, MessageFormat};
 
 #[test]
 fn test_diagnostics() {
@@ -13,3 +13,21 @@ fn test_diagnostics() {
 
     assert_eq!(get_diagnostics_as_string(&mut db), "no/such/path/lib.cairo not found\n");
 }
+
+#[test]
+fn test_json_diagnostics() {
+    let mut db = RootDatabase::default();
+
+    let crate_id = db.intern_crate(CrateLongId("bad_create".into()));
+    db.set_crate_root(crate_id, Some(Directory("no/such/path".into())));
+
+    let mut diagnostics = String::default();
+    DiagnosticsReporter::write_to_string(&mut diagnostics)
+        .with_message_format(MessageFormat::Json)
+        .check(&mut db);
+    assert_eq!(
+        diagnostics,
+        "{\"severity\":\"error\",\"code\":null,\"message\":\"no/such/path/lib.cairo not \
+         found\",\"file\":null,\"span\":null,\"notes\":[]}\n"
+    );
+}
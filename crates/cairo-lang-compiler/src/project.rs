@@ -1,11 +1,12 @@
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::sync::Arc;
 
 use cairo_lang_defs::ids::ModuleId;
-use cairo_lang_filesystem::db::FilesGroupEx;
+use cairo_lang_filesystem::db::{CrateSettings, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory};
 pub use cairo_lang_project::*;
+use cairo_lang_semantic::corelib::core_crate;
 use cairo_lang_semantic::db::SemanticGroup;
 
 #[derive(thiserror::Error, Debug)]
@@ -18,6 +19,13 @@ pub enum ProjectError {
     BadPath { path: String },
     #[error("Failed to load project config.")]
     LoadProjectError,
+    #[error("Unknown plugin `{plugin}` in the settings of crate `{crate_name}`.")]
+    UnknownPlugin { crate_name: String, plugin: String },
+    #[error(
+        "Unknown dependency `{dependency}` of crate `{crate_name}`: not a crate of the project, \
+         and has no path."
+    )]
+    UnknownDependency { crate_name: String, dependency: String },
 }
 
 /// Setup to 'db' to compile the file at the given path.
@@ -57,19 +65,84 @@ fn setup_single_file_project(
     }
 }
 
-/// Updates the crate roots from a ProjectConfig object.
-pub fn update_crate_roots_from_project_config(db: &mut dyn SemanticGroup, config: ProjectConfig) {
+/// Updates the crate roots and the crate settings from a ProjectConfig object.
+pub fn update_crate_roots_from_project_config(
+    db: &mut dyn SemanticGroup,
+    config: ProjectConfig,
+) -> Result<(), ProjectError> {
     for (crate_name, directory_path) in config.content.crate_roots {
         let crate_id = db.intern_crate(CrateLongId(crate_name));
-        let mut path = PathBuf::from(&directory_path);
-        if path.is_relative() {
-            path = PathBuf::from(&config.base_path).join(path);
+        db.set_crate_root(crate_id, Some(project_directory(&config.base_path, &directory_path)));
+    }
+
+    let named_plugins = db.named_semantic_plugins();
+    for (crate_name, crate_config) in config.content.crates {
+        if let Some(plugin) =
+            crate_config.plugins.iter().find(|plugin| !named_plugins.contains_key(*plugin))
+        {
+            return Err(ProjectError::UnknownPlugin {
+                crate_name: crate_name.to_string(),
+                plugin: plugin.to_string(),
+            });
         }
-        let root = Directory(path);
-        db.set_crate_root(crate_id, Some(root));
+        let dependencies = match crate_config.dependencies {
+            Some(dependencies_config) => {
+                let mut dependencies = vec![];
+                for (dependency_name, dependency_config) in dependencies_config {
+                    let dependency_id = db.intern_crate(CrateLongId(dependency_name.clone()));
+                    if let Some(path) = dependency_config.path {
+                        let root = project_directory(&config.base_path, &path);
+                        db.set_crate_root(dependency_id, Some(root));
+                    } else if db.crate_root_dir(dependency_id).is_none() {
+                        return Err(ProjectError::UnknownDependency {
+                            crate_name: crate_name.to_string(),
+                            dependency: dependency_name.to_string(),
+                        });
+                    }
+                    dependencies.push(dependency_id);
+                }
+                Some(dependencies)
+            }
+            None => None,
+        };
+        let crate_id = db.intern_crate(CrateLongId(crate_name));
+        let settings = CrateSettings {
+            edition: crate_config.edition,
+            cfg_set: crate_config.cfg.into_iter().collect(),
+            plugins: crate_config.plugins,
+            dependencies,
+        };
+        db.set_crate_settings(crate_id, Some(settings));
+    }
+    Ok(())
+}
+
+/// Returns the directory at `path`, relative to the directory of the project config file unless
+/// absolute.
+fn project_directory(base_path: &Path, path: &Path) -> Directory {
+    // Joining an absolute path replaces the base path.
+    Directory(base_path.join(path))
+}
+
+/// Enables a config for the `#[cfg(...)]` attributes of the given crates. For example, "test" when
+/// compiling the tests of the crates.
+pub fn enable_crates_cfg(db: &mut dyn SemanticGroup, crate_ids: &[CrateId], cfg: &str) {
+    for crate_id in crate_ids {
+        let mut settings = db.crate_settings(*crate_id).unwrap_or_default();
+        settings.cfg_set.insert(cfg.to_string());
+        db.set_crate_settings(*crate_id, Some(settings));
     }
 }
 
+/// Enables the "test" config for compiling the tests of the given crates. The "testing" config is
+/// enabled for the core crate, making its testing utilities, such as `starknet::testing`, available
+/// to the tests without compiling the tests of the core crate itself.
+pub fn enable_tests_cfg(db: &mut dyn SemanticGroup, crate_ids: &[CrateId]) {
+    enable_crates_cfg(db, crate_ids, "test");
+    let core_crate_id = core_crate(db);
+    enable_crates_cfg(db, &[core_crate_id], "testing");
+}
+
 /// Setup the 'db' to compile the project in the given path.
 /// The path can be either a directory with cairo project file or a .cairo file.
 /// Returns the ids of the project crates.
@@ -81,7 +154,7 @@ pub fn setup_project(
         match ProjectConfig::from_directory(path) {
             Ok(config) => {
                 let main_crate_ids = get_main_crate_ids_from_project(db, &config);
-                update_crate_roots_from_project_config(db, config);
+                update_crate_roots_from_project_config(db, config)?;
                 Ok(main_crate_ids)
             }
             _ => Err(ProjectError::LoadProjectError),
@@ -0,0 +1,5 @@
+use std::io;
+
+fn main() -> anyhow::Result<()> {
+    cairo_lang_debug_adapter::serve(io::stdin().lock(), io::stdout().lock())
+}
@@ -0,0 +1,241 @@
+//! Compiling and running the Cairo program or test debugged in a session.
+
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use cairo_felt::Felt;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::{enable_tests_cfg, setup_project};
+use cairo_lang_defs::ids::{
+    FreeFunctionId, FunctionWithBodyId, ModuleItemId, TopLevelLanguageElementId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_runner::debugger::{get_statements_source_locations, SourceLocation};
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_runner::typed_value::decode_value;
+use cairo_lang_runner::{RunResult, RunResultValue, SierraCasmRunner};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::ConcreteFunctionWithBodyId;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_starknet::db::StarknetRootDatabaseBuilderEx;
+use cairo_lang_syntax::node::ast::Expr;
+use cairo_lang_syntax::node::Token;
+use itertools::Itertools;
+use serde::Deserialize;
+
+/// The arguments of the `launch` request.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchArguments {
+    /// The Cairo file or project to compile.
+    pub program: String,
+    /// The path of the function to run, or a suffix of it. Defaults to `main`.
+    #[serde(default)]
+    pub function: Option<String>,
+    /// The path of the test to run instead of a function, or a suffix of it. The program is then
+    /// compiled as `cairo-test` compiles it.
+    #[serde(default)]
+    pub test: Option<String>,
+    /// The amount of gas provided to the run. For tests, defaults to their `available_gas`
+    /// attribute.
+    #[serde(default)]
+    pub available_gas: Option<usize>,
+    /// Whether to compile the program with the StarkNet plugin. Ignored for tests, which use the
+    /// plugins enabled in the project settings, as in `cairo-test`.
+    #[serde(default)]
+    pub starknet: bool,
+    /// Whether to stop at the first statement of the run, instead of at the first breakpoint.
+    #[serde(default)]
+    pub stop_on_entry: bool,
+}
+
+/// A completed run of the debugged function, to be replayed.
+pub struct Launch {
+    pub runner: SierraCasmRunner,
+    pub result: RunResult,
+    /// The source location of each Sierra statement of the program, if known.
+    pub locations: Vec<Option<SourceLocation>>,
+    /// A description of the result of the run, shown once the replay reaches its end.
+    pub summary: String,
+}
+
+/// Compiles the program and runs the requested function or test.
+pub fn launch(args: &LaunchArguments) -> anyhow::Result<Launch> {
+    let path = Path::new(&args.program)
+        .canonicalize()
+        .with_context(|| format!("`{}` not found.", args.program))?;
+    let mut builder = RootDatabase::builder();
+    builder.detect_corelib().with_named_starknet_plugin();
+    if args.starknet && args.test.is_none() {
+        builder.with_starknet();
+    }
+    let db = &mut builder.build()?;
+    let main_crate_ids = setup_project(db, &path)?;
+    if args.test.is_some() {
+        // Compiling as `cairo-test` does.
+        enable_tests_cfg(db, &main_crate_ids);
+    }
+    let mut diagnostics = String::new();
+    if DiagnosticsReporter::write_to_string(&mut diagnostics).check(db) {
+        bail!("Failed to compile `{}`:\n{diagnostics}", args.program);
+    }
+    match &args.test {
+        Some(test) => launch_test(db, main_crate_ids, test, args.available_gas),
+        None => launch_function(
+            db,
+            main_crate_ids,
+            args.function.as_deref().unwrap_or("main"),
+            args.available_gas,
+        ),
+    }
+}
+
+/// Runs a function of the program, as `cairo-run` does.
+fn launch_function(
+    db: &RootDatabase,
+    main_crate_ids: Vec<CrateId>,
+    path: &str,
+    available_gas: Option<usize>,
+) -> anyhow::Result<Launch> {
+    let function_id = find_function(db, &main_crate_ids, path)
+        .with_context(|| format!("Function `{path}` not found."))?;
+    let return_type = db
+        .free_function_signature(function_id)
+        .to_option()
+        .with_context(|| "Failed getting the function signature.")?
+        .return_type;
+    let sierra_program = db
+        .get_sierra_program(main_crate_ids.clone())
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let locations = db
+        .get_sierra_program_statement_locations(main_crate_ids)
+        .to_option()
+        .with_context(|| "Failed getting the source locations of the program.")?;
+    let runner = SierraCasmRunner::new(
+        replace_sierra_ids_in_program(db, &sierra_program),
+        available_gas.is_some(),
+    )
+    .with_context(|| "Failed setting up runner.")?;
+    let result = runner
+        .run_function(&function_id.full_path(db), &[], available_gas)
+        .with_context(|| "Failed to run the function.")?;
+    let summary = match &result.value {
+        RunResultValue::Success(values) => {
+            let value = decode_value(db, return_type, values, &result.memory)
+                .to_option()
+                .with_context(|| "Failed decoding the return value.")?;
+            format!("Run completed successfully, returning {value}")
+        }
+        RunResultValue::Panic(values) => {
+            format!("Run panicked with err values: [{}]", format_panic_values(values))
+        }
+    };
+    Ok(Launch {
+        locations: get_statements_source_locations(db, &locations),
+        runner,
+        result,
+        summary,
+    })
+}
+
+/// Runs a test of the program, as `cairo-test` does.
+fn launch_test(
+    db: &RootDatabase,
+    main_crate_ids: Vec<CrateId>,
+    path: &str,
+    available_gas: Option<usize>,
+) -> anyhow::Result<Launch> {
+    let function_id = find_function(db, &main_crate_ids, path)
+        .with_context(|| format!("Test `{path}` not found."))?;
+    let attrs = db
+        .function_with_body_attributes(FunctionWithBodyId::Free(function_id))
+        .to_option()
+        .with_context(|| "Failed getting the attributes of the test.")?;
+    if !attrs.iter().any(|attr| attr.id.as_str() == "test") {
+        bail!("`{path}` is not a test.");
+    }
+    let signature = db
+        .free_function_signature(function_id)
+        .to_option()
+        .with_context(|| "Failed getting the test signature.")?;
+    if !signature.params.is_empty() {
+        bail!("Debugging tests with parameters is not supported.");
+    }
+    let available_gas = available_gas.or_else(|| {
+        attrs.iter().find(|attr| attr.id.as_str() == "available_gas").and_then(|attr| {
+            let [Expr::Literal(literal)] = &attr.args[..] else { return None };
+            literal.token(db).text(db).parse::<usize>().ok()
+        })
+    });
+    let should_panic = attrs.iter().any(|attr| attr.id.as_str() == "should_panic");
+    let function = ConcreteFunctionWithBodyId::from_no_generics_free(db, function_id)
+        .with_context(|| "Generic tests are not supported.")?;
+    let sierra_program = db
+        .get_sierra_program_for_functions(vec![function])
+        .to_option()
+        .with_context(|| "Compilation failed without any diagnostics.")?;
+    let locations = db
+        .get_sierra_program_statement_locations_for_functions(vec![function])
+        .to_option()
+        .with_context(|| "Failed getting the source locations of the program.")?;
+    let runner = SierraCasmRunner::new(replace_sierra_ids_in_program(db, &sierra_program), true)
+        .with_context(|| "Failed setting up runner.")?;
+    let test_path = function_id.full_path(db);
+    let result = runner
+        .run_function(&test_path, &[], available_gas)
+        .with_context(|| format!("Failed to run the test `{test_path}`."))?;
+    let status = match (&result.value, should_panic) {
+        (RunResultValue::Success(_), false) | (RunResultValue::Panic(_), true) => "ok".into(),
+        (RunResultValue::Success(_), true) => {
+            "fail - expected panic but finished successfully.".into()
+        }
+        (RunResultValue::Panic(values), false) => {
+            format!("fail - panicked with [{}].", format_panic_values(values))
+        }
+    };
+    let summary = format!("test {test_path} ... {status}");
+    Ok(Launch {
+        locations: get_statements_source_locations(db, &locations),
+        runner,
+        result,
+        summary,
+    })
+}
+
+/// Finds a free function in the main crates by its path, or by a suffix of it.
+fn find_function(
+    db: &dyn SemanticGroup,
+    main_crates: &[CrateId],
+    path: &str,
+) -> Option<FreeFunctionId> {
+    let path_suffix = format!("::{path}");
+    main_crates
+        .iter()
+        .flat_map(|crate_id| db.crate_modules(*crate_id).iter().copied().collect::<Vec<_>>())
+        .flat_map(|module_id| {
+            db.module_items(module_id).ok().unwrap_or_default().iter().copied().collect::<Vec<_>>()
+        })
+        .find_map(|item| match item {
+            ModuleItemId::FreeFunction(function_id) => {
+                let function_path = function_id.full_path(db.upcast());
+                (function_path == path || function_path.ends_with(&path_suffix))
+                    .then_some(function_id)
+            }
+            _ => None,
+        })
+}
+
+/// Formats the values a run panicked with, showing the values which are short strings as such.
+fn format_panic_values(values: &[Felt]) -> String {
+    values
+        .iter()
+        .map(|value| match as_cairo_short_string(value) {
+            Some(as_string) => format!("{value} ('{as_string}')"),
+            None => value.to_string(),
+        })
+        .join(", ")
+}
@@ -0,0 +1,386 @@
+//! Cairo debug adapter.
+//!
+//! Implements the Debug Adapter Protocol over stdin/out. A launched program or test is run to
+//! completion first, and its run is then replayed by a [Debugger], which the client controls.
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use cairo_lang_runner::debugger::{Breakpoint, Debugger, StopReason};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+use launch::{launch, Launch, LaunchArguments};
+use protocol::{read_request, Connection, Request};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+pub mod launch;
+pub mod protocol;
+
+#[cfg(test)]
+mod test;
+
+/// The id of the only thread of a debugged run.
+const THREAD_ID: i64 = 1;
+
+/// The arguments of the `setBreakpoints` request.
+#[derive(Debug, Deserialize)]
+struct SetBreakpointsArguments {
+    source: Source,
+    #[serde(default)]
+    breakpoints: Vec<SourceBreakpoint>,
+}
+#[derive(Debug, Deserialize)]
+struct Source {
+    path: String,
+}
+#[derive(Debug, Deserialize)]
+struct SourceBreakpoint {
+    line: usize,
+}
+
+/// The arguments of the `scopes` request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScopesArguments {
+    frame_id: usize,
+}
+
+/// The arguments of the `variables` request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VariablesArguments {
+    variables_reference: usize,
+}
+
+/// Serves a debug session, reading requests from `reader` and writing responses and events to
+/// `writer`, until the client disconnects.
+pub fn serve(mut reader: impl BufRead, writer: impl Write) -> anyhow::Result<()> {
+    let mut connection = Connection::new(writer);
+    let mut client = ClientConfig::default();
+    // The lines of the breakpoints of each source, as set before the launch.
+    let mut breakpoints = OrderedHashMap::<String, Vec<usize>>::default();
+    while let Some(request) = read_request(&mut reader)? {
+        match request.command.as_str() {
+            "initialize" => {
+                client = request.parse_arguments().unwrap_or_default();
+                connection.respond(
+                    &request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsRestartRequest": true,
+                    }),
+                )?;
+            }
+            "setBreakpoints" => {
+                let args: SetBreakpointsArguments = match request.parse_arguments() {
+                    Ok(args) => args,
+                    Err(err) => {
+                        connection.respond_error(&request, &format!("{err:#}"))?;
+                        continue;
+                    }
+                };
+                let lines = args.breakpoints.iter().map(|bp| client.line_from(bp.line)).collect();
+                breakpoints.insert(args.source.path, lines);
+                // The breakpoints are verified once the program is compiled.
+                let body = args
+                    .breakpoints
+                    .iter()
+                    .map(|bp| json!({ "verified": false, "line": bp.line }))
+                    .collect::<Vec<_>>();
+                connection.respond(&request, json!({ "breakpoints": body }))?;
+            }
+            "launch" => {
+                let args: LaunchArguments = match request.parse_arguments() {
+                    Ok(args) => args,
+                    Err(err) => {
+                        connection.respond_error(&request, &format!("{err:#}"))?;
+                        continue;
+                    }
+                };
+                let launched = match launch(&args) {
+                    Ok(launched) => launched,
+                    Err(err) => {
+                        connection.respond_error(&request, &format!("{err:#}"))?;
+                        continue;
+                    }
+                };
+                connection.respond(&request, json!({}))?;
+                let mut session =
+                    Session::new(&mut connection, client, &launched, args.stop_on_entry);
+                for (path, lines) in breakpoints.iter() {
+                    session.set_breakpoints(path, lines);
+                }
+                session.connection.event("initialized", json!({}))?;
+                return session.serve(&mut reader);
+            }
+            "disconnect" | "terminate" => {
+                connection.respond(&request, json!({}))?;
+                return Ok(());
+            }
+            command => {
+                connection.respond_error(
+                    &request,
+                    &format!("Unexpected request `{command}` before launch."),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The conventions of the client for lines and columns, given as the arguments of the
+/// `initialize` request.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ClientConfig {
+    lines_start_at1: bool,
+    columns_start_at1: bool,
+}
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self { lines_start_at1: true, columns_start_at1: true }
+    }
+}
+impl ClientConfig {
+    /// Converts a line of the client to a line starting from 0.
+    fn line_from(&self, line: usize) -> usize {
+        if self.lines_start_at1 { line.saturating_sub(1) } else { line }
+    }
+
+    /// Converts a line starting from 0 to a line of the client.
+    fn line_to(&self, line: usize) -> usize {
+        if self.lines_start_at1 { line + 1 } else { line }
+    }
+
+    /// Converts a column starting from 0 to a column of the client.
+    fn column_to(&self, col: usize) -> usize {
+        if self.columns_start_at1 { col + 1 } else { col }
+    }
+}
+
+/// A debug session of a launched run.
+struct Session<'a, W: Write> {
+    connection: &'a mut Connection<W>,
+    client: ClientConfig,
+    debugger: Debugger<'a>,
+    summary: &'a str,
+    stop_on_entry: bool,
+    /// The ids of the breakpoints of each source.
+    breakpoints: OrderedHashMap<String, Vec<usize>>,
+}
+impl<'a, W: Write> Session<'a, W> {
+    fn new(
+        connection: &'a mut Connection<W>,
+        client: ClientConfig,
+        launched: &'a Launch,
+        stop_on_entry: bool,
+    ) -> Self {
+        Self {
+            connection,
+            client,
+            debugger: Debugger::new(&launched.runner, &launched.result, launched.locations.clone()),
+            summary: &launched.summary,
+            stop_on_entry,
+            breakpoints: OrderedHashMap::default(),
+        }
+    }
+
+    /// Serves the requests of the client until it disconnects.
+    fn serve(&mut self, reader: &mut impl BufRead) -> anyhow::Result<()> {
+        while let Some(request) = read_request(reader)? {
+            match self.handle(&request) {
+                Ok(true) => {}
+                Ok(false) => return Ok(()),
+                Err(err) => self.connection.respond_error(&request, &format!("{err:#}"))?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a request. Returns whether the session should go on.
+    fn handle(&mut self, request: &Request) -> anyhow::Result<bool> {
+        match request.command.as_str() {
+            "setBreakpoints" => {
+                let args: SetBreakpointsArguments = request.parse_arguments()?;
+                let lines =
+                    args.breakpoints.iter().map(|bp| self.client.line_from(bp.line)).collect_vec();
+                let body = self.set_breakpoints(&args.source.path, &lines);
+                self.connection.respond(request, json!({ "breakpoints": body }))?;
+            }
+            "configurationDone" => {
+                self.connection.respond(request, json!({}))?;
+                if self.stop_on_entry {
+                    self.report_stop("entry", None)?;
+                } else {
+                    self.start()?;
+                }
+            }
+            "threads" => {
+                self.connection.respond(
+                    request,
+                    json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] }),
+                )?;
+            }
+            "stackTrace" => {
+                let frames = self
+                    .debugger
+                    .stack_frames()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(id, frame)| {
+                        let mut body = json!({
+                            "id": id,
+                            "name": frame.function,
+                            "line": 0,
+                            "column": 0,
+                        });
+                        if let Some(location) = frame.location {
+                            let name = Path::new(&location.file_path)
+                                .file_name()
+                                .map_or(location.file_path.clone(), |name| {
+                                    name.to_string_lossy().to_string()
+                                });
+                            body["source"] = json!({ "name": name, "path": location.file_path });
+                            body["line"] = self.client.line_to(location.line).into();
+                            body["column"] = self.client.column_to(location.col).into();
+                        }
+                        body
+                    })
+                    .collect_vec();
+                let total_frames = frames.len();
+                self.connection.respond(
+                    request,
+                    json!({ "stackFrames": frames, "totalFrames": total_frames }),
+                )?;
+            }
+            "scopes" => {
+                let args: ScopesArguments = request.parse_arguments()?;
+                // Variables references must be positive, so frame `i` has the reference `i + 1`.
+                self.connection.respond(
+                    request,
+                    json!({ "scopes": [{
+                        "name": "Locals",
+                        "presentationHint": "locals",
+                        "variablesReference": args.frame_id + 1,
+                        "expensive": false,
+                    }] }),
+                )?;
+            }
+            "variables" => {
+                let args: VariablesArguments = request.parse_arguments()?;
+                let variables = self
+                    .debugger
+                    .variables(args.variables_reference.saturating_sub(1))
+                    .into_iter()
+                    .map(|variable| {
+                        json!({
+                            "name": variable.name,
+                            "value": variable.value,
+                            "type": variable.ty,
+                            "variablesReference": 0,
+                        })
+                    })
+                    .collect_vec();
+                self.connection.respond(request, json!({ "variables": variables }))?;
+            }
+            "continue" => {
+                self.connection.respond(request, json!({ "allThreadsContinued": true }))?;
+                let reason = self.debugger.continue_run();
+                self.report(reason)?;
+            }
+            "next" => {
+                self.connection.respond(request, json!({}))?;
+                let reason = self.debugger.step_over();
+                self.report(reason)?;
+            }
+            "stepIn" => {
+                self.connection.respond(request, json!({}))?;
+                let reason = self.debugger.step_into();
+                self.report(reason)?;
+            }
+            "stepOut" => {
+                self.connection.respond(request, json!({}))?;
+                let reason = self.debugger.step_out();
+                self.report(reason)?;
+            }
+            "restart" => {
+                self.connection.respond(request, json!({}))?;
+                self.debugger.restart();
+                if self.stop_on_entry {
+                    self.report_stop("entry", None)?;
+                } else {
+                    self.start()?;
+                }
+            }
+            "disconnect" | "terminate" => {
+                self.connection.respond(request, json!({}))?;
+                return Ok(false);
+            }
+            command => {
+                self.connection
+                    .respond_error(request, &format!("Unsupported request `{command}`."))?;
+            }
+        }
+        Ok(true)
+    }
+
+    /// Replaces the breakpoints of the source `path` with breakpoints on the given lines. Returns
+    /// the breakpoints, as reported to the client.
+    fn set_breakpoints(&mut self, path: &str, lines: &[usize]) -> Vec<Value> {
+        for id in self.breakpoints.swap_remove(path).unwrap_or_default() {
+            self.debugger.remove_breakpoint(id);
+        }
+        let mut ids = vec![];
+        let body = lines
+            .iter()
+            .map(|line| {
+                let breakpoint = Breakpoint { file: path.to_string(), line: *line };
+                let verified = self.debugger.has_code_at(&breakpoint);
+                let id = self.debugger.add_breakpoint(breakpoint);
+                ids.push(id);
+                json!({ "id": id, "verified": verified, "line": self.client.line_to(*line) })
+            })
+            .collect();
+        self.breakpoints.insert(path.to_string(), ids);
+        body
+    }
+
+    /// Starts the replay from its current position, which may be on a breakpoint.
+    fn start(&mut self) -> anyhow::Result<()> {
+        if self.debugger.is_finished() {
+            return self.report(StopReason::End);
+        }
+        match self.debugger.current_breakpoint() {
+            Some(id) => self.report(StopReason::Breakpoint(id)),
+            None => {
+                let reason = self.debugger.continue_run();
+                self.report(reason)
+            }
+        }
+    }
+
+    /// Reports to the client where the replay stopped.
+    fn report(&mut self, reason: StopReason) -> anyhow::Result<()> {
+        match reason {
+            StopReason::Step => self.report_stop("step", None),
+            StopReason::Breakpoint(id) => self.report_stop("breakpoint", Some(id)),
+            StopReason::End => {
+                self.connection.event(
+                    "output",
+                    json!({ "category": "console", "output": format!("{}\n", self.summary) }),
+                )?;
+                self.connection.event("terminated", json!({}))
+            }
+        }
+    }
+
+    fn report_stop(&mut self, reason: &str, breakpoint: Option<usize>) -> anyhow::Result<()> {
+        let mut body =
+            json!({ "reason": reason, "threadId": THREAD_ID, "allThreadsStopped": true });
+        if let Some(id) = breakpoint {
+            body["hitBreakpointIds"] = json!([id]);
+        }
+        self.connection.event("stopped", body)
+    }
+}
@@ -0,0 +1,100 @@
+//! The base protocol of the Debug Adapter Protocol: messages are JSON objects, each preceded by a
+//! `Content-Length` header.
+
+use std::io::{BufRead, Write};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// A request sent by the client.
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    /// The sequence number of the request.
+    pub seq: i64,
+    /// The command to execute.
+    pub command: String,
+    /// The arguments of the command.
+    #[serde(default)]
+    pub arguments: Value,
+}
+impl Request {
+    /// Returns the arguments of the request, parsed as `T`.
+    pub fn parse_arguments<T: for<'de> Deserialize<'de>>(&self) -> anyhow::Result<T> {
+        serde_json::from_value(self.arguments.clone())
+            .with_context(|| format!("Invalid arguments for `{}`.", self.command))
+    }
+}
+
+/// Reads the next request sent by the client. Returns `None` once the client closed the input.
+pub fn read_request(reader: &mut impl BufRead) -> anyhow::Result<Option<Request>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(value) = header.strip_prefix("Content-Length:") {
+            content_length =
+                Some(value.trim().parse::<usize>().context("Invalid content length.")?);
+        }
+    }
+    let Some(content_length) = content_length else {
+        bail!("Missing `Content-Length` header.");
+    };
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+    Ok(Some(serde_json::from_slice(&content).context("Invalid request.")?))
+}
+
+/// The sending side of the connection to the client.
+pub struct Connection<W: Write> {
+    writer: W,
+    /// The sequence number of the next message sent.
+    seq: i64,
+}
+impl<W: Write> Connection<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, seq: 1 }
+    }
+
+    /// Sends a successful response to `request`.
+    pub fn respond(&mut self, request: &Request, body: Value) -> anyhow::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "success": true,
+            "command": request.command,
+            "body": body,
+        }))
+    }
+
+    /// Sends a response to `request` reporting it failed.
+    pub fn respond_error(&mut self, request: &Request, message: &str) -> anyhow::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "success": false,
+            "command": request.command,
+            "message": message,
+        }))
+    }
+
+    /// Sends an event.
+    pub fn event(&mut self, event: &str, body: Value) -> anyhow::Result<()> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+    }
+
+    fn send(&mut self, mut message: Value) -> anyhow::Result<()> {
+        message["seq"] = self.seq.into();
+        self.seq += 1;
+        let content = message.to_string();
+        write!(self.writer, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use indoc::indoc;
+use itertools::Itertools;
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+use crate::serve;
+
+/// Returns the path of the example program.
+fn example_path() -> String {
+    let path: PathBuf =
+        [env!("CARGO_MANIFEST_DIR"), "test_data", "example.cairo"].into_iter().collect();
+    path.to_string_lossy().to_string()
+}
+
+/// Serves a session of the given requests, and returns the messages sent to the client, one per
+/// line, with the directory of the example program omitted.
+fn run_session(requests: &[(&str, Value)]) -> String {
+    let input = requests
+        .iter()
+        .enumerate()
+        .map(|(i, (command, arguments))| {
+            let content = json!({
+                "seq": i + 1,
+                "type": "request",
+                "command": command,
+                "arguments": arguments,
+            })
+            .to_string();
+            format!("Content-Length: {}\r\n\r\n{content}", content.len())
+        })
+        .join("");
+    let mut output = vec![];
+    serve(input.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let directory = format!("{}/test_data/", env!("CARGO_MANIFEST_DIR"));
+    output
+        .split("Content-Length: ")
+        .skip(1)
+        .map(|message| {
+            let (_, content) = message.split_once("\r\n\r\n").unwrap();
+            let message: Value = serde_json::from_str(content).unwrap();
+            let line = match message["type"].as_str().unwrap() {
+                "response" if message["success"] == json!(true) => {
+                    format!("{} -> {}", message["command"].as_str().unwrap(), message["body"])
+                }
+                "response" => {
+                    format!("{} !! {}", message["command"].as_str().unwrap(), message["message"])
+                }
+                _ => format!("event {}: {}", message["event"].as_str().unwrap(), message["body"]),
+            };
+            line.replace(&directory, "")
+        })
+        .join("\n")
+}
+
+#[test]
+fn test_debug_run() {
+    let path = example_path();
+    assert_eq!(
+        run_session(&[
+            ("initialize", json!({ "adapterID": "cairo" })),
+            ("launch", json!({ "program": path, "function": "missing" })),
+            ("launch", json!({ "program": path })),
+            (
+                "setBreakpoints",
+                json!({ "source": { "path": path }, "breakpoints": [
+                { "line": 9 },
+                { "line": 10 },
+                { "line": 6 },
+            ] })
+            ),
+            ("configurationDone", json!({})),
+            ("stackTrace", json!({ "threadId": 1 })),
+            ("scopes", json!({ "frameId": 1 })),
+            ("variables", json!({ "variablesReference": 2 })),
+            ("stepOut", json!({ "threadId": 1 })),
+            ("next", json!({ "threadId": 1 })),
+            ("stepIn", json!({ "threadId": 1 })),
+            ("stackTrace", json!({ "threadId": 1 })),
+            ("setBreakpoints", json!({ "source": { "path": path }, "breakpoints": [] })),
+            ("continue", json!({ "threadId": 1 })),
+            ("disconnect", json!({})),
+        ]),
+        indoc! {r#"
+            initialize -> {"supportsConfigurationDoneRequest":true,"supportsRestartRequest":true}
+            launch !! "Function `missing` not found."
+            launch -> {}
+            event initialized: {}
+            setBreakpoints -> {"breakpoints":[{"id":1,"line":9,"verified":false},{"id":2,"line":10,"verified":true},{"id":3,"line":6,"verified":false}]}
+            configurationDone -> {}
+            event stopped: {"allThreadsStopped":true,"hitBreakpointIds":[2],"reason":"breakpoint","threadId":1}
+            stackTrace -> {"stackFrames":[{"column":5,"id":0,"line":10,"name":"example::example::scale","source":{"name":"example.cairo","path":"example.cairo"}},{"column":9,"id":1,"line":14,"name":"example::example::main","source":{"name":"example.cairo","path":"example.cairo"}}],"totalFrames":2}
+            scopes -> {"scopes":[{"expensive":false,"name":"Locals","presentationHint":"locals","variablesReference":2}]}
+            variables -> {"variables":[{"name":"[5]","type":"example::example::Point","value":"Point(1, 2)","variablesReference":0},{"name":"[6]","type":"felt","value":"3","variablesReference":0}]}
+            stepOut -> {}
+            event stopped: {"allThreadsStopped":true,"reason":"step","threadId":1}
+            next -> {}
+            event stopped: {"allThreadsStopped":true,"hitBreakpointIds":[2],"reason":"breakpoint","threadId":1}
+            stepIn -> {}
+            event stopped: {"allThreadsStopped":true,"reason":"step","threadId":1}
+            stackTrace -> {"stackFrames":[{"column":5,"id":0,"line":16,"name":"example::example::main","source":{"name":"example.cairo","path":"example.cairo"}}],"totalFrames":1}
+            setBreakpoints -> {"breakpoints":[]}
+            continue -> {"allThreadsContinued":true}
+            event output: {"category":"console","output":"Run completed successfully, returning 90\n"}
+            event terminated: {}
+            disconnect -> {}"#}
+    );
+}
+
+#[test]
+fn test_debug_test() {
+    assert_eq!(
+        run_session(&[
+            ("initialize", json!({ "adapterID": "cairo" })),
+            (
+                "launch",
+                json!({ "program": example_path(), "test": "test_scale", "stopOnEntry": true })
+            ),
+            ("configurationDone", json!({})),
+            ("stackTrace", json!({ "threadId": 1 })),
+            ("next", json!({ "threadId": 1 })),
+            ("variables", json!({ "variablesReference": 1 })),
+            ("continue", json!({ "threadId": 1 })),
+            ("disconnect", json!({})),
+        ]),
+        indoc! {r#"
+            initialize -> {"supportsConfigurationDoneRequest":true,"supportsRestartRequest":true}
+            launch -> {}
+            event initialized: {}
+            configurationDone -> {}
+            event stopped: {"allThreadsStopped":true,"reason":"entry","threadId":1}
+            stackTrace -> {"stackFrames":[{"column":9,"id":0,"line":21,"name":"example::example::test_scale","source":{"name":"example.cairo","path":"example.cairo"}}],"totalFrames":1}
+            next -> {}
+            event stopped: {"allThreadsStopped":true,"reason":"step","threadId":1}
+            variables -> {"variables":[{"name":"[10]","type":"felt","value":"0","variablesReference":0}]}
+            continue -> {"allThreadsContinued":true}
+            event output: {"category":"console","output":"test example::example::test_scale ... ok\n"}
+            event terminated: {}
+            disconnect -> {}"#}
+    );
+}
@@ -136,6 +136,11 @@ pub trait DefsGroup:
 
 pub trait HasMacroPlugins {
     fn macro_plugins(&self) -> Vec<Arc<dyn MacroPlugin>>;
+    /// Returns the plugins of a crate. Defaults to [Self::macro_plugins], for databases whose
+    /// crates all use the same plugins.
+    fn crate_macro_plugins(&self, _crate_id: CrateId) -> Vec<Arc<dyn MacroPlugin>> {
+        self.macro_plugins()
+    }
 }
 
 fn module_main_file(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<FileId> {
@@ -279,6 +284,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
         }
     };
 
+    let plugins = db.crate_macro_plugins(module_id.owning_crate(db));
     let mut module_queue = VecDeque::new();
     module_queue.push_back((module_file, item_asts));
     let mut res = ModuleData::default();
@@ -295,7 +301,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
             // Iterate the plugins by their order. The first one to change something (either
             // generate new code, remove the original code, or both), breaks the loop. If more
             // plugins might have act on the item, they can do it on the generated code.
-            for plugin in db.macro_plugins() {
+            for plugin in &plugins {
                 let result = plugin.generate_code(db.upcast(), item_ast.clone());
                 for plugin_diag in result.diagnostics {
                     res.plugin_diagnostics.push((module_file_id, plugin_diag));
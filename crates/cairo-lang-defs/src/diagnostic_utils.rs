@@ -1,7 +1,8 @@
 use cairo_lang_diagnostics::DiagnosticLocation;
+use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
 
 use crate::db::DefsGroup;
 use crate::ids::ModuleFileId;
@@ -20,8 +21,8 @@ impl StableLocation {
         Self { module_file_id, stable_ptr: node.as_syntax_node().stable_ptr() }
     }
 
-    /// Returns the [DiagnosticLocation] that corresponds to the [StableLocation].
-    pub fn diagnostic_location(&self, db: &dyn DefsGroup) -> DiagnosticLocation {
+    /// Returns the file of the [StableLocation] and the syntax node it points to.
+    pub fn syntax_node(&self, db: &dyn DefsGroup) -> (FileId, SyntaxNode) {
         let file_id =
             db.module_file(self.module_file_id).expect("Module in diagnostic does not exist");
         let syntax_node = db
@@ -29,6 +30,12 @@ impl StableLocation {
             .expect("File for diagnostic not found")
             .as_syntax_node()
             .lookup_ptr(db.upcast(), self.stable_ptr);
+        (file_id, syntax_node)
+    }
+
+    /// Returns the [DiagnosticLocation] that corresponds to the [StableLocation].
+    pub fn diagnostic_location(&self, db: &dyn DefsGroup) -> DiagnosticLocation {
+        let (file_id, syntax_node) = self.syntax_node(db);
         DiagnosticLocation { file_id, span: syntax_node.span_without_trivia(db.upcast()) }
     }
 
@@ -2,14 +2,16 @@
 #[path = "diagnostics_test.rs"]
 mod test;
 
+use std::fmt;
 use std::sync::Arc;
 
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
 use cairo_lang_utils::Upcast;
 use itertools::Itertools;
 
+use crate::json::JsonDiagnostic;
 use crate::location_marks::get_location_marks;
 
 /// A trait for diagnostics (i.e., errors and warnings) across the compiler.
@@ -18,8 +20,38 @@ pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     type DbType: Upcast<dyn FilesGroup> + ?Sized;
     fn format(&self, db: &Self::DbType) -> String;
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation;
+    /// Returns the machine-applicable fixes for this diagnostic, if any.
+    fn fixes(&self, _db: &Self::DbType) -> Vec<DiagnosticFix> {
+        vec![]
+    }
+    /// Returns a code identifying the kind of this diagnostic, if any.
+    fn code(&self) -> Option<String> {
+        None
+    }
+    /// Returns the severity of this diagnostic. Only errors fail the compilation.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
     // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
 }
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
+}
+
 pub struct DiagnosticLocation {
     pub file_id: FileId,
     pub span: TextSpan,
@@ -31,6 +63,56 @@ impl DiagnosticLocation {
     }
 }
 
+/// A suggested fix for a diagnostic, consisting of edits to apply together.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DiagnosticFix {
+    /// A short description of the fix, e.g. "Add `ref`.".
+    pub description: String,
+    pub edits: Vec<DiagnosticEdit>,
+}
+
+/// A replacement of the text in a span of a file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DiagnosticEdit {
+    pub file_id: FileId,
+    pub span: TextSpan,
+    pub replacement: String,
+}
+impl DiagnosticEdit {
+    /// Creates an edit inserting `text` at `offset`.
+    pub fn insert(file_id: FileId, offset: TextOffset, text: String) -> Self {
+        Self { file_id, span: TextSpan { start: offset, end: offset }, replacement: text }
+    }
+}
+
+/// Applies edits of a single file to its content. Edits overlapping a previously accepted edit, or
+/// identical to it, are skipped. Returns the new content and the number of edits applied.
+pub fn apply_edits<'a>(
+    content: &str,
+    edits: impl IntoIterator<Item = &'a DiagnosticEdit>,
+) -> (String, usize) {
+    let mut accepted: Vec<&DiagnosticEdit> = vec![];
+    for edit in edits {
+        let overlaps = accepted.iter().any(|other| {
+            edit == *other || edit.span.start < other.span.end && other.span.start < edit.span.end
+        });
+        if !overlaps {
+            accepted.push(edit);
+        }
+    }
+    // Insertions at the same offset keep their order.
+    accepted.sort_by_key(|edit| (edit.span.start, edit.span.end));
+    let mut res = String::new();
+    let mut offset = TextOffset::default();
+    for edit in &accepted {
+        res += TextSpan { start: offset, end: edit.span.start }.take(content);
+        res += &edit.replacement;
+        offset = edit.span.end;
+    }
+    res += offset.take_from(content);
+    (res, accepted.len())
+}
+
 /// This struct is used to ensure that when an error occurs, a diagnostic is properly reported.
 ///
 /// It must not be constructed directly. Instead it is returned by [DiagnosticsBuilder::add]
@@ -94,6 +176,11 @@ impl<TEntry: DiagnosticEntry> DiagnosticsBuilder<TEntry> {
         self.count += diagnostics.len();
         self.subtrees.push(diagnostics);
     }
+    /// Returns whether any of the diagnostics added so far is an error.
+    pub fn has_errors(&self) -> bool {
+        self.leaves.iter().any(|entry| entry.severity() == Severity::Error)
+            || self.subtrees.iter().any(|subtree| subtree.has_errors())
+    }
     pub fn build(self) -> Diagnostics<TEntry> {
         Diagnostics(Arc::new(self))
     }
@@ -107,6 +194,7 @@ impl<TEntry: DiagnosticEntry> Default for DiagnosticsBuilder<TEntry> {
 
 pub fn format_diagnostics(
     db: &dyn FilesGroup,
+    severity: Severity,
     message: &str,
     location: DiagnosticLocation,
 ) -> String {
@@ -116,7 +204,7 @@ pub fn format_diagnostics(
         Some(pos) => format!("{}:{}", pos.line + 1, pos.col + 1),
         None => "?".into(),
     };
-    format!("error: {message}\n --> {file_name}:{pos}\n{marks}\n")
+    format!("{severity}: {message}\n --> {file_name}:{pos}\n{marks}\n")
 }
 
 /// A set of diagnostic entries that arose during a computation.
@@ -135,12 +223,17 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         self.0.count == 0
     }
 
+    /// Returns whether any of the diagnostics is an error, as opposed to warnings and notes.
+    pub fn has_errors(&self) -> bool {
+        self.0.has_errors()
+    }
+
     pub fn format(&self, db: &TEntry::DbType) -> String {
         let mut res = String::new();
         // Format leaves.
         for entry in &self.0.leaves {
             let message = entry.format(db);
-            res += &format_diagnostics(db.upcast(), &message, entry.location(db));
+            res += &format_diagnostics(db.upcast(), entry.severity(), &message, entry.location(db));
             res += "\n";
         }
         // Format subtrees.
@@ -148,6 +241,11 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
         res
     }
 
+    /// Returns the JSON representation of the diagnostics.
+    pub fn to_json(&self, db: &TEntry::DbType) -> Vec<JsonDiagnostic> {
+        self.get_all().iter().map(|entry| JsonDiagnostic::new(db, entry)).collect()
+    }
+
     /// Asserts that no diagnostic has occurred, panicking with an error message on failure.
     pub fn expect(&self, error_message: &str) {
         assert!(self.0.leaves.is_empty(), "{error_message}\n{self:?}");
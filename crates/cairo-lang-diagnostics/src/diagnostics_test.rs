@@ -7,7 +7,7 @@ use cairo_lang_filesystem::test_utils::FilesDatabaseForTesting;
 use indoc::indoc;
 use test_log::test;
 
-use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
+use super::{apply_edits, DiagnosticEdit, DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
 
 // Test diagnostic.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -21,6 +21,10 @@ impl DiagnosticEntry for SimpleDiag {
         "Simple diagnostic.".into()
     }
 
+    fn code(&self) -> Option<String> {
+        Some("SimpleDiag".into())
+    }
+
     fn location(&self, _db: &dyn cairo_lang_filesystem::db::FilesGroup) -> DiagnosticLocation {
         DiagnosticLocation {
             file_id: self.file_id,
@@ -61,3 +65,49 @@ fn test_diagnostics() {
         " }
     );
 }
+
+#[test]
+fn test_json_diagnostics() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(SimpleDiag { file_id });
+
+    let json = serde_json::to_value(diagnostics.build().to_json(&db_val)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "severity": "error",
+            "code": "SimpleDiag",
+            "message": "Simple diagnostic.",
+            "file": "dummy_file.sierra",
+            "span": {"start": {"line": 1, "column": 1}, "end": {"line": 2, "column": 2}},
+            "notes": [],
+        }])
+    );
+}
+
+#[test]
+fn test_apply_edits() {
+    let (_db_val, file_id) = setup();
+    let offset = |value| TextOffset::default().add_width(TextWidth::new_for_testing(value));
+    let edits = [
+        DiagnosticEdit {
+            file_id,
+            span: TextSpan { start: offset(5), end: offset(8) },
+            replacement: "xy".into(),
+        },
+        DiagnosticEdit::insert(file_id, offset(0), "1".into()),
+        DiagnosticEdit::insert(file_id, offset(0), "2".into()),
+        // Identical to a previous edit.
+        DiagnosticEdit::insert(file_id, offset(0), "1".into()),
+        // Overlaps a previous edit.
+        DiagnosticEdit {
+            file_id,
+            span: TextSpan { start: offset(6), end: offset(9) },
+            replacement: "z".into(),
+        },
+    ];
+
+    assert_eq!(apply_edits("abcd\nefg.\n", &edits), ("12abcd\nxy.\n".to_string(), 3));
+}
@@ -0,0 +1,121 @@
+//! A machine-readable representation of diagnostics, serialized as JSON objects.
+
+use std::fmt::Debug;
+
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_utils::Upcast;
+use serde::Serialize;
+
+use crate::{DiagnosticEntry, DiagnosticLocation, Severity};
+
+/// A diagnostic, as reported in JSON.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonDiagnostic {
+    /// "error", "warning" or "note".
+    pub severity: String,
+    /// Identifies the kind of the diagnostic, e.g. "UnknownFunction". None for diagnostics that
+    /// are not reported by a compilation phase, such as a missing crate root file.
+    pub code: Option<String>,
+    pub message: String,
+    /// The path of the file the diagnostic is in, or the name of the file for virtual files. None
+    /// if the diagnostic is not related to a file.
+    pub file: Option<String>,
+    pub span: Option<JsonSpan>,
+    pub notes: Vec<JsonNote>,
+}
+impl JsonDiagnostic {
+    /// Creates an error diagnostic that has no code, location or notes.
+    pub fn message_only(message: String) -> Self {
+        Self {
+            severity: Severity::Error.to_string(),
+            code: None,
+            message,
+            file: None,
+            span: None,
+            notes: vec![],
+        }
+    }
+
+    /// Creates the JSON representation of a diagnostic entry.
+    pub fn new<TEntry: DiagnosticEntry>(db: &TEntry::DbType, entry: &TEntry) -> Self {
+        let files_db: &dyn FilesGroup = db.upcast();
+        let location = entry.location(db);
+        let notes = entry
+            .fixes(db)
+            .into_iter()
+            .map(|fix| {
+                // A fix is located at its first edit.
+                let location = fix
+                    .edits
+                    .first()
+                    .map(|edit| DiagnosticLocation { file_id: edit.file_id, span: edit.span });
+                JsonNote {
+                    message: format!("help: {}", fix.description),
+                    file: location.as_ref().map(|location| file_path(files_db, location.file_id)),
+                    span: location.and_then(|location| JsonSpan::new(files_db, &location)),
+                }
+            })
+            .collect();
+        Self {
+            severity: entry.severity().to_string(),
+            code: entry.code(),
+            message: entry.format(db),
+            file: Some(file_path(files_db, location.file_id)),
+            span: JsonSpan::new(files_db, &location),
+            notes,
+        }
+    }
+}
+
+/// A note related to a diagnostic, such as a suggested fix.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonNote {
+    pub message: String,
+    pub file: Option<String>,
+    pub span: Option<JsonSpan>,
+}
+
+/// A span in a file, given by its start and end positions.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonSpan {
+    pub start: JsonPosition,
+    pub end: JsonPosition,
+}
+impl JsonSpan {
+    fn new(db: &dyn FilesGroup, location: &DiagnosticLocation) -> Option<Self> {
+        Some(Self {
+            start: JsonPosition::new(db, location, location.span.start)?,
+            end: JsonPosition::new(db, location, location.span.end)?,
+        })
+    }
+}
+
+/// A position in a file. Both the line and the column are 1-based, as in the human-readable
+/// diagnostics, and the column counts characters.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct JsonPosition {
+    pub line: usize,
+    pub column: usize,
+}
+impl JsonPosition {
+    fn new(db: &dyn FilesGroup, location: &DiagnosticLocation, offset: TextOffset) -> Option<Self> {
+        let position = offset.position_in_file(db, location.file_id)?;
+        Some(Self { line: position.line + 1, column: position.col + 1 })
+    }
+}
+
+/// Returns the path of a file on disk, or the name of a virtual file.
+fn file_path(db: &dyn FilesGroup, file_id: FileId) -> String {
+    match db.lookup_intern_file(file_id) {
+        FileLongId::OnDisk(path) => path.display().to_string(),
+        FileLongId::Virtual(_) => file_id.file_name(db),
+    }
+}
+
+/// Returns the name of the enum variant `value` is, for diagnostic kinds deriving [Debug]. Used as
+/// the code of diagnostics.
+pub fn variant_name(value: &impl Debug) -> String {
+    format!("{value:?}").chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect()
+}
@@ -2,9 +2,12 @@
 //! source files.
 
 mod diagnostics;
+mod json;
 mod location_marks;
 
 pub use self::diagnostics::{
-    format_diagnostics, skip_diagnostic, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation,
-    Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe, ToOption,
+    apply_edits, format_diagnostics, skip_diagnostic, DiagnosticAdded, DiagnosticEdit,
+    DiagnosticEntry, DiagnosticFix, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, Maybe,
+    Severity, ToMaybe, ToOption,
 };
+pub use self::json::{variant_name, JsonDiagnostic, JsonNote, JsonPosition, JsonSpan};
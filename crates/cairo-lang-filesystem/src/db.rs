@@ -2,18 +2,42 @@
 #[path = "db_test.rs"]
 mod test;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use cairo_lang_utils::Upcast;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
 
 use crate::ids::{CrateId, CrateLongId, Directory, FileId, FileLongId};
 use crate::span::{FileSummary, TextOffset, TextWidth};
 
 pub const CORELIB_CRATE_NAME: &str = "core";
 
+/// The edition of a crate, determining the version of the language it is written in.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Edition {
+    #[default]
+    #[serde(rename = "2023_01")]
+    V2023_01,
+}
+
+/// The settings of a crate, set by its project configuration.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CrateSettings {
+    /// The edition of the crate.
+    pub edition: Edition,
+    /// The configs enabled for the `#[cfg(...)]` attributes of the crate, e.g. "test".
+    pub cfg_set: HashSet<String>,
+    /// The names of the plugins enabled for the crate, in addition to the default plugins.
+    pub plugins: Vec<SmolStr>,
+    /// The crates the crate can refer to by name, in addition to itself and the core crate. None
+    /// if it can refer to all the crates.
+    pub dependencies: Option<Vec<CrateId>>,
+}
+
 // Salsa database interface.
 #[salsa::query_group(FilesDatabase)]
 pub trait FilesGroup {
@@ -25,6 +49,9 @@ pub trait FilesGroup {
     /// Main input of the project. Lists all the crates.
     #[salsa::input]
     fn crate_roots(&self) -> Arc<HashMap<CrateId, Directory>>;
+    /// The settings of the crates which have them. Crates without settings use the defaults.
+    #[salsa::input]
+    fn crates_settings(&self) -> Arc<HashMap<CrateId, CrateSettings>>;
     /// Overrides for file content. Mostly used by language server and tests.
     /// TODO(spapini): Currently, when this input changes, all the file_content() queries will
     /// be invalidated.
@@ -37,6 +64,8 @@ pub trait FilesGroup {
     fn crates(&self) -> Vec<CrateId>;
     /// Root directory of the crate.
     fn crate_root_dir(&self, crate_id: CrateId) -> Option<Directory>;
+    /// Settings of the crate, if it has any.
+    fn crate_settings(&self, crate_id: CrateId) -> Option<CrateSettings>;
     /// Query for raw file contents. Private.
     fn priv_raw_file_content(&self, file_id: FileId) -> Option<Arc<String>>;
     /// Query for the file contents. This takes overrides into consideration.
@@ -48,6 +77,7 @@ pub fn init_files_group(db: &mut (dyn FilesGroup + 'static)) {
     // Initialize inputs.
     db.set_file_overrides(Arc::new(HashMap::new()));
     db.set_crate_roots(Arc::new(HashMap::new()));
+    db.set_crates_settings(Arc::new(HashMap::new()));
 }
 
 pub fn init_dev_corelib(db: &mut (dyn FilesGroup + 'static), path: PathBuf) {
@@ -81,6 +111,15 @@ pub trait FilesGroupEx: Upcast<dyn FilesGroup> + AsFilesGroupMut {
         };
         self.as_files_group_mut().set_crate_roots(Arc::new(crate_roots));
     }
+    /// Sets the settings of the crate. None value removes them.
+    fn set_crate_settings(&mut self, crt: CrateId, settings: Option<CrateSettings>) {
+        let mut crates_settings = Upcast::upcast(self).crates_settings().as_ref().clone();
+        match settings {
+            Some(settings) => crates_settings.insert(crt, settings),
+            None => crates_settings.remove(&crt),
+        };
+        self.as_files_group_mut().set_crates_settings(Arc::new(crates_settings));
+    }
 }
 impl<T: Upcast<dyn FilesGroup> + AsFilesGroupMut + ?Sized> FilesGroupEx for T {}
 
@@ -95,6 +134,9 @@ fn crates(db: &dyn FilesGroup) -> Vec<CrateId> {
 fn crate_root_dir(db: &dyn FilesGroup, crt: CrateId) -> Option<Directory> {
     db.crate_roots().get(&crt).cloned()
 }
+fn crate_settings(db: &dyn FilesGroup, crt: CrateId) -> Option<CrateSettings> {
+    db.crates_settings().get(&crt).cloned()
+}
 
 fn priv_raw_file_content(db: &dyn FilesGroup, file: FileId) -> Option<Arc<String>> {
     match db.lookup_intern_file(file) {
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use test_log::test;
 
 use super::FilesGroup;
-use crate::db::FilesGroupEx;
+use crate::db::{CrateSettings, FilesGroupEx};
 use crate::ids::{CrateLongId, Directory};
 use crate::test_utils::FilesDatabaseForTesting;
 
@@ -23,3 +23,19 @@ fn test_filesystem() {
 
     assert_eq!(*db.file_content(file_id).unwrap(), "content\n");
 }
+
+#[test]
+fn test_crate_settings() {
+    let mut db = FilesDatabaseForTesting::default();
+
+    let crt = db.intern_crate(CrateLongId("my_crate".into()));
+    let crt2 = db.intern_crate(CrateLongId("my_crate2".into()));
+    let settings = CrateSettings { dependencies: Some(vec![crt2]), ..CrateSettings::default() };
+    db.set_crate_settings(crt, Some(settings.clone()));
+
+    assert_eq!(db.crate_settings(crt), Some(settings));
+    assert_eq!(db.crate_settings(crt2), None);
+
+    db.set_crate_settings(crt, None);
+    assert_eq!(db.crate_settings(crt), None);
+}
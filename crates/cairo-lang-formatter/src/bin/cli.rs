@@ -7,7 +7,7 @@ use std::sync::Arc;
 use anyhow::{bail, Result};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{FileId, FileLongId, VirtualFile};
-use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
+use cairo_lang_formatter::{get_formatted_file, get_verified_formatted_file, FormatterConfig};
 use cairo_lang_parser::utils::{get_syntax_root_and_diagnostics, SimpleParserDatabase};
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
@@ -106,7 +106,24 @@ fn format_input(
         bail!("Unable to parse input");
     }
 
-    let formatted_text = get_formatted_file(&db, &syntax_root, config.clone());
+    let formatted_text = if args.verify {
+        match get_verified_formatted_file(&db, &syntax_root, config.clone()) {
+            Ok(formatted_text) => formatted_text,
+            Err(err) => {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "Failed to verify the formatting of {input}. {err} The content was not \
+                         formatted."
+                    )
+                    .red()
+                );
+                bail!("Unable to verify formatting");
+            }
+        }
+    } else {
+        get_formatted_file(&db, &syntax_root, config.clone())
+    };
 
     if &formatted_text == original_text.as_ref() {
         // Always print if input is stdin, unless --check is used
@@ -136,7 +153,7 @@ fn format_directory(
     path: &str,
     args: &FormatterArgs,
     recursion_depth: usize,
-    config: &FormatterConfig,
+    config: Option<&FormatterConfig>,
 ) -> bool {
     if !args.recursive && recursion_depth > 0 {
         return true;
@@ -165,7 +182,7 @@ fn format_path(
     path: &str,
     args: &FormatterArgs,
     recursion_depth: usize,
-    config: &FormatterConfig,
+    config: Option<&FormatterConfig>,
 ) -> bool {
     match fs::metadata(path) {
         // File exists
@@ -179,8 +196,11 @@ fn format_path(
                     true
                 } else {
                     eprintln_if_verbose(&format!("Formatting file: {path}."), args.verbose);
+                    let Some(config) = get_config(Path::new(path), config) else {
+                        return false;
+                    };
                     matches!(
-                        (format_input(&Input::File { path }, config, args), args.check),
+                        (format_input(&Input::File { path }, &config, args), args.check),
                         (Ok(FormatResult::Identical), _) | (Ok(FormatResult::DiffFound), false)
                     )
                 }
@@ -200,6 +220,21 @@ fn format_path(
     }
 }
 
+/// Returns the given configuration, or the configuration found for `path` if none was given.
+/// Returns None if the found configuration is invalid.
+fn get_config(path: &Path, config: Option<&FormatterConfig>) -> Option<FormatterConfig> {
+    if let Some(config) = config {
+        return Some(config.clone());
+    }
+    match FormatterConfig::discover(path) {
+        Ok(config) => Some(config),
+        Err(err) => {
+            eprintln!("{}", format!("{err:?}").red());
+            None
+        }
+    }
+}
+
 /// Checks if the file extension is "cairo".
 /// Should only be called with a file path.
 fn is_cairo_file(file_path: &str) -> bool {
@@ -245,6 +280,15 @@ struct FormatterArgs {
     /// Print parsing errors.
     #[arg(short, long, default_value_t = false)]
     print_parsing_errors: bool,
+    /// Verify that formatting is idempotent and keeps the comments in place, leaving the inputs
+    /// for which it doesn't unchanged.
+    #[arg(long, default_value_t = false)]
+    verify: bool,
+    /// The formatter configuration file: either a cairofmt.toml file, or a cairo_project.toml
+    /// file with a [tool.fmt] section. By default, the configuration is searched for in the
+    /// directories containing each formatted file.
+    #[arg(long)]
+    config: Option<PathBuf>,
     /// A list of files and directories to format. Use "-" for stdin.
     files: Vec<String>,
 }
@@ -254,7 +298,16 @@ fn main() -> ExitCode {
     log::info!("Starting formatting.");
 
     let args = FormatterArgs::parse();
-    let config = FormatterConfig::default();
+    let config = match &args.config {
+        Some(config_path) => match FormatterConfig::from_file(config_path) {
+            Ok(config) => Some(config),
+            Err(err) => {
+                eprintln!("{}", format!("{err:?}").red());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
     eprintln_if_verbose(
         &format!("Start formatting. Check: {}, Recursive: {}.", args.check, args.recursive),
         args.verbose,
@@ -262,6 +315,9 @@ fn main() -> ExitCode {
 
     if args.files.len() == 1 && args.files[0] == "-" {
         // Input comes from stdin
+        let Some(config) = get_config(Path::new("."), config.as_ref()) else {
+            return ExitCode::FAILURE;
+        };
         match (format_input(&Input::Stdin, &config, &args), args.check) {
             (Ok(FormatResult::Identical), _) => ExitCode::SUCCESS,
             (Ok(FormatResult::DiffFound), false) => ExitCode::SUCCESS,
@@ -270,10 +326,10 @@ fn main() -> ExitCode {
     } else {
         let mut all_correct = true;
         if args.files.is_empty() {
-            all_correct = format_path(".", &args, 0, &config);
+            all_correct = format_path(".", &args, 0, config.as_ref());
         } else {
             for file in args.files.iter() {
-                all_correct &= format_path(file, &args, 0, &config);
+                all_correct &= format_path(file, &args, 0, config.as_ref());
             }
         }
         if !all_correct && args.check { ExitCode::FAILURE } else { ExitCode::SUCCESS }
@@ -8,7 +8,7 @@ use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
 use itertools::Itertools;
 use syntax::node::kind::SyntaxKind;
 
-use crate::FormatterConfig;
+use crate::{FormatterConfig, TrailingComma};
 
 #[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// Defines the break point behaviour.
@@ -129,6 +129,9 @@ enum LineComponent {
     /// A component representing a comment in the code. Leading (not trailing) comments are
     /// disregarded when computing line width as it belongs to another line.
     Comment { content: String, is_trailing: bool },
+    /// A trailing comma of a list, printed only if the list ends up broken into several lines,
+    /// i.e. if it is the last component of a line.
+    OptionalTrailingComma,
 }
 impl LineComponent {
     pub fn width(&self) -> usize {
@@ -146,6 +149,7 @@ impl LineComponent {
                     0
                 }
             }
+            Self::OptionalTrailingComma => 0,
         }
     }
 }
@@ -160,6 +164,7 @@ impl fmt::Display for LineComponent {
                 write!(f, "{}", if properties.space_if_not_broken { " " } else { "" })
             }
             Self::Comment { content, .. } => write!(f, "{content}"),
+            Self::OptionalTrailingComma => write!(f, ""),
         }
     }
 }
@@ -325,7 +330,9 @@ impl LineBuilder {
             } else {
                 // All break line points were already broken or removed.
                 // TODO(Gil): Propagate error to user if line is still too long.
-                return vec![self.to_string()];
+                let mut line = self.clone();
+                line.print_line_end_trailing_comma();
+                return vec![line.to_string()];
             }
         }
         // Keep breaking recursively the new lines.
@@ -346,7 +353,10 @@ impl LineBuilder {
         };
         let mut breaking_positions =
             self.get_break_point_indices_by_precedence(break_line_point_properties.precedence);
-        if self.width() <= max_line_width && break_line_point_properties.is_optional {
+        if self.width() <= max_line_width
+            && break_line_point_properties.is_optional
+            && !self.contains_code_after_trailing_comment(&mut false)
+        {
             return vec![self.remove_all_optional_break_line_points()];
         }
         let base_indent = self.get_leading_indent();
@@ -413,7 +423,13 @@ impl LineBuilder {
     /// less than max_line_width (if possible).
     /// Each line is prepended by the leading
     pub fn build(&self, max_line_width: usize, tab_size: usize) -> String {
-        self.break_line_tree(max_line_width, tab_size).iter().join("\n") + "\n"
+        let mut lines = self.break_line_tree(max_line_width, tab_size);
+        // The break line point following a comment at the end of the file leaves an empty last
+        // line, which should not be printed.
+        while matches!(lines.last(), Some(line) if line.is_empty()) {
+            lines.pop();
+        }
+        lines.iter().join("\n") + "\n"
     }
     /// Returns the highest protected zone precedence (minimum number) from within all the protected
     /// zones which are direct children of this builder, or None if there are no protected zones
@@ -469,6 +485,46 @@ impl LineBuilder {
         }
         leading_indent
     }
+    /// Replaces an optional trailing comma at the end of the line, ignoring trailing comments, by
+    /// a comma. Returns whether the end of the line was found, that is, whether the builder
+    /// contains anything other than spaces, break line points and trailing comments.
+    fn print_line_end_trailing_comma(&mut self) -> bool {
+        for child in self.children.iter_mut().rev() {
+            match child {
+                LineComponent::Space
+                | LineComponent::BreakLinePoint(_)
+                | LineComponent::Comment { is_trailing: true, .. } => {}
+                LineComponent::OptionalTrailingComma => {
+                    *child = LineComponent::Token(",".to_string());
+                    return true;
+                }
+                LineComponent::ProtectedZone { builder, .. } => {
+                    if builder.print_line_end_trailing_comma() {
+                        return true;
+                    }
+                }
+                _ => return true,
+            }
+        }
+        false
+    }
+    /// Returns whether the line, including its protected zones, contains code after a trailing
+    /// comment, in which case it is broken after the comment, and should be broken at its optional
+    /// break line points as well. `after_trailing_comment` indicates whether a trailing comment
+    /// was found, and is updated.
+    fn contains_code_after_trailing_comment(&self, after_trailing_comment: &mut bool) -> bool {
+        self.children.iter().any(|child| match child {
+            LineComponent::Comment { is_trailing: true, .. } => {
+                *after_trailing_comment = true;
+                false
+            }
+            LineComponent::Token(_) => *after_trailing_comment,
+            LineComponent::ProtectedZone { builder, .. } => {
+                builder.contains_code_after_trailing_comment(after_trailing_comment)
+            }
+            _ => false,
+        })
+    }
     /// Returns whether the line contains a break point.
     fn contains_break_line_points(&self) -> bool {
         self.children.iter().any(|child| matches!(child, LineComponent::BreakLinePoint(_)))
@@ -479,13 +535,15 @@ impl LineBuilder {
             children: self
                 .children
                 .iter()
-                .map(|child| match child {
+                .filter_map(|child| match child {
+                    // A space replacing a break line point is removed if it ends up at the start
+                    // of a line, as other spaces.
                     LineComponent::BreakLinePoint(node_properties)
                         if node_properties.is_optional =>
                     {
-                        LineComponent::Token(child.to_string())
+                        node_properties.space_if_not_broken.then_some(LineComponent::Space)
                     }
-                    _ => child.clone(),
+                    _ => Some(child.clone()),
                 })
                 .collect_vec(),
             is_open: true,
@@ -592,15 +650,135 @@ impl<'a> Formatter<'a> {
         let allowed_empty_between = syntax_node.allowed_empty_between(self.db);
 
         let no_space_after = no_space_after || syntax_node.force_no_space_after(self.db);
-        let children = syntax_node.children(self.db);
+        let children = syntax_node.children(self.db).collect_vec();
         let n_children = children.len();
-        for (i, child) in children.enumerate() {
+        let is_item_list = syntax_node.kind(self.db) == SyntaxKind::ItemList;
+        let trailing_comma = if has_trailing_comma_policy(syntax_node.kind(self.db)) {
+            self.config.trailing_comma
+        } else {
+            TrailingComma::Preserve
+        };
+        let mut i = 0;
+        while i < n_children {
+            let child = &children[i];
             if child.width(self.db) == TextWidth::default() {
+                i += 1;
+                continue;
+            }
+            if is_item_list
+                && self.config.sort_imports
+                && child.kind(self.db) == SyntaxKind::ItemUse
+            {
+                let n_uses = children[i..]
+                    .iter()
+                    .take_while(|child| child.kind(self.db) == SyntaxKind::ItemUse)
+                    .count();
+                self.format_uses(
+                    &children[i..i + n_uses],
+                    allowed_empty_between,
+                    no_space_after && i + n_uses == n_children,
+                );
+                i += n_uses;
                 continue;
             }
-            self.format_node(&child, no_space_after && i == n_children - 1);
+            let is_last = i == n_children - 1;
+            if trailing_comma != TrailingComma::Preserve
+                && is_last
+                && child.kind(self.db) == SyntaxKind::TerminalComma
+            {
+                self.format_trailing_comma(child, trailing_comma);
+            } else {
+                self.format_node(child, no_space_after && is_last);
+                if trailing_comma != TrailingComma::Preserve
+                    && is_last
+                    && child.kind(self.db) != SyntaxKind::StructArgTail
+                    && !has_trailing_comment(self.db, child)
+                {
+                    self.push_trailing_comma(trailing_comma);
+                }
+            }
 
             self.empty_lines_allowance = allowed_empty_between;
+            i += 1;
+        }
+    }
+    /// Formats a run of consecutive `use` items, sorted by their paths. If imports are grouped, the
+    /// groups are the items with the same first path segment, and otherwise the runs of items not
+    /// separated by an empty line.
+    fn format_uses(
+        &mut self,
+        uses: &[SyntaxNode],
+        allowed_empty_between: usize,
+        no_space_after: bool,
+    ) {
+        let path_segments = |item: &SyntaxNode| {
+            let item = ast::ItemUse::from_syntax_node(self.db, item.clone());
+            item.name(self.db)
+                .elements(self.db)
+                .iter()
+                .map(|segment| segment.as_syntax_node().get_text_without_trivia(self.db))
+                .collect_vec()
+        };
+        // The groups, along with the number of empty lines before each.
+        let mut groups: Vec<(usize, Vec<&SyntaxNode>)> = vec![];
+        if self.config.group_imports {
+            let sorted_uses = uses.iter().sorted_by_cached_key(|item| path_segments(item));
+            for (i, (_, group)) in
+                sorted_uses.group_by(|item| path_segments(item).remove(0)).into_iter().enumerate()
+            {
+                let n_empty_lines = if i == 0 { leading_empty_lines(self.db, &uses[0]) } else { 1 };
+                groups.push((n_empty_lines, group.collect()));
+            }
+        } else {
+            for item in uses {
+                let n_empty_lines = leading_empty_lines(self.db, item);
+                match groups.last_mut() {
+                    Some((_, group)) if n_empty_lines == 0 => group.push(item),
+                    _ => groups.push((n_empty_lines, vec![item])),
+                }
+            }
+            for (_, group) in groups.iter_mut() {
+                group.sort_by_cached_key(|item| path_segments(item));
+            }
+        }
+
+        let n_uses = uses.len();
+        let mut n_formatted = 0;
+        for (i, (n_empty_lines, group)) in groups.into_iter().enumerate() {
+            // The empty lines in the leading trivia of the items are replaced by the empty lines
+            // between the groups.
+            let empty_lines_allowance =
+                if i == 0 { self.empty_lines_allowance } else { allowed_empty_between };
+            for _ in 0..n_empty_lines.min(empty_lines_allowance) {
+                self.line_state.line_buffer.push_empty_line_break_line_point();
+            }
+            for item in group {
+                self.empty_lines_allowance = 0;
+                n_formatted += 1;
+                self.format_node(item, no_space_after && n_formatted == n_uses);
+            }
+        }
+        self.empty_lines_allowance = allowed_empty_between;
+    }
+    /// Formats the trailing comma of a list according to `trailing_comma`, keeping its trivia.
+    fn format_trailing_comma(&mut self, comma: &SyntaxNode, trailing_comma: TrailingComma) {
+        let mut children = comma.children(self.db);
+        let leading_trivia = ast::Trivia::from_syntax_node(self.db, children.next().unwrap());
+        children.next();
+        let trailing_trivia = ast::Trivia::from_syntax_node(self.db, children.next().unwrap());
+
+        self.format_trivia(leading_trivia, true);
+        self.push_trailing_comma(trailing_comma);
+        self.format_trivia(trailing_trivia, false);
+    }
+    /// Appends a trailing comma to the end of a list according to `trailing_comma`.
+    fn push_trailing_comma(&mut self, trailing_comma: TrailingComma) {
+        match trailing_comma {
+            TrailingComma::Always => self.line_state.line_buffer.push_str(","),
+            TrailingComma::Vertical => {
+                self.line_state.line_buffer.push_child(LineComponent::OptionalTrailingComma)
+            }
+            TrailingComma::Preserve | TrailingComma::Never => {}
         }
     }
     /// Formats a terminal node and appends the formatted string to the result.
@@ -680,3 +858,55 @@ impl<'a> Formatter<'a> {
         }
     }
 }
+
+/// Returns whether the trailing comma policy applies to lists of the given kind. These are the
+/// comma separated lists which may be broken into several lines. Tuples are excluded, as a trailing
+/// comma is significant in a single element tuple.
+fn has_trailing_comma_policy(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::ArgList
+            | SyntaxKind::ParamList
+            | SyntaxKind::ImplicitsList
+            | SyntaxKind::StructArgList
+            | SyntaxKind::MemberList
+    )
+}
+
+/// Returns the first or the last terminal of a node.
+fn boundary_terminal(db: &dyn SyntaxGroup, node: &SyntaxNode, first: bool) -> Option<SyntaxNode> {
+    let mut node = node.clone();
+    while !node.kind(db).is_terminal() {
+        let mut children =
+            node.children(db).filter(|child| child.width(db) != TextWidth::default());
+        node = if first { children.next()? } else { children.last()? };
+    }
+    Some(node)
+}
+
+/// Returns whether a node ends with a comment.
+fn has_trailing_comment(db: &dyn SyntaxGroup, node: &SyntaxNode) -> bool {
+    let Some(terminal) = boundary_terminal(db, node, false) else {
+        return false;
+    };
+    let trailing_trivia = ast::Trivia::from_syntax_node(db, terminal.children(db).nth(2).unwrap());
+    trailing_trivia
+        .elements(db)
+        .into_iter()
+        .any(|trivium| matches!(trivium, ast::Trivium::SingleLineComment(_)))
+}
+
+/// Returns the number of empty lines before a node, that is, the newlines in its leading trivia
+/// before any comment.
+fn leading_empty_lines(db: &dyn SyntaxGroup, node: &SyntaxNode) -> usize {
+    let Some(terminal) = boundary_terminal(db, node, true) else {
+        return 0;
+    };
+    let leading_trivia = ast::Trivia::from_syntax_node(db, terminal.children(db).next().unwrap());
+    leading_trivia
+        .elements(db)
+        .into_iter()
+        .take_while(|trivium| !matches!(trivium, ast::Trivium::SingleLineComment(_)))
+        .filter(|trivium| matches!(trivium, ast::Trivium::Newline(_)))
+        .count()
+}
@@ -4,13 +4,19 @@
 pub mod formatter;
 pub mod node_properties;
 
+use std::fmt;
+use std::path::Path;
 use std::sync::Arc;
 
+use anyhow::Context;
 use cairo_lang_diagnostics::DiagnosticsBuilder;
 use cairo_lang_filesystem::ids::{FileLongId, VirtualFile};
 use cairo_lang_parser::parser::Parser;
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Token, TypedSyntaxNode};
+use diffy::{DiffOptions, Line};
+use serde::Deserialize;
 
 use crate::formatter::Formatter;
 
@@ -40,6 +46,13 @@ pub fn get_formatted_file(
 /// # Returns
 /// * `String` - The formatted code.
 pub fn format_string(db: &dyn SyntaxGroup, content: String) -> String {
+    let (syntax_root, _) = parse_string(db, content);
+    get_formatted_file(db, &syntax_root, FormatterConfig::default())
+}
+
+/// Parses Cairo code given as a string. Returns the syntax root, and whether the code parsed
+/// without errors.
+fn parse_string(db: &dyn SyntaxGroup, content: String) -> (SyntaxNode, bool) {
     let virtual_file = db.upcast().intern_file(FileLongId::Virtual(VirtualFile {
         parent: None,
         name: "string_to_format".into(),
@@ -48,27 +61,222 @@ pub fn format_string(db: &dyn SyntaxGroup, content: String) -> String {
     let mut diagnostics = DiagnosticsBuilder::new();
     let syntax_root =
         Parser::parse_file(db, &mut diagnostics, virtual_file, content.as_str()).as_syntax_node();
-    get_formatted_file(db, &syntax_root, FormatterConfig::default())
+    (syntax_root, diagnostics.build().get_all().is_empty())
+}
+
+/// The reasons for which a verified formatting may fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormattingError {
+    /// The formatted code has parsing errors.
+    InvalidOutput,
+    /// Formatting the formatted code changes it.
+    NotIdempotent,
+    /// A comment was lost or duplicated, or moved between its own line and the end of a line of
+    /// code.
+    CommentsChanged,
+}
+impl fmt::Display for FormattingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOutput => write!(f, "The formatted code has parsing errors."),
+            Self::NotIdempotent => write!(f, "Formatting the formatted code changes it."),
+            Self::CommentsChanged => write!(f, "Formatting changes the comments of the code."),
+        }
+    }
+}
+
+/// Returns the formatted syntax tree as a string, like [get_formatted_file], after verifying that
+/// the formatting is idempotent and preserves the comments of the code and their placement, either
+/// on their own line or at the end of a line of code.
+pub fn get_verified_formatted_file(
+    db: &dyn SyntaxGroup,
+    syntax_root: &SyntaxNode,
+    config: FormatterConfig,
+) -> Result<String, FormattingError> {
+    let formatted = get_formatted_file(db, syntax_root, config.clone());
+    let (formatted_root, is_valid) = parse_string(db, formatted.clone());
+    if !is_valid {
+        return Err(FormattingError::InvalidOutput);
+    }
+    if get_formatted_file(db, &formatted_root, config) != formatted {
+        return Err(FormattingError::NotIdempotent);
+    }
+    if get_comments(db, syntax_root) != get_comments(db, &formatted_root) {
+        return Err(FormattingError::CommentsChanged);
+    }
+    Ok(formatted)
+}
+
+/// Returns the comments of a syntax tree, sorted, along with whether each is at the end of a line
+/// of code, that is, in the trailing trivia of a token.
+fn get_comments(db: &dyn SyntaxGroup, syntax_root: &SyntaxNode) -> Vec<(String, bool)> {
+    let mut comments = vec![];
+    let mut nodes = vec![syntax_root.clone()];
+    while let Some(node) = nodes.pop() {
+        if node.kind(db) != SyntaxKind::Trivia {
+            nodes.extend(node.children(db));
+            continue;
+        }
+        let is_trailing = matches!(
+            node.parent().and_then(|terminal| terminal.children(db).last()),
+            Some(trailing_trivia) if trailing_trivia.stable_ptr() == node.stable_ptr()
+        );
+        for trivium in ast::Trivia::from_syntax_node(db, node).elements(db) {
+            if let ast::Trivium::SingleLineComment(comment) = trivium {
+                comments.push((comment.text(db).trim_end().to_string(), is_trailing));
+            }
+        }
+    }
+    comments.sort();
+    comments
+}
+
+/// A replacement of whole lines of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineEdit {
+    /// The first replaced line, zero-based.
+    pub start_line: usize,
+    /// The line following the last replaced line. Equal to `start_line` for an insertion.
+    pub end_line: usize,
+    /// The text replacing the lines.
+    pub new_text: String,
+}
+
+/// Returns the minimal line edits turning `original` into `formatted`, in order.
+pub fn get_line_edits(original: &str, formatted: &str) -> Vec<LineEdit> {
+    if original.is_empty() {
+        return vec![LineEdit { start_line: 0, end_line: 0, new_text: formatted.to_string() }];
+    }
+    let patch = DiffOptions::new().set_context_len(0).create_patch(original, formatted);
+    patch
+        .hunks()
+        .iter()
+        .map(|hunk| {
+            let old_range = hunk.old_range();
+            // Hunk ranges are one-based, except for empty ranges, which start at the line before
+            // which lines are inserted.
+            let start_line =
+                if old_range.is_empty() { old_range.start() } else { old_range.start() - 1 };
+            let new_text = hunk
+                .lines()
+                .iter()
+                .filter_map(|line| match line {
+                    Line::Insert(text) => Some(*text),
+                    Line::Context(_) | Line::Delete(_) => None,
+                })
+                .collect();
+            LineEdit { start_line, end_line: start_line + old_range.len(), new_text }
+        })
+        .collect()
+}
+
+/// The name of a dedicated formatter configuration file.
+pub const FORMATTER_CONFIG_FILE_NAME: &str = "cairofmt.toml";
+/// The name of a project file, whose `[tool.fmt]` section configures the formatter.
+const PROJECT_FILE_NAME: &str = "cairo_project.toml";
+
+/// Defines where trailing commas are placed in lists that may be broken into several lines, such
+/// as arguments, parameters and struct members.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrailingComma {
+    /// Trailing commas are kept as written.
+    #[default]
+    Preserve,
+    /// Lists always end with a trailing comma.
+    Always,
+    /// Lists never end with a trailing comma.
+    Never,
+    /// Lists end with a trailing comma only when broken into several lines.
+    Vertical,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FormatterConfig {
-    tab_size: usize,
-    max_line_length: usize,
+    /// The number of spaces of each indentation level.
+    pub tab_size: usize,
+    /// The maximal length of a line. Longer lines are broken if possible.
+    pub max_line_length: usize,
+    /// Whether to sort consecutive `use` items by their paths.
+    pub sort_imports: bool,
+    /// Whether to group sorted `use` items by the first segment of their paths, separating the
+    /// groups with an empty line. Otherwise, `use` items are sorted within each run of items not
+    /// separated by an empty line.
+    pub group_imports: bool,
+    /// The trailing commas policy.
+    pub trailing_comma: TrailingComma,
 }
 
-// Config params
-// TODO(Gil): export to file and load from file
 const TAB_SIZE: usize = 4;
 const MAX_LINE_LENGTH: usize = 100;
 
 impl FormatterConfig {
     pub fn new(tab_size: usize, max_line_length: usize) -> Self {
-        Self { tab_size, max_line_length }
+        Self { tab_size, max_line_length, ..Self::default() }
+    }
+
+    /// Loads the configuration from a file: either a dedicated formatter configuration file, or
+    /// the `[tool.fmt]` section of a project file. A project file without this section gives the
+    /// default configuration.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        Ok(Self::load(path)?.unwrap_or_default())
+    }
+
+    /// Finds the configuration of the code at `path`: the first dedicated formatter configuration
+    /// file or project file with a `[tool.fmt]` section found in the directories containing
+    /// `path`, starting from the innermost. Returns the default configuration if there is none.
+    pub fn discover(path: &Path) -> anyhow::Result<Self> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        for directory in path.ancestors().filter(|ancestor| ancestor.is_dir()) {
+            let config_file = directory.join(FORMATTER_CONFIG_FILE_NAME);
+            if config_file.is_file() {
+                return Self::from_file(&config_file);
+            }
+            let project_file = directory.join(PROJECT_FILE_NAME);
+            if project_file.is_file() {
+                if let Some(config) = Self::load(&project_file)? {
+                    return Ok(config);
+                }
+            }
+        }
+        Ok(Self::default())
+    }
+
+    /// Loads the configuration from a file. Returns None for a project file without a
+    /// `[tool.fmt]` section.
+    fn load(path: &Path) -> anyhow::Result<Option<Self>> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the formatter configuration at {path:?}."))?;
+        let error_context = || format!("Invalid formatter configuration at {path:?}.");
+        if path.file_name() == Some(PROJECT_FILE_NAME.as_ref()) {
+            let project: ProjectFileContent =
+                toml::from_str(&content).with_context(error_context)?;
+            Ok(project.tool.fmt)
+        } else {
+            Ok(Some(toml::from_str(&content).with_context(error_context)?))
+        }
     }
 }
 impl Default for FormatterConfig {
     fn default() -> Self {
-        Self::new(TAB_SIZE, MAX_LINE_LENGTH)
+        Self {
+            tab_size: TAB_SIZE,
+            max_line_length: MAX_LINE_LENGTH,
+            sort_imports: false,
+            group_imports: false,
+            trailing_comma: TrailingComma::default(),
+        }
     }
 }
+
+/// The parts of a project file relevant to the formatter.
+#[derive(Deserialize)]
+struct ProjectFileContent {
+    #[serde(default)]
+    tool: ProjectFileTools,
+}
+#[derive(Default, Deserialize)]
+struct ProjectFileTools {
+    fmt: Option<FormatterConfig>,
+}
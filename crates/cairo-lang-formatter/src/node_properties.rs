@@ -145,13 +145,15 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::ExprFunctionCall
                 | SyntaxKind::ExprIf
                 | SyntaxKind::ExprList
+                | SyntaxKind::ExprLoop
                 | SyntaxKind::ExprMatch
                 | SyntaxKind::ExprMissing
                 | SyntaxKind::ExprParenthesized
                 | SyntaxKind::ExprPath
                 | SyntaxKind::ExprStructCtorCall
                 | SyntaxKind::ExprTuple
-                | SyntaxKind::ExprUnary => Some(10),
+                | SyntaxKind::ExprUnary
+                | SyntaxKind::ExprWhile => Some(10),
                 _ => None,
             },
             Some(SyntaxKind::StatementLet) => match self.kind(db) {
@@ -162,13 +164,15 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::ExprFunctionCall
                 | SyntaxKind::ExprIf
                 | SyntaxKind::ExprList
+                | SyntaxKind::ExprLoop
                 | SyntaxKind::ExprMatch
                 | SyntaxKind::ExprMissing
                 | SyntaxKind::ExprParenthesized
                 | SyntaxKind::ExprPath
                 | SyntaxKind::ExprStructCtorCall
                 | SyntaxKind::ExprTuple
-                | SyntaxKind::ExprUnary => Some(1),
+                | SyntaxKind::ExprUnary
+                | SyntaxKind::ExprWhile => Some(1),
                 SyntaxKind::TerminalEq => Some(10),
                 SyntaxKind::PatternEnum | SyntaxKind::PatternTuple | SyntaxKind::PatternStruct => {
                     Some(11)
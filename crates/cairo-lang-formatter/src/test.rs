@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use cairo_lang_filesystem::db::{FilesDatabase, FilesGroup};
 use cairo_lang_parser::utils::{get_syntax_root_and_diagnostics_from_file, SimpleParserDatabase};
@@ -7,7 +8,7 @@ use cairo_lang_utils::Upcast;
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
-use crate::{get_formatted_file, FormatterConfig};
+use crate::{get_formatted_file, get_verified_formatted_file, FormatterConfig, TrailingComma};
 
 #[salsa::database(SyntaxDatabase, FilesDatabase)]
 #[derive(Default)]
@@ -27,7 +28,45 @@ impl Upcast<dyn FilesGroup> for DatabaseImpl {
     "test_data/cairo_files/linebreaking.cairo",
     "test_data/expected_results/linebreaking.cairo"
 )]
+#[test_case("test_data/cairo_files/loops.cairo", "test_data/expected_results/loops.cairo")]
 fn format_and_compare_file(unformatted_filename: &str, expected_filename: &str) {
+    check_formatted_file(unformatted_filename, expected_filename, FormatterConfig::default());
+}
+
+#[test_case(
+    "test_data/cairo_files/imports.cairo",
+    "test_data/expected_results/sorted_imports.cairo",
+    "test_data/configs/sort_imports.toml"
+)]
+#[test_case(
+    "test_data/cairo_files/imports.cairo",
+    "test_data/expected_results/grouped_imports.cairo",
+    "test_data/configs/group_imports.toml"
+)]
+#[test_case(
+    "test_data/cairo_files/trailing_comma.cairo",
+    "test_data/expected_results/trailing_comma_vertical.cairo",
+    "test_data/configs/trailing_comma_vertical.toml"
+)]
+#[test_case(
+    "test_data/cairo_files/trailing_comma.cairo",
+    "test_data/expected_results/trailing_comma_never.cairo",
+    "test_data/configs/trailing_comma_never.toml"
+)]
+fn format_with_config_and_compare_file(
+    unformatted_filename: &str,
+    expected_filename: &str,
+    config_filename: &str,
+) {
+    let config = FormatterConfig::from_file(Path::new(config_filename)).unwrap();
+    check_formatted_file(unformatted_filename, expected_filename, config);
+}
+
+fn check_formatted_file(
+    unformatted_filename: &str,
+    expected_filename: &str,
+    config: FormatterConfig,
+) {
     let db_val = SimpleParserDatabase::default();
     let db = &db_val;
 
@@ -37,9 +76,90 @@ fn format_and_compare_file(unformatted_filename: &str, expected_filename: &str)
         "There were parsing errors while trying to format the code:\n{}",
         diagnostics.format(db)
     ));
-    let config = FormatterConfig::default();
     let formatted_file = get_formatted_file(db, &syntax_root, config);
     let expected_file =
         fs::read_to_string(expected_filename).expect("Expected file does not exists.");
     assert_eq!(formatted_file, expected_file);
 }
+
+#[test]
+fn discover_config_in_project_file() {
+    let config =
+        FormatterConfig::discover(Path::new("test_data/configs/project/lib.cairo")).unwrap();
+    assert_eq!(
+        config,
+        FormatterConfig {
+            tab_size: 2,
+            max_line_length: 80,
+            trailing_comma: TrailingComma::Always,
+            ..FormatterConfig::default()
+        }
+    );
+}
+
+#[test]
+fn unknown_config_key_is_rejected() {
+    let err =
+        FormatterConfig::from_file(Path::new("test_data/configs/invalid_key.toml")).unwrap_err();
+    assert!(format!("{err:#}").contains("unknown field `max_line_lenght`"), "{err:#}");
+}
+
+/// Formats all the Cairo files of the core library and the examples, checking that the formatting
+/// is idempotent and preserves the comments.
+#[test_case(FormatterConfig::default(); "default")]
+#[test_case(
+    FormatterConfig {
+        tab_size: 2,
+        max_line_length: 60,
+        sort_imports: true,
+        group_imports: true,
+        trailing_comma: TrailingComma::Vertical,
+    };
+    "narrow"
+)]
+#[test_case(
+    FormatterConfig {
+        max_line_length: 40,
+        sort_imports: true,
+        trailing_comma: TrailingComma::Always,
+        ..FormatterConfig::default()
+    };
+    "very_narrow"
+)]
+#[test_case(
+    FormatterConfig { max_line_length: 120, trailing_comma: TrailingComma::Never, ..FormatterConfig::default() };
+    "wide"
+)]
+fn format_corelib_and_examples_round_trip(config: FormatterConfig) {
+    let db = SimpleParserDatabase::default();
+    let mut failures = vec![];
+    for path in
+        cairo_files(Path::new("../../corelib")).chain(cairo_files(Path::new("../../examples")))
+    {
+        let (syntax_root, diagnostics) =
+            get_syntax_root_and_diagnostics_from_file(&db, path.to_str().unwrap());
+        diagnostics.expect("There were parsing errors.");
+        if let Err(err) = get_verified_formatted_file(&db, &syntax_root, config.clone()) {
+            failures.push(format!("{}: {err}", path.display()));
+        }
+    }
+    assert!(failures.is_empty(), "Formatting failed for:\n{}", failures.join("\n"));
+}
+
+/// Returns the Cairo files in a directory and its subdirectories.
+fn cairo_files(directory: &Path) -> impl Iterator<Item = PathBuf> {
+    let mut files = vec![];
+    let mut directories = vec![directory.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                directories.push(path);
+            } else if path.extension() == Some("cairo".as_ref()) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files.into_iter()
+}
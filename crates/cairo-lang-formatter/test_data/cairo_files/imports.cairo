@@ -0,0 +1,13 @@
+// Leading comment.
+use starknet::get_caller_address;
+use array::ArrayTrait;
+
+use starknet::ContractAddress;
+use option::OptionTrait;
+use array::ArrayImpl;
+fn foo() {}
+
+mod inner {
+    use b::c;
+    use a::d;
+}
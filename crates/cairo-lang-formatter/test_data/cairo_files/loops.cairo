@@ -0,0 +1,12 @@
+fn foo(x: felt) -> felt {
+    let first_result = loop { if a_very_very_very_long_condition_name == another_very_very_long_name { break 1; } };
+    let second_result = loop { x = x + a_very_very_very_long_condition_name + another_very_very_long_name + yet_another_long_name; };
+    let (first_long_variable_name, second_long_variable_name): (felt, felt) = loop { break (1, 2); };
+    let (first_long_variable_name, second_long_variable_name, third_long_name): () = while x != 0 { x = x - 1; };
+    while x != 10 { x = x + 1; if x == 5 { continue; } }
+    loop { x = x + 1; if x == 20 { break; } }
+    match x {
+        0 => loop { break a_very_very_very_long_condition_name + another_very_very_long_name + x; },
+        _ => 2,
+    }
+}
@@ -0,0 +1,15 @@
+use array::ArrayImpl;
+use array::ArrayTrait;
+
+use option::OptionTrait;
+
+use starknet::ContractAddress;
+// Leading comment.
+use starknet::get_caller_address;
+fn foo() {}
+
+mod inner {
+    use a::d;
+
+    use b::c;
+}
@@ -0,0 +1,13 @@
+use array::ArrayTrait;
+// Leading comment.
+use starknet::get_caller_address;
+
+use array::ArrayImpl;
+use option::OptionTrait;
+use starknet::ContractAddress;
+fn foo() {}
+
+mod inner {
+    use a::d;
+    use b::c;
+}
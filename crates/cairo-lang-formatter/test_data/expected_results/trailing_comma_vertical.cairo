@@ -0,0 +1,19 @@
+struct A {
+    first: felt,
+    second: felt,
+}
+
+fn foo(
+    first_argument: felt,
+    second_argument: felt,
+    third_argument: felt,
+) -> felt {
+    let x = foo(
+        first_argument,
+        second_argument,
+        third_argument_with_long_name,
+        4,
+    );
+    let y = A { first: 1, second: 2 };
+    bar(1, 2)
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_language_server::{Backend, State};
 use cairo_lang_starknet::db::StarknetRootDatabaseBuilderEx;
@@ -12,18 +14,22 @@ async fn main() {
     #[cfg(feature = "runtime-agnostic")]
     let (stdin, stdout) = (stdin.compat(), stdout.compat_write());
 
+    // Crates enable plugins, such as the StarkNet plugin, in their project settings.
     let db = RootDatabase::builder()
         .detect_corelib()
-        .with_starknet()
+        .with_named_starknet_plugin()
         .build()
         .expect("Failed to initialize Cairo compiler database.");
 
     let (service, socket) = LspService::build(|client| Backend {
         client,
-        db_mutex: db.into(),
-        state_mutex: State::default().into(),
+        db_mutex: Arc::new(db.into()),
+        state_mutex: Arc::new(State::default().into()),
+        diagnostics_generation: Default::default(),
+        out_of_sync_files: Default::default(),
     })
     .custom_method("vfs/provide", Backend::vfs_provide)
+    .custom_method("textDocument/inlayHint", Backend::inlay_hint)
     .finish();
     Server::new(stdin, stdout, socket).serve(service).await;
 }
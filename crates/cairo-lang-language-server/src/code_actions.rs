@@ -0,0 +1,101 @@
+//! Code actions applying the suggested fixes of diagnostics.
+
+use std::collections::HashMap;
+
+use cairo_lang_diagnostics::{DiagnosticEntry, DiagnosticFix, Diagnostics};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::{get_range, map_to_origin};
+
+/// Returns the quick fixes of the diagnostics of `file` intersecting `range`. `get_uri` converts
+/// file ids to URIs.
+pub fn code_actions(
+    db: &(dyn LoweringGroup + 'static),
+    file: FileId,
+    range: Range,
+    get_uri: impl Fn(FileId) -> Url,
+) -> Vec<CodeActionOrCommand> {
+    let semantic_db: &(dyn SemanticGroup + 'static) = db.upcast();
+    let mut fixes = vec![];
+    collect_fixes(
+        semantic_db,
+        file,
+        range,
+        db.file_semantic_diagnostics(file).unwrap_or_default(),
+        &mut fixes,
+    );
+    collect_fixes(
+        semantic_db,
+        file,
+        range,
+        db.file_lowering_diagnostics(file).unwrap_or_default(),
+        &mut fixes,
+    );
+
+    let mut actions = vec![];
+    for (fix, is_preferred) in fixes {
+        let Some(changes) = fix_changes(semantic_db, &fix, &get_uri) else {
+            continue;
+        };
+        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title: fix.description,
+            kind: Some(CodeActionKind::QUICKFIX),
+            edit: Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }),
+            is_preferred: Some(is_preferred),
+            ..CodeAction::default()
+        }));
+    }
+    actions
+}
+
+/// Collects the fixes of the diagnostics intersecting `range`, along with whether each fix is the
+/// only one of its diagnostic.
+fn collect_fixes<TEntry: DiagnosticEntry<DbType = dyn SemanticGroup>>(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    range: Range,
+    diagnostics: Diagnostics<TEntry>,
+    fixes: &mut Vec<(DiagnosticFix, bool)>,
+) {
+    let files_db: &dyn FilesGroup = db.upcast();
+    for diagnostic in diagnostics.get_all() {
+        let location = diagnostic.location(db);
+        if location.file_id != file {
+            continue;
+        }
+        let Some(diagnostic_range) = get_range(files_db, file, location.span) else {
+            continue;
+        };
+        if diagnostic_range.end < range.start || range.end < diagnostic_range.start {
+            continue;
+        }
+        let diagnostic_fixes = diagnostic.fixes(db);
+        let is_preferred = diagnostic_fixes.len() == 1;
+        fixes.extend(diagnostic_fixes.into_iter().map(|fix| (fix, is_preferred)));
+    }
+}
+
+/// Converts the edits of a fix to LSP text edits. Edits of plugin generated files are mapped back
+/// to the code they were copied from. Returns None if an edit cannot be mapped.
+fn fix_changes(
+    db: &(dyn SemanticGroup + 'static),
+    fix: &DiagnosticFix,
+    get_uri: &impl Fn(FileId) -> Url,
+) -> Option<HashMap<Url, Vec<TextEdit>>> {
+    let mut changes = HashMap::<Url, Vec<TextEdit>>::new();
+    for edit in &fix.edits {
+        let (file, span) = map_to_origin(db, edit.file_id, edit.span)?;
+        let range = get_range(db.upcast(), file, span)?;
+        changes
+            .entry(get_uri(file))
+            .or_default()
+            .push(TextEdit { range, new_text: edit.replacement.clone() });
+    }
+    Some(changes)
+}
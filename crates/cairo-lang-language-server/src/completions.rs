@@ -0,0 +1,462 @@
+//! Code completions, driven by the semantic model.
+
+use std::collections::HashSet;
+
+use cairo_lang_defs::ids::{
+    FunctionSignatureId, FunctionWithBodyId, GenericTypeId, LanguageElementId, LookupItemId,
+    ModuleFileId, ModuleId, ModuleItemId, TopLevelLanguageElementId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::CrateLongId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::corelib::core_module;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::expr::compute::find_methods_for_type;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::items::generics::{GenericArgumentId, GenericParam};
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::resolve_path::{ResolvedGenericItem, Resolver};
+use cairo_lang_semantic::types::ConcreteTypeId;
+use cairo_lang_semantic::{Expr, Mutability, Pattern, Statement, TypeLongId};
+use cairo_lang_syntax::node::ast::{self, BinaryOperator};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use itertools::Itertools;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
+
+/// The syntactic context of a completion request.
+enum CompletionContext {
+    /// After a `.`, completing a member or a method of the given expression.
+    Dot(ast::Expr),
+    /// After a `::`, completing an item under the path ending with the given segment.
+    ColonColon(ast::PathSegment),
+    /// Anywhere else, completing an identifier in the current scope.
+    Scope,
+}
+
+/// Returns the completion items for the token `node`, in the module file `module_file_id`.
+/// `lookup_items` are the lookup items containing the token.
+pub fn completion_items(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    node: SyntaxNode,
+    lookup_items: &[LookupItemId],
+) -> Vec<CompletionItem> {
+    let items = match completion_context(db.upcast(), &node) {
+        CompletionContext::Dot(lhs) => dot_completions(db, module_file_id, lookup_items, lhs),
+        CompletionContext::ColonColon(segment) => {
+            colon_colon_completions(db, lookup_items, segment)
+        }
+        CompletionContext::Scope => {
+            scope_completions(db, module_file_id, lookup_items, node.offset())
+        }
+    };
+    items.unwrap_or_default()
+}
+
+/// Finds the completion context of the token `node`.
+fn completion_context(db: &dyn SyntaxGroup, node: &SyntaxNode) -> CompletionContext {
+    let Some(parent) = node.parent().and_then(|terminal| terminal.parent()) else {
+        return CompletionContext::Scope;
+    };
+    match (node.kind(db), parent.kind(db)) {
+        (SyntaxKind::TokenDot, SyntaxKind::ExprBinary) => {
+            CompletionContext::Dot(ast::ExprBinary::from_syntax_node(db, parent).lhs(db))
+        }
+        (SyntaxKind::TokenColonColon, SyntaxKind::ExprPath) => {
+            path_completion_context(db, parent, node.offset())
+        }
+        (SyntaxKind::TokenIdentifier, SyntaxKind::PathSegmentSimple) => match parent.parent() {
+            Some(path) if path.kind(db) == SyntaxKind::ExprPath => {
+                path_completion_context(db, path, node.offset())
+            }
+            _ => CompletionContext::Scope,
+        },
+        _ => CompletionContext::Scope,
+    }
+}
+
+/// Finds the completion context of a token at `offset` inside the path `path_node`.
+fn path_completion_context(
+    db: &dyn SyntaxGroup,
+    path_node: SyntaxNode,
+    offset: TextOffset,
+) -> CompletionContext {
+    let path = ast::ExprPath::from_syntax_node(db, path_node.clone());
+    let prefix = path
+        .elements(db)
+        .into_iter()
+        .take_while(|segment| segment.as_syntax_node().span_end_without_trivia(db) <= offset)
+        .last();
+    if let Some(segment) = prefix {
+        return CompletionContext::ColonColon(segment);
+    }
+
+    // A single segment path may be the member or method name of a `.` expression.
+    match path_node.parent() {
+        Some(parent) if parent.kind(db) == SyntaxKind::ExprBinary => {
+            let binary = ast::ExprBinary::from_syntax_node(db, parent);
+            if matches!(binary.op(db), BinaryOperator::Dot(_))
+                && binary.rhs(db).as_syntax_node().offset() == path_node.offset()
+            {
+                CompletionContext::Dot(binary.lhs(db))
+            } else {
+                CompletionContext::Scope
+            }
+        }
+        _ => CompletionContext::Scope,
+    }
+}
+
+/// Returns the function with a body among the lookup items, if any.
+fn function_with_body(lookup_items: &[LookupItemId]) -> Option<FunctionWithBodyId> {
+    lookup_items.iter().find_map(|lookup_item| match lookup_item {
+        LookupItemId::ModuleItem(ModuleItemId::FreeFunction(id)) => {
+            Some(FunctionWithBodyId::Free(*id))
+        }
+        LookupItemId::ImplFunction(id) => Some(FunctionWithBodyId::Impl(*id)),
+        _ => None,
+    })
+}
+
+/// Completes the members and methods of the type of `lhs`.
+fn dot_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    lhs: ast::Expr,
+) -> Option<Vec<CompletionItem>> {
+    let function_id = function_with_body(lookup_items)?;
+    let expr_id = db.lookup_expr_by_ptr(function_id, lhs.stable_ptr()).to_option()?;
+    let ty = db.expr_semantic(function_id, expr_id).ty();
+
+    let mut completions = vec![];
+    if let TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) =
+        db.lookup_intern_type(ty)
+    {
+        for (name, member) in db.concrete_struct_members(concrete_struct_id).to_option()?.iter() {
+            completions.push(CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::FIELD),
+                detail: Some(member.ty.format(db)),
+                ..CompletionItem::default()
+            });
+        }
+    }
+    let mut resolver = Resolver::new_with_inference(db, module_file_id);
+    let methods =
+        find_methods_for_type(db, &mut resolver, ty, lhs.stable_ptr().untyped()).to_option()?;
+    for trait_function in methods {
+        completions.push(CompletionItem {
+            label: trait_function.name(db.upcast()).to_string(),
+            kind: Some(CompletionItemKind::METHOD),
+            detail: format_signature(db, FunctionSignatureId::Trait(trait_function)),
+            ..CompletionItem::default()
+        });
+    }
+    Some(completions)
+}
+
+/// Completes the items under the path ending with `segment`: module items, enum variants, and
+/// trait or impl functions.
+fn colon_colon_completions(
+    db: &(dyn SemanticGroup + 'static),
+    lookup_items: &[LookupItemId],
+    segment: ast::PathSegment,
+) -> Option<Vec<CompletionItem>> {
+    let identifier = segment.identifier_ast(db.upcast());
+    let item = lookup_items.iter().find_map(|lookup_item| {
+        db.lookup_resolved_generic_item_by_ptr(*lookup_item, identifier.stable_ptr())
+    })?;
+    Some(match item {
+        ResolvedGenericItem::Module(module_id) => module_completions(db, module_id)?,
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(enum_id)) => db
+            .enum_variants(enum_id)
+            .to_option()?
+            .iter()
+            .map(|(name, variant_id)| CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::ENUM_MEMBER),
+                detail: db
+                    .variant_semantic(enum_id, *variant_id)
+                    .to_option()
+                    .map(|variant| variant.ty.format(db)),
+                ..CompletionItem::default()
+            })
+            .collect(),
+        ResolvedGenericItem::Trait(trait_id) => db
+            .trait_functions(trait_id)
+            .to_option()?
+            .iter()
+            .map(|(name, trait_function)| CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: format_signature(db, FunctionSignatureId::Trait(*trait_function)),
+                ..CompletionItem::default()
+            })
+            .collect(),
+        ResolvedGenericItem::Impl(impl_def_id) => db
+            .impl_functions(impl_def_id)
+            .to_option()?
+            .iter()
+            .map(|(name, impl_function)| CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: format_signature(db, FunctionSignatureId::Impl(*impl_function)),
+                ..CompletionItem::default()
+            })
+            .collect(),
+        _ => return None,
+    })
+}
+
+/// Completes the identifiers visible at `offset`: local variables, params, the items of the
+/// current module (including `use`d items), the items of the core library, and crates.
+fn scope_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_file_id: ModuleFileId,
+    lookup_items: &[LookupItemId],
+    offset: TextOffset,
+) -> Option<Vec<CompletionItem>> {
+    let mut completions = vec![];
+    if let Some(function_id) = function_with_body(lookup_items) {
+        completions.extend(local_completions(db, function_id, offset));
+    }
+    completions.extend(module_completions(db, module_file_id.0)?);
+    if module_file_id.0.owning_crate(db.upcast()) != core_module(db).owning_crate(db.upcast()) {
+        completions.extend(module_completions(db, core_module(db))?);
+    }
+    completions.extend(db.crates().into_iter().map(|crate_id| {
+        let CrateLongId(name) = db.lookup_intern_crate(crate_id);
+        CompletionItem {
+            label: name.to_string(),
+            kind: Some(CompletionItemKind::MODULE),
+            ..CompletionItem::default()
+        }
+    }));
+
+    // Inner names shadow outer ones.
+    let mut names = HashSet::new();
+    completions.retain(|completion| names.insert(completion.label.clone()));
+    Some(completions)
+}
+
+/// Completes the local variables and params of a function which are in scope at `offset`,
+/// innermost first.
+fn local_completions(
+    db: &(dyn SemanticGroup + 'static),
+    function_id: FunctionWithBodyId,
+    offset: TextOffset,
+) -> Vec<CompletionItem> {
+    let syntax_db = db.upcast();
+    let mut completions = vec![];
+    let syntax = db
+        .module_file(function_id.module_file_id(db.upcast()))
+        .and_then(|file_id| db.file_syntax(file_id));
+    if let (Ok(body), Ok(syntax)) = (db.function_body(function_id), syntax) {
+        let root = syntax.as_syntax_node();
+        let let_patterns = body.statements.iter().filter_map(|(_, statement)| match statement {
+            Statement::Let(statement) => Some(&statement.pattern),
+            _ => None,
+        });
+        let arm_patterns = body.exprs.iter().flat_map(|(_, expr)| match expr {
+            Expr::Match(expr) => expr.arms.iter().map(|arm| &arm.pattern).collect(),
+            _ => vec![],
+        });
+        let mut locals = let_patterns
+            .chain(arm_patterns)
+            .flat_map(Pattern::variables)
+            .map(|variable| (root.lookup_ptr(syntax_db, variable.stable_ptr.untyped()), variable))
+            .filter(|(node, _)| is_in_scope(syntax_db, node.clone(), offset))
+            .collect_vec();
+        // Later definitions shadow earlier ones.
+        locals.sort_by_key(|(node, _)| std::cmp::Reverse(node.offset()));
+        completions.extend(locals.into_iter().map(|(_, variable)| CompletionItem {
+            label: variable.name.to_string(),
+            kind: Some(CompletionItemKind::VARIABLE),
+            detail: Some(variable.var.ty.format(db)),
+            ..CompletionItem::default()
+        }));
+    }
+    if let Ok(signature) = db.function_with_body_signature(function_id) {
+        completions.extend(signature.params.iter().map(|param| CompletionItem {
+            label: param.name.to_string(),
+            kind: Some(CompletionItemKind::VARIABLE),
+            detail: Some(param.ty.format(db)),
+            ..CompletionItem::default()
+        }));
+    }
+    completions
+}
+
+/// Returns whether a variable defined by the pattern `pattern` is in scope at `offset`: after
+/// its `let` statement and inside the block containing it, or inside the expression of its match
+/// arm.
+fn is_in_scope(db: &dyn SyntaxGroup, mut pattern: SyntaxNode, offset: TextOffset) -> bool {
+    while let Some(parent) = pattern.parent() {
+        match parent.kind(db) {
+            SyntaxKind::StatementLet => {
+                let Some(block) = parent.parent().and_then(|statements| statements.parent()) else {
+                    return false;
+                };
+                return parent.span_end_without_trivia(db) <= offset
+                    && offset < block.span_end_without_trivia(db);
+            }
+            SyntaxKind::MatchArm => {
+                return pattern.span_end_without_trivia(db) <= offset
+                    && offset < parent.span_end_without_trivia(db);
+            }
+            _ => pattern = parent,
+        }
+    }
+    false
+}
+
+/// Completes the items of a module.
+fn module_completions(
+    db: &(dyn SemanticGroup + 'static),
+    module_id: ModuleId,
+) -> Option<Vec<CompletionItem>> {
+    Some(
+        db.module_items(module_id)
+            .to_option()?
+            .iter()
+            .filter_map(|item| module_item_completion(db, *item))
+            .collect(),
+    )
+}
+
+/// Returns the completion item of a module item. `use` items are completed as the item they
+/// import.
+fn module_item_completion(
+    db: &(dyn SemanticGroup + 'static),
+    item: ModuleItemId,
+) -> Option<CompletionItem> {
+    let defs_db = db.upcast();
+    let (name, item) = match item {
+        ModuleItemId::Constant(id) => (id.name(defs_db), ResolvedGenericItem::Constant(id)),
+        ModuleItemId::Submodule(id) => {
+            (id.name(defs_db), ResolvedGenericItem::Module(ModuleId::Submodule(id)))
+        }
+        ModuleItemId::Use(id) => (id.name(defs_db), db.use_resolved_item(id).to_option()?),
+        ModuleItemId::FreeFunction(id) => {
+            (id.name(defs_db), ResolvedGenericItem::GenericFunction(GenericFunctionId::Free(id)))
+        }
+        ModuleItemId::Struct(id) => {
+            (id.name(defs_db), ResolvedGenericItem::GenericType(GenericTypeId::Struct(id)))
+        }
+        ModuleItemId::Enum(id) => {
+            (id.name(defs_db), ResolvedGenericItem::GenericType(GenericTypeId::Enum(id)))
+        }
+        ModuleItemId::TypeAlias(id) => {
+            (id.name(defs_db), ResolvedGenericItem::GenericTypeAlias(id))
+        }
+        ModuleItemId::Trait(id) => (id.name(defs_db), ResolvedGenericItem::Trait(id)),
+        ModuleItemId::Impl(id) => (id.name(defs_db), ResolvedGenericItem::Impl(id)),
+        ModuleItemId::ExternType(id) => {
+            (id.name(defs_db), ResolvedGenericItem::GenericType(GenericTypeId::Extern(id)))
+        }
+        ModuleItemId::ExternFunction(id) => {
+            (id.name(defs_db), ResolvedGenericItem::GenericFunction(GenericFunctionId::Extern(id)))
+        }
+    };
+    let (kind, detail) = match item {
+        ResolvedGenericItem::Constant(id) => (
+            CompletionItemKind::CONSTANT,
+            db.constant_semantic_data(id)
+                .to_option()
+                .map(|constant| constant.value.ty().format(db)),
+        ),
+        ResolvedGenericItem::Module(_) => (CompletionItemKind::MODULE, None),
+        ResolvedGenericItem::GenericFunction(function) => {
+            let detail = function
+                .signature(db)
+                .to_option()
+                .and_then(|signature| format_signature(db, signature));
+            (CompletionItemKind::FUNCTION, detail)
+        }
+        ResolvedGenericItem::TraitFunction(trait_function) => (
+            CompletionItemKind::FUNCTION,
+            format_signature(db, FunctionSignatureId::Trait(trait_function)),
+        ),
+        ResolvedGenericItem::GenericType(GenericTypeId::Enum(_)) => {
+            (CompletionItemKind::ENUM, None)
+        }
+        ResolvedGenericItem::GenericType(_) | ResolvedGenericItem::GenericTypeAlias(_) => {
+            (CompletionItemKind::STRUCT, None)
+        }
+        ResolvedGenericItem::Variant(variant) => {
+            (CompletionItemKind::ENUM_MEMBER, Some(variant.ty.format(db)))
+        }
+        ResolvedGenericItem::Trait(_) => (CompletionItemKind::INTERFACE, None),
+        ResolvedGenericItem::Impl(_) => (CompletionItemKind::CLASS, None),
+    };
+    Some(CompletionItem {
+        label: name.to_string(),
+        kind: Some(kind),
+        detail,
+        ..CompletionItem::default()
+    })
+}
+
+/// Formats the signature of a function, e.g.
+/// `fn foo<T, impl TDrop: Drop::<T>>(ref a: T, b: felt) -> T implicits(RangeCheck) nopanic`.
+pub fn format_signature(
+    db: &(dyn SemanticGroup + 'static),
+    signature_id: FunctionSignatureId,
+) -> Option<String> {
+    let signature = db.function_signature_signature(signature_id).to_option()?;
+    let generic_params = db.function_signature_generic_params(signature_id).to_option()?;
+    let mut res = format!("fn {}", signature_id.name(db.upcast()));
+    if !generic_params.is_empty() {
+        let generic_params = generic_params.iter().map(|param| format_generic_param(db, param));
+        res += &format!("<{}>", generic_params.format(", "));
+    }
+    let params = signature.params.iter().map(|param| {
+        let modifier = match param.mutability {
+            Mutability::Immutable => "",
+            Mutability::Mutable => "mut ",
+            Mutability::Reference => "ref ",
+        };
+        format!("{modifier}{}: {}", param.name, param.ty.format(db))
+    });
+    res += &format!("({})", params.format(", "));
+    if signature.return_type != db.intern_type(TypeLongId::Tuple(vec![])) {
+        res += &format!(" -> {}", signature.return_type.format(db));
+    }
+    if !signature.implicits.is_empty() {
+        let implicits = signature.implicits.iter().map(|ty| ty.format(db));
+        res += &format!(" implicits({})", implicits.format(", "));
+    }
+    if !signature.panicable {
+        res += " nopanic";
+    }
+    Some(res)
+}
+
+/// Formats a generic param, e.g. `T` or `impl TDrop: Drop::<T>`.
+fn format_generic_param(db: &(dyn SemanticGroup + 'static), param: &GenericParam) -> String {
+    let name = param.id().name(db.upcast());
+    let GenericParam::Impl(param) = param else {
+        return name.to_string();
+    };
+    let Ok(concrete_trait) = param.concrete_trait else {
+        return format!("impl {name}");
+    };
+    let concrete_trait = db.lookup_intern_concrete_trait(concrete_trait);
+    let mut res = format!("impl {name}: {}", concrete_trait.trait_id.name(db.upcast()));
+    if !concrete_trait.generic_args.is_empty() {
+        let generic_args = concrete_trait.generic_args.iter().map(|arg| match arg {
+            GenericArgumentId::Type(ty) => ty.format(db),
+            GenericArgumentId::Literal(literal) => {
+                db.lookup_intern_literal(*literal).value.to_string()
+            }
+            GenericArgumentId::Impl(_) => "_".to_string(),
+        });
+        res += &format!("::<{}>", generic_args.format(", "));
+    }
+    res
+}
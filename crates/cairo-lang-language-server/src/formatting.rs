@@ -0,0 +1,139 @@
+//! Document, range and on type formatting.
+
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::{TextOffset, TextWidth};
+use cairo_lang_formatter::{
+    get_line_edits, get_verified_formatted_file, FormatterConfig, LineEdit,
+};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_utils::OptionHelper;
+use tower_lsp::lsp_types::{Position, Range, TextEdit};
+
+use crate::position_to_byte_offset;
+
+/// Returns the edits formatting `file`.
+pub fn format_file(db: &(dyn SemanticGroup + 'static), file: FileId) -> Option<Vec<TextEdit>> {
+    Some(formatting_edits(db, file)?.into_iter().map(text_edit).collect())
+}
+
+/// Returns the edits formatting the lines of `file` intersecting `range`.
+pub fn format_range(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    // A range ending at the start of a line doesn't include that line.
+    let end_line = if range.end.character == 0 && range.end.line > range.start.line {
+        range.end.line - 1
+    } else {
+        range.end.line
+    };
+    lines_edits(db, file, range.start.line as usize, end_line as usize)
+}
+
+/// Returns the edits formatting the code ended by the character `ch` typed before `position` in
+/// `file`: the statement ended by a `;`, or the statement or item ended by a `}`.
+pub fn format_on_type(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+    ch: &str,
+) -> Option<Vec<TextEdit>> {
+    let syntax_db = db.upcast();
+    let content = db.file_content(file)?;
+    // The typed character ends at `position`. The content may differ from the one of the client,
+    // e.g. when the changes of the client could not be applied.
+    let offset = position_to_byte_offset(&content, position)?.checked_sub(ch.len())?;
+    if !content.get(offset..)?.starts_with(ch) {
+        return None;
+    }
+    let root = db.file_syntax(file).ok()?.as_syntax_node();
+    let offset = TextOffset::default().add_width(TextWidth::from_str(content.get(..offset)?));
+    let terminal = root.lookup_offset(syntax_db, offset);
+    if terminal.clone().get_text_without_trivia(syntax_db) != ch {
+        return None;
+    }
+    // The largest node ended by the typed character, which is an element of a list of items or
+    // statements at most.
+    let end = terminal.span_end_without_trivia(syntax_db);
+    let mut node = terminal;
+    while let Some(parent) = node.parent() {
+        if parent.span_end_without_trivia(syntax_db) != end
+            || matches!(
+                parent.kind(syntax_db),
+                SyntaxKind::ItemList
+                    | SyntaxKind::StatementList
+                    | SyntaxKind::TraitItemList
+                    | SyntaxKind::SyntaxFile
+            )
+        {
+            break;
+        }
+        node = parent;
+    }
+    let span = node.span_without_trivia(syntax_db);
+    let start_line = span.start.get_line_number(db.upcast(), file)?;
+    let end_line = span.end.get_line_number(db.upcast(), file)?;
+    lines_edits(db, file, start_line, end_line)
+}
+
+/// Returns the edits formatting the lines from `start_line` to `end_line` of `file`, inclusive.
+fn lines_edits(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    start_line: usize,
+    end_line: usize,
+) -> Option<Vec<TextEdit>> {
+    Some(
+        formatting_edits(db, file)?
+            .into_iter()
+            .filter(|edit| {
+                // An insertion is considered to intersect the line it is inserted before.
+                edit.start_line <= end_line && edit.end_line.max(edit.start_line + 1) > start_line
+            })
+            .map(text_edit)
+            .collect(),
+    )
+}
+
+/// Returns the line edits formatting `file`, with the configuration found for it. Returns None if
+/// the file has parsing errors, or if formatting it can't be verified to be idempotent and to keep
+/// its comments in place.
+fn formatting_edits(db: &(dyn SemanticGroup + 'static), file: FileId) -> Option<Vec<LineEdit>> {
+    let file_name = file.file_name(db.upcast());
+    let syntax = db.file_syntax(file).ok().on_none(|| {
+        eprintln!("Formatting failed. File '{file_name}' does not exist.");
+    })?;
+    if !db.file_syntax_diagnostics(file).get_all().is_empty() {
+        eprintln!("Formatting failed. File '{file_name}' has parsing errors.");
+        return None;
+    }
+    let config = match db.lookup_intern_file(file) {
+        FileLongId::OnDisk(path) => match FormatterConfig::discover(&path) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("Formatting failed. {err:?}");
+                return None;
+            }
+        },
+        FileLongId::Virtual(_) => FormatterConfig::default(),
+    };
+    let content = db.file_content(file)?;
+    let formatted = get_verified_formatted_file(db.upcast(), &syntax.as_syntax_node(), config)
+        .map_err(|err| eprintln!("Formatting failed. {err}"))
+        .ok()?;
+    Some(get_line_edits(&content, &formatted))
+}
+
+/// Converts a line edit to an LSP text edit.
+fn text_edit(edit: LineEdit) -> TextEdit {
+    TextEdit {
+        range: Range {
+            start: Position { line: edit.start_line as u32, character: 0 },
+            end: Position { line: edit.end_line as u32, character: 0 },
+        },
+        new_text: edit.new_text,
+    }
+}
@@ -0,0 +1,211 @@
+//! Inlay hints, showing the inferred types of `let` bindings and the parameter names of call
+//! arguments.
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId, ModuleFileId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::{Expr, Pattern, Statement, StatementLet};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Range};
+
+use crate::{from_pos, generated_file_info, map_to_origin};
+
+/// Returns the inlay hints of `file` within `range`.
+pub fn inlay_hints(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    range: Range,
+) -> Vec<InlayHint> {
+    let mut hints = vec![];
+    for (function_id, function_file) in file_functions(db, file) {
+        let mut function_hints = vec![];
+        let Ok(body) = db.function_body(function_id) else {
+            continue;
+        };
+        let Ok(syntax) = db.file_syntax(function_file) else {
+            continue;
+        };
+        let root = syntax.as_syntax_node();
+        for (_, statement) in body.statements.iter() {
+            if let Statement::Let(statement_let) = statement {
+                type_hints(db, &root, statement_let, &mut function_hints);
+            }
+        }
+        for (_, expr) in body.exprs.iter() {
+            if let Expr::FunctionCall(call) = expr {
+                let node = root.lookup_ptr(db.upcast(), call.stable_ptr.untyped());
+                if let Ok(signature) = db.concrete_function_signature(call.function) {
+                    let param_names = signature.params.iter().map(|param| param.name.as_str());
+                    parameter_hints(db, node, param_names, &mut function_hints);
+                }
+            }
+        }
+
+        for (offset, label, kind) in function_hints {
+            let span = TextSpan { start: offset, end: offset };
+            let Some((origin_file, origin_span)) = map_to_origin(db, function_file, span) else {
+                continue;
+            };
+            if origin_file != file {
+                continue;
+            }
+            let Some(position) = origin_span.start.position_in_file(db.upcast(), file) else {
+                continue;
+            };
+            let position = from_pos(position);
+            if position < range.start || range.end < position {
+                continue;
+            }
+            hints.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(label),
+                kind: Some(kind),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: None,
+                data: None,
+            });
+        }
+    }
+    hints.sort_by_key(|hint| hint.position);
+    hints
+}
+
+/// Returns the functions with a body whose code originates from `file`, either directly or through
+/// plugin generated files, along with the file each is in.
+fn file_functions(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+) -> Vec<(FunctionWithBodyId, FileId)> {
+    let defs_db = db.upcast();
+    let crate_ids: OrderedHashSet<_> = db
+        .file_modules(file)
+        .unwrap_or_default()
+        .iter()
+        .map(|module_id| module_id.owning_crate(defs_db))
+        .collect();
+    let mut functions = vec![];
+    for crate_id in crate_ids {
+        for module_id in db.crate_modules(crate_id).iter() {
+            let module_files = db.module_files(*module_id).unwrap_or_default();
+            if !module_files.iter().any(|module_file| origin_file(db, *module_file) == Some(file)) {
+                continue;
+            }
+            let mut module_functions: Vec<(FunctionWithBodyId, ModuleFileId)> = vec![];
+            for free_function_id in db.module_free_functions_ids(*module_id).unwrap_or_default() {
+                module_functions.push((
+                    FunctionWithBodyId::Free(free_function_id),
+                    free_function_id.module_file_id(defs_db),
+                ));
+            }
+            for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default() {
+                for impl_function_id in
+                    db.impl_functions(impl_def_id).unwrap_or_default().values().copied()
+                {
+                    module_functions.push((
+                        FunctionWithBodyId::Impl(impl_function_id),
+                        impl_function_id.module_file_id(defs_db),
+                    ));
+                }
+            }
+            for (function_id, module_file_id) in module_functions {
+                let Ok(function_file) = db.module_file(module_file_id) else {
+                    continue;
+                };
+                if origin_file(db, function_file) == Some(file) {
+                    functions.push((function_id, function_file));
+                }
+            }
+        }
+    }
+    functions
+}
+
+/// Returns the user file the code of `file` originates from: the file itself, or the file a plugin
+/// generated it from, recursively.
+fn origin_file(db: &(dyn SemanticGroup + 'static), mut file: FileId) -> Option<FileId> {
+    while let Some((_, origin)) = generated_file_info(db, file) {
+        file = db.module_file(origin).ok()?;
+    }
+    Some(file)
+}
+
+/// Adds hints of the types of the variables bound by a `let` statement without a type clause.
+fn type_hints(
+    db: &dyn SemanticGroup,
+    root: &SyntaxNode,
+    statement_let: &StatementLet,
+    hints: &mut Vec<(TextOffset, String, InlayHintKind)>,
+) {
+    let syntax_db = db.upcast();
+    let statement = root.lookup_ptr(syntax_db, statement_let.stable_ptr.untyped());
+    if statement.kind(syntax_db) != SyntaxKind::StatementLet {
+        return;
+    }
+    let statement = ast::StatementLet::from_syntax_node(syntax_db, statement);
+    if matches!(statement.type_clause(syntax_db), ast::OptionTypeClause::TypeClause(_)) {
+        return;
+    }
+    let mut patterns = vec![&statement_let.pattern];
+    while let Some(pattern) = patterns.pop() {
+        match pattern {
+            Pattern::Variable(pattern_variable) => {
+                let ty = pattern_variable.var.ty;
+                if ty.is_missing(db) {
+                    continue;
+                }
+                let identifier = root
+                    .lookup_ptr(syntax_db, pattern_variable.var.stable_ptr(db.upcast()).untyped());
+                hints.push((
+                    identifier.span_end_without_trivia(syntax_db),
+                    format!(": {}", ty.format(db)),
+                    InlayHintKind::TYPE,
+                ));
+            }
+            Pattern::Struct(pattern_struct) => {
+                patterns.extend(pattern_struct.field_patterns.iter().map(|(_, pattern)| &**pattern))
+            }
+            Pattern::Tuple(pattern_tuple) => {
+                patterns.extend(pattern_tuple.field_patterns.iter().map(|pattern| &**pattern))
+            }
+            Pattern::EnumVariant(pattern_enum_variant) => {
+                patterns.push(&pattern_enum_variant.inner_pattern)
+            }
+            Pattern::Literal(_) | Pattern::Otherwise(_) => {}
+        }
+    }
+}
+
+/// Adds hints of the parameter names of the unnamed arguments of a call. Arguments that are a
+/// variable named like their parameter are not hinted.
+fn parameter_hints<'a>(
+    db: &dyn SemanticGroup,
+    call: SyntaxNode,
+    param_names: impl Iterator<Item = &'a str>,
+    hints: &mut Vec<(TextOffset, String, InlayHintKind)>,
+) {
+    let syntax_db = db.upcast();
+    if call.kind(syntax_db) != SyntaxKind::ExprFunctionCall {
+        return;
+    }
+    let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, call);
+    let args = call.arguments(syntax_db).args(syntax_db).elements(syntax_db);
+    for (arg, param_name) in args.into_iter().zip(param_names) {
+        let ast::ArgClause::Unnamed(arg_clause) = arg.arg_clause(syntax_db) else {
+            continue;
+        };
+        let value = arg_clause.value(syntax_db).as_syntax_node();
+        if value.get_text_without_trivia(syntax_db) == param_name {
+            continue;
+        }
+        hints.push((
+            arg.as_syntax_node().span_start_without_trivia(syntax_db),
+            format!("{param_name}: "),
+            InlayHintKind::PARAMETER,
+        ));
+    }
+}
@@ -2,10 +2,19 @@
 //!
 //! Implements the LSP protocol over stdin/out.
 
+mod code_actions;
+mod completions;
+mod formatting;
+mod inlay_hints;
+mod references;
 mod semantic_highlighting;
+mod signature_help;
+mod symbols;
 
 use std::collections::{HashMap, HashSet};
+use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use cairo_lang_compiler::db::RootDatabase;
@@ -15,36 +24,46 @@ use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::{
     ConstantLongId, EnumLongId, ExternFunctionLongId, ExternTypeLongId, FileIndex,
     FreeFunctionLongId, FunctionWithBodyId, ImplDefLongId, ImplFunctionLongId, LanguageElementId,
-    LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructLongId, TraitLongId, UseLongId,
+    LookupItemId, ModuleFileId, ModuleId, ModuleItemId, StructLongId, SubmoduleLongId, TraitLongId,
+    UseLongId,
 };
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ToOption};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, Severity, ToOption};
 use cairo_lang_filesystem::db::{
     AsFilesGroupMut, FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
 };
 use cairo_lang_filesystem::ids::{FileId, FileLongId};
-use cairo_lang_filesystem::span::{TextPosition, TextWidth};
-use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
+use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_lowering::diagnostic::LoweringDiagnostic;
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_parser::ParserDiagnostic;
-use cairo_lang_project::ProjectConfig;
+use cairo_lang_project::{DeserializationError, ProjectConfig};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::plugin::DynPluginAuxData;
 use cairo_lang_semantic::resolve_path::ResolvedGenericItem;
 use cairo_lang_semantic::SemanticDiagnostic;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::stable_ptr::SyntaxStablePtr;
 use cairo_lang_syntax::node::utils::is_grandparent_of_kind;
 use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper, Upcast};
-use salsa::InternKey;
+use code_actions::code_actions;
+use completions::completion_items;
+use formatting::{format_file, format_on_type, format_range};
+use inlay_hints::inlay_hints;
+use itertools::zip_eq;
+use references::{find_references, identifier_definition, is_valid_identifier};
+use salsa::{Database, InternKey, ParallelDatabase};
 use semantic_highlighting::token_kind::SemanticTokenKind;
 use semantic_highlighting::SemanticTokensTraverser;
 use serde_json::Value;
+use signature_help::signature_help;
+use symbols::{document_symbols, workspace_symbols};
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
@@ -63,16 +82,49 @@ pub struct FileDiagnostics {
 pub struct State {
     pub file_diagnostics: HashMap<FileId, FileDiagnostics>,
     pub open_files: HashSet<FileId>,
+    /// Whether the client supports server initiated `$/progress` notifications.
+    pub work_done_progress: bool,
 }
+#[derive(Clone)]
 pub struct Backend {
     pub client: Client,
-    // TODO(spapini): Remove this once we support ParallelDatabase.
-    pub db_mutex: tokio::sync::Mutex<RootDatabase>,
-    pub state_mutex: tokio::sync::Mutex<State>,
+    /// The database, locked by modifications and requests. Diagnostics are computed on snapshots
+    /// of it in the background.
+    pub db_mutex: Arc<tokio::sync::Mutex<RootDatabase>>,
+    /// The state, locked by diagnostics refreshes, which therefore run one at a time.
+    pub state_mutex: Arc<tokio::sync::Mutex<State>>,
+    /// Incremented on each diagnostics refresh, so that outdated refreshes stop early.
+    pub diagnostics_generation: Arc<AtomicU64>,
+    /// Open files whose changes could not be applied. Their saved content is used until they are
+    /// saved, reopened or sent in full again. Locked while the database is locked.
+    pub out_of_sync_files: Arc<tokio::sync::Mutex<HashSet<FileId>>>,
 }
 fn from_pos(pos: TextPosition) -> Position {
     Position { line: pos.line as u32, character: pos.col as u32 }
 }
+/// Converts an LSP position in a text to a byte offset. The character of the position counts UTF-16
+/// code units, as specified by the protocol.
+fn position_to_byte_offset(text: &str, position: Position) -> Option<usize> {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        line_start += text[line_start..].find('\n')? + 1;
+    }
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+    let mut utf16_offset = 0;
+    for (byte_offset, c) in line.char_indices() {
+        if utf16_offset >= position.character as usize {
+            return Some(line_start + byte_offset);
+        }
+        utf16_offset += c.len_utf16();
+    }
+    Some(line_start + line.len())
+}
+/// Converts a span in a file to an LSP range.
+fn get_range(db: &dyn FilesGroup, file: FileId, span: TextSpan) -> Option<Range> {
+    let start = from_pos(span.start.position_in_file(db, file)?);
+    let end = from_pos(span.end.position_in_file(db, file)?);
+    Some(Range { start, end })
+}
 impl Backend {
     /// Locks and gets a database instance.
     async fn db(&self) -> tokio::sync::MutexGuard<'_, RootDatabase> {
@@ -109,28 +161,64 @@ impl Backend {
     // TODO(spapini): Consider managing vfs in a different way, using the
     // client.send_notification::<UpdateVirtualFile> call.
 
-    // Refresh diagnostics and send diffs to client.
-    async fn refresh_diagnostics(&self) {
+    /// Refreshes diagnostics in a background task, and sends diffs to the client. Cancels any
+    /// previous refresh.
+    fn refresh_diagnostics(&self) {
+        let generation = self.diagnostics_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let backend = self.clone();
+        tokio::spawn(async move { backend.refresh_diagnostics_task(generation).await });
+    }
+
+    /// Computes the diagnostics of all the files, and sends diffs to the client. Each computation
+    /// runs on a snapshot of the database in a blocking thread, so requests are not blocked by it.
+    /// Stops early if a newer refresh is scheduled, or if the database is being modified.
+    async fn refresh_diagnostics_task(&self, generation: u64) {
         let mut state = self.state_mutex.lock().await;
+        let is_canceled = || self.diagnostics_generation.load(Ordering::SeqCst) != generation;
 
         // Get all files. Try to go over open files first.
-        let mut files_set: OrderedHashSet<_> = state.open_files.iter().copied().collect();
-        let db = self.db().await;
-        for crate_id in db.crates() {
-            for module_id in db.crate_modules(crate_id).iter() {
-                for file_id in db.module_files(*module_id).unwrap_or_default() {
-                    files_set.insert(file_id);
+        let open_files: OrderedHashSet<_> = state.open_files.iter().copied().collect();
+        let Some(files_set) = self
+            .run_on_snapshot(move |db| {
+                let mut files_set = open_files;
+                for crate_id in db.crates() {
+                    for module_id in db.crate_modules(crate_id).iter() {
+                        for file_id in db.module_files(*module_id).unwrap_or_default() {
+                            files_set.insert(file_id);
+                        }
+                    }
                 }
-            }
-        }
+                files_set
+            })
+            .await
+        else {
+            return;
+        };
 
         // Get all diagnostics.
-        for file_id in files_set.iter().copied() {
-            let uri = self.get_uri(&db, file_id);
-            let new_file_diagnostics = FileDiagnostics {
-                parser: db.file_syntax_diagnostics(file_id),
-                semantic: db.file_semantic_diagnostics(file_id).unwrap_or_default(),
-                lowering: db.file_lowering_diagnostics(file_id).unwrap_or_default(),
+        let progress = self.begin_progress(state.work_done_progress, "Analyzing").await;
+        let files_count = files_set.len();
+        for (i, file_id) in files_set.iter().copied().enumerate() {
+            if is_canceled() {
+                self.end_progress(progress).await;
+                return;
+            }
+            self.report_progress(
+                &progress,
+                format!("{i}/{files_count} files"),
+                i * 100 / files_count,
+            )
+            .await;
+            let Some(new_file_diagnostics) = self
+                .run_on_snapshot(move |db| FileDiagnostics {
+                    parser: db.file_syntax_diagnostics(file_id),
+                    semantic: db.file_semantic_diagnostics(file_id).unwrap_or_default(),
+                    lowering: db.file_lowering_diagnostics(file_id).unwrap_or_default(),
+                })
+                .await
+            else {
+                self.end_progress(progress).await;
+                return;
             };
             // Since we are using Arcs, this comparison should be efficient.
             if let Some(old_file_diagnostics) = state.file_diagnostics.get(&file_id) {
@@ -138,10 +226,13 @@ impl Backend {
                     continue;
                 }
             }
+            let db = self.db().await;
+            let uri = self.get_uri(&db, file_id);
             let mut diags = Vec::new();
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.parser);
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.semantic);
             self.get_diagnostics((*db).upcast(), &mut diags, &new_file_diagnostics.lowering);
+            drop(db);
             state.file_diagnostics.insert(file_id, new_file_diagnostics);
 
             self.client.publish_diagnostics(uri, diags, None).await
@@ -154,9 +245,94 @@ impl Backend {
                 continue;
             }
             state.file_diagnostics.remove(&file_id);
-            let uri = self.get_uri(&db, file_id);
+            let uri = self.get_uri(&*self.db().await, file_id);
             self.client.publish_diagnostics(uri, Vec::new(), None).await;
         }
+        self.end_progress(progress).await;
+    }
+
+    /// Runs a function on a snapshot of the database in a blocking thread. Returns None if the
+    /// database is modified while the function runs, as the result may then be outdated.
+    async fn run_on_snapshot<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&RootDatabase) -> T + Send + 'static,
+    ) -> Option<T> {
+        let db = self.db().await.snapshot();
+        tokio::task::spawn_blocking(move || {
+            let res = f(&db);
+            if db.salsa_runtime().is_current_revision_canceled() { None } else { Some(res) }
+        })
+        .await
+        .expect("Background analysis panicked.")
+    }
+
+    /// Starts reporting the progress of a background task through `$/progress` notifications.
+    /// Returns the token of the progress, or None if the client does not support it.
+    async fn begin_progress(
+        &self,
+        work_done_progress: bool,
+        title: &str,
+    ) -> Option<NumberOrString> {
+        if !work_done_progress {
+            return None;
+        }
+        let token = NumberOrString::String(format!("cairo/{}", title.to_lowercase()));
+        self.client
+            .send_request::<request::WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await
+            .ok()?;
+        self.send_progress(
+            token.clone(),
+            WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: title.into(),
+                cancellable: Some(false),
+                message: None,
+                percentage: Some(0),
+            }),
+        )
+        .await;
+        Some(token)
+    }
+
+    /// Reports the progress of a background task started by [Self::begin_progress].
+    async fn report_progress(
+        &self,
+        token: &Option<NumberOrString>,
+        message: String,
+        percentage: usize,
+    ) {
+        let Some(token) = token else {
+            return;
+        };
+        self.send_progress(
+            token.clone(),
+            WorkDoneProgress::Report(WorkDoneProgressReport {
+                cancellable: Some(false),
+                message: Some(message),
+                percentage: Some(percentage as u32),
+            }),
+        )
+        .await;
+    }
+
+    /// Ends the progress of a background task started by [Self::begin_progress].
+    async fn end_progress(&self, token: Option<NumberOrString>) {
+        let Some(token) = token else {
+            return;
+        };
+        self.send_progress(token, WorkDoneProgress::End(WorkDoneProgressEnd { message: None }))
+            .await;
+    }
+
+    async fn send_progress(&self, token: NumberOrString, progress: WorkDoneProgress) {
+        self.client
+            .send_notification::<notification::Progress>(ProgressParams {
+                token,
+                value: ProgressParamsValue::WorkDone(progress),
+            })
+            .await;
     }
 
     /// Converts internal format diagnostics to LSP format.
@@ -175,14 +351,79 @@ impl Backend {
             let end = from_pos(
                 location.span.start.position_in_file(db.upcast(), location.file_id).unwrap(),
             );
+            let severity = match diagnostic.severity() {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+                Severity::Note => DiagnosticSeverity::INFORMATION,
+            };
             diags.push(Diagnostic {
                 range: Range { start, end },
                 message,
+                severity: Some(severity),
+                code: diagnostic.code().map(NumberOrString::String),
                 ..Diagnostic::default()
             });
         }
     }
 
+    /// Returns the identifier token at a position, the file containing it, and the definition it
+    /// refers to, if it can be renamed. Items of the core library and modules in their own files
+    /// cannot be renamed.
+    fn renamed_identifier(
+        &self,
+        db: &RootDatabase,
+        file: FileId,
+        position: Position,
+    ) -> Result<Option<(FileId, SyntaxNode, references::IdentifierDefinition)>> {
+        let Some((file, node)) = get_node(db, file, position) else {
+            return Ok(None);
+        };
+        let Some(lookup_items) = get_lookup_items(db, file, node.clone()) else {
+            return Ok(None);
+        };
+        let Some(identifier_definition) = identifier_definition(db, file, &node, &lookup_items)
+        else {
+            return Ok(None);
+        };
+        let (definition_file, stable_ptr) = identifier_definition.definition;
+        if db.lookup_intern_stable_ptr(stable_ptr) == SyntaxStablePtr::Root {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                "Modules defined in their own files cannot be renamed.",
+            ));
+        }
+        let core_crate = db.core_module().owning_crate(db.upcast());
+        let is_core = db
+            .file_modules(definition_file)
+            .unwrap_or_default()
+            .into_iter()
+            .any(|module_id| module_id.owning_crate(db.upcast()) == core_crate);
+        if is_core {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                "Items of the core library cannot be renamed.",
+            ));
+        }
+        Ok(Some((file, node, identifier_definition)))
+    }
+
+    /// Returns true if the span is the name in the declaration of `definition`.
+    fn is_definition_name(
+        &self,
+        db: &RootDatabase,
+        definition: references::Definition,
+        file: FileId,
+        span: TextSpan,
+    ) -> bool {
+        let (definition_file, stable_ptr) = definition;
+        let Ok(syntax) = db.file_syntax(definition_file) else {
+            return false;
+        };
+        let node = syntax.as_syntax_node().lookup_ptr(db.upcast(), stable_ptr);
+        matches!(
+            map_to_origin(db, definition_file, node.span_without_trivia(db.upcast())),
+            Some((origin_file, origin_span)) if origin_file == file && origin_span.contains(span)
+        )
+    }
+
     pub async fn vfs_provide(
         &self,
         params: ProvideVirtualFileRequest,
@@ -191,22 +432,36 @@ impl Backend {
         let file_id = self.file(&db, params.uri);
         Ok(ProvideVirtualFileResponse { content: db.file_content(file_id).map(|s| (*s).clone()) })
     }
+
+    /// Handles `textDocument/inlayHint`, which tower-lsp doesn't provide a method for.
+    pub async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        Ok(Some(inlay_hints(&*db, file, params.range)))
+    }
 }
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        self.state_mutex.lock().await.work_done_progress = params
+            .capabilities
+            .window
+            .and_then(|window| window.work_done_progress)
+            .unwrap_or_default();
         Ok(InitializeResult {
             server_info: None,
+            offset_encoding: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
-                    trigger_characters: Some(vec![".".to_string()]),
+                    trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                     work_done_progress_options: Default::default(),
                     all_commit_characters: None,
+                    completion_item: None,
                 }),
                 execute_command_provider: Some(ExecuteCommandOptions {
                     commands: vec!["dummy.do_something".to_string()],
@@ -231,8 +486,27 @@ impl LanguageServer for Backend {
                     .into(),
                 ),
                 document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                    first_trigger_character: "}".to_string(),
+                    more_trigger_character: Some(vec![";".to_string()]),
+                }),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: Default::default(),
+                }),
+                inlay_hint_provider: Some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -270,33 +544,79 @@ impl LanguageServer for Backend {
         let mut db = self.db().await;
         let uri = params.text_document.uri;
         let path = uri.path();
-        detect_crate_for(&mut db, path);
+        let crate_detection = detect_crate_for(&mut db, path);
 
         let file = self.file(&db, uri.clone());
-        self.state_mutex.lock().await.open_files.insert(file);
+        self.out_of_sync_files.lock().await.remove(&file);
         drop(db);
-        self.refresh_diagnostics().await;
+        if let Err(message) = crate_detection {
+            eprintln!("{message}");
+            self.client.show_message(MessageType::ERROR, message).await;
+        }
+        self.state_mutex.lock().await.open_files.insert(file);
+        self.refresh_diagnostics();
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let text =
-            if let [TextDocumentContentChangeEvent { text, .. }] = &params.content_changes[..] {
-                text
-            } else {
-                eprintln!("Unexpected format of document change.");
-                return;
-            };
         let mut db = self.db().await;
         let uri = params.text_document.uri;
         let file = self.file(&db, uri.clone());
-        db.override_file_content(file, Some(Arc::new(text.into())));
+        let mut out_of_sync_files = self.out_of_sync_files.lock().await;
+        let Some(content) = db.file_content(file) else {
+            eprintln!("Unexpected document change. File '{uri}' does not exist.");
+            return;
+        };
+        // The text is None while it is out of sync, until a change sends the full text again.
+        let mut text =
+            if out_of_sync_files.contains(&file) { None } else { Some((*content).clone()) };
+        for change in params.content_changes {
+            let Some(range) = change.range else {
+                text = Some(change.text);
+                continue;
+            };
+            let Some(current_text) = &mut text else {
+                continue;
+            };
+            let (Some(start), Some(end)) = (
+                position_to_byte_offset(current_text, range.start),
+                position_to_byte_offset(current_text, range.end),
+            ) else {
+                eprintln!("Unexpected format of document change. Range out of bounds.");
+                text = None;
+                continue;
+            };
+            if start > end {
+                eprintln!("Unexpected format of document change. Range start is after its end.");
+                text = None;
+                continue;
+            }
+            current_text.replace_range(start..end, &change.text);
+        }
+        match text {
+            Some(text) => {
+                out_of_sync_files.remove(&file);
+                db.override_file_content(file, Some(Arc::new(text)));
+            }
+            None => {
+                if out_of_sync_files.insert(file) {
+                    eprintln!(
+                        "Document '{uri}' is out of sync. Using its saved content until it is \
+                         saved or reopened."
+                    );
+                }
+                PrivRawFileContentQuery.in_db_mut(db.as_files_group_mut()).invalidate(&file);
+                db.override_file_content(file, None);
+            }
+        }
+        drop(out_of_sync_files);
         drop(db);
-        self.refresh_diagnostics().await;
+        self.refresh_diagnostics();
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         let mut db = self.db().await;
         let file = self.file(&db, params.text_document.uri);
+        self.out_of_sync_files.lock().await.remove(&file);
         PrivRawFileContentQuery.in_db_mut(db.as_files_group_mut()).invalidate(&file);
         db.override_file_content(file, None);
     }
@@ -304,17 +624,36 @@ impl LanguageServer for Backend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let mut db = self.db().await;
         let file = self.file(&db, params.text_document.uri);
-        self.state_mutex.lock().await.open_files.remove(&file);
+        self.out_of_sync_files.lock().await.remove(&file);
         db.override_file_content(file, None);
         drop(db);
-        self.refresh_diagnostics().await;
+        self.state_mutex.lock().await.open_files.remove(&file);
+        self.refresh_diagnostics();
     }
 
-    async fn completion(&self, _: CompletionParams) -> Result<Option<CompletionResponse>> {
-        Ok(Some(CompletionResponse::Array(vec![
-            CompletionItem::new_simple("Hello".to_string(), "Some detail".to_string()),
-            CompletionItem::new_simple("Bye".to_string(), "More detail".to_string()),
-        ])))
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let db = self.db().await;
+        let file_uri = params.text_document_position.text_document.uri;
+        eprintln!("Complete {file_uri}");
+        let file = self.file(&db, file_uri);
+        // Look at the token before the cursor, which is the one being completed.
+        let mut position = params.text_document_position.position;
+        position.character = position.character.saturating_sub(1);
+        let Some((file, node)) = get_node(&*db, file, position) else {
+            return Ok(None);
+        };
+        let Some(lookup_items) = get_lookup_items(&*db, file, node.clone()) else {
+            return Ok(None);
+        };
+        let Some(module_file_id) = find_node_module_file(&*db, file, node.clone()) else {
+            return Ok(None);
+        };
+        Ok(Some(CompletionResponse::Array(completion_items(
+            &*db,
+            module_file_id,
+            node,
+            &lookup_items,
+        ))))
     }
 
     async fn semantic_tokens_full(
@@ -339,40 +678,27 @@ impl LanguageServer for Backend {
 
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
         let db = self.db().await;
-        let file_uri = params.text_document.uri;
-        let file = self.file(&db, file_uri.clone());
-        let syntax = if let Ok(syntax) = db.file_syntax(file) {
-            syntax
-        } else {
-            eprintln!("Formatting failed. File '{file_uri}' does not exist.");
-            return Ok(None);
-        };
-        let new_text = get_formatted_file(
-            (*db).upcast(),
-            &syntax.as_syntax_node(),
-            FormatterConfig::default(),
-        );
-
-        let file_summary = if let Some(summary) = db.file_summary(file) {
-            summary
-        } else {
-            eprintln!("Formatting failed. Cannot get summary for file '{file_uri}'.");
-            return Ok(None);
-        };
-        let old_line_count = if let Ok(count) = file_summary.line_count().try_into() {
-            count
-        } else {
-            eprintln!("Formatting failed. Line count out of bound in file '{file_uri}'.");
-            return Ok(None);
-        };
+        let file = self.file(&db, params.text_document.uri);
+        Ok(format_file(&*db, file))
+    }
 
-        Ok(Some(vec![TextEdit {
-            range: Range {
-                start: Position { line: 0, character: 0 },
-                end: Position { line: old_line_count, character: 0 },
-            },
-            new_text,
-        }]))
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        Ok(format_range(&*db, file, params.range))
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document_position.text_document.uri);
+        let position = params.text_document_position.position;
+        Ok(format_on_type(&*db, file, position, &params.ch))
     }
 
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
@@ -425,67 +751,15 @@ impl LanguageServer for Backend {
             }
             let identifier =
                 ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
-            let Some(item) = db.lookup_resolved_generic_item_by_ptr(
-                lookup_item_id, identifier.stable_ptr())
-            else { continue; };
-
-            let defs_db = (*db).upcast();
-            let (module_id, file_index, stable_ptr) = match item {
-                ResolvedGenericItem::Constant(item) => (
-                    item.parent_module(defs_db),
-                    item.file_index(defs_db),
-                    item.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::Module(item) => {
-                    (item, FileIndex(0), db.intern_stable_ptr(SyntaxStablePtr::Root))
-                }
-                ResolvedGenericItem::GenericFunction(item) => {
-                    let sig = item.signature((*db).upcast());
-                    (
-                        sig.parent_module(defs_db),
-                        sig.file_index(defs_db),
-                        sig.untyped_stable_ptr(defs_db),
-                    )
-                }
-                ResolvedGenericItem::GenericType(generic_type) => (
-                    generic_type.parent_module(defs_db),
-                    generic_type.file_index(defs_db),
-                    generic_type.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::GenericTypeAlias(type_alias) => (
-                    type_alias.parent_module(defs_db),
-                    type_alias.file_index(defs_db),
-                    type_alias.untyped_stable_ptr(defs_db),
-                ),
-                ResolvedGenericItem::Variant(variant) => (
-                    variant.id.parent_module(defs_db),
-                    variant.id.file_index(defs_db),
-                    variant.id.stable_ptr(defs_db).untyped(),
-                ),
-                ResolvedGenericItem::Trait(trt) => (
-                    trt.parent_module(defs_db),
-                    trt.file_index(defs_db),
-                    trt.stable_ptr(defs_db).untyped(),
-                ),
-                ResolvedGenericItem::Impl(imp) => (
-                    imp.parent_module(defs_db),
-                    imp.file_index(defs_db),
-                    imp.stable_ptr(defs_db).untyped(),
-                ),
-                ResolvedGenericItem::TraitFunction(trait_function) => (
-                    trait_function.parent_module(defs_db),
-                    trait_function.file_index(defs_db),
-                    trait_function.stable_ptr(defs_db).untyped(),
-                ),
+            let Some(item) =
+                db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+            else {
+                continue;
             };
 
-            let file = if let Ok(files) = db.module_files(module_id) {
-                files[file_index.0]
-            } else {
+            let Some((file, stable_ptr)) = resolved_item_definition(&*db, &item) else {
                 return Ok(None);
             };
-
-            let uri = self.get_uri(&db, file);
             let syntax = if let Ok(syntax) = db.file_syntax(file) {
                 syntax
             } else {
@@ -493,8 +767,12 @@ impl LanguageServer for Backend {
                 return Ok(None);
             };
             let node = syntax.as_syntax_node().lookup_ptr(syntax_db, stable_ptr);
-            let span = node.span_without_trivia(syntax_db);
+            let Some((file, span)) = map_to_origin(&*db, file, node.span_without_trivia(syntax_db))
+            else {
+                return Ok(None);
+            };
 
+            let uri = self.get_uri(&db, file);
             let start = from_pos(span.start.position_in_file((*db).upcast(), file).unwrap());
             let end = from_pos(span.end.position_in_file((*db).upcast(), file).unwrap());
 
@@ -505,6 +783,123 @@ impl LanguageServer for Backend {
         }
         return Ok(None);
     }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let db = self.db().await;
+        let file_uri = params.text_document_position.text_document.uri;
+        eprintln!("References {file_uri}");
+        let file = self.file(&db, file_uri);
+        let position = params.text_document_position.position;
+        let Some((file, node)) = get_node(&*db, file, position) else {
+            return Ok(None);
+        };
+        let Some(lookup_items) = get_lookup_items(&*db, file, node.clone()) else {
+            return Ok(None);
+        };
+        let Some(identifier_definition) = identifier_definition(&*db, file, &node, &lookup_items)
+        else {
+            return Ok(None);
+        };
+        let references =
+            find_references(&*db, &identifier_definition.name, identifier_definition.definition);
+
+        let mut locations = vec![];
+        for (file, span) in references {
+            if !params.context.include_declaration
+                && self.is_definition_name(&db, identifier_definition.definition, file, span)
+            {
+                continue;
+            }
+            let Some(range) = get_range((*db).upcast(), file, span) else {
+                continue;
+            };
+            locations.push(Location { uri: self.get_uri(&db, file), range });
+        }
+        Ok(Some(locations))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        let Some((file, node, _)) = self.renamed_identifier(&db, file, params.position)? else {
+            return Ok(None);
+        };
+        let Some((file, span)) =
+            map_to_origin(&*db, file, node.span_without_trivia((*db).upcast()))
+        else {
+            return Ok(None);
+        };
+        Ok(get_range((*db).upcast(), file, span).map(PrepareRenameResponse::Range))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let db = self.db().await;
+        let file_uri = params.text_document_position.text_document.uri;
+        eprintln!("Rename {file_uri}");
+        if !is_valid_identifier(&params.new_name) {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "`{}` is not a valid identifier.",
+                params.new_name
+            )));
+        }
+        let file = self.file(&db, file_uri);
+        let position = params.text_document_position.position;
+        let Some((_, _, identifier_definition)) = self.renamed_identifier(&db, file, position)?
+        else {
+            return Ok(None);
+        };
+        let references =
+            find_references(&*db, &identifier_definition.name, identifier_definition.definition);
+
+        let mut changes = HashMap::<Url, Vec<TextEdit>>::new();
+        for (file, span) in references {
+            let Some(range) = get_range((*db).upcast(), file, span) else {
+                continue;
+            };
+            changes
+                .entry(self.get_uri(&db, file))
+                .or_default()
+                .push(TextEdit { range, new_text: params.new_name.clone() });
+        }
+        Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        Ok(document_symbols(&*db, file).map(DocumentSymbolResponse::Nested))
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Result<Option<Vec<SymbolInformation>>> {
+        let db = self.db().await;
+        Ok(Some(workspace_symbols(&*db, &params.query, |file| self.get_uri(&db, file))))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let db = self.db().await;
+        let file = self.file(&db, params.text_document.uri);
+        Ok(Some(code_actions(&*db, file, params.range, |file| self.get_uri(&db, file))))
+    }
+
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let db = self.db().await;
+        let file_uri = params.text_document_position_params.text_document.uri;
+        let file = self.file(&db, file_uri);
+        let position = params.text_document_position_params.position;
+        let Some((file, offset)) = get_offset(&*db, file, position) else {
+            return Ok(None);
+        };
+        Ok(signature_help(&*db, file, offset))
+    }
 }
 
 /// If the ast node is a lookup item, return the corresponding id. Otherwise, return None.
@@ -577,6 +972,12 @@ fn lookup_item_from_ast(
         SyntaxKind::ItemUse => Some(LookupItemId::ModuleItem(ModuleItemId::Use(db.intern_use(
             UseLongId(module_file_id, ast::ItemUse::from_syntax_node(syntax_db, node).stable_ptr()),
         )))),
+        SyntaxKind::ItemModule => Some(LookupItemId::ModuleItem(ModuleItemId::Submodule(
+            db.intern_submodule(SubmoduleLongId(
+                module_file_id,
+                ast::ItemModule::from_syntax_node(syntax_db, node).stable_ptr(),
+            )),
+        ))),
         _ => None,
     }
 }
@@ -588,14 +989,39 @@ fn get_node_and_lookup_items(
     file: FileId,
     position: Position,
 ) -> Option<(SyntaxNode, Vec<LookupItemId>)> {
-    let mut res = Vec::new();
+    let (file, node) = get_node(db, file, position)?;
+    let lookup_items = get_lookup_items(db, file, node.clone())?;
+    Some((node, lookup_items))
+}
+
+/// Given a position in a file, return the syntax node for the token at that position, and the file
+/// containing it. If the position lies in code that a plugin copied into a generated file, the node
+/// is taken from the generated file, since only it is semantically analyzed.
+fn get_node(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<(FileId, SyntaxNode)> {
     let syntax_db = db.upcast();
     let filename = file.file_name(db.upcast());
+    let (file, offset) = get_offset(db, file, position)?;
 
     // Get syntax for file.
     let syntax = db.file_syntax(file).to_option().on_none(|| {
         eprintln!("Formatting failed. File '{filename}' does not exist.");
     })?;
+    Some((file, syntax.as_syntax_node().lookup_offset(syntax_db, offset)))
+}
+
+/// Given a position in a file, return the offset of that position, and the file containing it. If
+/// the position lies in code that a plugin copied into a generated file, the offset is in the
+/// generated file.
+fn get_offset(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    position: Position,
+) -> Option<(FileId, TextOffset)> {
+    let filename = file.file_name(db.upcast());
 
     // Get file summary and content.
     let file_summary = db.file_summary(file).on_none(|| {
@@ -616,17 +1042,24 @@ fn get_node_and_lookup_items(
         })?;
         offset = offset.add_width(TextWidth::from_char(c));
     }
-    let node = syntax.as_syntax_node().lookup_offset(syntax_db, offset);
+    Some(map_to_generated(db, file, offset))
+}
+
+/// Returns all the lookup items above a syntax node of a file.
+fn get_lookup_items(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<Vec<LookupItemId>> {
+    let mut res = Vec::new();
 
     // Find module.
-    let module_id = find_node_module(db, file, node.clone()).on_none(|| {
+    let module_file_id = find_node_module_file(db, file, node.clone()).on_none(|| {
         eprintln!("Hover failed. Failed to find module.");
     })?;
-    let file_index = FileIndex(0);
-    let module_file_id = ModuleFileId(module_id, file_index);
 
     // Find containing function.
-    let mut item_node = node.clone();
+    let mut item_node = node;
     loop {
         if let Some(item) = lookup_item_from_ast(db, module_file_id, item_node.clone()) {
             res.push(item);
@@ -635,11 +1068,152 @@ fn get_node_and_lookup_items(
             Some(next_node) => {
                 item_node = next_node;
             }
-            None => return Some((node, res)),
+            None => return Some(res),
         }
     }
 }
 
+/// Finds the module file of a syntax node of a file. The file is usually the main file of the
+/// module, but may also be a file generated by a plugin.
+fn find_node_module_file(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<ModuleFileId> {
+    let module_id = find_node_module(db, file, node)?;
+    let file_index = db
+        .module_files(module_id)
+        .ok()
+        .and_then(|files| files.iter().position(|module_file| *module_file == file))
+        .unwrap_or(0);
+    Some(ModuleFileId(module_id, FileIndex(file_index)))
+}
+
+/// Returns the plugin auxiliary data of a file, if it was generated by a plugin, along with the
+/// module file it was generated from.
+fn generated_file_info(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+) -> Option<(DynPluginAuxData, ModuleFileId)> {
+    for module_id in db.file_modules(file).ok()? {
+        let Ok(files) = db.module_files(module_id) else {
+            continue;
+        };
+        let Some(file_index) = files.iter().position(|module_file| *module_file == file) else {
+            continue;
+        };
+        let Ok(file_infos) = db.module_generated_file_infos(module_id) else {
+            continue;
+        };
+        let Some(Some(file_info)) = file_infos.get(file_index) else {
+            continue;
+        };
+        let aux_data = file_info.aux_data.0.as_any().downcast_ref::<DynPluginAuxData>()?;
+        return Some((aux_data.clone(), file_info.origin));
+    }
+    None
+}
+
+/// Maps an offset in a file to the offset in a plugin generated file the code was copied to, if
+/// any. Otherwise, returns the original offset.
+fn map_to_generated(
+    db: &(dyn SemanticGroup + 'static),
+    mut file: FileId,
+    mut offset: TextOffset,
+) -> (FileId, TextOffset) {
+    'outer: loop {
+        for module_id in db.file_modules(file).unwrap_or_default() {
+            let files = db.module_files(module_id).unwrap_or_default();
+            let file_infos = db.module_generated_file_infos(module_id).unwrap_or_default();
+            for (generated_file, file_info) in zip_eq(files, file_infos) {
+                let Some(file_info) = file_info else {
+                    continue;
+                };
+                if db.module_file(file_info.origin) != Ok(file) {
+                    continue;
+                }
+                let Some(aux_data) =
+                    file_info.aux_data.0.as_any().downcast_ref::<DynPluginAuxData>()
+                else {
+                    continue;
+                };
+                let span = TextSpan { start: offset, end: offset };
+                if let Some(span) = aux_data.map_origin_span(db, span) {
+                    file = generated_file;
+                    offset = span.start;
+                    continue 'outer;
+                }
+            }
+        }
+        return (file, offset);
+    }
+}
+
+/// Maps a span in a file to the span of the user code it originates from. Spans in plugin
+/// generated files are mapped back to the code they were copied from. Returns None if the span
+/// was generated by a plugin rather than copied.
+fn map_to_origin(
+    db: &(dyn SemanticGroup + 'static),
+    mut file: FileId,
+    mut span: TextSpan,
+) -> Option<(FileId, TextSpan)> {
+    while let Some((aux_data, origin)) = generated_file_info(db, file) {
+        span = aux_data.map_span(db, span)?;
+        file = db.module_file(origin).ok()?;
+    }
+    Some((file, span))
+}
+
+/// Returns the file and the stable pointer of the definition of a resolved item.
+fn resolved_item_definition(
+    db: &(dyn SemanticGroup + 'static),
+    item: &ResolvedGenericItem,
+) -> Option<(FileId, SyntaxStablePtrId)> {
+    let defs_db = db.upcast();
+    let (module_id, file_index, stable_ptr) = match item {
+        ResolvedGenericItem::Constant(item) => (
+            item.parent_module(defs_db),
+            item.file_index(defs_db),
+            item.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::Module(item) => {
+            (*item, FileIndex(0), db.intern_stable_ptr(SyntaxStablePtr::Root))
+        }
+        ResolvedGenericItem::GenericFunction(item) => {
+            let sig = item.signature(db).to_option()?;
+            (sig.parent_module(defs_db), sig.file_index(defs_db), sig.untyped_stable_ptr(defs_db))
+        }
+        ResolvedGenericItem::GenericType(generic_type) => (
+            generic_type.parent_module(defs_db),
+            generic_type.file_index(defs_db),
+            generic_type.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::GenericTypeAlias(type_alias) => (
+            type_alias.parent_module(defs_db),
+            type_alias.file_index(defs_db),
+            type_alias.untyped_stable_ptr(defs_db),
+        ),
+        ResolvedGenericItem::Variant(variant) => (
+            variant.id.parent_module(defs_db),
+            variant.id.file_index(defs_db),
+            variant.id.stable_ptr(defs_db).untyped(),
+        ),
+        ResolvedGenericItem::Trait(trt) => {
+            (trt.parent_module(defs_db), trt.file_index(defs_db), trt.stable_ptr(defs_db).untyped())
+        }
+        ResolvedGenericItem::Impl(imp) => {
+            (imp.parent_module(defs_db), imp.file_index(defs_db), imp.stable_ptr(defs_db).untyped())
+        }
+        ResolvedGenericItem::TraitFunction(trait_function) => (
+            trait_function.parent_module(defs_db),
+            trait_function.file_index(defs_db),
+            trait_function.stable_ptr(defs_db).untyped(),
+        ),
+    };
+    let file = *db.module_files(module_id).ok()?.get(file_index.0)?;
+    Some((file, stable_ptr))
+}
+
 fn find_node_module(
     db: &(dyn SemanticGroup + 'static),
     main_file: FileId,
@@ -730,17 +1304,25 @@ fn is_expr(kind: SyntaxKind) -> bool {
 }
 
 /// Tries to detect the crate root the config that contains a cairo file, and add it to the system.
-fn detect_crate_for(db: &mut RootDatabase, file_path: &str) {
+/// Returns an error message if the project config or the file could not be loaded.
+fn detect_crate_for(db: &mut RootDatabase, file_path: &str) -> std::result::Result<(), String> {
     let mut path = PathBuf::from(file_path);
     for _ in 0..MAX_CRATE_DETECTION_DEPTH {
         path.pop();
-        if let Ok(config) = ProjectConfig::from_directory(path.as_path()) {
-            update_crate_roots_from_project_config(db, config);
-            return;
+        let config = match ProjectConfig::from_directory(path.as_path()) {
+            Ok(config) => config,
+            Err(DeserializationError::IoError(err)) if err.kind() == ErrorKind::NotFound => {
+                continue;
+            }
+            Err(err) => {
+                return Err(format!("Error loading the project config of {file_path}: {err}"));
+            }
         };
+        return update_crate_roots_from_project_config(db, config)
+            .map_err(|err| format!("Error loading the project config of {file_path}: {err}"));
     }
     // Fallback to a single file.
-    if let Err(err) = setup_project(&mut *db, PathBuf::from(file_path).as_path()) {
-        eprintln!("Error loading file {file_path} as a single crate: {err}");
-    }
+    setup_project(&mut *db, PathBuf::from(file_path).as_path())
+        .map(|_| ())
+        .map_err(|err| format!("Error loading file {file_path} as a single crate: {err}"))
 }
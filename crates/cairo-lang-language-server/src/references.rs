@@ -0,0 +1,168 @@
+//! Finding the references of items, used for find-references and rename.
+
+use cairo_lang_defs::ids::{LookupItemId, ModuleId, SubmoduleLongId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::resolve_path::ResolvedGenericItem;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+
+use crate::{find_node_module_file, get_lookup_items, map_to_origin, resolved_item_definition};
+
+/// The definition of an item, identified by the file and the stable pointer of its declaration.
+pub type Definition = (FileId, SyntaxStablePtrId);
+
+/// An identifier referring to a definition.
+pub struct IdentifierDefinition {
+    /// The identifier's name.
+    pub name: String,
+    /// The definition the identifier refers to.
+    pub definition: Definition,
+}
+
+/// Returns the definition the identifier token `node` in `file` refers to, either as a usage of the
+/// item or as the name in its declaration. `lookup_items` are the lookup items containing the
+/// token.
+pub fn identifier_definition(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    node: &SyntaxNode,
+    lookup_items: &[LookupItemId],
+) -> Option<IdentifierDefinition> {
+    let syntax_db = db.upcast();
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let terminal = node.parent()?;
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, terminal.clone());
+    let definition = lookup_items
+        .iter()
+        .find_map(|lookup_item_id| {
+            db.lookup_resolved_generic_item_by_ptr(*lookup_item_id, identifier.stable_ptr())
+        })
+        .and_then(|item| resolved_item_definition(db, &item))
+        .or_else(|| declaration_definition(db, file, terminal))?;
+    Some(IdentifierDefinition { name: identifier.text(syntax_db).into(), definition })
+}
+
+/// If the identifier `terminal` is the name of an item declaration, returns the definition of the
+/// declared item.
+fn declaration_definition(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    terminal: SyntaxNode,
+) -> Option<Definition> {
+    let syntax_db = db.upcast();
+    let parent = terminal.parent()?;
+    // The name is the only identifier directly under the supported declarations. Other identifiers
+    // are nested in paths and expressions.
+    let declaration = match parent.kind(syntax_db) {
+        SyntaxKind::FunctionDeclaration => parent.parent()?,
+        SyntaxKind::Member => {
+            // Only enum variants are items. Struct members are not.
+            let item = parent.parent()?.parent()?;
+            if item.kind(syntax_db) != SyntaxKind::ItemEnum {
+                return None;
+            }
+            parent
+        }
+        SyntaxKind::ItemModule => {
+            let module_file_id = find_node_module_file(db, file, parent.clone())?;
+            let submodule_id = db.intern_submodule(SubmoduleLongId(
+                module_file_id,
+                ast::ItemModule::from_syntax_node(syntax_db, parent).stable_ptr(),
+            ));
+            return resolved_item_definition(
+                db,
+                &ResolvedGenericItem::Module(ModuleId::Submodule(submodule_id)),
+            );
+        }
+        SyntaxKind::ItemConstant
+        | SyntaxKind::ItemStruct
+        | SyntaxKind::ItemEnum
+        | SyntaxKind::ItemTypeAlias
+        | SyntaxKind::ItemTrait
+        | SyntaxKind::ItemImpl
+        | SyntaxKind::ItemExternType => parent,
+        _ => return None,
+    };
+    Some((file, declaration.stable_ptr()))
+}
+
+/// Returns the spans of all the identifiers named `name` referring to `definition`, in all the
+/// files of all the crates. Identifiers in plugin generated files are mapped back to the code they
+/// were copied from, and dropped if they were generated.
+pub fn find_references(
+    db: &(dyn SemanticGroup + 'static),
+    name: &str,
+    definition: Definition,
+) -> OrderedHashSet<(FileId, TextSpan)> {
+    let mut files = OrderedHashSet::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            for file in db.module_files(*module_id).unwrap_or_default() {
+                files.insert(file);
+            }
+        }
+    }
+
+    let mut references = OrderedHashSet::default();
+    for file in files {
+        // Skip files that cannot contain the name.
+        match db.file_content(file) {
+            Some(content) if content.contains(name) => {}
+            _ => continue,
+        }
+        let Ok(syntax) = db.file_syntax(file) else {
+            continue;
+        };
+        let mut identifiers = vec![];
+        collect_identifiers(db, syntax.as_syntax_node(), name, &mut identifiers);
+        for node in identifiers {
+            let Some(lookup_items) = get_lookup_items(db, file, node.clone()) else {
+                continue;
+            };
+            let Some(identifier_definition) = identifier_definition(db, file, &node, &lookup_items)
+            else {
+                continue;
+            };
+            if identifier_definition.definition != definition {
+                continue;
+            }
+            let span = node.parent().unwrap().span_without_trivia(db.upcast());
+            if let Some(reference) = map_to_origin(db, file, span) {
+                references.insert(reference);
+            }
+        }
+    }
+    references
+}
+
+/// Collects all the identifier tokens named `name` under `node`.
+fn collect_identifiers(
+    db: &(dyn SemanticGroup + 'static),
+    node: SyntaxNode,
+    name: &str,
+    identifiers: &mut Vec<SyntaxNode>,
+) {
+    let syntax_db = db.upcast();
+    if node.kind(syntax_db) == SyntaxKind::TokenIdentifier {
+        if node.text(syntax_db).as_deref() == Some(name) {
+            identifiers.push(node);
+        }
+        return;
+    }
+    for child in node.children(syntax_db) {
+        collect_identifiers(db, child, name, identifiers);
+    }
+}
+
+/// Returns true if `name` is a valid identifier.
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,138 @@
+//! Signature help, showing the signature of the function called at a position.
+
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::resolve_path::ResolvedGenericItem;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use tower_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+
+use crate::{get_lookup_items, resolved_item_definition};
+
+/// Returns the signature of the function called by the innermost call whose argument list contains
+/// `offset` in `file`, with the parameter at `offset` being the active one.
+pub fn signature_help(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    offset: TextOffset,
+) -> Option<SignatureHelp> {
+    let syntax_db = db.upcast();
+    let root = db.file_syntax(file).ok()?.as_syntax_node();
+    let mut node = root.lookup_offset(syntax_db, offset);
+    let call = loop {
+        node = node.parent()?;
+        if node.kind(syntax_db) != SyntaxKind::ExprFunctionCall {
+            continue;
+        }
+        let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, node.clone());
+        let arguments = call.arguments(syntax_db);
+        let args_start =
+            arguments.lparen(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db);
+        let args_end =
+            arguments.rparen(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db);
+        if args_start <= offset && offset <= args_end {
+            break call;
+        }
+    };
+
+    // Resolve the called function by the last segment of its path.
+    let identifier = match call.path(syntax_db).elements(syntax_db).last()? {
+        ast::PathSegment::Simple(segment) => segment.ident(syntax_db),
+        ast::PathSegment::WithGenericArgs(segment) => segment.ident(syntax_db),
+    };
+    let lookup_items = get_lookup_items(db, file, call.as_syntax_node())?;
+    let item = lookup_items.into_iter().find_map(|lookup_item_id| {
+        db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+    })?;
+    if !matches!(item, ResolvedGenericItem::GenericFunction(_)) {
+        return None;
+    }
+    let (definition_file, definition_ptr) = resolved_item_definition(db, &item)?;
+    let definition = db
+        .file_syntax(definition_file)
+        .ok()?
+        .as_syntax_node()
+        .lookup_ptr(syntax_db, definition_ptr);
+    let declaration = function_declaration(syntax_db, definition)?;
+    let signature = signature_information(syntax_db, &declaration);
+
+    // The active parameter is the number of argument separators before the offset.
+    let active_parameter = call
+        .arguments(syntax_db)
+        .args(syntax_db)
+        .as_syntax_node()
+        .children(syntax_db)
+        .filter(|child| {
+            child.kind(syntax_db) == SyntaxKind::TerminalComma
+                && child.span_end_without_trivia(syntax_db) <= offset
+        })
+        .count() as u32;
+    Some(SignatureHelp {
+        signatures: vec![signature],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// Returns the declaration of a function item: a free function, an extern function, or a function
+/// of a trait or an impl.
+fn function_declaration(
+    db: &dyn SyntaxGroup,
+    function_item: SyntaxNode,
+) -> Option<ast::FunctionDeclaration> {
+    let declaration = function_item
+        .children(db)
+        .find(|child| child.kind(db) == SyntaxKind::FunctionDeclaration)?;
+    Some(ast::FunctionDeclaration::from_syntax_node(db, declaration))
+}
+
+/// Returns the signature information of a function declaration, of the form
+/// `fn name<generic params>(params) -> return type implicits(implicits) nopanic`.
+fn signature_information(
+    db: &dyn SyntaxGroup,
+    declaration: &ast::FunctionDeclaration,
+) -> SignatureInformation {
+    let text = |node: SyntaxNode| node.get_text_without_trivia(db);
+    let signature = declaration.signature(db);
+
+    let mut label = format!("fn {}", text(declaration.name(db).as_syntax_node()));
+    label += &text(declaration.generic_params(db).as_syntax_node());
+    label += "(";
+    let mut parameters = vec![];
+    for (i, param) in signature.parameters(db).elements(db).into_iter().enumerate() {
+        if i > 0 {
+            label += ", ";
+        }
+        // Parameter offsets are in UTF-16 code units.
+        let start = label.encode_utf16().count() as u32;
+        label += &text(param.as_syntax_node());
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+    label += ")";
+    for suffix in [
+        signature.ret_ty(db).as_syntax_node(),
+        signature.implicits_clause(db).as_syntax_node(),
+        signature.optional_no_panic(db).as_syntax_node(),
+    ] {
+        let suffix = text(suffix);
+        if !suffix.is_empty() {
+            label += " ";
+            label += &suffix;
+        }
+    }
+
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: None,
+    }
+}
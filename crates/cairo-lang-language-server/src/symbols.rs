@@ -0,0 +1,251 @@
+//! Document and workspace symbols, outlining the items of files.
+
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_starknet::plugin::{
+    CONSTRUCTOR_ATTR, CONTRACT_ATTR, EVENT_ATTR, EXTERNAL_ATTR, VIEW_ATTR,
+};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use tower_lsp::lsp_types::{DocumentSymbol, Location, SymbolInformation, SymbolKind, Url};
+
+use crate::get_range;
+
+/// Returns the outline of the items of a file.
+pub fn document_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+) -> Option<Vec<DocumentSymbol>> {
+    let syntax = db.file_syntax(file).ok()?;
+    Some(item_symbols(db, file, syntax.items(db.upcast())))
+}
+
+/// Returns the symbols of all the items in all the files on disk whose name contains `query`,
+/// ignoring case. `get_uri` converts file ids to URIs.
+pub fn workspace_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    query: &str,
+    get_uri: impl Fn(FileId) -> Url,
+) -> Vec<SymbolInformation> {
+    let mut files = OrderedHashSet::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            for file in db.module_files(*module_id).unwrap_or_default() {
+                if matches!(db.lookup_intern_file(file), FileLongId::OnDisk(_)) {
+                    files.insert(file);
+                }
+            }
+        }
+    }
+
+    let query = query.to_lowercase();
+    let mut res = vec![];
+    for file in files {
+        let Some(symbols) = document_symbols(db, file) else {
+            continue;
+        };
+        flatten_symbols(symbols, None, &query, &get_uri(file), &mut res);
+    }
+    res
+}
+
+/// Flattens nested document symbols into symbol information, keeping the ones whose name contains
+/// the lowercase `query`.
+#[allow(deprecated)]
+fn flatten_symbols(
+    symbols: Vec<DocumentSymbol>,
+    container_name: Option<String>,
+    query: &str,
+    uri: &Url,
+    res: &mut Vec<SymbolInformation>,
+) {
+    for symbol in symbols {
+        if symbol.name.to_lowercase().contains(query) {
+            res.push(SymbolInformation {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location: Location { uri: uri.clone(), range: symbol.selection_range },
+                container_name: container_name.clone(),
+            });
+        }
+        if let Some(children) = symbol.children {
+            flatten_symbols(children, Some(symbol.name), query, uri, res);
+        }
+    }
+}
+
+/// Returns the symbols of a list of items.
+fn item_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    items: ast::ItemList,
+) -> Vec<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    items.elements(syntax_db).into_iter().filter_map(|item| item_symbol(db, file, item)).collect()
+}
+
+/// Returns the symbol of an item, along with the symbols of its inner items. Returns None for
+/// items that are not outlined, such as `use` items.
+fn item_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    item: ast::Item,
+) -> Option<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    let (name, kind, detail, children) = match &item {
+        ast::Item::Constant(constant) => {
+            (constant.name(syntax_db), SymbolKind::CONSTANT, None, None)
+        }
+        ast::Item::Module(module) => {
+            let children = match module.body(syntax_db) {
+                ast::MaybeModuleBody::Some(body) => item_symbols(db, file, body.items(syntax_db)),
+                ast::MaybeModuleBody::None(_) => vec![],
+            };
+            let detail =
+                module.has_attr(syntax_db, CONTRACT_ATTR).then(|| CONTRACT_ATTR.to_string());
+            (module.name(syntax_db), SymbolKind::MODULE, detail, Some(children))
+        }
+        ast::Item::Use(_) => return None,
+        ast::Item::FreeFunction(function) => (
+            function.declaration(syntax_db).name(syntax_db),
+            SymbolKind::FUNCTION,
+            entry_point_kind(syntax_db, function),
+            None,
+        ),
+        ast::Item::ExternFunction(function) => {
+            (function.declaration(syntax_db).name(syntax_db), SymbolKind::FUNCTION, None, None)
+        }
+        ast::Item::ExternType(extern_type) => {
+            (extern_type.name(syntax_db), SymbolKind::STRUCT, None, None)
+        }
+        ast::Item::Trait(trait_ast) => {
+            let children = match trait_ast.body(syntax_db) {
+                ast::MaybeTraitBody::Some(body) => body
+                    .items(syntax_db)
+                    .elements(syntax_db)
+                    .into_iter()
+                    .filter_map(|trait_item| match trait_item {
+                        ast::TraitItem::Function(function) => new_symbol(
+                            db,
+                            file,
+                            function.declaration(syntax_db).name(syntax_db),
+                            SymbolKind::METHOD,
+                            None,
+                            function.as_syntax_node().span_without_trivia(syntax_db),
+                            None,
+                        ),
+                    })
+                    .collect(),
+                ast::MaybeTraitBody::None(_) => vec![],
+            };
+            (trait_ast.name(syntax_db), SymbolKind::INTERFACE, None, Some(children))
+        }
+        ast::Item::Impl(impl_ast) => {
+            let children = match impl_ast.body(syntax_db) {
+                ast::MaybeImplBody::Some(body) => {
+                    let mut children = item_symbols(db, file, body.items(syntax_db));
+                    for child in &mut children {
+                        if child.kind == SymbolKind::FUNCTION {
+                            child.kind = SymbolKind::METHOD;
+                        }
+                    }
+                    children
+                }
+                ast::MaybeImplBody::None(_) => vec![],
+            };
+            let detail = Some(
+                impl_ast.trait_path(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db),
+            );
+            (impl_ast.name(syntax_db), SymbolKind::OBJECT, detail, Some(children))
+        }
+        ast::Item::Struct(struct_ast) => {
+            let children =
+                member_symbols(db, file, struct_ast.members(syntax_db), SymbolKind::FIELD);
+            (struct_ast.name(syntax_db), SymbolKind::STRUCT, None, Some(children))
+        }
+        ast::Item::Enum(enum_ast) => {
+            let children =
+                member_symbols(db, file, enum_ast.variants(syntax_db), SymbolKind::ENUM_MEMBER);
+            (enum_ast.name(syntax_db), SymbolKind::ENUM, None, Some(children))
+        }
+        ast::Item::TypeAlias(type_alias) => {
+            (type_alias.name(syntax_db), SymbolKind::TYPE_PARAMETER, None, None)
+        }
+    };
+    new_symbol(
+        db,
+        file,
+        name,
+        kind,
+        detail,
+        item.as_syntax_node().span_without_trivia(syntax_db),
+        children,
+    )
+}
+
+/// Returns the symbols of the members of a struct or the variants of an enum.
+fn member_symbols(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    members: ast::MemberList,
+    kind: SymbolKind,
+) -> Vec<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    members
+        .elements(syntax_db)
+        .into_iter()
+        .filter_map(|member| {
+            new_symbol(
+                db,
+                file,
+                member.name(syntax_db),
+                kind,
+                None,
+                member.as_syntax_node().span_without_trivia(syntax_db),
+                None,
+            )
+        })
+        .collect()
+}
+
+/// Returns the StarkNet entry point attribute of a function, if any.
+fn entry_point_kind(db: &dyn SyntaxGroup, function: &ast::FunctionWithBody) -> Option<String> {
+    [EXTERNAL_ATTR, VIEW_ATTR, CONSTRUCTOR_ATTR, EVENT_ATTR]
+        .into_iter()
+        .find(|attr| function.has_attr(db, attr))
+        .map(|attr| attr.to_string())
+}
+
+/// Creates a document symbol named by the identifier `name`, spanning `span`.
+#[allow(deprecated)]
+fn new_symbol(
+    db: &(dyn SemanticGroup + 'static),
+    file: FileId,
+    name: ast::TerminalIdentifier,
+    kind: SymbolKind,
+    detail: Option<String>,
+    span: TextSpan,
+    children: Option<Vec<DocumentSymbol>>,
+) -> Option<DocumentSymbol> {
+    let syntax_db = db.upcast();
+    let files_db: &dyn FilesGroup = db.upcast();
+    let range = get_range(files_db, file, span)?;
+    let selection_range =
+        get_range(files_db, file, name.as_syntax_node().span_without_trivia(syntax_db))?;
+    Some(DocumentSymbol {
+        name: name.text(syntax_db).into(),
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range,
+        children,
+    })
+}
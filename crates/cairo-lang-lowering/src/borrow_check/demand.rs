@@ -35,7 +35,9 @@ impl Demand {
                 // Variable introduced, but not demanded. If it's not drop, that is an issue.
                 let var = &borrow_checker.lowered.variables[*var];
                 if !var.droppable {
-                    borrow_checker.diagnostics.report_by_location(var.location, VariableNotDropped);
+                    borrow_checker
+                        .diagnostics
+                        .report_by_location(var.location, VariableNotDropped { ty: var.ty });
                 }
             }
         }
@@ -178,7 +178,8 @@ impl<'a> BorrowChecker<'a> {
                     let var = &self.lowered.variables[*var];
                     #[allow(clippy::overly_complex_bool_expr)]
                     if false && !var.droppable {
-                        self.diagnostics.report_by_location(var.location, VariableNotDropped);
+                        self.diagnostics
+                            .report_by_location(var.location, VariableNotDropped { ty: var.ty });
                     }
                     // Report only once per variable.
                     break;
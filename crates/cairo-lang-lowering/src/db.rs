@@ -10,13 +10,17 @@ use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::TypeId;
 use cairo_lang_utils::Upcast;
 use semantic::corelib::core_crate;
-use semantic::items::functions::ConcreteFunctionWithBodyId;
+use semantic::items::functions::{
+    ConcreteFunctionWithBodyId, GenericFunctionWithBodyId, LoopFunctionId, LoopFunctionLongId,
+};
 
 use crate::borrow_check::borrow_check;
 use crate::concretize::concretize_lowered;
 use crate::diagnostic::LoweringDiagnostic;
 use crate::inline::{apply_inlining, PrivInlineData};
+use crate::lower::context::LoweringContextBuilder;
 use crate::lower::lower;
+use crate::lower::lower_loop::{lower_loop_function, LoopSignature};
 use crate::panic::lower_panics;
 use crate::{FlatLowered, Statement, StructuredLowered};
 
@@ -39,6 +43,16 @@ pub trait LoweringGroup: SemanticGroup + Upcast<dyn SemanticGroup> {
         function_id: FunctionWithBodyId,
     ) -> Maybe<Arc<FlatLowered>>;
 
+    /// Computes the variables captured by a loop, and the type it evaluates to.
+    #[salsa::invoke(crate::lower::lower_loop::loop_signature)]
+    fn loop_signature(&self, loop_function: LoopFunctionId) -> Maybe<Arc<LoopSignature>>;
+
+    /// Computes the lowered representation of the helper function of a loop.
+    fn priv_loop_function_lowered_flat(
+        &self,
+        loop_function: LoopFunctionId,
+    ) -> Maybe<Arc<FlatLowered>>;
+
     /// A concrete version of priv_function_with_body_lowered_flat
     fn priv_concrete_function_with_body_lowered_flat(
         &self,
@@ -152,19 +166,38 @@ fn priv_function_with_body_lowered_flat(
     function_id: FunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
     let structured = db.priv_function_with_body_lowered_structured(function_id)?;
-    let mut lowered = lower_panics(db, function_id, &structured)?;
+    let mut lowered =
+        lower_panics(db, &LoweringContextBuilder::new(db, function_id)?, &structured)?;
     borrow_check(function_id.module_file_id(db.upcast()), &mut lowered);
     Ok(Arc::new(lowered))
 }
 
+fn priv_loop_function_lowered_flat(
+    db: &dyn LoweringGroup,
+    loop_function: LoopFunctionId,
+) -> Maybe<Arc<FlatLowered>> {
+    let structured = lower_loop_function(db, loop_function)?;
+    let mut lowered =
+        lower_panics(db, &LoweringContextBuilder::new_loop(db, loop_function)?, &structured)?;
+    borrow_check(loop_function.parent(db.upcast()).module_file_id(db.upcast()), &mut lowered);
+    Ok(Arc::new(lowered))
+}
+
 fn priv_concrete_function_with_body_lowered_flat(
     db: &dyn LoweringGroup,
     function: ConcreteFunctionWithBodyId,
 ) -> Maybe<Arc<FlatLowered>> {
     let semantic_db = db.upcast();
-    let mut lowered = (*db
-        .priv_function_with_body_lowered_flat(function.function_with_body_id(semantic_db))?)
-    .clone();
+    let generic_lowered =
+        match db.lookup_intern_concrete_function_with_body(function).generic_function {
+            GenericFunctionWithBodyId::Loop(loop_function) => {
+                db.priv_loop_function_lowered_flat(loop_function)?
+            }
+            _ => db.priv_function_with_body_lowered_flat(
+                function.function_with_body_id(semantic_db),
+            )?,
+        };
+    let mut lowered = (*generic_lowered).clone();
     concretize_lowered(db, &mut lowered, &function.substitution(semantic_db)?);
     Ok(Arc::new(lowered))
 }
@@ -223,6 +256,21 @@ fn function_with_body_lowering_diagnostics(
             .map(|lowered| lowered.diagnostics.clone())
             .unwrap_or_default(),
     );
+
+    // Add the diagnostics of the helper functions of the loops in the function.
+    let function_body = db.function_body(function_id)?;
+    for (expr_id, expr) in function_body.exprs.iter() {
+        if !matches!(expr, semantic::Expr::Loop(_) | semantic::Expr::While(_)) {
+            continue;
+        }
+        let loop_function =
+            db.intern_loop_function(LoopFunctionLongId { parent: function_id, expr_id });
+        diagnostics.extend(
+            db.priv_loop_function_lowered_flat(loop_function)
+                .map(|lowered| lowered.diagnostics.clone())
+                .unwrap_or_default(),
+        );
+    }
     Ok(Arc::new(diagnostics.build()))
 }
 
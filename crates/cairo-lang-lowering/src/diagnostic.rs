@@ -1,9 +1,13 @@
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::ModuleFileId;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    variant_name, DiagnosticAdded, DiagnosticEntry, DiagnosticFix, DiagnosticLocation, Diagnostics,
+    DiagnosticsBuilder, Severity,
 };
+use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::fixes::derive_fix;
+use cairo_lang_semantic::lint::{lint_level, Lint};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 
 pub struct LoweringDiagnostics {
@@ -29,7 +33,24 @@ impl LoweringDiagnostics {
         stable_location: StableLocation,
         kind: LoweringDiagnosticKind,
     ) -> DiagnosticAdded {
-        self.diagnostics.add(LoweringDiagnostic { stable_location, kind })
+        self.diagnostics.add(LoweringDiagnostic {
+            stable_location,
+            kind,
+            severity: Severity::Error,
+        })
+    }
+    /// Reports a lint diagnostic, with the severity set for the lint in its location. Nothing is
+    /// reported if the lint is allowed.
+    pub fn report_lint(
+        &mut self,
+        db: &dyn SemanticGroup,
+        stable_ptr: SyntaxStablePtrId,
+        kind: LoweringDiagnosticKind,
+        lint: Lint,
+    ) -> Option<DiagnosticAdded> {
+        let stable_location = StableLocation::new(self.module_file_id, stable_ptr);
+        let severity = lint_level(db, &stable_location, lint).severity()?;
+        Some(self.diagnostics.add(LoweringDiagnostic { stable_location, kind, severity }))
     }
 }
 
@@ -37,26 +58,24 @@ impl LoweringDiagnostics {
 pub struct LoweringDiagnostic {
     pub stable_location: StableLocation,
     pub kind: LoweringDiagnosticKind,
+    pub severity: Severity,
 }
 impl DiagnosticEntry for LoweringDiagnostic {
     type DbType = dyn SemanticGroup;
 
+    fn code(&self) -> Option<String> {
+        Some(variant_name(&self.kind))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
     fn format(&self, _db: &Self::DbType) -> String {
         match &self.kind {
             LoweringDiagnosticKind::Unreachable { .. } => "Unreachable code".into(),
-            LoweringDiagnosticKind::NonZeroValueInMatch => {
-                "Match with a non-zero value is not supported.".into()
-            }
-            LoweringDiagnosticKind::OnlyMatchZeroIsSupported => {
-                "Only match zero (match ... { 0 => ..., _ => ... }) is currently supported.".into()
-            }
             LoweringDiagnosticKind::VariableMoved => "Variable was previously moved.".into(),
-            LoweringDiagnosticKind::VariableNotDropped => "Variable not dropped.".into(),
-            LoweringDiagnosticKind::UnsupportedMatch => "Unsupported match. Currently, matches \
-                                                         require one arm per variant, in the \
-                                                         order of variant definition."
-                .into(),
-            LoweringDiagnosticKind::UnsupportedMatchArm => "Unsupported match arm.".into(),
+            LoweringDiagnosticKind::VariableNotDropped { .. } => "Variable not dropped.".into(),
             LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself => {
                 "Cannot inline a function that might call itself.".into()
             }
@@ -94,19 +113,22 @@ impl DiagnosticEntry for LoweringDiagnostic {
         }
         self.stable_location.diagnostic_location(db.upcast())
     }
+
+    fn fixes(&self, db: &Self::DbType) -> Vec<DiagnosticFix> {
+        match &self.kind {
+            LoweringDiagnosticKind::VariableNotDropped { ty } => {
+                derive_fix(db, *ty, "Drop").into_iter().collect()
+            }
+            _ => vec![],
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum LoweringDiagnosticKind {
     Unreachable { last_statement_ptr: SyntaxStablePtrId },
-    // TODO(lior): Remove once supported.
-    NonZeroValueInMatch,
-    // TODO(lior): Remove once supported.
-    OnlyMatchZeroIsSupported,
     VariableMoved,
-    VariableNotDropped,
-    UnsupportedMatch,
-    UnsupportedMatchArm,
+    VariableNotDropped { ty: semantic::TypeId },
     CannotInlineFunctionThatMightCallItself,
     UnsupportedInlineArguments,
     RedundantInlineAttribute,
@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId};
 use cairo_lang_diagnostics::{skip_diagnostic, Diagnostics, Maybe};
+use cairo_lang_semantic::items::functions::GenericFunctionId;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_utils::extract_matches;
@@ -410,7 +411,10 @@ impl<'db> FunctionInlinerRewriter<'db> {
         if let Statement::Call(ref stmt) = statement {
             let concrete_function = self.ctx.db.lookup_intern_function(stmt.function).function;
             let semantic_db = self.ctx.db.upcast();
-            if let Some(function_id) = concrete_function.get_body(semantic_db) {
+            // Loop functions are recursive, and are never inlined.
+            let is_loop = matches!(concrete_function.generic_function, GenericFunctionId::Loop(_));
+            if let Some(function_id) = concrete_function.get_body(semantic_db).filter(|_| !is_loop)
+            {
                 let inline_data =
                     self.ctx.db.priv_inline_data(function_id.function_with_body_id(semantic_db))?;
 
@@ -6,6 +6,7 @@ use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::expr::fmt::ExprFormatter;
 use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::functions::LoopFunctionId;
 use cairo_lang_semantic::items::imp::ImplLookupContext;
 use cairo_lang_semantic::{Mutability, VarId};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
@@ -33,6 +34,9 @@ pub struct LoweringContextBuilder<'db> {
     pub ref_params: Vec<semantic::VarId>,
     /// The available implicits in this function.
     pub implicits: Vec<semantic::TypeId>,
+    /// The loop whose helper function is lowered, if any. Otherwise, the function with body itself
+    /// is lowered.
+    pub current_loop: Option<LoopFunctionId>,
 }
 impl<'db> LoweringContextBuilder<'db> {
     pub fn new(db: &'db dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<Self> {
@@ -52,8 +56,21 @@ impl<'db> LoweringContextBuilder<'db> {
             signature,
             ref_params,
             implicits,
+            current_loop: None,
         })
     }
+    /// Creates a builder for lowering the helper function of a loop. The helper function has the
+    /// implicits of the function containing the loop, takes the variables changed by the loop as
+    /// ref parameters, and returns the value the loop evaluates to.
+    pub fn new_loop(db: &'db dyn LoweringGroup, loop_function: LoopFunctionId) -> Maybe<Self> {
+        let mut builder = Self::new(db, loop_function.parent(db.upcast()))?;
+        let loop_signature = db.loop_signature(loop_function)?;
+        builder.signature.params = vec![];
+        builder.signature.return_type = loop_signature.return_type;
+        builder.ref_params = loop_signature.ref_params.iter().map(|var| var.id()).collect();
+        builder.current_loop = Some(loop_function);
+        Ok(builder)
+    }
     pub fn ctx<'a: 'db>(&'a self) -> Maybe<LoweringContext<'db>> {
         let generic_params = self.db.function_with_body_generic_params(self.function_id)?;
         Ok(LoweringContext {
@@ -69,6 +86,7 @@ impl<'db> LoweringContextBuilder<'db> {
             semantic_defs: UnorderedHashMap::default(),
             ref_params: &self.ref_params,
             implicits: &self.implicits,
+            current_loop: self.current_loop,
             lookup_context: ImplLookupContext {
                 module_id: self.function_id.parent_module(self.db.upcast()),
                 extra_modules: vec![],
@@ -101,6 +119,8 @@ pub struct LoweringContext<'db> {
     pub ref_params: &'db [semantic::VarId],
     // The available implicits in this function.
     pub implicits: &'db [semantic::TypeId],
+    /// The loop whose helper function is being lowered, if any.
+    pub current_loop: Option<LoopFunctionId>,
     // Lookup context for impls.
     pub lookup_context: ImplLookupContext,
     // Expression formatter of the free function.
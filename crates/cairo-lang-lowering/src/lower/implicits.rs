@@ -69,6 +69,10 @@ pub fn function_all_implicits(
         }
         GenericFunctionId::Impl(impl_function) => db
             .function_with_body_all_implicits_vec(FunctionWithBodyId::Impl(impl_function.function)),
+        // A loop function has the implicits of the function containing the loop.
+        GenericFunctionId::Loop(loop_function) => {
+            db.function_with_body_all_implicits_vec(loop_function.parent(db.upcast()))
+        }
         GenericFunctionId::Trait(_) => unreachable!(),
     }
 }
@@ -115,7 +119,8 @@ pub fn function_with_body_all_implicits(
                     // All implicits of a libfunc are explicit implicits.
                     db.extern_function_declaration_implicits(extern_function)?.into_iter().collect()
                 }
-                GenericFunctionId::Trait(_) => unreachable!(),
+                // Loops are not function calls in the semantic model.
+                GenericFunctionId::Trait(_) | GenericFunctionId::Loop(_) => unreachable!(),
             };
         all_implicits.extend(&current_implicits);
     }
@@ -189,6 +194,10 @@ pub fn function_may_panic(db: &dyn LoweringGroup, function: semantic::FunctionId
         GenericFunctionId::Extern(extern_function) => {
             Ok(db.extern_function_signature(extern_function)?.panicable)
         }
+        // A loop function may panic if the function containing the loop may.
+        GenericFunctionId::Loop(loop_function) => {
+            db.function_with_body_may_panic(loop_function.parent(db.upcast()))
+        }
         GenericFunctionId::Trait(_) => unreachable!(),
     }
 }
@@ -220,7 +229,8 @@ pub fn function_with_body_may_panic(
                     }
                     continue;
                 }
-                GenericFunctionId::Trait(_) => unreachable!(),
+                // Loops are not function calls in the semantic model.
+                GenericFunctionId::Trait(_) | GenericFunctionId::Loop(_) => unreachable!(),
             };
         if direct_callee_representative == scc_representative {
             // We already have the implicits of this SCC - do nothing.
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::FunctionWithBodyId;
+use cairo_lang_diagnostics::{DiagnosticAdded, Maybe, ToMaybe};
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::corelib;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::{extract_matches, try_extract_matches};
+use itertools::{chain, zip_eq};
+use semantic::items::functions::{LoopFunctionId, LoopFunctionLongId};
+
+use super::context::{
+    lowering_flow_error_to_sealed_block, LoweredExpr, LoweringContext, LoweringContextBuilder,
+    LoweringFlowError, LoweringResult, VarRequest,
+};
+use super::scope::{merge_sealed, BlockBuilder, SealedBlockBuilder};
+use super::usage::Usage;
+use super::{generators, lower_expr, lower_expr_block, lowered_expr_to_block_scope_end};
+use crate::db::LoweringGroup;
+use crate::{Statement, StatementMatchEnum, StructuredLowered, VariableId};
+
+/// The signature of the helper function of a loop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoopSignature {
+    /// The variables defined outside the loop and changed by it, passed as ref parameters.
+    pub ref_params: Vec<semantic::Variable>,
+    /// The other variables defined outside the loop and used by it.
+    pub params: Vec<semantic::Variable>,
+    /// The type the loop evaluates to. A loop that never breaks does not evaluate to a value, so
+    /// the result of its helper function is returned from the enclosing function instead, and this
+    /// is the return type of the enclosing function.
+    pub return_type: semantic::TypeId,
+}
+
+/// Query implementation of [crate::db::LoweringGroup::loop_signature].
+pub fn loop_signature(
+    db: &dyn LoweringGroup,
+    loop_function: LoopFunctionId,
+) -> Maybe<Arc<LoopSignature>> {
+    let LoopFunctionLongId { parent, expr_id } = db.lookup_intern_loop_function(loop_function);
+    let function_body = db.function_body(parent)?;
+    let definitions = variable_definitions(db, parent, &function_body)?;
+    let usage = Usage::from_expr(&function_body, expr_id);
+    let changed: OrderedHashSet<_> = usage.changed().collect();
+    let mut ref_params = vec![];
+    let mut params = vec![];
+    for var_id in usage.captured() {
+        let var = definitions.get(&var_id).cloned().to_maybe()?;
+        if changed.contains(&var_id) { ref_params.push(var) } else { params.push(var) }
+    }
+    let mut return_type = function_body.exprs[expr_id].ty();
+    if return_type == corelib::never_ty(db.upcast()) {
+        return_type = enclosing_return_type(db, parent, &function_body, expr_id)?;
+    }
+    Ok(Arc::new(LoopSignature { ref_params, params, return_type }))
+}
+
+/// Returns the return type of the function enclosing a loop expression: either the helper function
+/// of the innermost loop containing it, or the function with body itself.
+fn enclosing_return_type(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+    function_body: &semantic::FunctionBody,
+    expr_id: semantic::ExprId,
+) -> Maybe<semantic::TypeId> {
+    let enclosing_loop = function_body
+        .exprs
+        .iter()
+        .filter(|(id, expr)| {
+            *id != expr_id && matches!(expr, semantic::Expr::Loop(_) | semantic::Expr::While(_))
+        })
+        .map(|(id, _)| (id, Usage::from_expr(function_body, id).loops))
+        .filter(|(_, loops)| loops.contains(&expr_id))
+        // The innermost loop contains the least loops.
+        .min_by_key(|(_, loops)| loops.len());
+    Ok(match enclosing_loop {
+        Some((id, _)) => {
+            let loop_function =
+                db.intern_loop_function(LoopFunctionLongId { parent: function_id, expr_id: id });
+            db.loop_signature(loop_function)?.return_type
+        }
+        None => db.function_with_body_signature(function_id)?.return_type,
+    })
+}
+
+/// Returns the definitions of all the semantic variables of a function: its parameters and the
+/// variables bound by its patterns.
+fn variable_definitions(
+    db: &dyn LoweringGroup,
+    function_id: FunctionWithBodyId,
+    function_body: &semantic::FunctionBody,
+) -> Maybe<UnorderedHashMap<semantic::VarId, semantic::Variable>> {
+    let signature = db.function_with_body_signature(function_id)?;
+    let params = signature.params.into_iter().map(semantic::Variable::Param);
+    let let_patterns = function_body.statements.iter().filter_map(|(_, statement)| {
+        Some(&try_extract_matches!(statement, semantic::Statement::Let)?.pattern)
+    });
+    let arm_patterns = function_body
+        .exprs
+        .iter()
+        .filter_map(|(_, expr)| try_extract_matches!(expr, semantic::Expr::Match))
+        .flat_map(|expr| expr.arms.iter().map(|arm| &arm.pattern));
+    let locals = chain!(let_patterns, arm_patterns)
+        .flat_map(|pattern| pattern.variables())
+        .map(|variable| semantic::Variable::Local(variable.var.clone()));
+    Ok(chain!(params, locals).map(|var| (var.id(), var)).collect())
+}
+
+/// Lowers the helper function of a loop.
+pub fn lower_loop_function(
+    db: &dyn LoweringGroup,
+    loop_function: LoopFunctionId,
+) -> Maybe<StructuredLowered> {
+    log::trace!("Lowering a loop function.");
+    let function_id = loop_function.parent(db.upcast());
+    let has_semantic_errors = db.function_declaration_diagnostics(function_id).has_errors()
+        || db.function_body_diagnostics(function_id).has_errors();
+    let loop_signature = db.loop_signature(loop_function)?;
+
+    let lowering_builder = LoweringContextBuilder::new_loop(db, loop_function)?;
+    let mut ctx = lowering_builder.ctx()?;
+    let expr = &ctx.function_body.exprs[loop_function.expr_id(db.upcast())];
+    let location = ctx.get_location(expr.stable_ptr().untyped());
+
+    // Initialize scope.
+    let mut scope = BlockBuilder::root(&ctx);
+    for ty in ctx.implicits {
+        let var = scope.add_input(&mut ctx, VarRequest { ty: *ty, location });
+        scope.put_implicit(&mut ctx, *ty, var);
+    }
+    for semantic_var in chain!(&loop_signature.ref_params, &loop_signature.params) {
+        let var = scope.add_input(&mut ctx, VarRequest { ty: semantic_var.ty(), location });
+        scope.put_semantic(&mut ctx, semantic_var.id(), var);
+        ctx.semantic_defs.insert(semantic_var.id(), semantic_var.clone());
+    }
+    scope.bind_refs();
+    let root = if !has_semantic_errors {
+        let maybe_sealed_block = match expr {
+            semantic::Expr::Loop(expr) => lower_loop_iteration(&mut ctx, scope, expr.body),
+            semantic::Expr::While(expr) => lower_while_iteration(&mut ctx, scope, expr),
+            _ => unreachable!("Loop functions are only created for loop expressions."),
+        };
+        maybe_sealed_block.map(|block_sealed| match block_sealed {
+            SealedBlockBuilder::Ends(block) => ctx.blocks.alloc(block),
+            SealedBlockBuilder::GotoCallsite { .. } => {
+                unreachable!(
+                    "An iteration of a loop always ends by returning from the loop function."
+                )
+            }
+        })
+    } else {
+        Err(DiagnosticAdded)
+    };
+    Ok(StructuredLowered {
+        diagnostics: ctx.diagnostics.build(),
+        root,
+        variables: ctx.variables,
+        blocks: ctx.blocks,
+    })
+}
+
+/// Lowers the body of a loop, followed by a recursive call to the loop function for the next
+/// iteration.
+fn lower_loop_iteration(
+    ctx: &mut LoweringContext<'_>,
+    mut scope: BlockBuilder,
+    body: semantic::ExprId,
+) -> Maybe<SealedBlockBuilder> {
+    let block = extract_matches!(&ctx.function_body.exprs[body], semantic::Expr::Block);
+    let location = ctx.get_location(block.stable_ptr.untyped());
+    let lowered_expr = lower_expr_block(ctx, &mut scope, block)
+        .and_then(|_| Err(lower_continue(ctx, &mut scope, location)));
+    lowered_expr_to_block_scope_end(ctx, scope, lowered_expr)
+}
+
+/// Lowers an iteration of a while loop: if the condition holds, the body is lowered as in
+/// [lower_loop_iteration]. Otherwise, the loop function returns.
+fn lower_while_iteration(
+    ctx: &mut LoweringContext<'_>,
+    mut scope: BlockBuilder,
+    expr: &semantic::ExprWhile,
+) -> Maybe<SealedBlockBuilder> {
+    let semantic_db = ctx.db.upcast();
+    let unit_ty = corelib::unit_ty(semantic_db);
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let condition_var = match lower_expr(ctx, &mut scope, expr.condition)
+        .and_then(|lowered_expr| lowered_expr.var(ctx, &mut scope))
+    {
+        Ok(var) => var,
+        Err(err) => return lowering_flow_error_to_sealed_block(ctx, scope, err),
+    };
+
+    // Main block.
+    let mut subscope_main = scope.subscope_with_bound_refs();
+    subscope_main.add_input(ctx, VarRequest { ty: unit_ty, location });
+    let block_main = lower_loop_iteration(ctx, subscope_main, expr.body)?;
+
+    // Exit block.
+    let mut subscope_exit = scope.subscope_with_bound_refs();
+    subscope_exit.add_input(ctx, VarRequest { ty: unit_ty, location });
+    let unit = generators::StructConstruct { inputs: vec![], ty: unit_ty, location }
+        .add(ctx, &mut subscope_exit);
+    let block_exit = subscope_exit.ret(ctx, unit)?;
+
+    let merged = merge_sealed(ctx, &mut scope, vec![block_main, block_exit.into()], location);
+
+    // Emit the statement.
+    scope.push_finalized_statement(Statement::MatchEnum(StatementMatchEnum {
+        concrete_enum_id: corelib::core_bool_enum(semantic_db),
+        input: condition_var,
+        arms: vec![
+            (corelib::false_variant(semantic_db), merged.blocks[1]),
+            (corelib::true_variant(semantic_db), merged.blocks[0]),
+        ],
+    }));
+    lowered_expr_to_block_scope_end(ctx, scope, merged.expr)
+}
+
+/// Lowers a loop expression, which is either a `loop` or a `while`, as a call to its helper
+/// function.
+pub fn lower_expr_loop(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    expr_id: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    let expr = &ctx.function_body.exprs[expr_id];
+    log::trace!("Lowering a loop expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr().untyped());
+    let is_never = expr.ty() == corelib::never_ty(ctx.db.upcast());
+    let loop_function =
+        ctx.db.intern_loop_function(LoopFunctionLongId { parent: ctx.function_id, expr_id });
+    let res = call_loop_function(ctx, scope, loop_function, location)?;
+    if is_never {
+        // A loop that never breaks returns from the enclosing function. See [LoopSignature].
+        return Err(LoweringFlowError::Return(res));
+    }
+    Ok(LoweredExpr::AtVariable(res))
+}
+
+/// Lowers a `continue` statement, by calling the current loop function for the next iteration.
+/// Returns the flow error that ends the current iteration, returning the result of the call.
+pub fn lower_continue(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    location: StableLocation,
+) -> LoweringFlowError {
+    let loop_function = ctx.current_loop.expect("'continue' is only allowed inside a loop.");
+    match call_loop_function(ctx, scope, loop_function, location) {
+        Ok(res) => LoweringFlowError::Return(res),
+        Err(err) => err,
+    }
+}
+
+/// Calls the helper function of a loop with the current values of the variables it captures, and
+/// rebinds the variables it changes.
+fn call_loop_function(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    loop_function: LoopFunctionId,
+    location: StableLocation,
+) -> LoweringResult<VariableId> {
+    let loop_signature = ctx.db.loop_signature(loop_function).map_err(LoweringFlowError::Failed)?;
+    let function = loop_function.function_id(ctx.db.upcast()).map_err(LoweringFlowError::Failed)?;
+    let implicits = ctx.db.function_all_implicits(function).map_err(LoweringFlowError::Failed)?;
+    let mut inputs: Vec<_> = implicits.iter().map(|ty| scope.get_implicit(*ty)).collect();
+    inputs.extend(
+        chain!(&loop_signature.ref_params, &loop_signature.params)
+            .map(|var| scope.get_semantic(var.id())),
+    );
+    let call_result = generators::Call {
+        function,
+        inputs,
+        ref_tys: loop_signature.ref_params.iter().map(|var| var.ty()).collect(),
+        ret_tys: vec![loop_signature.return_type],
+        location,
+    }
+    .add(ctx, scope);
+
+    // Rebind the implicits.
+    for (implicit_type, implicit_output) in zip_eq(implicits, call_result.implicit_outputs) {
+        scope.put_implicit(ctx, implicit_type, implicit_output);
+    }
+    // Rebind the ref variables.
+    for (var, output_var) in zip_eq(&loop_signature.ref_params, call_result.ref_outputs) {
+        scope.put_semantic(ctx, var.id(), output_var);
+    }
+
+    // Finalize call statement after ref rebinding.
+    scope.finalize_statement();
+
+    Ok(call_result.returns.into_iter().next().unwrap())
+}
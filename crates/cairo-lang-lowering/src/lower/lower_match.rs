@@ -0,0 +1,676 @@
+//! Lowering of match expressions.
+//!
+//! The arms of a match are compiled into a decision tree. Each node of the tree tests a single part
+//! of the matched value: an enum is matched on its variant, a felt or an unsigned integer is
+//! compared to the literals of the arms, and a struct or a tuple is destructured to its members.
+//! A leaf is reached when the first remaining arm matches any value of the untested parts. The
+//! expression of an arm reachable from several leaves is lowered once for each of them.
+
+use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::MemberId;
+use cairo_lang_diagnostics::skip_diagnostic;
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::corelib::{
+    core_felt_is_zero, core_felt_ty, core_nonzero_ty, felt_sub, get_core_function_id,
+    get_core_ty_by_name, jump_nz_nonzero_variant, jump_nz_zero_variant,
+};
+use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::{ConcreteTypeId, ConcreteVariant, TypeLongId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use itertools::{zip_eq, Itertools};
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use super::context::{
+    LoweredExpr, LoweredExprExternEnum, LoweringContext, LoweringFlowError, LoweringResult,
+    VarRequest,
+};
+use super::external::extern_facade_expr;
+use super::scope::{merge_sealed, BlockBuilder};
+use super::{
+    generators, lower_expr, lower_single_pattern, lowered_expr_to_block_scope_end,
+    match_extern_arm_ref_args_bind, match_extern_variant_arm_input_types,
+};
+use crate::{Statement, StatementMatchEnum, StatementMatchExtern, VariableId};
+
+/// The unsigned integer types that can be matched against literals, by converting them to felts.
+const UINT_TYPES: [&str; 5] = ["u8", "u16", "u32", "u64", "u128"];
+
+/// A part of the matched value.
+#[derive(Clone)]
+enum MatchedValue {
+    /// The value lies in a variable.
+    Var(VariableId),
+    /// The value was destructured to its members, given by their indices in the matched values.
+    /// `original` is the variable holding the value before it was destructured, if there is one.
+    Destructured { ty: semantic::TypeId, members: Vec<usize>, original: Option<VariableId> },
+    /// The value was matched to be of the variant, with the inner value given by its index in the
+    /// matched values. `original` is the variable holding the value before it was matched, if
+    /// there is one.
+    Variant { variant: ConcreteVariant, inner: usize, original: Option<VariableId> },
+}
+
+/// An arm of a match, with the patterns it has left to match.
+#[derive(Clone)]
+struct MatchRow<'a> {
+    /// The patterns of the parts of the matched value tested by the columns of the tree. `None`
+    /// for the members omitted from a struct pattern, which match any value.
+    patterns: Vec<Option<&'a semantic::Pattern>>,
+    /// The variable patterns already matched, with the indices of the values bound to them.
+    bindings: Vec<(&'a semantic::Pattern, usize)>,
+    /// The expression of the arm.
+    expression: semantic::ExprId,
+}
+impl<'a> MatchRow<'a> {
+    /// Replaces the pattern of the column, which must match any value, with `None`. If the
+    /// pattern is a variable, binds it to the value of the column.
+    fn bind_wildcard(&mut self, column: usize, value: usize) {
+        if let Some(pattern @ semantic::Pattern::Variable(_)) = self.patterns[column] {
+            self.bindings.push((pattern, value));
+        }
+        self.patterns[column] = None;
+    }
+}
+
+/// A node in the decision tree of a match.
+#[derive(Clone)]
+struct MatchTree<'a> {
+    /// The arms that may still match, in their order in the match.
+    rows: Vec<MatchRow<'a>>,
+    /// The indices of the values tested by each column.
+    columns: Vec<usize>,
+    /// The parts of the matched value.
+    values: Vec<MatchedValue>,
+    /// The location of the match expression.
+    location: StableLocation,
+}
+impl<'a> MatchTree<'a> {
+    /// Adds a matched value, and returns its index.
+    fn add_value(&mut self, value: MatchedValue) -> usize {
+        self.values.push(value);
+        self.values.len() - 1
+    }
+
+    /// Returns the node where the value of the column is of the given variant, with the column
+    /// testing the inner value of the variant.
+    fn specialize_variant(
+        mut self,
+        column: usize,
+        variant: &ConcreteVariant,
+        inner: usize,
+    ) -> Self {
+        let value = self.columns[column];
+        self.columns[column] = inner;
+        self.rows = std::mem::take(&mut self.rows)
+            .into_iter()
+            .filter_map(|mut row| {
+                match row.patterns[column] {
+                    Some(semantic::Pattern::EnumVariant(pattern)) => {
+                        if pattern.variant != *variant {
+                            return None;
+                        }
+                        row.patterns[column] = Some(&pattern.inner_pattern);
+                    }
+                    _ => row.bind_wildcard(column, value),
+                }
+                Some(row)
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the node where the value of the column is equal to `literal`, without the column.
+    /// If `literal` is `None`, returns the node where the value is not equal to any of the
+    /// literals of the column.
+    fn specialize_literal(mut self, column: usize, literal: Option<&BigInt>) -> Self {
+        let value = self.columns.remove(column);
+        self.rows = std::mem::take(&mut self.rows)
+            .into_iter()
+            .filter_map(|mut row| {
+                match row.patterns[column] {
+                    Some(semantic::Pattern::Literal(pattern)) => {
+                        if Some(&pattern.literal.value) != literal {
+                            return None;
+                        }
+                    }
+                    _ => row.bind_wildcard(column, value),
+                }
+                row.patterns.remove(column);
+                Some(row)
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the node with the column, testing a struct or a tuple, replaced by columns testing
+    /// its members. `member_ids` are the ids of the members of a struct, empty for a tuple.
+    fn specialize_members(
+        mut self,
+        column: usize,
+        members: &[usize],
+        member_ids: &[MemberId],
+    ) -> Self {
+        let value = self.columns[column];
+        self.columns.splice(column..column + 1, members.iter().copied());
+        for row in self.rows.iter_mut() {
+            let member_patterns: Vec<_> = match row.patterns[column] {
+                Some(semantic::Pattern::Tuple(pattern)) => {
+                    pattern.field_patterns.iter().map(|pattern| Some(&**pattern)).collect()
+                }
+                Some(semantic::Pattern::Struct(pattern)) => member_ids
+                    .iter()
+                    .map(|member_id| {
+                        pattern
+                            .field_patterns
+                            .iter()
+                            .find(|(member, _)| member.id == *member_id)
+                            .map(|(_, pattern)| &**pattern)
+                    })
+                    .collect(),
+                _ => {
+                    row.bind_wildcard(column, value);
+                    vec![None; members.len()]
+                }
+            };
+            row.patterns.splice(column..column + 1, member_patterns);
+        }
+        self
+    }
+
+    /// Returns the location of the pattern of the first row in the column, or the location of the
+    /// match if there is no such pattern.
+    fn column_location(&self, ctx: &LoweringContext<'_>, column: usize) -> StableLocation {
+        self.rows
+            .iter()
+            .find_map(|row| row.patterns[column])
+            .map(|pattern| ctx.get_location(pattern.stable_ptr().untyped()))
+            .unwrap_or(self.location)
+    }
+}
+
+/// Returns whether the pattern matches any value.
+fn is_wildcard(pattern: Option<&semantic::Pattern>) -> bool {
+    matches!(
+        pattern,
+        None | Some(semantic::Pattern::Variable(_)) | Some(semantic::Pattern::Otherwise(_))
+    )
+}
+
+/// Lowers an expression of type [semantic::ExprMatch].
+pub fn lower_expr_match(
+    ctx: &mut LoweringContext<'_>,
+    expr: &semantic::ExprMatch,
+    scope: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a match expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let lowered_expr = lower_expr(ctx, scope, expr.matched_expr)?;
+    let mut tree = MatchTree {
+        rows: expr
+            .arms
+            .iter()
+            .map(|arm| MatchRow {
+                patterns: vec![Some(&arm.pattern)],
+                bindings: vec![],
+                expression: arm.expression,
+            })
+            .collect(),
+        columns: vec![],
+        values: vec![],
+        location,
+    };
+    match lowered_expr {
+        // Match directly on the result of the extern function, without constructing the enum.
+        LoweredExpr::ExternEnum(extern_enum) if !matches!(tree.rows.first(), Some(row) if is_wildcard(row.patterns[0])) => {
+            lower_optimized_extern_match(ctx, scope, extern_enum, tree)
+        }
+        lowered_expr => {
+            let value = add_lowered_value(ctx, scope, &mut tree, lowered_expr)?;
+            tree.columns.push(value);
+            lower_match_tree(ctx, scope, tree)
+        }
+    }
+}
+
+/// Adds a lowered expression as a matched value, and returns its index.
+fn add_lowered_value(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    tree: &mut MatchTree<'_>,
+    lowered_expr: LoweredExpr,
+) -> LoweringResult<usize> {
+    let value = match lowered_expr {
+        // A tuple expression is already destructured to its members.
+        LoweredExpr::Tuple { exprs, .. } => {
+            let ty = lowered_expr_ty(ctx, &exprs);
+            let members = exprs
+                .into_iter()
+                .map(|expr| add_lowered_value(ctx, scope, tree, expr))
+                .collect::<LoweringResult<_>>()?;
+            MatchedValue::Destructured { ty, members, original: None }
+        }
+        lowered_expr => MatchedValue::Var(lowered_expr.var(ctx, scope)?),
+    };
+    Ok(tree.add_value(value))
+}
+
+/// Returns the type of the tuple of the given lowered expressions.
+fn lowered_expr_ty(ctx: &mut LoweringContext<'_>, exprs: &[LoweredExpr]) -> semantic::TypeId {
+    let tys = exprs.iter().map(|expr| expr.ty(ctx)).collect();
+    ctx.db.intern_type(TypeLongId::Tuple(tys))
+}
+
+/// Lowers a node of the decision tree of a match.
+fn lower_match_tree(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    mut tree: MatchTree<'_>,
+) -> LoweringResult<LoweredExpr> {
+    let Some(first_row) = tree.rows.first() else {
+        // No arm matches the remaining values, so there are none, unless the match is not
+        // exhaustive, which is reported in the semantic model.
+        return match tree.columns.first() {
+            Some(value) if value_enum(ctx, &tree, *value).is_some() => {
+                lower_enum_switch(ctx, scope, tree, 0)
+            }
+            _ => Err(LoweringFlowError::Failed(skip_diagnostic())),
+        };
+    };
+    let Some(column) = first_row.patterns.iter().position(|pattern| !is_wildcard(*pattern)) else {
+        return lower_match_leaf(ctx, scope, tree);
+    };
+    match first_row.patterns[column] {
+        Some(semantic::Pattern::EnumVariant(_)) => lower_enum_switch(ctx, scope, tree, column),
+        Some(semantic::Pattern::Literal(_)) => lower_literal_switch(ctx, scope, tree, column),
+        Some(semantic::Pattern::Struct(_) | semantic::Pattern::Tuple(_)) => {
+            let (members, member_ids) = destructure_value(ctx, scope, &mut tree, column)?;
+            lower_match_tree(ctx, scope, tree.specialize_members(column, &members, &member_ids))
+        }
+        Some(semantic::Pattern::Variable(_) | semantic::Pattern::Otherwise(_)) | None => {
+            unreachable!()
+        }
+    }
+}
+
+/// Lowers a leaf of the decision tree of a match: binds the variables of the first remaining arm
+/// and lowers its expression.
+fn lower_match_leaf(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    mut tree: MatchTree<'_>,
+) -> LoweringResult<LoweredExpr> {
+    let mut row = tree.rows.swap_remove(0);
+    for (column, value) in tree.columns.clone().into_iter().enumerate() {
+        row.bind_wildcard(column, value);
+    }
+    for (pattern, value) in row.bindings {
+        let var = materialize_value(ctx, scope, &tree, value);
+        lower_single_pattern(ctx, scope, pattern, LoweredExpr::AtVariable(var))?;
+    }
+    lower_expr(ctx, scope, row.expression)
+}
+
+/// Returns a variable holding the matched value, reconstructing it from its parts if it was
+/// destructured or matched.
+fn materialize_value(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    tree: &MatchTree<'_>,
+    value: usize,
+) -> VariableId {
+    match &tree.values[value] {
+        MatchedValue::Var(var) => *var,
+        MatchedValue::Destructured { original: Some(var), .. }
+        | MatchedValue::Variant { original: Some(var), .. }
+            if ctx.variables[*var].duplicatable =>
+        {
+            *var
+        }
+        MatchedValue::Destructured { ty, members, .. } => {
+            let inputs =
+                members.iter().map(|member| materialize_value(ctx, scope, tree, *member)).collect();
+            generators::StructConstruct { inputs, ty: *ty, location: tree.location }.add(ctx, scope)
+        }
+        MatchedValue::Variant { variant, inner, .. } => {
+            let input = materialize_value(ctx, scope, tree, *inner);
+            generators::EnumConstruct { input, variant: variant.clone(), location: tree.location }
+                .add(ctx, scope)
+        }
+    }
+}
+
+/// Returns the variable holding the value of the column. The value of a column testing an enum or
+/// a literal always lies in a variable.
+fn column_var(tree: &MatchTree<'_>, column: usize) -> VariableId {
+    match &tree.values[tree.columns[column]] {
+        MatchedValue::Var(var) => *var,
+        MatchedValue::Destructured { .. } | MatchedValue::Variant { .. } => {
+            unreachable!("Only values of structs and tuples are destructured.")
+        }
+    }
+}
+
+/// Returns the concrete enum of the matched value, if it is an enum.
+fn value_enum(
+    ctx: &LoweringContext<'_>,
+    tree: &MatchTree<'_>,
+    value: usize,
+) -> Option<semantic::ConcreteEnumId> {
+    let MatchedValue::Var(var) = &tree.values[value] else {
+        return None;
+    };
+    match ctx.db.lookup_intern_type(ctx.variables[*var].ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => Some(concrete_enum_id),
+        _ => None,
+    }
+}
+
+/// Destructures the value of the column, a struct or a tuple, to its members.
+/// Returns the indices of the values of the members, and the ids of the members of a struct.
+fn destructure_value(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    tree: &mut MatchTree<'_>,
+    column: usize,
+) -> LoweringResult<(Vec<usize>, Vec<MemberId>)> {
+    let value = tree.columns[column];
+    let (ty, original) = match &tree.values[value] {
+        MatchedValue::Var(var) => (ctx.variables[*var].ty, Some(*var)),
+        MatchedValue::Destructured { ty, original, .. } => (*ty, *original),
+        MatchedValue::Variant { .. } => unreachable!("Only values of enums are matched."),
+    };
+    let (member_tys, member_ids) = match ctx.db.lookup_intern_type(ty) {
+        TypeLongId::Tuple(tys) => (tys, vec![]),
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => ctx
+            .db
+            .concrete_struct_members(concrete_struct_id)
+            .map_err(LoweringFlowError::Failed)?
+            .values()
+            .map(|member| (member.ty, member.id))
+            .unzip(),
+        _ => unreachable!("Only values of structs and tuples are destructured."),
+    };
+    let members = match &tree.values[value] {
+        MatchedValue::Destructured { members, .. } => members.clone(),
+        _ => {
+            // The locations of the member patterns of the first arm destructuring the value.
+            let locations = (0..member_tys.len())
+                .map(|i| member_location(ctx, tree, column, &member_ids, i))
+                .collect_vec();
+            let vars = generators::StructDestructure {
+                input: original.unwrap(),
+                var_reqs: zip_eq(member_tys, locations)
+                    .map(|(ty, location)| VarRequest { ty, location })
+                    .collect(),
+            }
+            .add(ctx, scope);
+            let members =
+                vars.into_iter().map(|var| tree.add_value(MatchedValue::Var(var))).collect_vec();
+            tree.values[value] =
+                MatchedValue::Destructured { ty, members: members.clone(), original };
+            members
+        }
+    };
+    Ok((members, member_ids))
+}
+
+/// Returns the location of the pattern of the member with index `i`, in the pattern of the first
+/// row in the column, or the location of the pattern if the member is omitted.
+fn member_location(
+    ctx: &LoweringContext<'_>,
+    tree: &MatchTree<'_>,
+    column: usize,
+    member_ids: &[MemberId],
+    i: usize,
+) -> StableLocation {
+    let ptr: Option<SyntaxStablePtrId> = tree.rows.iter().find_map(|row| {
+        Some(match row.patterns[column]? {
+            semantic::Pattern::Tuple(pattern) => pattern.field_patterns[i].stable_ptr().untyped(),
+            semantic::Pattern::Struct(pattern) => pattern
+                .field_patterns
+                .iter()
+                .find(|(member, _)| member.id == member_ids[i])
+                .map(|(_, pattern)| pattern.stable_ptr().untyped())
+                .unwrap_or_else(|| pattern.stable_ptr.untyped()),
+            _ => return None,
+        })
+    });
+    ptr.map(|ptr| ctx.get_location(ptr)).unwrap_or(tree.location)
+}
+
+/// Lowers a node of the decision tree of a match, testing the variant of the enum value of the
+/// column.
+fn lower_enum_switch(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    tree: MatchTree<'_>,
+    column: usize,
+) -> LoweringResult<LoweredExpr> {
+    let input = column_var(&tree, column);
+    let concrete_enum_id = value_enum(ctx, &tree, tree.columns[column]).unwrap();
+    let concrete_variants =
+        ctx.db.concrete_enum_variants(concrete_enum_id).map_err(LoweringFlowError::Failed)?;
+    let sealed_blocks = concrete_variants
+        .iter()
+        .map(|concrete_variant| {
+            let mut subscope = scope.subscope_with_bound_refs();
+            let location = variant_location(ctx, &tree, column, concrete_variant);
+            let var = subscope.add_input(ctx, VarRequest { ty: concrete_variant.ty, location });
+            let mut tree = tree.clone();
+            let inner = tree.add_value(MatchedValue::Var(var));
+            tree.values[tree.columns[column]] = MatchedValue::Variant {
+                variant: concrete_variant.clone(),
+                inner,
+                original: Some(input),
+            };
+            let tree = tree.specialize_variant(column, concrete_variant, inner);
+            let lowered_expr = lower_match_tree(ctx, &mut subscope, tree);
+            lowered_expr_to_block_scope_end(ctx, subscope, lowered_expr)
+                .map_err(LoweringFlowError::Failed)
+        })
+        .collect::<LoweringResult<_>>()?;
+    let merged = merge_sealed(ctx, scope, sealed_blocks, tree.location);
+    let arms = zip_eq(concrete_variants, merged.blocks).collect();
+    scope.push_finalized_statement(Statement::MatchEnum(StatementMatchEnum {
+        concrete_enum_id,
+        input,
+        arms,
+    }));
+    merged.expr
+}
+
+/// Returns the location of the inner pattern of the first arm matching the variant in the column,
+/// or the location of the match if there is no such arm.
+fn variant_location(
+    ctx: &LoweringContext<'_>,
+    tree: &MatchTree<'_>,
+    column: usize,
+    concrete_variant: &ConcreteVariant,
+) -> StableLocation {
+    tree.rows
+        .iter()
+        .find_map(|row| match row.patterns[column] {
+            Some(semantic::Pattern::EnumVariant(pattern))
+                if pattern.variant == *concrete_variant =>
+            {
+                Some(ctx.get_location(pattern.inner_pattern.stable_ptr().untyped()))
+            }
+            _ => None,
+        })
+        .unwrap_or(tree.location)
+}
+
+/// Lowers a match on the enum result of an extern function, given the tree of the match without
+/// columns.
+fn lower_optimized_extern_match(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    extern_enum: LoweredExprExternEnum,
+    tree: MatchTree<'_>,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Started lowering of an optimized extern match.");
+    let location = extern_enum.location;
+    let concrete_variants = ctx
+        .db
+        .concrete_enum_variants(extern_enum.concrete_enum_id)
+        .map_err(LoweringFlowError::Failed)?;
+    let sealed_blocks = concrete_variants
+        .iter()
+        .map(|concrete_variant| {
+            let mut subscope = scope.subscope();
+            let input_tys =
+                match_extern_variant_arm_input_types(ctx, concrete_variant.ty, &extern_enum);
+            let mut input_vars = input_tys
+                .into_iter()
+                .map(|ty| subscope.add_input(ctx, VarRequest { ty, location }))
+                .collect();
+            // Bind the arm inputs to implicits and semantic variables.
+            match_extern_arm_ref_args_bind(ctx, &mut input_vars, &extern_enum, &mut subscope);
+
+            let variant_expr = extern_facade_expr(ctx, concrete_variant.ty, input_vars, location);
+            let mut tree = tree.clone();
+            let lowered_expr = add_lowered_value(ctx, &mut subscope, &mut tree, variant_expr)
+                .and_then(|inner| {
+                    let value = tree.add_value(MatchedValue::Variant {
+                        variant: concrete_variant.clone(),
+                        inner,
+                        original: None,
+                    });
+                    tree.columns.push(value);
+                    lower_match_tree(
+                        ctx,
+                        &mut subscope,
+                        tree.specialize_variant(0, concrete_variant, inner),
+                    )
+                });
+            lowered_expr_to_block_scope_end(ctx, subscope, lowered_expr)
+                .map_err(LoweringFlowError::Failed)
+        })
+        .collect::<LoweringResult<_>>()?;
+
+    let merged = merge_sealed(ctx, scope, sealed_blocks, location);
+    let arms = zip_eq(concrete_variants, merged.blocks).collect();
+
+    // Emit the statement.
+    scope.push_finalized_statement(Statement::MatchExtern(StatementMatchExtern {
+        function: extern_enum.function,
+        inputs: extern_enum.inputs,
+        arms,
+    }));
+    merged.expr
+}
+
+/// Lowers a node of the decision tree of a match, comparing the felt or unsigned integer value of
+/// the column to the literals of the column.
+fn lower_literal_switch(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    tree: MatchTree<'_>,
+    column: usize,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a match on literals.");
+    let var = column_var(&tree, column);
+    let location = tree.column_location(ctx, column);
+    let felt_ty = core_felt_ty(ctx.db.upcast());
+    let ty = ctx.variables[var].ty;
+    // Unsigned integers are compared to the literals as felts.
+    let felt_var = if ty == felt_ty {
+        var
+    } else {
+        let name = UINT_TYPES
+            .into_iter()
+            .find(|name| ty == get_core_ty_by_name(ctx.db.upcast(), (*name).into(), vec![]))
+            .expect("Literal patterns are only allowed for felts and unsigned integers.");
+        let call_result = generators::Call {
+            function: get_core_function_id(
+                ctx.db.upcast(),
+                format!("{name}_to_felt").into(),
+                vec![],
+            ),
+            inputs: vec![var],
+            ref_tys: vec![],
+            ret_tys: vec![felt_ty],
+            location,
+        }
+        .add(ctx, scope);
+        scope.finalize_statement();
+        call_result.returns[0]
+    };
+    let literals = tree
+        .rows
+        .iter()
+        .filter_map(|row| match row.patterns[column] {
+            Some(semantic::Pattern::Literal(pattern)) => Some(pattern.literal.value.clone()),
+            _ => None,
+        })
+        .unique()
+        .collect_vec();
+    lower_literal_comparisons(ctx, scope, tree, column, felt_var, &literals)
+}
+
+/// Lowers the comparisons of a felt value to `literals`, one at a time, in the order they appear
+/// in the arms.
+fn lower_literal_comparisons(
+    ctx: &mut LoweringContext<'_>,
+    scope: &mut BlockBuilder,
+    tree: MatchTree<'_>,
+    column: usize,
+    felt_var: VariableId,
+    literals: &[BigInt],
+) -> LoweringResult<LoweredExpr> {
+    let Some((literal, rest)) = literals.split_first() else {
+        return lower_match_tree(ctx, scope, tree.specialize_literal(column, None));
+    };
+    let semantic_db = ctx.db.upcast();
+    let felt_ty = core_felt_ty(semantic_db);
+    let location = tree.location;
+    // The value is equal to the literal if their difference is zero.
+    let condition_var = if literal.is_zero() {
+        felt_var
+    } else {
+        let literal_var =
+            generators::Literal { value: literal.clone(), ty: felt_ty, location }.add(ctx, scope);
+        let call_result = generators::Call {
+            function: felt_sub(semantic_db),
+            inputs: vec![felt_var, literal_var],
+            ref_tys: vec![],
+            ret_tys: vec![felt_ty],
+            location,
+        }
+        .add(ctx, scope);
+        scope.finalize_statement();
+        call_result.returns[0]
+    };
+
+    let mut subscope_nz = scope.subscope_with_bound_refs();
+    subscope_nz.add_input(ctx, VarRequest { ty: core_nonzero_ty(semantic_db, felt_ty), location });
+
+    let mut subscope_zero = scope.subscope_with_bound_refs();
+    let lowered_expr = lower_match_tree(
+        ctx,
+        &mut subscope_zero,
+        tree.clone().specialize_literal(column, Some(literal)),
+    );
+    let block_zero = lowered_expr_to_block_scope_end(ctx, subscope_zero, lowered_expr)
+        .map_err(LoweringFlowError::Failed)?;
+
+    let lowered_expr =
+        lower_literal_comparisons(ctx, &mut subscope_nz, tree, column, felt_var, rest);
+    let block_nz = lowered_expr_to_block_scope_end(ctx, subscope_nz, lowered_expr)
+        .map_err(LoweringFlowError::Failed)?;
+
+    let merged = merge_sealed(ctx, scope, vec![block_zero, block_nz], location);
+    let concrete_variants =
+        vec![jump_nz_zero_variant(ctx.db.upcast()), jump_nz_nonzero_variant(ctx.db.upcast())];
+    let arms = zip_eq(concrete_variants, merged.blocks).collect();
+
+    // Emit the statement.
+    scope.push_finalized_statement(Statement::MatchExtern(StatementMatchExtern {
+        function: core_felt_is_zero(semantic_db),
+        inputs: vec![condition_var],
+        arms,
+    }));
+    merged.expr
+}
@@ -3,17 +3,13 @@ use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::FunctionWithBodyId;
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe, ToMaybe};
 use cairo_lang_semantic as semantic;
+use cairo_lang_utils::extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
-use cairo_lang_utils::{extract_matches, try_extract_matches};
 use itertools::{chain, zip_eq};
-use num_traits::Zero;
 use scope::BlockBuilder;
-use semantic::corelib::{
-    core_felt_is_zero, core_felt_ty, core_nonzero_ty, get_core_function_id,
-    jump_nz_nonzero_variant, jump_nz_zero_variant, unit_ty,
-};
-use semantic::items::enm::SemanticEnumEx;
-use semantic::{ConcreteTypeId, ExprPropagateError, TypeLongId};
+use semantic::corelib::{get_core_function_id, unit_ty};
+use semantic::lint::Lint;
+use semantic::{ExprPropagateError, TypeLongId};
 
 use self::context::{
     lowering_flow_error_to_sealed_block, LoweredExpr, LoweredExprExternEnum, LoweringContext,
@@ -21,6 +17,8 @@ use self::context::{
 };
 use self::external::{extern_facade_expr, extern_facade_return_tys};
 use self::lower_if::lower_expr_if;
+use self::lower_loop::{lower_continue, lower_expr_loop};
+use self::lower_match::lower_expr_match;
 use self::scope::SealedBlockBuilder;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnosticKind::*;
@@ -33,13 +31,16 @@ pub mod context;
 mod external;
 pub mod implicits;
 mod lower_if;
+pub mod lower_loop;
+mod lower_match;
 mod scope;
+mod usage;
 
 /// Lowers a semantic free function.
 pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<StructuredLowered> {
     log::trace!("Lowering a free function.");
-    let is_empty_semantic_diagnostics = db.function_declaration_diagnostics(function_id).is_empty()
-        && db.function_body_diagnostics(function_id).is_empty();
+    let has_semantic_errors = db.function_declaration_diagnostics(function_id).has_errors()
+        || db.function_body_diagnostics(function_id).has_errors();
     let function_def = db.function_body(function_id)?;
     let signature = db.function_with_body_signature(function_id)?;
 
@@ -74,7 +75,7 @@ pub fn lower(db: &dyn LoweringGroup, function_id: FunctionWithBodyId) -> Maybe<S
         scope.put_semantic(&mut ctx, semantic.id(), var);
     }
     scope.bind_refs();
-    let root = if is_empty_semantic_diagnostics {
+    let root = if !has_semantic_errors {
         let maybe_sealed_block = lower_block(&mut ctx, scope, semantic_block);
         maybe_sealed_block.and_then(|block_sealed| {
             let block = match block_sealed {
@@ -134,9 +135,11 @@ fn lower_expr_block(
                 let end_stmt =
                     &ctx.function_body.statements[*expr_block.statements.last().unwrap()];
                 // Emit diagnostic fo the rest of the statements with unreachable.
-                ctx.diagnostics.report(
+                ctx.diagnostics.report_lint(
+                    ctx.db.upcast(),
                     start_stmt.stable_ptr().untyped(),
                     Unreachable { last_statement_ptr: end_stmt.stable_ptr().untyped() },
+                    Lint::UnreachableCode,
                 );
             }
         }
@@ -207,6 +210,24 @@ pub fn lower_statement(
             let ret_var = lower_expr(ctx, scope, *expr)?.var(ctx, scope)?;
             return Err(LoweringFlowError::Return(ret_var));
         }
+        semantic::Statement::Break(semantic::StatementBreak { expr_option, stable_ptr }) => {
+            log::trace!("Lowering a break statement.");
+            let lowered_expr = match expr_option {
+                Some(expr) => lower_expr(ctx, scope, *expr)?,
+                None => LoweredExpr::Tuple {
+                    exprs: vec![],
+                    location: ctx.get_location(stable_ptr.untyped()),
+                },
+            };
+            // Breaking from a loop returns from its helper function.
+            let ret_var = lowered_expr.var(ctx, scope)?;
+            return Err(LoweringFlowError::Return(ret_var));
+        }
+        semantic::Statement::Continue(semantic::StatementContinue { stable_ptr }) => {
+            log::trace!("Lowering a continue statement.");
+            let location = ctx.get_location(stable_ptr.untyped());
+            return Err(lower_continue(ctx, scope, location));
+        }
     }
     Ok(())
 }
@@ -311,6 +332,7 @@ fn lower_expr(
         semantic::Expr::FunctionCall(expr) => lower_expr_function_call(ctx, expr, scope),
         semantic::Expr::Match(expr) => lower_expr_match(ctx, expr, scope),
         semantic::Expr::If(expr) => lower_expr_if(ctx, scope, expr),
+        semantic::Expr::Loop(_) | semantic::Expr::While(_) => lower_expr_loop(ctx, scope, expr_id),
         semantic::Expr::Var(expr) => {
             log::trace!("Lowering a variable: {:?}", expr.debug(&ctx.expr_formatter));
             Ok(LoweredExpr::AtVariable(scope.get_semantic(expr.var)))
@@ -473,258 +495,6 @@ fn perform_function_call(
     ))
 }
 
-/// Lowers an expression of type [semantic::ExprMatch].
-fn lower_expr_match(
-    ctx: &mut LoweringContext<'_>,
-    expr: &semantic::ExprMatch,
-    scope: &mut BlockBuilder,
-) -> LoweringResult<LoweredExpr> {
-    log::trace!("Lowering a match expression: {:?}", expr.debug(&ctx.expr_formatter));
-    let location = ctx.get_location(expr.stable_ptr.untyped());
-    let lowered_expr = lower_expr(ctx, scope, expr.matched_expr)?;
-
-    if ctx.function_body.exprs[expr.matched_expr].ty() == ctx.db.core_felt_ty() {
-        let var = lowered_expr.var(ctx, scope)?;
-        return lower_expr_match_felt(ctx, expr, var, scope);
-    }
-
-    // TODO(spapini): Use diagnostics.
-    // TODO(spapini): Handle more than just enums.
-    if let LoweredExpr::ExternEnum(extern_enum) = lowered_expr {
-        return lower_optimized_extern_match(ctx, scope, extern_enum, &expr.arms);
-    }
-
-    let (concrete_enum_id, concrete_variants) = extract_concrete_enum(ctx, expr)?;
-    let expr_var = lowered_expr.var(ctx, scope)?;
-
-    // Merge arm blocks.
-    let sealed_blocks = zip_eq(&concrete_variants, &expr.arms)
-        .map(|(concrete_variant, arm)| {
-            let mut subscope = scope.subscope_with_bound_refs();
-
-            // TODO(spapini): Make a better diagnostic.
-            let enum_pattern = try_extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant)
-                .ok_or_else(|| {
-                LoweringFlowError::Failed(
-                    ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatchArm),
-                )
-            })?;
-            // TODO(spapini): Make a better diagnostic.
-            if &enum_pattern.variant != concrete_variant {
-                return Err(LoweringFlowError::Failed(
-                    ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatchArm),
-                ));
-            }
-
-            let pattern_location =
-                ctx.get_location(enum_pattern.inner_pattern.stable_ptr().untyped());
-            let variant_expr = LoweredExpr::AtVariable(subscope.add_input(
-                ctx,
-                VarRequest { ty: concrete_variant.ty, location: pattern_location },
-            ));
-
-            match lower_single_pattern(
-                ctx,
-                &mut subscope,
-                &enum_pattern.inner_pattern,
-                variant_expr,
-            ) {
-                Ok(_) => {
-                    // Lower the arm expression.
-                    lower_tail_expr(ctx, subscope, arm.expression)
-                }
-                Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope, err),
-            }
-            .map_err(LoweringFlowError::Failed)
-        })
-        .collect::<LoweringResult<_>>()?;
-    let merged = merge_sealed(ctx, scope, sealed_blocks, location);
-    let arms = zip_eq(concrete_variants, merged.blocks).collect();
-    scope.push_finalized_statement(Statement::MatchEnum(StatementMatchEnum {
-        concrete_enum_id,
-        input: expr_var,
-        arms,
-    }));
-    merged.expr
-}
-
-/// Lowers a match expression on a LoweredExpr::ExternEnum lowered expression.
-fn lower_optimized_extern_match(
-    ctx: &mut LoweringContext<'_>,
-    scope: &mut BlockBuilder,
-    extern_enum: LoweredExprExternEnum,
-    match_arms: &[semantic::MatchArm],
-) -> LoweringResult<LoweredExpr> {
-    log::trace!("Started lowering of an optimized extern match.");
-    let location = extern_enum.location;
-    let concrete_variants = ctx
-        .db
-        .concrete_enum_variants(extern_enum.concrete_enum_id)
-        .map_err(LoweringFlowError::Failed)?;
-    if match_arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report_by_location(location, UnsupportedMatch),
-        ));
-    }
-    // Merge arm blocks.
-    let sealed_blocks = zip_eq(&concrete_variants, match_arms)
-        .map(|(concrete_variant, arm)| {
-            let mut subscope = scope.subscope();
-            let input_tys =
-                match_extern_variant_arm_input_types(ctx, concrete_variant.ty, &extern_enum);
-            let mut input_vars = input_tys
-                .into_iter()
-                .map(|ty| subscope.add_input(ctx, VarRequest { ty, location }))
-                .collect();
-
-            // TODO(spapini): Make a better diagnostic.
-            let enum_pattern = try_extract_matches!(&arm.pattern, semantic::Pattern::EnumVariant)
-                .ok_or_else(|| {
-                LoweringFlowError::Failed(
-                    ctx.diagnostics.report_by_location(location, UnsupportedMatchArm),
-                )
-            })?;
-            // TODO(spapini): Make a better diagnostic.
-            if &enum_pattern.variant != concrete_variant {
-                return Err(LoweringFlowError::Failed(
-                    ctx.diagnostics.report_by_location(location, UnsupportedMatchArm),
-                ));
-            }
-
-            // Bind the arm inputs to implicits and semantic variables.
-            match_extern_arm_ref_args_bind(ctx, &mut input_vars, &extern_enum, &mut subscope);
-
-            let variant_expr = extern_facade_expr(ctx, concrete_variant.ty, input_vars, location);
-            match lower_single_pattern(
-                ctx,
-                &mut subscope,
-                &enum_pattern.inner_pattern,
-                variant_expr,
-            ) {
-                Ok(_) => {
-                    // Lower the arm expression.
-                    lower_tail_expr(ctx, subscope, arm.expression)
-                }
-                Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope, err),
-            }
-            .map_err(LoweringFlowError::Failed)
-        })
-        .collect::<LoweringResult<_>>()?;
-
-    let merged = merge_sealed(ctx, scope, sealed_blocks, location);
-    let arms = zip_eq(concrete_variants, merged.blocks).collect();
-
-    // Emit the statement.
-    scope.push_finalized_statement(Statement::MatchExtern(StatementMatchExtern {
-        function: extern_enum.function,
-        inputs: extern_enum.inputs,
-        arms,
-    }));
-    merged.expr
-}
-
-/// Lowers an expression of type [semantic::ExprMatch] where the matched expression is a felt.
-/// Currently only a simple match-zero is supported.
-fn lower_expr_match_felt(
-    ctx: &mut LoweringContext<'_>,
-    expr: &semantic::ExprMatch,
-    expr_var: VariableId,
-    scope: &mut BlockBuilder,
-) -> LoweringResult<LoweredExpr> {
-    log::trace!("Lowering a match-felt expression.");
-    let location = ctx.get_location(expr.stable_ptr.untyped());
-    // Check that the match has the expected form.
-    let (literal, block0, block_otherwise) = if let [
-        semantic::MatchArm {
-            pattern: semantic::Pattern::Literal(semantic::PatternLiteral { literal, .. }),
-            expression: block0,
-        },
-        semantic::MatchArm {
-            pattern: semantic::Pattern::Otherwise(_),
-            expression: block_otherwise,
-        },
-    ] = &expr.arms[..]
-    {
-        (literal, block0, block_otherwise)
-    } else {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), OnlyMatchZeroIsSupported),
-        ));
-    };
-
-    // Make sure literal is 0.
-    if !literal.value.is_zero() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(literal.stable_ptr.untyped(), NonZeroValueInMatch),
-        ));
-    }
-
-    let semantic_db = ctx.db.upcast();
-
-    // Lower both blocks.
-    let mut subscope_nz = scope.subscope_with_bound_refs();
-    subscope_nz.add_input(
-        ctx,
-        VarRequest { ty: core_nonzero_ty(semantic_db, core_felt_ty(semantic_db)), location },
-    );
-
-    let sealed_blocks = vec![
-        lower_tail_expr(ctx, scope.subscope_with_bound_refs(), *block0)
-            .map_err(LoweringFlowError::Failed)?,
-        lower_tail_expr(ctx, subscope_nz, *block_otherwise).map_err(LoweringFlowError::Failed)?,
-    ];
-    let merged = merge_sealed(ctx, scope, sealed_blocks, location);
-
-    let concrete_variants =
-        vec![jump_nz_zero_variant(ctx.db.upcast()), jump_nz_nonzero_variant(ctx.db.upcast())];
-    let arms = zip_eq(concrete_variants, merged.blocks).collect();
-
-    // Emit the statement.
-    scope.push_finalized_statement(Statement::MatchExtern(StatementMatchExtern {
-        function: core_felt_is_zero(semantic_db),
-        inputs: vec![expr_var],
-        arms,
-    }));
-    merged.expr
-}
-
-/// Extracts concrete enum and variants from a match expression. Assumes it is indeed a concrete
-/// enum.
-fn extract_concrete_enum(
-    ctx: &mut LoweringContext<'_>,
-    expr: &semantic::ExprMatch,
-) -> Result<(semantic::ConcreteEnumId, Vec<semantic::ConcreteVariant>), LoweringFlowError> {
-    let concrete_ty = try_extract_matches!(
-        ctx.db.lookup_intern_type(ctx.function_body.exprs[expr.matched_expr].ty()),
-        TypeLongId::Concrete
-    )
-    .to_maybe()
-    .map_err(LoweringFlowError::Failed)?;
-    let concrete_enum_id = try_extract_matches!(concrete_ty, ConcreteTypeId::Enum)
-        .to_maybe()
-        .map_err(LoweringFlowError::Failed)?;
-    let enum_id = concrete_enum_id.enum_id(ctx.db.upcast());
-    let variants = ctx.db.enum_variants(enum_id).map_err(LoweringFlowError::Failed)?;
-    let concrete_variants = variants
-        .values()
-        .map(|variant_id| {
-            let variant =
-                ctx.db.variant_semantic(enum_id, *variant_id).map_err(LoweringFlowError::Failed)?;
-
-            ctx.db
-                .concrete_enum_variant(concrete_enum_id, &variant)
-                .map_err(LoweringFlowError::Failed)
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    if expr.arms.len() != concrete_variants.len() {
-        return Err(LoweringFlowError::Failed(
-            ctx.diagnostics.report(expr.stable_ptr.untyped(), UnsupportedMatch),
-        ));
-    }
-    Ok((concrete_enum_id, concrete_variants))
-}
-
 /// Lowers a sequence of expressions and return them all. If the flow ended in the middle,
 /// propagates that flow error without returning any variable.
 fn lower_exprs_as_vars(
@@ -0,0 +1,126 @@
+use cairo_lang_semantic as semantic;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use semantic::{Expr, ExprId, FunctionBody, Pattern, Statement, VarId};
+
+/// The semantic variables used by an expression, as needed to capture them in a loop function.
+#[derive(Debug, Default)]
+pub struct Usage {
+    /// Variables read by the expression.
+    pub usage: OrderedHashSet<VarId>,
+    /// Variables assigned to, or passed as ref arguments, by the expression.
+    pub changes: OrderedHashSet<VarId>,
+    /// Variables defined inside the expression.
+    pub introductions: OrderedHashSet<VarId>,
+    /// Loop expressions inside the expression, including the expression itself.
+    pub loops: OrderedHashSet<ExprId>,
+}
+impl Usage {
+    /// Computes the usage of an expression in a function body.
+    pub fn from_expr(function_body: &FunctionBody, expr_id: ExprId) -> Self {
+        let mut usage = Self::default();
+        usage.handle_expr(function_body, expr_id);
+        usage
+    }
+
+    /// Returns the variables defined outside the expression and used by it, in order of first
+    /// use. Changed variables come first.
+    pub fn captured(&self) -> impl Iterator<Item = VarId> + '_ {
+        let mut captured: OrderedHashSet<VarId> = self.changed().collect();
+        captured.extend(self.usage.iter().copied());
+        captured.into_iter().filter(|var| !self.introductions.contains(var))
+    }
+
+    /// Returns the variables defined outside the expression and changed by it.
+    pub fn changed(&self) -> impl Iterator<Item = VarId> + '_ {
+        self.changes.iter().copied().filter(|var| !self.introductions.contains(var))
+    }
+
+    fn handle_expr(&mut self, function_body: &FunctionBody, expr_id: ExprId) {
+        match &function_body.exprs[expr_id] {
+            Expr::Tuple(expr) => {
+                for item in &expr.items {
+                    self.handle_expr(function_body, *item);
+                }
+            }
+            Expr::Assignment(expr) => {
+                self.handle_expr(function_body, expr.rhs);
+                self.changes.insert(expr.var);
+            }
+            Expr::Block(expr) => {
+                for statement in &expr.statements {
+                    self.handle_statement(function_body, &function_body.statements[*statement]);
+                }
+                if let Some(tail) = expr.tail {
+                    self.handle_expr(function_body, tail);
+                }
+            }
+            Expr::FunctionCall(expr) => {
+                for arg in &expr.args {
+                    self.handle_expr(function_body, *arg);
+                }
+                for ref_arg in &expr.ref_args {
+                    self.usage.insert(*ref_arg);
+                    self.changes.insert(*ref_arg);
+                }
+            }
+            Expr::Match(expr) => {
+                self.handle_expr(function_body, expr.matched_expr);
+                for arm in &expr.arms {
+                    self.handle_pattern(&arm.pattern);
+                    self.handle_expr(function_body, arm.expression);
+                }
+            }
+            Expr::If(expr) => {
+                self.handle_expr(function_body, expr.condition);
+                self.handle_expr(function_body, expr.if_block);
+                if let Some(else_block) = expr.else_block {
+                    self.handle_expr(function_body, else_block);
+                }
+            }
+            Expr::Loop(expr) => {
+                self.loops.insert(expr_id);
+                self.handle_expr(function_body, expr.body);
+            }
+            Expr::While(expr) => {
+                self.loops.insert(expr_id);
+                self.handle_expr(function_body, expr.condition);
+                self.handle_expr(function_body, expr.body);
+            }
+            Expr::Var(expr) => {
+                self.usage.insert(expr.var);
+            }
+            Expr::MemberAccess(expr) => self.handle_expr(function_body, expr.expr),
+            Expr::StructCtor(expr) => {
+                for (_, member_expr) in &expr.members {
+                    self.handle_expr(function_body, *member_expr);
+                }
+            }
+            Expr::EnumVariantCtor(expr) => self.handle_expr(function_body, expr.value_expr),
+            Expr::PropagateError(expr) => self.handle_expr(function_body, expr.inner),
+            Expr::Literal(_) | Expr::Constant(_) | Expr::Missing(_) => {}
+        }
+    }
+
+    fn handle_statement(&mut self, function_body: &FunctionBody, statement: &Statement) {
+        match statement {
+            Statement::Expr(stmt) => self.handle_expr(function_body, stmt.expr),
+            Statement::Let(stmt) => {
+                self.handle_expr(function_body, stmt.expr);
+                self.handle_pattern(&stmt.pattern);
+            }
+            Statement::Return(stmt) => self.handle_expr(function_body, stmt.expr),
+            Statement::Break(stmt) => {
+                if let Some(expr) = stmt.expr_option {
+                    self.handle_expr(function_body, expr);
+                }
+            }
+            Statement::Continue(_) => {}
+        }
+    }
+
+    fn handle_pattern(&mut self, pattern: &Pattern) {
+        for variable in pattern.variables() {
+            self.introductions.insert(VarId::Local(variable.var.id));
+        }
+    }
+}
@@ -23,15 +23,14 @@ use crate::{
 /// with PanicResult<>.
 pub fn lower_panics(
     db: &dyn LoweringGroup,
-    function_id: FunctionWithBodyId,
+    lowering_info: &LoweringContextBuilder<'_>,
     lowered: &StructuredLowered,
 ) -> Maybe<FlatLowered> {
-    let lowering_info = LoweringContextBuilder::new(db, function_id)?;
     let mut ctx = lowering_info.ctx()?;
     ctx.variables = lowered.variables.clone();
 
     // Skip this phase for non panicable functions.
-    if !db.function_with_body_may_panic(function_id)? {
+    if !db.function_with_body_may_panic(lowering_info.function_id)? {
         return Ok(FlatLowered {
             diagnostics: Default::default(),
             root: lowered.root,
@@ -148,6 +147,11 @@ impl<'a> PanicBlockLoweringContext<'a> {
                     {
                         self.handle_stmt_call(call)
                     }
+                    GenericFunctionId::Loop(_)
+                        if self.db().function_may_panic(call.function)? =>
+                    {
+                        self.handle_stmt_call(call)
+                    }
                     _ => {
                         self.statements.push(stmt.statement.clone());
                     }
@@ -1,4 +1,4 @@
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{variant_name, DiagnosticEntry};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
@@ -26,6 +26,10 @@ pub enum ParserDiagnosticKind {
 impl DiagnosticEntry for ParserDiagnostic {
     type DbType = dyn FilesGroup;
 
+    fn code(&self) -> Option<String> {
+        Some(variant_name(&self.kind))
+    }
+
     fn format(&self, _db: &dyn FilesGroup) -> String {
         match self.kind {
             ParserDiagnosticKind::SkippedElement { ref element_name } => {
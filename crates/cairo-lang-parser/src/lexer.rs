@@ -181,6 +181,10 @@ impl<'a> Lexer<'a> {
             "match" => TokenKind::Match,
             "if" => TokenKind::If,
             "else" => TokenKind::Else,
+            "loop" => TokenKind::Loop,
+            "while" => TokenKind::While,
+            "break" => TokenKind::Break,
+            "continue" => TokenKind::Continue,
             "use" => TokenKind::Use,
             "implicits" => TokenKind::Implicits,
             "ref" => TokenKind::Ref,
@@ -327,6 +331,10 @@ enum TokenKind {
     Match,
     If,
     Else,
+    Loop,
+    While,
+    Break,
+    Continue,
     Use,
     Implicits,
     NoPanic,
@@ -400,6 +408,10 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Match => SyntaxKind::TerminalMatch,
         TokenKind::If => SyntaxKind::TerminalIf,
         TokenKind::Else => SyntaxKind::TerminalElse,
+        TokenKind::Loop => SyntaxKind::TerminalLoop,
+        TokenKind::While => SyntaxKind::TerminalWhile,
+        TokenKind::Break => SyntaxKind::TerminalBreak,
+        TokenKind::Continue => SyntaxKind::TerminalContinue,
         TokenKind::Use => SyntaxKind::TerminalUse,
         TokenKind::Implicits => SyntaxKind::TerminalImplicits,
         TokenKind::NoPanic => SyntaxKind::TerminalNoPanic,
@@ -44,6 +44,10 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalMatch => vec!["match"],
         SyntaxKind::TerminalIf => vec!["if"],
         SyntaxKind::TerminalElse => vec!["else"],
+        SyntaxKind::TerminalLoop => vec!["loop"],
+        SyntaxKind::TerminalWhile => vec!["while"],
+        SyntaxKind::TerminalBreak => vec!["break"],
+        SyntaxKind::TerminalContinue => vec!["continue"],
         SyntaxKind::TerminalUse => vec!["use"],
         SyntaxKind::TerminalAnd => vec!["&"],
         SyntaxKind::TerminalAndAnd => vec!["&&"],
@@ -110,6 +114,10 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalMatch,
         SyntaxKind::TerminalIf,
         SyntaxKind::TerminalElse,
+        SyntaxKind::TerminalLoop,
+        SyntaxKind::TerminalWhile,
+        SyntaxKind::TerminalBreak,
+        SyntaxKind::TerminalContinue,
         SyntaxKind::TerminalUse,
         SyntaxKind::TerminalAnd,
         SyntaxKind::TerminalAndAnd,
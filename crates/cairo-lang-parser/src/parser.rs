@@ -667,6 +667,12 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalIf if lbrace_allowed == LbraceAllowed::Allow => {
                 Some(self.expect_if_expr().into())
             }
+            SyntaxKind::TerminalLoop if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_loop_expr().into())
+            }
+            SyntaxKind::TerminalWhile if lbrace_allowed == LbraceAllowed::Allow => {
+                Some(self.expect_while_expr().into())
+            }
             _ => {
                 // TODO(yuval): report to diagnostics.
                 None
@@ -940,6 +946,23 @@ impl<'a> Parser<'a> {
         ExprIf::new_green(self.db, if_kw, condition, if_block, else_clause)
     }
 
+    /// Assumes the current token is `Loop`.
+    /// Expected pattern: `loop <block>`.
+    fn expect_loop_expr(&mut self) -> ExprLoopGreen {
+        let loop_kw = self.take::<TerminalLoop>();
+        let body = self.parse_block();
+        ExprLoop::new_green(self.db, loop_kw, body)
+    }
+
+    /// Assumes the current token is `While`.
+    /// Expected pattern: `while <expr> <block>`.
+    fn expect_while_expr(&mut self) -> ExprWhileGreen {
+        let while_kw = self.take::<TerminalWhile>();
+        let condition = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+        ExprWhile::new_green(self.db, while_kw, condition, body)
+    }
+
     /// Returns a GreenId of a node with a MatchArm kind or None if a match arm can't be parsed.
     pub fn try_parse_match_arm(&mut self) -> Option<MatchArmGreen> {
         let pattern = self.try_parse_pattern()?;
@@ -1078,6 +1101,22 @@ impl<'a> Parser<'a> {
                 let semicolon = self.parse_token::<TerminalSemicolon>();
                 Some(StatementReturn::new_green(self.db, return_kw, expr, semicolon).into())
             }
+            SyntaxKind::TerminalBreak => {
+                let break_kw = self.take::<TerminalBreak>();
+                let expr_clause = match self.peek().kind {
+                    SyntaxKind::TerminalSemicolon => {
+                        OptionExprClauseEmpty::new_green(self.db).into()
+                    }
+                    _ => ExprClause::new_green(self.db, self.parse_expr()).into(),
+                };
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementBreak::new_green(self.db, break_kw, expr_clause, semicolon).into())
+            }
+            SyntaxKind::TerminalContinue => {
+                let continue_kw = self.take::<TerminalContinue>();
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                Some(StatementContinue::new_green(self.db, continue_kw, semicolon).into())
+            }
             _ => match self.try_parse_expr() {
                 None => None,
                 Some(expr) => {
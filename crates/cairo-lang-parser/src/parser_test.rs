@@ -292,6 +292,7 @@ cairo_lang_test_utils::test_file_test!(
         exprs: "exprs",
         fn_: "fn",
         if_: "if",
+        loop_diagnostics: "loop_diagnostics",
         match_: "match",
         pattern: "pattern",
         question_mark: "question_mark",
@@ -316,6 +317,7 @@ cairo_lang_test_utils::test_file_test!(
         let_statement: "let_statement",
         if_else: "if_else",
         literal: "literal",
+        loop_: "loop",
         module: "module",
     },
     test_partial_parser_tree
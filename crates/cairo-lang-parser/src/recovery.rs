@@ -80,7 +80,13 @@ pub(crate) use top_level;
 
 macro_rules! block {
     () => {
-        SyntaxKind::TerminalLet | SyntaxKind::TerminalMatch | SyntaxKind::TerminalReturn
+        SyntaxKind::TerminalLet
+            | SyntaxKind::TerminalMatch
+            | SyntaxKind::TerminalReturn
+            | SyntaxKind::TerminalLoop
+            | SyntaxKind::TerminalWhile
+            | SyntaxKind::TerminalBreak
+            | SyntaxKind::TerminalContinue
     };
 }
 pub(crate) use block;
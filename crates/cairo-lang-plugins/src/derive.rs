@@ -7,6 +7,8 @@ use cairo_lang_semantic::plugin::{AsDynMacroPlugin, SemanticPlugin, TrivialPlugi
 use cairo_lang_syntax::node::ast::AttributeList;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use itertools::Itertools;
+use smol_str::SmolStr;
 
 #[derive(Debug)]
 pub struct DerivePlugin {}
@@ -14,15 +16,28 @@ pub struct DerivePlugin {}
 impl MacroPlugin for DerivePlugin {
     fn generate_code(&self, db: &dyn SyntaxGroup, item_ast: ast::Item) -> PluginResult {
         match item_ast {
-            ast::Item::Struct(struct_ast) => {
-                generate_derive_code_for_type(db, struct_ast.name(db), struct_ast.attributes(db))
-            }
-            ast::Item::Enum(enum_ast) => {
-                generate_derive_code_for_type(db, enum_ast.name(db), enum_ast.attributes(db))
-            }
+            ast::Item::Struct(struct_ast) => generate_derive_code_for_type(
+                db,
+                DeriveInfo::new(
+                    db,
+                    struct_ast.name(db),
+                    TypeVariant::Struct,
+                    struct_ast.members(db),
+                ),
+                struct_ast.attributes(db),
+            ),
+            ast::Item::Enum(enum_ast) => generate_derive_code_for_type(
+                db,
+                DeriveInfo::new(db, enum_ast.name(db), TypeVariant::Enum, enum_ast.variants(db)),
+                enum_ast.attributes(db),
+            ),
             ast::Item::ExternType(extern_type_ast) => generate_derive_code_for_type(
                 db,
-                extern_type_ast.name(db),
+                DeriveInfo {
+                    name: extern_type_ast.name(db).text(db),
+                    type_variant: TypeVariant::ExternType,
+                    members: vec![],
+                },
                 extern_type_ast.attributes(db),
             ),
             _ => PluginResult::default(),
@@ -39,23 +54,110 @@ impl AsDynMacroPlugin for DerivePlugin {
 }
 impl SemanticPlugin for DerivePlugin {}
 
+/// The kind of the type a derive is applied to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TypeVariant {
+    Struct,
+    Enum,
+    ExternType,
+}
+
+/// A member of a struct, or a variant of an enum.
+struct MemberInfo {
+    name: SmolStr,
+    ty: String,
+}
+
+/// The information about a type required for deriving traits for it.
+struct DeriveInfo {
+    name: SmolStr,
+    type_variant: TypeVariant,
+    /// The members of a struct, or the variants of an enum.
+    members: Vec<MemberInfo>,
+}
+impl DeriveInfo {
+    fn new(
+        db: &dyn SyntaxGroup,
+        ident: ast::TerminalIdentifier,
+        type_variant: TypeVariant,
+        members: ast::MemberList,
+    ) -> Self {
+        Self {
+            name: ident.text(db),
+            type_variant,
+            members: members
+                .elements(db)
+                .into_iter()
+                .map(|member| MemberInfo {
+                    name: member.name(db).text(db),
+                    ty: member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db),
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Adds an implementation for all requested derives for the type.
 fn generate_derive_code_for_type(
     db: &dyn SyntaxGroup,
-    ident: ast::TerminalIdentifier,
+    info: DeriveInfo,
     attributes: AttributeList,
 ) -> PluginResult {
     let mut diagnostics = vec![];
     let mut impls = vec![];
+    let name = &info.name;
+    for (derived, expr) in derived_traits(db, attributes, &mut diagnostics) {
+        let code_generator = match derived.as_str() {
+            "Clone" => Some(get_clone_impl as fn(&DeriveInfo) -> String),
+            "LegacyHash" => Some(get_legacy_hash_impl as _),
+            "PartialEq" => Some(get_partial_eq_impl as _),
+            "Serde" => Some(get_serde_impl as _),
+            "StorageAccess" => Some(get_storage_access_impl as _),
+            _ => None,
+        };
+        match code_generator {
+            Some(_) if info.type_variant == TypeVariant::ExternType => {
+                diagnostics.push(PluginDiagnostic {
+                    stable_ptr: expr.stable_ptr().untyped(),
+                    message: format!(
+                        "Deriving `{derived}` is only supported for structs and enums."
+                    ),
+                });
+            }
+            Some(code_generator) => impls.push(code_generator(&info)),
+            None => impls.push(format!("impl {name}{derived} of {derived}::<{name}>;\n")),
+        }
+    }
+    PluginResult {
+        code: if impls.is_empty() {
+            None
+        } else {
+            Some(PluginGeneratedFile {
+                name: "impls".into(),
+                content: impls.join(""),
+                aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
+            })
+        },
+        diagnostics,
+        remove_original_item: false,
+    }
+}
+
+/// Returns the traits requested by the `#[derive(...)]` attributes in `attributes`, each with the
+/// path naming it. Arguments that do not name a trait are reported to `diagnostics`.
+pub fn derived_traits(
+    db: &dyn SyntaxGroup,
+    attributes: AttributeList,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Vec<(SmolStr, ast::ExprPath)> {
+    let mut traits = vec![];
     for attr in attributes.elements(db) {
         if attr.attr(db).text(db) == "derive" {
             if let ast::OptionAttributeArgs::AttributeArgs(args) = attr.args(db) {
                 for arg in args.arg_list(db).elements(db) {
                     if let ast::Expr::Path(expr) = arg {
                         if let [ast::PathSegment::Simple(segment)] = &expr.elements(db)[..] {
-                            let name = ident.text(db);
-                            let derived = segment.ident(db).text(db);
-                            impls.push(format!("impl {name}{derived} of {derived}::<{name}>;\n"));
+                            traits.push((segment.ident(db).text(db), expr));
                         } else {
                             diagnostics.push(PluginDiagnostic {
                                 stable_ptr: expr.stable_ptr().untyped(),
@@ -77,17 +179,527 @@ fn generate_derive_code_for_type(
             }
         }
     }
-    PluginResult {
-        code: if impls.is_empty() {
-            None
-        } else {
-            Some(PluginGeneratedFile {
-                name: "impls".into(),
-                content: impls.join(""),
-                aux_data: DynGeneratedFileAuxData(Arc::new(TrivialPluginAuxData {})),
-            })
-        },
-        diagnostics,
-        remove_original_item: false,
+    traits
+}
+
+/// Returns the name of a variable holding the member of a struct at the given index, e.g.
+/// `__member_0`. The names are built from the index rather than the name of the member, as member
+/// names may be the same as the parameters of the generated functions.
+fn member_var(prefix: &str, index: usize) -> String {
+    format!("__{prefix}_{index}")
+}
+
+/// Returns the pattern destructuring a struct into variables named by [member_var], e.g.
+/// `A { x: __member_0, y: __member_1 }`, with the variables marked as mutable if `mutable` is
+/// true.
+fn struct_pattern(info: &DeriveInfo, prefix: &str, mutable: bool) -> String {
+    let name = &info.name;
+    if info.members.is_empty() {
+        return format!("{name} {{}}");
     }
+    let modifier = if mutable { "mut " } else { "" };
+    let members = info
+        .members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| format!("{}: {modifier}{}", member.name, member_var(prefix, i)))
+        .join(", ");
+    format!("{name} {{ {members} }}")
+}
+
+/// Returns the expression constructing a struct, with the member at each index initialized by
+/// `member_expr`.
+fn struct_ctor(info: &DeriveInfo, member_expr: impl Fn(usize) -> String) -> String {
+    let name = &info.name;
+    if info.members.is_empty() {
+        return format!("{name} {{}}");
+    }
+    let members = info
+        .members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| format!("{}: {}", member.name, member_expr(i)))
+        .join(", ");
+    format!("{name} {{ {members} }}")
+}
+
+/// Returns the arms of a match over the variants of an enum, binding the value of the variant to
+/// `value_name`. The body of each arm is generated by `arm_body` from the index of the variant.
+fn enum_match_arms(
+    info: &DeriveInfo,
+    value_name: &str,
+    arm_body: impl Fn(usize, &MemberInfo) -> String,
+) -> String {
+    info.members
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
+            format!(
+                "
+            {}::{}({value_name}) => {{{}
+            }},",
+                info.name,
+                variant.name,
+                arm_body(i, variant)
+            )
+        })
+        .join("")
+}
+
+/// Returns an if-else chain choosing the variant of an enum according to the value of the felt
+/// variable `variant`. The expression of each variant is generated by `variant_expr`, and
+/// `fallback` is used when the value does not match any variant.
+fn enum_variant_chain(
+    info: &DeriveInfo,
+    variant_expr: impl Fn(&MemberInfo) -> String,
+    fallback: &str,
+) -> String {
+    let mut code = String::new();
+    for (i, variant) in info.members.iter().enumerate() {
+        code += &format!(
+            "if variant == {i} {{
+            {}
+        }} else ",
+            variant_expr(variant)
+        );
+    }
+    code += &format!(
+        "{{
+            {fallback}
+        }}"
+    );
+    code
+}
+
+/// Returns an implementation of `traits::Clone`, cloning each member of a struct, or the value of
+/// the variant of an enum.
+fn get_clone_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let body = match info.type_variant {
+        TypeVariant::Struct => {
+            let clones = info
+                .members
+                .iter()
+                .enumerate()
+                .map(|(i, member)| {
+                    format!(
+                        "
+        let {} = traits::Clone::<{}>::clone(ref {});",
+                        member_var("clone", i),
+                        member.ty,
+                        member_var("member", i)
+                    )
+                })
+                .join("");
+            format!(
+                "
+        let {} = self;{clones}
+        self = {};
+        {}",
+                struct_pattern(info, "member", true),
+                struct_ctor(info, |i| member_var("member", i)),
+                struct_ctor(info, |i| member_var("clone", i)),
+            )
+        }
+        TypeVariant::Enum => {
+            let arms = enum_match_arms(info, "value", |_, variant| {
+                format!(
+                    "
+                let mut value = value;
+                let clone = traits::Clone::<{1}>::clone(ref value);
+                self = {name}::{0}(value);
+                {name}::{0}(clone)",
+                    variant.name, variant.ty
+                )
+            });
+            format!(
+                "
+        match self {{{arms}
+        }}"
+            )
+        }
+        TypeVariant::ExternType => unreachable!("Clone is not derived for extern types."),
+    };
+    format!(
+        "impl {name}Clone of traits::Clone::<{name}> {{
+    fn clone(ref self: {name}) -> {name} {{{body}
+    }}
+}}
+"
+    )
+}
+
+/// Returns an implementation of `hash::LegacyHash`, hashing the members of a struct in order, or
+/// the index of the variant of an enum followed by its value.
+fn get_legacy_hash_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let body = match info.type_variant {
+        TypeVariant::Struct => {
+            let hashes = info
+                .members
+                .iter()
+                .enumerate()
+                .map(|(i, member)| {
+                    format!(
+                        "
+        let state = hash::LegacyHash::<{}>::hash(state, {});",
+                        member.ty,
+                        member_var("member", i)
+                    )
+                })
+                .join("");
+            format!(
+                "
+        let {} = value;{hashes}
+        state",
+                struct_pattern(info, "member", false)
+            )
+        }
+        TypeVariant::Enum => {
+            let arms = enum_match_arms(info, "value", |i, variant| {
+                format!(
+                    "
+                let state = hash::LegacyHash::<felt>::hash(state, {i});
+                hash::LegacyHash::<{}>::hash(state, value)",
+                    variant.ty
+                )
+            });
+            format!(
+                "
+        match value {{{arms}
+        }}"
+            )
+        }
+        TypeVariant::ExternType => unreachable!("LegacyHash is not derived for extern types."),
+    };
+    format!(
+        "impl {name}LegacyHash of hash::LegacyHash::<{name}> {{
+    fn hash(state: felt, value: {name}) -> felt {{{body}
+    }}
+}}
+"
+    )
+}
+
+/// Returns an implementation of `traits::PartialEq`, comparing the members of a struct, or the
+/// variants of an enum and their values.
+fn get_partial_eq_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let body = match info.type_variant {
+        TypeVariant::Struct => {
+            let comparison = if info.members.is_empty() {
+                "true".to_string()
+            } else {
+                info.members
+                    .iter()
+                    .enumerate()
+                    .map(|(i, member)| {
+                        format!(
+                            "traits::PartialEq::<{}>::eq({}, {})",
+                            member.ty,
+                            member_var("lhs", i),
+                            member_var("rhs", i)
+                        )
+                    })
+                    .join(" & ")
+            };
+            format!(
+                "
+        let {} = a;
+        let {} = b;
+        {comparison}",
+                struct_pattern(info, "lhs", false),
+                struct_pattern(info, "rhs", false)
+            )
+        }
+        TypeVariant::Enum => {
+            let arms = enum_match_arms(info, "lhs", |_, variant| {
+                format!(
+                    "
+                match b {{
+                    {name}::{}(rhs) => traits::PartialEq::<{}>::eq(lhs, rhs),
+                    _ => false,
+                }}",
+                    variant.name, variant.ty
+                )
+            });
+            format!(
+                "
+        match a {{{arms}
+        }}"
+            )
+        }
+        TypeVariant::ExternType => unreachable!("PartialEq is not derived for extern types."),
+    };
+    format!(
+        "impl {name}PartialEq of traits::PartialEq::<{name}> {{
+    fn eq(a: {name}, b: {name}) -> bool {{{body}
+    }}
+    fn ne(a: {name}, b: {name}) -> bool {{
+        !traits::PartialEq::<{name}>::eq(a, b)
+    }}
+}}
+"
+    )
+}
+
+/// Returns an implementation of `serde::Serde`, serializing the members of a struct in order, or
+/// the index of the variant of an enum followed by its value.
+fn get_serde_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let (serialize, deserialize) = match info.type_variant {
+        TypeVariant::Struct => {
+            let serializations = info
+                .members
+                .iter()
+                .enumerate()
+                .map(|(i, member)| {
+                    format!(
+                        "
+        serde::Serde::<{}>::serialize(ref serialized, {});",
+                        member.ty,
+                        member_var("member", i)
+                    )
+                })
+                .join("");
+            let deserializations = info
+                .members
+                .iter()
+                .enumerate()
+                .map(|(i, member)| {
+                    format!(
+                        "
+        let {} = serde::Serde::<{}>::deserialize(ref serialized)?;",
+                        member_var("member", i),
+                        member.ty
+                    )
+                })
+                .join("");
+            (
+                format!(
+                    "
+        let {} = input;{serializations}",
+                    struct_pattern(info, "member", false)
+                ),
+                format!(
+                    "{deserializations}
+        Option::Some({})",
+                    struct_ctor(info, |i| member_var("member", i))
+                ),
+            )
+        }
+        TypeVariant::Enum => {
+            let arms = enum_match_arms(info, "value", |i, variant| {
+                format!(
+                    "
+                serde::Serde::<felt>::serialize(ref serialized, {i});
+                serde::Serde::<{}>::serialize(ref serialized, value);",
+                    variant.ty
+                )
+            });
+            let variants = enum_variant_chain(
+                info,
+                |variant| {
+                    format!(
+                        "Option::Some({name}::{}(serde::Serde::<{}>::deserialize(ref \
+                         serialized)?))",
+                        variant.name, variant.ty
+                    )
+                },
+                "Option::None(())",
+            );
+            (
+                format!(
+                    "
+        match input {{{arms}
+        }}"
+                ),
+                format!(
+                    "
+        let variant = serde::Serde::<felt>::deserialize(ref serialized)?;
+        {variants}"
+                ),
+            )
+        }
+        TypeVariant::ExternType => unreachable!("Serde is not derived for extern types."),
+    };
+    format!(
+        "impl {name}Serde of serde::Serde::<{name}> {{
+    fn serialize(ref serialized: Array::<felt>, input: {name}) {{{serialize}
+    }}
+    fn deserialize(ref serialized: Array::<felt>) -> Option::<{name}> {{{deserialize}
+    }}
+}}
+"
+    )
+}
+
+/// Returns an implementation of `starknet::StorageAccess`. The members of a struct are stored in
+/// consecutive storage slots. An enum stores the index of its variant, followed by the value of
+/// the variant.
+fn get_storage_access_impl(info: &DeriveInfo) -> String {
+    let name = &info.name;
+    let (read, write, size) = match info.type_variant {
+        TypeVariant::Struct => {
+            // The code for each member, with the offset advanced past the previous member.
+            let with_offset_increments = |member_code: &dyn Fn(usize, &MemberInfo) -> String| {
+                let mut code = String::new();
+                for (i, member) in info.members.iter().enumerate() {
+                    if i > 0 {
+                        code += &format!(
+                            "
+        let offset = offset + starknet::StorageAccess::<{}>::size_internal();",
+                            info.members[i - 1].ty
+                        );
+                    }
+                    code += &member_code(i, member);
+                }
+                code
+            };
+            let reads = with_offset_increments(&|i, member| {
+                format!(
+                    "
+        let {} = starknet::StorageAccess::<{}>::read_at_offset_internal(
+            address_domain, base, offset
+        )?;",
+                    member_var("member", i),
+                    member.ty
+                )
+            });
+            let writes = with_offset_increments(&|i, member| {
+                format!(
+                    "
+        starknet::StorageAccess::<{}>::write_at_offset_internal(
+            address_domain, base, offset, {}
+        )?;",
+                    member.ty,
+                    member_var("member", i)
+                )
+            });
+            let size = if info.members.is_empty() {
+                "0_u8".to_string()
+            } else {
+                info.members
+                    .iter()
+                    .map(|member| {
+                        format!("starknet::StorageAccess::<{}>::size_internal()", member.ty)
+                    })
+                    .join(" + ")
+            };
+            (
+                format!(
+                    "{reads}
+        Result::Ok({})",
+                    struct_ctor(info, |i| member_var("member", i))
+                ),
+                format!(
+                    "
+        let {} = value;{writes}
+        Result::Ok(())",
+                    struct_pattern(info, "member", false)
+                ),
+                format!(
+                    "
+        {size}"
+                ),
+            )
+        }
+        TypeVariant::Enum => {
+            let variants = enum_variant_chain(
+                info,
+                |variant| {
+                    format!(
+                        "Result::Ok(
+                {name}::{}(
+                    starknet::StorageAccess::<{}>::read_at_offset_internal(
+                        address_domain, base, offset
+                    )?
+                )
+            )",
+                        variant.name, variant.ty
+                    )
+                },
+                "let mut data = array::ArrayTrait::<felt>::new();
+            array::ArrayTrait::<felt>::append(ref data, 'Invalid enum variant');
+            Result::Err(data)",
+            );
+            let arms = enum_match_arms(info, "value", |i, variant| {
+                format!(
+                    "
+                starknet::StorageAccess::<felt>::write_at_offset_internal(
+                    address_domain, base, offset, {i}
+                )?;
+                starknet::StorageAccess::<{}>::write_at_offset_internal(
+                    address_domain, base, offset + 1_u8, value
+                )",
+                    variant.ty
+                )
+            });
+            let sizes = info
+                .members
+                .iter()
+                .map(|variant| {
+                    format!(
+                        "
+        let variant_size = starknet::StorageAccess::<{}>::size_internal();
+        let size = if variant_size > size {{
+            variant_size
+        }} else {{
+            size
+        }};",
+                        variant.ty
+                    )
+                })
+                .join("");
+            (
+                format!(
+                    "
+        let variant = starknet::StorageAccess::<felt>::read_at_offset_internal(
+            address_domain, base, offset
+        )?;
+        let offset = offset + 1_u8;
+        {variants}"
+                ),
+                format!(
+                    "
+        match value {{{arms}
+        }}"
+                ),
+                format!(
+                    "
+        // The index of the variant, followed by the largest variant.
+        let size = 0_u8;{sizes}
+        1_u8 + size"
+                ),
+            )
+        }
+        TypeVariant::ExternType => unreachable!("StorageAccess is not derived for extern types."),
+    };
+    format!(
+        "impl {name}StorageAccess of starknet::StorageAccess::<{name}> {{
+    fn read(
+        address_domain: felt, base: starknet::StorageBaseAddress
+    ) -> starknet::SyscallResult::<{name}> {{
+        starknet::StorageAccess::<{name}>::read_at_offset_internal(address_domain, base, 0_u8)
+    }}
+    fn write(
+        address_domain: felt, base: starknet::StorageBaseAddress, value: {name}
+    ) -> starknet::SyscallResult::<()> {{
+        starknet::StorageAccess::<{name}>::write_at_offset_internal(
+            address_domain, base, 0_u8, value
+        )
+    }}
+    fn read_at_offset_internal(
+        address_domain: felt, base: starknet::StorageBaseAddress, offset: u8
+    ) -> starknet::SyscallResult::<{name}> {{{read}
+    }}
+    fn write_at_offset_internal(
+        address_domain: felt, base: starknet::StorageBaseAddress, offset: u8, value: {name}
+    ) -> starknet::SyscallResult::<()> {{{write}
+    }}
+    fn size_internal() -> u8 {{{size}
+    }}
+}}
+"
+    )
 }
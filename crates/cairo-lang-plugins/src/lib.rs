@@ -17,9 +17,15 @@ mod test;
 
 /// Gets the list of default plugins to load into the Cairo compiler.
 pub fn get_default_plugins() -> Vec<Arc<dyn SemanticPlugin>> {
+    get_default_plugins_with_configs(HashSet::default())
+}
+
+/// Gets the list of default plugins, where the configs enabled for `#[cfg(...)]` attributes are
+/// `configs`.
+pub fn get_default_plugins_with_configs(configs: HashSet<String>) -> Vec<Arc<dyn SemanticPlugin>> {
     vec![
         Arc::new(DerivePlugin {}),
         Arc::new(PanicablePlugin {}),
-        Arc::new(ConfigPlugin { configs: HashSet::default() }),
+        Arc::new(ConfigPlugin { configs }),
     ]
 }
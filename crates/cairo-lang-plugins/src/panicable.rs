@@ -115,7 +115,7 @@ fn generate_panicable_code(
                             {success_variant} (v) => {{
                                 v
                             }},
-                            {failure_variant} (v) => {{
+                            {failure_variant} (_) => {{
                                 let mut data = array_new::<felt>();
                                 array_append::<felt>(ref data, {err_value});
                                 panic(data)
@@ -1,5 +1,5 @@
 use cairo_lang_defs::plugin::{PluginGeneratedFile, PluginResult};
-use cairo_lang_diagnostics::{format_diagnostics, DiagnosticLocation};
+use cairo_lang_diagnostics::{format_diagnostics, DiagnosticLocation, Severity};
 use cairo_lang_parser::test_utils::create_virtual_file;
 use cairo_lang_parser::utils::{get_syntax_file_and_diagnostics, SimpleParserDatabase};
 use cairo_lang_syntax::node::TypedSyntaxNode;
@@ -42,7 +42,7 @@ pub fn test_expand_plugin(
 
                 let location =
                     DiagnosticLocation { file_id, span: syntax_node.span_without_trivia(db) };
-                format_diagnostics(db, &diag.message, location)
+                format_diagnostics(db, Severity::Error, &diag.message, location)
             }));
 
             let content = match code {
@@ -2,9 +2,10 @@
 #[cfg(test)]
 mod test;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
+use cairo_lang_filesystem::db::Edition;
 use cairo_lang_filesystem::ids::Directory;
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
@@ -33,6 +34,37 @@ pub struct ProjectConfig {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProjectConfigContent {
     pub crate_roots: HashMap<SmolStr, PathBuf>,
+    /// The settings of the crates, by their names. Crates without settings use the defaults.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub crates: HashMap<SmolStr, CrateConfig>,
+}
+
+/// The settings of a crate, given in the `[crates.<crate name>]` section of the project config.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrateConfig {
+    /// The edition of the crate, "2023_01" by default.
+    #[serde(default)]
+    pub edition: Edition,
+    /// The configs enabled for the `#[cfg(...)]` attributes of the crate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cfg: Vec<String>,
+    /// The names of the plugins enabled for the crate in addition to the default plugins, e.g.
+    /// "starknet".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<SmolStr>,
+    /// The crates the crate depends on, by their names. If given, these are the only crates
+    /// (other than the crate itself and the core crate) the crate can refer to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<BTreeMap<SmolStr, DependencyConfig>>,
+}
+
+/// A dependency of a crate.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyConfig {
+    /// The root directory of the dependency, relative to the project config file. Not needed if
+    /// the dependency is a crate of the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
 }
 
 impl ProjectConfig {
@@ -1,11 +1,13 @@
+use cairo_lang_filesystem::db::Edition;
 use indoc::indoc;
 
-use crate::ProjectConfigContent;
+use crate::{CrateConfig, DependencyConfig, ProjectConfigContent};
 
 #[test]
 fn test_serde() {
     let config = ProjectConfigContent {
         crate_roots: [("crate".into(), "dir".into())].into_iter().collect(),
+        crates: Default::default(),
     };
     let serialized = toml::to_string(&config).unwrap();
     assert_eq!(
@@ -17,3 +19,55 @@ fn test_serde() {
     );
     assert_eq!(config, toml::from_str(&serialized).unwrap());
 }
+
+#[test]
+fn test_serde_crate_settings() {
+    let config = ProjectConfigContent {
+        crate_roots: [("crate".into(), "dir".into())].into_iter().collect(),
+        crates: [(
+            "crate".into(),
+            CrateConfig {
+                edition: Edition::V2023_01,
+                cfg: vec!["feature".into()],
+                plugins: vec!["starknet".into()],
+                dependencies: Some(
+                    [(
+                        "dependency".into(),
+                        DependencyConfig { path: Some("../dependency".into()) },
+                    )]
+                    .into_iter()
+                    .collect(),
+                ),
+            },
+        )]
+        .into_iter()
+        .collect(),
+    };
+    let serialized = toml::to_string(&config).unwrap();
+    assert_eq!(
+        serialized,
+        indoc! { r#"
+            [crate_roots]
+            crate = "dir"
+            [crates.crate]
+            edition = "2023_01"
+            cfg = ["feature"]
+            plugins = ["starknet"]
+            [crates.crate.dependencies.dependency]
+            path = "../dependency"
+        "# }
+    );
+    assert_eq!(config, toml::from_str(&serialized).unwrap());
+}
+
+#[test]
+fn test_unknown_edition() {
+    let err = toml::from_str::<ProjectConfigContent>(indoc! { r#"
+        [crate_roots]
+        crate = "dir"
+        [crates.crate]
+        edition = "2022_01"
+    "# })
+    .unwrap_err();
+    assert!(err.to_string().contains("unknown variant `2022_01`"), "{err}");
+}
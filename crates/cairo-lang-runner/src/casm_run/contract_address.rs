@@ -0,0 +1,43 @@
+use cairo_felt::Felt;
+use num_bigint::BigUint;
+use num_traits::One;
+use starknet_crypto::{pedersen_hash, FieldElement};
+
+/// Converts a felt to a field element of `starknet_crypto`.
+fn to_field_element(value: &Felt) -> FieldElement {
+    let bytes = value.to_bytes_be();
+    let mut padded = [0; 32];
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    FieldElement::from_bytes_be(&padded).unwrap()
+}
+
+/// Returns the Pedersen hash of `elements`, chained from zero and ending with their number, as
+/// `compute_hash_on_elements` of StarkNet.
+fn compute_hash_on_elements<'a>(elements: impl IntoIterator<Item = &'a Felt>) -> FieldElement {
+    let (hash, len) = elements.into_iter().fold((FieldElement::ZERO, 0), |(hash, len), element| {
+        (pedersen_hash(&hash, &to_field_element(element)), len + 1)
+    });
+    pedersen_hash(&hash, &FieldElement::from(len as u64))
+}
+
+/// Returns the address of the contract deployed by `deployer_address` with the given salt, class
+/// and constructor calldata, as computed by StarkNet.
+pub fn calculate_contract_address(
+    salt: &Felt,
+    class_hash: &Felt,
+    constructor_calldata: &[Felt],
+    deployer_address: &Felt,
+) -> Felt {
+    let calldata_hash =
+        Felt::from_bytes_be(&compute_hash_on_elements(constructor_calldata).to_bytes_be());
+    let hash = compute_hash_on_elements(&[
+        Felt::from_bytes_be(b"STARKNET_CONTRACT_ADDRESS"),
+        deployer_address.clone(),
+        salt.clone(),
+        class_hash.clone(),
+        calldata_hash,
+    ]);
+    // Addresses are smaller than 2^251 - 256.
+    let upper_bound = (BigUint::one() << 251) - 256u32;
+    Felt::from(BigUint::from_bytes_be(&hash.to_bytes_be()) % upper_bound)
+}
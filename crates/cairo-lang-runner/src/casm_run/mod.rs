@@ -5,11 +5,13 @@ use ark_ff::fields::{Fp256, MontBackend, MontConfig};
 use ark_ff::{Field, PrimeField};
 use ark_std::UniformRand;
 use cairo_felt::{self as felt, felt_str, Felt, PRIME_STR};
+use cairo_lang_casm::casm;
 use cairo_lang_casm::hints::Hint;
 use cairo_lang_casm::instructions::Instruction;
 use cairo_lang_casm::operand::{
     BinOpOperand, CellRef, DerefOrImmediate, Operation, Register, ResOperand,
 };
+use cairo_lang_starknet::casm_contract_class::{CasmContractEntryPoint, CasmContractEntryPoints};
 use cairo_lang_utils::extract_matches;
 use cairo_vm::hint_processor::hint_processor_definition::{HintProcessor, HintReference};
 use cairo_vm::serde::deserialize_program::{
@@ -21,18 +23,27 @@ use cairo_vm::types::relocatable::{MaybeRelocatable, Relocatable};
 use cairo_vm::vm::errors::hint_errors::HintError;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
 use cairo_vm::vm::runners::cairo_runner::CairoRunner;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
 use cairo_vm::vm::vm_core::VirtualMachine;
 use dict_manager::DictManagerExecScope;
+use itertools::chain;
 use num_bigint::BigUint;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
+use self::contract_address::calculate_contract_address;
 use self::dict_manager::DictSquashExecScope;
+use self::starknet_state::create_entry_point_code;
+pub use self::starknet_state::{DeployedContract, Event, L2ToL1Message, StarknetState};
+pub use self::syscall_handler::{SyscallHandler, SyscallResult, TxInfo};
 use crate::short_string::as_cairo_short_string;
 
 #[cfg(test)]
 mod test;
 
+mod contract_address;
 mod dict_manager;
+mod starknet_state;
+mod syscall_handler;
 
 // TODO(orizi): This def is duplicated.
 /// Returns the Beta value of the Starkware elliptic curve.
@@ -58,17 +69,37 @@ fn hint_to_hint_params(hint: &Hint) -> HintParams {
     }
 }
 
+/// The result of a contract call - the remaining gas, and either the returned values or the
+/// revert reason.
+type CallContractResult = (Felt, Result<Vec<Felt>, Vec<Felt>>);
+
+/// The result of a deployment - the remaining gas, and either the address of the deployed contract
+/// with the values returned by its constructor, or the revert reason.
+type DeployResult = (Felt, Result<(Felt, Vec<Felt>), Vec<Felt>>);
+
+/// The simulated gas costs of the system calls, on top of the gas consumed by their code.
+const STORAGE_READ_GAS_COST: usize = 100;
+const STORAGE_WRITE_GAS_COST: usize = 1000;
+const EMIT_EVENT_GAS_COST: usize = 50;
+const SEND_MESSAGE_TO_L1_GAS_COST: usize = 50;
+const CALL_CONTRACT_GAS_COST: usize = 10;
+const DEPLOY_GAS_COST: usize = 200;
+const GETTER_GAS_COST: usize = 10;
+
 /// HintProcessor for Cairo compiler hints.
-struct CairoHintProcessor {
+struct CairoHintProcessor<'a> {
     // A dict from instruction offset to hint vector.
     pub hints_dict: HashMap<usize, Vec<HintParams>>,
     // A mapping from a string that represents a hint to the hint object.
     pub string_to_hint: HashMap<String, Hint>,
+    // The handler of the system calls and cheatcodes.
+    pub syscall_handler: &'a mut dyn SyscallHandler,
 }
 
-impl CairoHintProcessor {
-    pub fn new<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
+impl<'a> CairoHintProcessor<'a> {
+    pub fn new<'b, Instructions: Iterator<Item = &'b Instruction> + Clone>(
         instructions: Instructions,
+        syscall_handler: &'a mut dyn SyscallHandler,
     ) -> Self {
         let mut hints_dict: HashMap<usize, Vec<HintParams>> = HashMap::new();
         let mut string_to_hint: HashMap<String, Hint> = HashMap::new();
@@ -89,10 +120,404 @@ impl CairoHintProcessor {
             }
             hint_offset += instruction.body.op_size();
         }
-        CairoHintProcessor { hints_dict, string_to_hint }
+        CairoHintProcessor { hints_dict, string_to_hint, syscall_handler }
+    }
+
+    /// Runs the entry point matching `entry_point_selector` of the contract deployed at
+    /// `contract_address`, with the given calldata and gas.
+    /// Returns the remaining gas, and either the returned data or the revert reason.
+    fn call_contract(
+        &mut self,
+        contract_address: Felt,
+        entry_point_selector: Felt,
+        calldata: &[Felt],
+        gas_counter: Felt,
+    ) -> Result<CallContractResult, HintError> {
+        let selector = entry_point_selector.to_biguint();
+        // The called contract shares the state of the caller, while running in its own context.
+        self.syscall_handler.enter_call(contract_address.clone());
+        let result = self.run_entry_point(
+            &contract_address,
+            |entry_points| entry_points.external.iter().find(|ep| ep.selector == selector),
+            calldata,
+            gas_counter,
+        );
+        // The state changes of a failed call are rolled back.
+        self.syscall_handler.exit_call(!matches!(result, Ok((_, Ok(_)))));
+        result
+    }
+
+    /// Deploys an instance of the declared class `class_hash` and runs its constructor with the
+    /// given calldata and gas. The deployment is rolled back if the constructor fails.
+    fn deploy(
+        &mut self,
+        class_hash: Felt,
+        contract_address_salt: Felt,
+        calldata: &[Felt],
+        deploy_from_zero: bool,
+        gas_counter: Felt,
+    ) -> Result<DeployResult, HintError> {
+        let deployer_address = if deploy_from_zero {
+            Felt::zero()
+        } else {
+            match self.syscall_handler.get_contract_address() {
+                Ok(address) => address,
+                Err(revert_reason) => return Ok((gas_counter, Err(revert_reason))),
+            }
+        };
+        let contract_address = calculate_contract_address(
+            &contract_address_salt,
+            &class_hash,
+            calldata,
+            &deployer_address,
+        );
+        self.syscall_handler.enter_call(contract_address.clone());
+        let result = match self.syscall_handler.deploy(class_hash, contract_address.clone()) {
+            // A contract without a constructor is deployed without calldata.
+            Ok(()) if !self.has_constructor(&contract_address) => Ok((
+                gas_counter,
+                if calldata.is_empty() {
+                    Ok(vec![])
+                } else {
+                    Err(vec![Felt::from_bytes_be(b"INVALID_CALLDATA_LEN")])
+                },
+            )),
+            Ok(()) => self.run_entry_point(
+                &contract_address,
+                |entry_points| entry_points.constructor.first(),
+                calldata,
+                gas_counter,
+            ),
+            Err(revert_reason) => Ok((gas_counter, Err(revert_reason))),
+        };
+        self.syscall_handler.exit_call(!matches!(result, Ok((_, Ok(_)))));
+        let (gas_counter, result) = result?;
+        Ok((gas_counter, result.map(|res_data| (contract_address, res_data))))
+    }
+
+    /// Returns whether the contract deployed at `contract_address` has a constructor.
+    fn has_constructor(&self, contract_address: &Felt) -> bool {
+        match self.syscall_handler.deployed_contract(contract_address) {
+            Some(contract) => !contract.class.entry_points_by_type.constructor.is_empty(),
+            None => false,
+        }
+    }
+
+    /// Runs the entry point of the contract deployed at `contract_address` that `find_entry_point`
+    /// finds in the entry points of its class, with the given calldata and gas, in the context of
+    /// the running call.
+    /// Returns the remaining gas, and either the returned data or the revert reason.
+    fn run_entry_point(
+        &mut self,
+        contract_address: &Felt,
+        find_entry_point: impl FnOnce(&CasmContractEntryPoints) -> Option<&CasmContractEntryPoint>,
+        calldata: &[Felt],
+        gas_counter: Felt,
+    ) -> Result<CallContractResult, HintError> {
+        let Some(contract) = self.syscall_handler.deployed_contract(contract_address) else {
+            return Ok((gas_counter, Err(vec![Felt::from_bytes_be(b"CONTRACT_NOT_DEPLOYED")])));
+        };
+        let Some(entry_point) = find_entry_point(&contract.class.entry_points_by_type) else {
+            return Ok((gas_counter, Err(vec![Felt::from_bytes_be(b"ENTRYPOINT_NOT_FOUND")])));
+        };
+        let entry_code =
+            create_entry_point_code(entry_point, calldata, &gas_counter).ok_or_else(|| {
+                HintError::CustomHint(format!(
+                    "Unsupported builtins for a contract entry point: {:?}.",
+                    entry_point.builtins
+                ))
+            })?;
+        // Matches the footer added by the `SierraCasmRunner`.
+        let footer = casm! { ret; }.instructions;
+        let data: Vec<MaybeRelocatable> = chain!(
+            entry_code.iter().flat_map(|inst| inst.assemble().encode()).map(Felt::from),
+            contract.class.bytecode.iter().map(|value| Felt::from(value.value.clone())),
+            footer.iter().flat_map(|inst| inst.assemble().encode()).map(Felt::from),
+        )
+        .map(MaybeRelocatable::from)
+        .collect();
+
+        let contract_hints = contract.hints.clone();
+
+        let mut hint_processor = CairoHintProcessor::new(entry_code.iter(), self.syscall_handler);
+        let entry_code_size: usize = entry_code.iter().map(|inst| inst.body.op_size()).sum();
+        for (offset, hints) in &contract_hints {
+            for hint in hints {
+                hint_processor.string_to_hint.insert(hint.to_string(), hint.clone());
+            }
+            hint_processor
+                .hints_dict
+                .insert(entry_code_size + offset, hints.iter().map(hint_to_hint_params).collect());
+        }
+        let run_result = run_program(
+            data,
+            ["pedersen", "range_check", "bitwise", "ec_op"].map(&str::to_string).to_vec(),
+            &mut hint_processor,
+            crate::initialize_vm,
+        )
+        .map(|(cells, trace)| {
+            let ap = trace.last().unwrap().ap;
+
+            // The entry point returns
+            // `[builtins..., gas_builtin, system, PanicResult<Array<felt>>]`, where the panic
+            // result is its variant, followed by the start and end of the data.
+            let get_cell = |index: usize| cells[index].clone().unwrap();
+            let gas_counter = get_cell(ap - 5);
+            let failure_flag = get_cell(ap - 3);
+            let res_start = get_cell(ap - 2).to_usize().unwrap();
+            let res_end = get_cell(ap - 1).to_usize().unwrap();
+            let res_data = (res_start..res_end).map(get_cell).collect();
+            (gas_counter, if failure_flag.is_zero() { Ok(res_data) } else { Err(res_data) })
+        });
+        Ok(run_result.map_err(|err| *err)?)
+    }
+
+    /// Executes the system call whose request starts at `system_buffer`, and writes its response
+    /// right after the request.
+    fn execute_syscall(&mut self, mut system_buffer: MemBuffer<'_>) -> Result<(), HintError> {
+        let selector = system_buffer.next_felt()?.to_bytes_be();
+        let mut gas_counter = system_buffer.next_felt()?;
+        match selector.as_slice() {
+            b"StorageRead" => {
+                let address_domain = system_buffer.next_felt()?;
+                let address = system_buffer.next_felt()?;
+                let result = deduct_gas(&mut gas_counter, STORAGE_READ_GAS_COST)
+                    .and_then(|()| self.syscall_handler.storage_read(address_domain, address));
+                write_syscall_result(&mut system_buffer, gas_counter, result, |buffer, value| {
+                    buffer.write(value)
+                })
+            }
+            b"StorageWrite" => {
+                let address_domain = system_buffer.next_felt()?;
+                let address = system_buffer.next_felt()?;
+                let value = system_buffer.next_felt()?;
+                let result = deduct_gas(&mut gas_counter, STORAGE_WRITE_GAS_COST).and_then(|()| {
+                    self.syscall_handler.storage_write(address_domain, address, value)
+                });
+                write_syscall_result(&mut system_buffer, gas_counter, result, |_, ()| Ok(()))
+            }
+            b"EmitEvent" => {
+                let keys = system_buffer.next_felt_array()?;
+                let data = system_buffer.next_felt_array()?;
+                let result = deduct_gas(&mut gas_counter, EMIT_EVENT_GAS_COST)
+                    .and_then(|()| self.syscall_handler.emit_event(keys, data));
+                write_syscall_result(&mut system_buffer, gas_counter, result, |_, ()| Ok(()))
+            }
+            b"SendMessageToL1" => {
+                let to_address = system_buffer.next_felt()?;
+                let payload = system_buffer.next_felt_array()?;
+                let result = deduct_gas(&mut gas_counter, SEND_MESSAGE_TO_L1_GAS_COST)
+                    .and_then(|()| self.syscall_handler.send_message_to_l1(to_address, payload));
+                write_syscall_result(&mut system_buffer, gas_counter, result, |_, ()| Ok(()))
+            }
+            b"GetCallerAddress" => {
+                let result = deduct_gas(&mut gas_counter, GETTER_GAS_COST)
+                    .and_then(|()| self.syscall_handler.get_caller_address());
+                write_syscall_result(&mut system_buffer, gas_counter, result, MemBuffer::write)
+            }
+            b"GetContractAddress" => {
+                let result = deduct_gas(&mut gas_counter, GETTER_GAS_COST)
+                    .and_then(|()| self.syscall_handler.get_contract_address());
+                write_syscall_result(&mut system_buffer, gas_counter, result, MemBuffer::write)
+            }
+            b"GetBlockNumber" => {
+                let result = deduct_gas(&mut gas_counter, GETTER_GAS_COST)
+                    .and_then(|()| self.syscall_handler.get_block_number());
+                write_syscall_result(&mut system_buffer, gas_counter, result, |buffer, value| {
+                    buffer.write(Felt::from(value))
+                })
+            }
+            b"GetBlockTimestamp" => {
+                let result = deduct_gas(&mut gas_counter, GETTER_GAS_COST)
+                    .and_then(|()| self.syscall_handler.get_block_timestamp());
+                write_syscall_result(&mut system_buffer, gas_counter, result, |buffer, value| {
+                    buffer.write(Felt::from(value))
+                })
+            }
+            b"GetSequencerAddress" => {
+                let result = deduct_gas(&mut gas_counter, GETTER_GAS_COST)
+                    .and_then(|()| self.syscall_handler.get_sequencer_address());
+                write_syscall_result(&mut system_buffer, gas_counter, result, MemBuffer::write)
+            }
+            b"GetTxInfo" => {
+                let result = deduct_gas(&mut gas_counter, GETTER_GAS_COST)
+                    .and_then(|()| self.syscall_handler.get_tx_info());
+                write_syscall_result(&mut system_buffer, gas_counter, result, |buffer, tx_info| {
+                    // The response is a pointer to the info, laid out as the fields of `TxInfo`.
+                    let mut info_buffer =
+                        MemBuffer { ptr: buffer.vm.add_memory_segment(), vm: &mut *buffer.vm };
+                    let info_ptr = info_buffer.ptr;
+                    info_buffer.write(tx_info.version)?;
+                    info_buffer.write(tx_info.account_contract_address)?;
+                    info_buffer.write(tx_info.max_fee)?;
+                    info_buffer.write_felt_array(&tx_info.signature)?;
+                    info_buffer.write(tx_info.transaction_hash)?;
+                    info_buffer.write(tx_info.chain_id)?;
+                    info_buffer.write(tx_info.nonce)?;
+                    buffer.write(info_ptr)
+                })
+            }
+            b"CallContract" => {
+                let contract_address = system_buffer.next_felt()?;
+                let entry_point_selector = system_buffer.next_felt()?;
+                let calldata = system_buffer.next_felt_array()?;
+                let result = match deduct_gas(&mut gas_counter, CALL_CONTRACT_GAS_COST) {
+                    Ok(()) => {
+                        let (updated_gas_counter, result) = self.call_contract(
+                            contract_address,
+                            entry_point_selector,
+                            &calldata,
+                            gas_counter,
+                        )?;
+                        gas_counter = updated_gas_counter;
+                        result
+                    }
+                    Err(revert_reason) => Err(revert_reason),
+                };
+                write_syscall_result(&mut system_buffer, gas_counter, result, |buffer, res_data| {
+                    buffer.write_felt_array(&res_data)
+                })
+            }
+            b"Deploy" => {
+                let class_hash = system_buffer.next_felt()?;
+                let contract_address_salt = system_buffer.next_felt()?;
+                let calldata = system_buffer.next_felt_array()?;
+                let deploy_from_zero = !system_buffer.next_felt()?.is_zero();
+                let result = match deduct_gas(&mut gas_counter, DEPLOY_GAS_COST) {
+                    Ok(()) => {
+                        let (updated_gas_counter, result) = self.deploy(
+                            class_hash,
+                            contract_address_salt,
+                            &calldata,
+                            deploy_from_zero,
+                            gas_counter,
+                        )?;
+                        gas_counter = updated_gas_counter;
+                        result
+                    }
+                    Err(revert_reason) => Err(revert_reason),
+                };
+                write_syscall_result(
+                    &mut system_buffer,
+                    gas_counter,
+                    result,
+                    |buffer, (contract_address, res_data)| {
+                        buffer.write(contract_address)?;
+                        buffer.write_felt_array(&res_data)
+                    },
+                )
+            }
+            // Library calls run classes in the context of the calling contract, and replacing a
+            // class requires running inside a deployed contract, which the runner does not
+            // support.
+            b"LibraryCall" | b"ReplaceClass" => write_syscall_result(
+                &mut system_buffer,
+                gas_counter,
+                Err(vec![Felt::from_bytes_be(b"UNSUPPORTED_SYSCALL")]),
+                |_, ()| Ok(()),
+            ),
+            _ => Err(HintError::CustomHint(format!(
+                "Unknown system call selector: {}.",
+                String::from_utf8_lossy(&selector)
+            ))),
+        }
+    }
+}
+
+/// A cursor over consecutive memory cells, used for reading system call requests and writing their
+/// responses.
+struct MemBuffer<'a> {
+    vm: &'a mut VirtualMachine,
+    /// The address of the next cell.
+    ptr: Relocatable,
+}
+impl MemBuffer<'_> {
+    /// Returns the address of the next cell, and advances the cursor.
+    fn next(&mut self) -> Relocatable {
+        let ptr = self.ptr;
+        self.ptr = self.ptr + 1;
+        ptr
+    }
+
+    /// Reads the next cell as a felt.
+    fn next_felt(&mut self) -> Result<Felt, VirtualMachineError> {
+        let ptr = self.next();
+        Ok(self.vm.get_integer(&ptr)?.into_owned())
+    }
+
+    /// Reads the next two cells as the start and end of an array of felts, and returns its values.
+    fn next_felt_array(&mut self) -> Result<Vec<Felt>, VirtualMachineError> {
+        let start_ptr = self.next();
+        let end_ptr = self.next();
+        let start = self.vm.get_relocatable(&start_ptr)?;
+        let end = self.vm.get_relocatable(&end_ptr)?;
+        read_felts(self.vm, start, end)
+    }
+
+    /// Writes `value` to the next cell.
+    fn write<T: Into<MaybeRelocatable>>(&mut self, value: T) -> Result<(), VirtualMachineError> {
+        let ptr = self.next();
+        self.vm.insert_value(&ptr, value)
+    }
+
+    /// Writes `values` to a new segment, and its start and end to the next two cells.
+    fn write_felt_array(&mut self, values: &[Felt]) -> Result<(), VirtualMachineError> {
+        let start = self.vm.add_memory_segment();
+        for (i, value) in values.iter().enumerate() {
+            self.vm.insert_value(&(start + i), value)?;
+        }
+        self.write(start)?;
+        self.write(start + values.len())
     }
 }
 
+/// Returns the felts in the memory range between `start` (inclusive) and `end` (exclusive).
+fn read_felts(
+    vm: &VirtualMachine,
+    start: Relocatable,
+    end: Relocatable,
+) -> Result<Vec<Felt>, VirtualMachineError> {
+    let mut values = vec![];
+    let mut curr = start;
+    while curr != end {
+        values.push(vm.get_integer(&curr)?.into_owned());
+        curr = curr.add_int(&1.into())?;
+    }
+    Ok(values)
+}
+
+/// Deducts the simulated gas `cost` of a system call from `gas_counter`, or reverts if there is not
+/// enough gas.
+fn deduct_gas(gas_counter: &mut Felt, cost: usize) -> SyscallResult<()> {
+    if *gas_counter < Felt::from(cost) {
+        return Err(vec![Felt::from_bytes_be(b"Out of gas")]);
+    }
+    *gas_counter = gas_counter.clone() - cost;
+    Ok(())
+}
+
+/// Writes the outcome of a system call to `system_buffer` - the updated gas counter, the failure
+/// flag and then either the response, written by `write_response`, or the revert reason.
+fn write_syscall_result<'a, T>(
+    system_buffer: &mut MemBuffer<'a>,
+    gas_counter: Felt,
+    result: SyscallResult<T>,
+    write_response: impl FnOnce(&mut MemBuffer<'a>, T) -> Result<(), VirtualMachineError>,
+) -> Result<(), HintError> {
+    system_buffer.write(gas_counter)?;
+    match result {
+        Ok(response) => {
+            system_buffer.write(Felt::from(0))?;
+            write_response(system_buffer, response)?;
+        }
+        Err(revert_reason) => {
+            system_buffer.write(Felt::from(1))?;
+            system_buffer.write_felt_array(&revert_reason)?;
+        }
+    }
+    Ok(())
+}
+
 fn cell_ref_to_relocatable(cell_ref: &CellRef, vm: &VirtualMachine) -> Relocatable {
     let base = match cell_ref.register {
         Register::AP => vm.get_ap(),
@@ -108,19 +533,13 @@ macro_rules! insert_value_to_cellref {
     };
 }
 
-/// Execution scope for starknet related data.
-struct StarknetExecScope {
-    /// The values of addresses in the simulated storage.
-    storage: HashMap<Felt, Felt>,
-}
-
 /// Execution scope for constant memory allocation.
 struct MemoryExecScope {
     /// The first free address in the segment.
     next_address: Relocatable,
 }
 
-impl HintProcessor for CairoHintProcessor {
+impl HintProcessor for CairoHintProcessor<'_> {
     /// Trait function to execute a given hint in the hint processor.
     fn execute_hint(
         &mut self,
@@ -234,74 +653,9 @@ impl HintProcessor for CairoHintProcessor {
                 })?;
             }
             Hint::SystemCall { system } => {
-                let starknet_exec_scope =
-                    match exec_scopes.get_mut_ref::<StarknetExecScope>("starknet_exec_scope") {
-                        Ok(starknet_exec_scope) => starknet_exec_scope,
-                        Err(_) => {
-                            exec_scopes.assign_or_update_variable(
-                                "starknet_exec_scope",
-                                Box::new(StarknetExecScope { storage: HashMap::default() }),
-                            );
-                            exec_scopes.get_mut_ref::<StarknetExecScope>("starknet_exec_scope")?
-                        }
-                    };
                 let (cell, base_offset) = extract_buffer(system);
-                let selector = get_double_deref_val(cell, &base_offset)?.to_bytes_be();
-                if selector == "StorageWrite".as_bytes() {
-                    let gas_counter = get_double_deref_val(cell, &(base_offset.clone() + 1u32))?;
-                    const WRITE_GAS_SIM_COST: usize = 1000;
-                    let gas_counter_updated_ptr = get_ptr(cell, &(base_offset.clone() + 5u32))?;
-                    let revert_reason_ptr = get_ptr(cell, &(base_offset.clone() + 6u32))?;
-                    let addr_domain = get_double_deref_val(cell, &(base_offset.clone() + 2u32))?;
-
-                    // Only address_domain 0 is currently supported.
-                    if addr_domain.is_zero() && gas_counter >= WRITE_GAS_SIM_COST.into() {
-                        let addr = get_double_deref_val(cell, &(base_offset.clone() + 3u32))?;
-                        let value = get_double_deref_val(cell, &(base_offset + 4u32))?;
-                        starknet_exec_scope.storage.insert(addr, value);
-                        vm.insert_value(
-                            &gas_counter_updated_ptr,
-                            gas_counter - WRITE_GAS_SIM_COST,
-                        )?;
-                        vm.insert_value(&revert_reason_ptr, Felt::from(0))?;
-                    } else {
-                        vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
-                        vm.insert_value(&revert_reason_ptr, Felt::from(1))?;
-                    }
-                } else if selector == "StorageRead".as_bytes() {
-                    let gas_counter = get_double_deref_val(cell, &(base_offset.clone() + 1u32))?;
-                    const READ_GAS_SIM_COST: usize = 100;
-                    let addr_domain = get_double_deref_val(cell, &(base_offset.clone() + 2u32))?;
-                    let addr = get_double_deref_val(cell, &(base_offset.clone() + 3u32))?;
-
-                    let gas_counter_updated_ptr = get_ptr(cell, &(base_offset.clone() + 4u32))?;
-                    let revert_reason_ptr = get_ptr(cell, &(base_offset.clone() + 5u32))?;
-
-                    // Only address_domain 0 is currently supported.
-                    if addr_domain.is_zero() && gas_counter >= READ_GAS_SIM_COST.into() {
-                        let value = starknet_exec_scope
-                            .storage
-                            .get(&addr)
-                            .cloned()
-                            .unwrap_or_else(|| Felt::from(0));
-                        let result_ptr = get_ptr(cell, &(base_offset + 6u32))?;
-
-                        vm.insert_value(&gas_counter_updated_ptr, gas_counter - READ_GAS_SIM_COST)?;
-                        vm.insert_value(&revert_reason_ptr, Felt::from(0))?;
-                        vm.insert_value(&result_ptr, value)?;
-                    } else {
-                        vm.insert_value(&gas_counter_updated_ptr, gas_counter)?;
-                        let revert_reason_start = vm.add_memory_segment();
-                        // TODO(ilya): Add revert reason.
-                        let revert_reason_end = revert_reason_start;
-                        vm.insert_value(&revert_reason_ptr, revert_reason_start)?;
-                        vm.insert_value(&revert_reason_ptr, revert_reason_end)?;
-                    }
-                } else if selector == "CallContract".as_bytes() {
-                    todo!()
-                } else {
-                    panic!("Unknown selector for system call!");
-                }
+                let system_ptr = get_ptr(cell, &base_offset)?;
+                self.execute_syscall(MemBuffer { vm, ptr: system_ptr })?;
             }
             Hint::AllocDictFeltTo { dict_manager_ptr } => {
                 let (cell, base_offset) = extract_buffer(dict_manager_ptr);
@@ -544,6 +898,29 @@ impl HintProcessor for CairoHintProcessor {
                 }
                 println!();
             }
+            Hint::Cheatcode { selector, input_start, input_end, output_start, output_end } => {
+                let as_relocatable = |value| {
+                    let (base, offset) = extract_buffer(value);
+                    get_ptr(base, &offset)
+                };
+                let selector = Felt::from(selector.clone());
+                let input =
+                    read_felts(vm, as_relocatable(input_start)?, as_relocatable(input_end)?)?;
+                let output =
+                    self.syscall_handler.cheatcode(&selector, &input).ok_or_else(|| {
+                        HintError::CustomHint(format!(
+                            "Invalid cheatcode `{}` with input {input:?}.",
+                            as_cairo_short_string(&selector)
+                                .unwrap_or_else(|| selector.to_string())
+                        ))
+                    })?;
+                let output_segment = vm.add_memory_segment();
+                for (i, value) in output.iter().enumerate() {
+                    vm.insert_value(&(output_segment + i), value)?;
+                }
+                insert_value_to_cellref!(vm, output_start, output_segment)?;
+                insert_value_to_cellref!(vm, output_end, output_segment + output.len())?;
+            }
             Hint::AllocConstantSize { size, dst } => {
                 let object_size = get_val(size)?.to_usize().expect("Object size too large.");
                 let memory_exec_scope =
@@ -594,14 +971,19 @@ pub struct RunFunctionContext<'a> {
     pub data_len: usize,
 }
 
-/// Runs `program` on layout with prime, and returns the memory layout and ap value.
+/// The result of [run_function] - the memory layout, ap value and trace.
+pub type RunFunctionRes = (Vec<Option<Felt>>, usize, Vec<RelocatedTraceEntry>);
+
+/// Runs `program` on layout with prime, using `syscall_handler` for its system calls and
+/// cheatcodes, and returns the memory layout, ap value and trace.
 pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
     instructions: Instructions,
     builtins: Vec<String>,
     additional_initialization: fn(
         context: RunFunctionContext<'_>,
     ) -> Result<(), Box<VirtualMachineError>>,
-) -> Result<(Vec<Option<Felt>>, usize), Box<VirtualMachineError>> {
+    syscall_handler: &mut dyn SyscallHandler,
+) -> Result<RunFunctionRes, Box<VirtualMachineError>> {
     let data: Vec<MaybeRelocatable> = instructions
         .clone()
         .flat_map(|inst| inst.assemble().encode())
@@ -609,8 +991,26 @@ pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
         .map(MaybeRelocatable::from)
         .collect();
 
-    let mut hint_processor = CairoHintProcessor::new(instructions);
+    let mut hint_processor = CairoHintProcessor::new(instructions, syscall_handler);
+    let (cells, trace) =
+        run_program(data, builtins, &mut hint_processor, additional_initialization)?;
+    let ap = trace.last().unwrap().ap;
+    Ok((cells, ap, trace))
+}
+
+/// The result of [run_program] - the memory layout and relocated trace.
+type RunProgramRes = (Vec<Option<Felt>>, Vec<RelocatedTraceEntry>);
 
+/// Runs the program encoded in `data` on layout with prime, using `hint_processor` for its hints,
+/// and returns the memory layout and the relocated trace.
+fn run_program(
+    data: Vec<MaybeRelocatable>,
+    builtins: Vec<String>,
+    hint_processor: &mut CairoHintProcessor<'_>,
+    additional_initialization: fn(
+        context: RunFunctionContext<'_>,
+    ) -> Result<(), Box<VirtualMachineError>>,
+) -> Result<RunProgramRes, Box<VirtualMachineError>> {
     let data_len = data.len();
     let program = Program {
         builtins,
@@ -635,8 +1035,8 @@ pub fn run_function<'a, Instructions: Iterator<Item = &'a Instruction> + Clone>(
 
     additional_initialization(RunFunctionContext { vm: &mut vm, data_len })?;
 
-    runner.run_until_pc(end, &mut vm, &mut hint_processor)?;
-    runner.end_run(true, false, &mut vm, &mut hint_processor).map_err(Box::new)?;
+    runner.run_until_pc(end, &mut vm, hint_processor)?;
+    runner.end_run(true, false, &mut vm, hint_processor).map_err(Box::new)?;
     runner.relocate(&mut vm).map_err(VirtualMachineError::from).map_err(Box::new)?;
-    Ok((runner.relocated_memory, runner.relocated_trace.unwrap().last().unwrap().ap))
+    Ok((runner.relocated_memory, runner.relocated_trace.unwrap()))
 }
@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+
+use cairo_felt::Felt;
+use cairo_lang_casm::instructions::Instruction;
+use cairo_lang_casm::{casm, casm_extend};
+use cairo_lang_starknet::casm_contract_class::{
+    CasmContractClass, CasmContractEntryPoint, HintsByOffset, StarknetSierraCompilationError,
+};
+use cairo_lang_starknet::contract_class::ContractClass;
+use itertools::chain;
+use num_traits::{ToPrimitive, Zero};
+
+use super::syscall_handler::{SyscallHandler, SyscallResult, TxInfo};
+use crate::short_string::as_cairo_short_string;
+
+/// A contract deployed in the simulated StarkNet state.
+pub struct DeployedContract {
+    /// The compiled class of the contract.
+    pub class: CasmContractClass,
+    /// The hints of the class bytecode, keyed by the bytecode offset of their instruction.
+    pub hints: HintsByOffset,
+    /// The values of addresses in the contract's storage.
+    pub storage: HashMap<Felt, Felt>,
+}
+
+/// An event emitted by a contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// The address of the emitting contract.
+    pub from_address: Felt,
+    /// The keys of the event.
+    pub keys: Vec<Felt>,
+    /// The data of the event.
+    pub data: Vec<Felt>,
+}
+
+/// A message sent by a contract to L1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct L2ToL1Message {
+    /// The address of the sending contract.
+    pub from_address: Felt,
+    /// The address of the recipient on L1.
+    pub to_address: Felt,
+    /// The payload of the message.
+    pub payload: Vec<Felt>,
+}
+
+/// The info available to the running code through the getter system calls.
+#[derive(Clone, Default)]
+struct ExecutionInfo {
+    block_number: u64,
+    block_timestamp: u64,
+    sequencer_address: Felt,
+    caller_address: Felt,
+    contract_address: Felt,
+    tx_info: TxInfo,
+}
+
+/// The parts of the state that a call can change, saved when entering the call to roll back its
+/// changes if it fails.
+struct StateSnapshot {
+    /// The storage of the deployed contracts, by their address. Restoring the snapshot removes the
+    /// contracts deployed after it.
+    contracts_storage: HashMap<Felt, HashMap<Felt, Felt>>,
+    storage: HashMap<Felt, HashMap<Felt, Felt>>,
+    events: Vec<Event>,
+    l2_to_l1_messages: Vec<L2ToL1Message>,
+}
+
+/// The simulated StarkNet state, shared by all the contracts called during a run.
+/// This is the default [SyscallHandler] of the runner.
+#[derive(Default)]
+pub struct StarknetState {
+    /// The compiled declared classes, with the hints of their bytecode, by their class hash.
+    declared_classes: HashMap<Felt, (CasmContractClass, HintsByOffset)>,
+    /// The deployed contracts, by their address.
+    deployed_contracts: HashMap<Felt, DeployedContract>,
+    /// The storage of the addresses without a deployed contract, such as the address of the code
+    /// running outside of a contract.
+    storage: HashMap<Felt, HashMap<Felt, Felt>>,
+    /// The info of the running call.
+    exec_info: ExecutionInfo,
+    /// The info of the calls that made the running call, from the outermost one, each with the
+    /// state from before the call it made.
+    call_stack: Vec<(ExecutionInfo, StateSnapshot)>,
+    /// The emitted events that were not popped yet, in emission order.
+    events: Vec<Event>,
+    /// The messages sent to L1, in sending order.
+    l2_to_l1_messages: Vec<L2ToL1Message>,
+}
+impl StarknetState {
+    /// Compiles `contract_class` and deploys it at `contract_address` with an empty storage,
+    /// replacing any contract previously deployed at that address.
+    #[allow(clippy::result_large_err)]
+    pub fn deploy_contract(
+        &mut self,
+        contract_address: Felt,
+        contract_class: ContractClass,
+    ) -> Result<(), StarknetSierraCompilationError> {
+        let (class, hints) = CasmContractClass::from_contract_class_with_hints(contract_class)?;
+        self.deployed_contracts
+            .insert(contract_address, DeployedContract { class, hints, storage: HashMap::new() });
+        Ok(())
+    }
+
+    /// Compiles `contract_class` and declares it with the hash `class_hash`, allowing the run to
+    /// deploy instances of it with the `deploy` system call.
+    #[allow(clippy::result_large_err)]
+    pub fn declare_class(
+        &mut self,
+        class_hash: Felt,
+        contract_class: ContractClass,
+    ) -> Result<(), StarknetSierraCompilationError> {
+        let class = CasmContractClass::from_contract_class_with_hints(contract_class)?;
+        self.declared_classes.insert(class_hash, class);
+        Ok(())
+    }
+
+    /// Returns the contract deployed at `contract_address`, if there is one.
+    pub fn deployed_contract(&self, contract_address: &Felt) -> Option<&DeployedContract> {
+        self.deployed_contracts.get(contract_address)
+    }
+
+    /// Returns the events emitted during the run that were not popped, in emission order.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the messages sent to L1 during the run, in sending order.
+    pub fn l2_to_l1_messages(&self) -> &[L2ToL1Message] {
+        &self.l2_to_l1_messages
+    }
+
+    /// Returns the storage of the contract at `contract_address`.
+    fn storage_mut(&mut self, contract_address: Felt) -> &mut HashMap<Felt, Felt> {
+        match self.deployed_contracts.get_mut(&contract_address) {
+            Some(contract) => &mut contract.storage,
+            None => self.storage.entry(contract_address).or_default(),
+        }
+    }
+
+    /// Returns a snapshot of the parts of the state that a call can change.
+    fn snapshot(&self) -> StateSnapshot {
+        StateSnapshot {
+            contracts_storage: self
+                .deployed_contracts
+                .iter()
+                .map(|(address, contract)| (address.clone(), contract.storage.clone()))
+                .collect(),
+            storage: self.storage.clone(),
+            events: self.events.clone(),
+            l2_to_l1_messages: self.l2_to_l1_messages.clone(),
+        }
+    }
+
+    /// Restores the state to the given snapshot.
+    fn restore(&mut self, snapshot: StateSnapshot) {
+        let StateSnapshot { mut contracts_storage, storage, events, l2_to_l1_messages } = snapshot;
+        self.deployed_contracts.retain(|address, contract| {
+            let Some(storage) = contracts_storage.remove(address) else { return false; };
+            contract.storage = storage;
+            true
+        });
+        self.storage = storage;
+        self.events = events;
+        self.l2_to_l1_messages = l2_to_l1_messages;
+    }
+
+    /// Removes the earliest event emitted by `from_address`, if there is one.
+    fn pop_event(&mut self, from_address: &Felt) -> Option<Event> {
+        let index = self.events.iter().position(|event| &event.from_address == from_address)?;
+        Some(self.events.remove(index))
+    }
+}
+
+/// Returns the revert reason for a storage access in an unsupported address domain.
+fn check_address_domain(address_domain: &Felt) -> SyscallResult<()> {
+    // Only address_domain 0 is currently supported.
+    if address_domain.is_zero() {
+        Ok(())
+    } else {
+        Err(vec![Felt::from_bytes_be(b"Unsupported address domain")])
+    }
+}
+
+impl SyscallHandler for StarknetState {
+    fn storage_read(&mut self, address_domain: Felt, address: Felt) -> SyscallResult<Felt> {
+        check_address_domain(&address_domain)?;
+        let contract_address = self.exec_info.contract_address.clone();
+        Ok(self.storage_mut(contract_address).get(&address).cloned().unwrap_or_else(Felt::zero))
+    }
+
+    fn storage_write(
+        &mut self,
+        address_domain: Felt,
+        address: Felt,
+        value: Felt,
+    ) -> SyscallResult<()> {
+        check_address_domain(&address_domain)?;
+        let contract_address = self.exec_info.contract_address.clone();
+        self.storage_mut(contract_address).insert(address, value);
+        Ok(())
+    }
+
+    fn emit_event(&mut self, keys: Vec<Felt>, data: Vec<Felt>) -> SyscallResult<()> {
+        let from_address = self.exec_info.contract_address.clone();
+        self.events.push(Event { from_address, keys, data });
+        Ok(())
+    }
+
+    fn send_message_to_l1(&mut self, to_address: Felt, payload: Vec<Felt>) -> SyscallResult<()> {
+        let from_address = self.exec_info.contract_address.clone();
+        self.l2_to_l1_messages.push(L2ToL1Message { from_address, to_address, payload });
+        Ok(())
+    }
+
+    fn get_caller_address(&mut self) -> SyscallResult<Felt> {
+        Ok(self.exec_info.caller_address.clone())
+    }
+
+    fn get_contract_address(&mut self) -> SyscallResult<Felt> {
+        Ok(self.exec_info.contract_address.clone())
+    }
+
+    fn get_block_number(&mut self) -> SyscallResult<u64> {
+        Ok(self.exec_info.block_number)
+    }
+
+    fn get_block_timestamp(&mut self) -> SyscallResult<u64> {
+        Ok(self.exec_info.block_timestamp)
+    }
+
+    fn get_sequencer_address(&mut self) -> SyscallResult<Felt> {
+        Ok(self.exec_info.sequencer_address.clone())
+    }
+
+    fn get_tx_info(&mut self) -> SyscallResult<TxInfo> {
+        Ok(self.exec_info.tx_info.clone())
+    }
+
+    fn deployed_contract(&self, contract_address: &Felt) -> Option<&DeployedContract> {
+        StarknetState::deployed_contract(self, contract_address)
+    }
+
+    fn deploy(&mut self, class_hash: Felt, contract_address: Felt) -> SyscallResult<()> {
+        let Some((class, hints)) = self.declared_classes.get(&class_hash) else {
+            return Err(vec![Felt::from_bytes_be(b"CLASS_HASH_NOT_DECLARED")]);
+        };
+        if self.deployed_contracts.contains_key(&contract_address) {
+            return Err(vec![Felt::from_bytes_be(b"CONTRACT_ALREADY_DEPLOYED")]);
+        }
+        let contract = DeployedContract {
+            class: class.clone(),
+            hints: hints.clone(),
+            storage: HashMap::new(),
+        };
+        self.deployed_contracts.insert(contract_address, contract);
+        Ok(())
+    }
+
+    fn enter_call(&mut self, contract_address: Felt) {
+        let exec_info = ExecutionInfo {
+            caller_address: self.exec_info.contract_address.clone(),
+            contract_address,
+            ..self.exec_info.clone()
+        };
+        let snapshot = self.snapshot();
+        self.call_stack.push((std::mem::replace(&mut self.exec_info, exec_info), snapshot));
+    }
+
+    fn exit_call(&mut self, failed: bool) {
+        let (exec_info, snapshot) =
+            self.call_stack.pop().expect("Exiting a call that was not entered.");
+        self.exec_info = exec_info;
+        if failed {
+            self.restore(snapshot);
+        }
+    }
+
+    fn cheatcode(&mut self, selector: &Felt, input: &[Felt]) -> Option<Vec<Felt>> {
+        match (as_cairo_short_string(selector)?.as_str(), input) {
+            ("set_caller_address", [address]) => {
+                self.exec_info.caller_address = address.clone();
+                Some(vec![])
+            }
+            ("set_contract_address", [address]) => {
+                self.exec_info.contract_address = address.clone();
+                Some(vec![])
+            }
+            ("set_block_timestamp", [block_timestamp]) => {
+                self.exec_info.block_timestamp = block_timestamp.to_u64()?;
+                Some(vec![])
+            }
+            ("set_storage", [address, key, value]) => {
+                self.storage_mut(address.clone()).insert(key.clone(), value.clone());
+                Some(vec![])
+            }
+            ("pop_event", [address]) => {
+                let Some(Event { keys, data, .. }) = self.pop_event(address) else {
+                    return Some(vec![]);
+                };
+                Some(
+                    chain!([Felt::from(keys.len())], keys, [Felt::from(data.len())], data)
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the instructions for calling `entry_point` with `calldata` and `gas_counter`, assuming
+/// the contract bytecode is placed right after them.
+///
+/// Returns `None` if the entry point requires a builtin that is not supported by the runner.
+pub fn create_entry_point_code(
+    entry_point: &CasmContractEntryPoint,
+    calldata: &[Felt],
+    gas_counter: &Felt,
+) -> Option<Vec<Instruction>> {
+    // The offset [fp - i] of each builtin, matching the builtins the runner initializes.
+    let builtin_offset = HashMap::<&str, i16>::from([
+        ("pedersen", 6),
+        ("range_check", 5),
+        ("bitwise", 4),
+        ("ec_op", 3),
+    ]);
+    let mut ctx = casm! {
+        // The calldata segment, at [fp + 0].
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 1;
+    };
+    for (i, value) in calldata.iter().enumerate() {
+        let i = i as i16;
+        casm_extend! {ctx,
+            [ap + 0] = (value.to_bigint()), ap++;
+            [ap - 1] = [[fp + 0] + i];
+        }
+    }
+    for builtin in &entry_point.builtins {
+        let offset = *builtin_offset.get(builtin.as_str())?;
+        casm_extend! {ctx,
+            [ap + 0] = [fp - offset], ap++;
+        }
+    }
+    let calldata_len = calldata.len();
+    casm_extend! {ctx,
+        [ap + 0] = (gas_counter.to_bigint()), ap++;
+        // The system segment.
+        %{ memory[ap + 0] = segments.add() %}
+        ap += 1;
+        [ap + 0] = [fp + 0], ap++;
+        [ap + 0] = [fp + 0] + (calldata_len), ap++;
+    }
+    let before_final_call = ctx.current_code_offset;
+    let final_call_size = 3;
+    let offset = final_call_size + entry_point.offset;
+    casm_extend! {ctx,
+        call rel offset;
+        ret;
+    }
+    assert_eq!(before_final_call + final_call_size, ctx.current_code_offset);
+    Some(ctx.instructions)
+}
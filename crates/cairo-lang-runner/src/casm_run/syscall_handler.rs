@@ -0,0 +1,73 @@
+use cairo_felt::Felt;
+
+use super::starknet_state::DeployedContract;
+
+/// The result of a system call - either its response, or the revert reason.
+pub type SyscallResult<T> = Result<T, Vec<Felt>>;
+
+/// The info of the running transaction, as returned by the `get_tx_info` system call.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxInfo {
+    /// The version of the transaction.
+    pub version: Felt,
+    /// The account contract from which the transaction originates.
+    pub account_contract_address: Felt,
+    /// The max fee of the transaction.
+    pub max_fee: Felt,
+    /// The signature of the transaction.
+    pub signature: Vec<Felt>,
+    /// The hash of the transaction.
+    pub transaction_hash: Felt,
+    /// The identifier of the chain.
+    pub chain_id: Felt,
+    /// The nonce of the transaction.
+    pub nonce: Felt,
+}
+
+/// Handles the system calls and cheatcodes of a run, by simulating the state of StarkNet.
+///
+/// The runner decodes the system calls and charges their simulated gas costs, while the handler
+/// provides their behavior. An `Err` result reverts the system call with the given reason.
+pub trait SyscallHandler {
+    /// Reads the value at `address` in the storage of the running contract.
+    fn storage_read(&mut self, address_domain: Felt, address: Felt) -> SyscallResult<Felt>;
+    /// Writes `value` at `address` in the storage of the running contract.
+    fn storage_write(
+        &mut self,
+        address_domain: Felt,
+        address: Felt,
+        value: Felt,
+    ) -> SyscallResult<()>;
+    /// Emits an event with the given keys and data from the running contract.
+    fn emit_event(&mut self, keys: Vec<Felt>, data: Vec<Felt>) -> SyscallResult<()>;
+    /// Sends a message with the given payload from the running contract to `to_address` on L1.
+    fn send_message_to_l1(&mut self, to_address: Felt, payload: Vec<Felt>) -> SyscallResult<()>;
+    /// Returns the address of the caller of the running contract.
+    fn get_caller_address(&mut self) -> SyscallResult<Felt>;
+    /// Returns the address of the running contract.
+    fn get_contract_address(&mut self) -> SyscallResult<Felt>;
+    /// Returns the number of the current block.
+    fn get_block_number(&mut self) -> SyscallResult<u64>;
+    /// Returns the timestamp of the current block.
+    fn get_block_timestamp(&mut self) -> SyscallResult<u64>;
+    /// Returns the address of the sequencer of the current block.
+    fn get_sequencer_address(&mut self) -> SyscallResult<Felt>;
+    /// Returns the info of the running transaction.
+    fn get_tx_info(&mut self) -> SyscallResult<TxInfo>;
+    /// Returns the contract deployed at `contract_address`, if there is one. Used for running the
+    /// called contract in `call_contract` system calls.
+    fn deployed_contract(&self, contract_address: &Felt) -> Option<&DeployedContract>;
+    /// Deploys an instance of the declared class `class_hash` at `contract_address`, with an empty
+    /// storage. Called after entering the call of the constructor of the new contract, which rolls
+    /// back the deployment if it fails.
+    fn deploy(&mut self, class_hash: Felt, contract_address: Felt) -> SyscallResult<()>;
+    /// Called before running a call to the contract at `contract_address`, which runs until the
+    /// matching [SyscallHandler::exit_call].
+    fn enter_call(&mut self, contract_address: Felt);
+    /// Called after a contract call returns. If the call failed, the changes it made to the state,
+    /// such as storage writes, emitted events and deployed contracts, are rolled back.
+    fn exit_call(&mut self, failed: bool);
+    /// Runs the cheatcode identified by `selector` with the given input, and returns its output.
+    /// Returns `None` if the cheatcode is unknown or its input is invalid.
+    fn cheatcode(&mut self, selector: &Felt, input: &[Felt]) -> Option<Vec<Felt>>;
+}
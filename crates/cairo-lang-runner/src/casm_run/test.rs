@@ -1,11 +1,12 @@
-use cairo_felt::Felt;
+use cairo_felt::{self as felt, felt_str, Felt};
 use cairo_lang_casm::inline::CasmContext;
 use cairo_lang_casm::{casm, deref};
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use test_case::test_case;
 
-use crate::casm_run::run_function;
+use crate::casm_run::contract_address::calculate_contract_address;
+use crate::casm_run::{run_function, StarknetState};
 
 #[test_case(
     casm! {
@@ -101,8 +102,13 @@ use crate::casm_run::run_function;
     "simple_division"
 )]
 fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
-    let (cells, ap) = run_function(function.instructions.iter(), vec![], |_| Ok(()))
-        .expect("Running code failed.");
+    let (cells, ap, _) = run_function(
+        function.instructions.iter(),
+        vec![],
+        |_| Ok(()),
+        &mut StarknetState::default(),
+    )
+    .expect("Running code failed.");
     let cells = cells.into_iter().skip(ap - n_returns);
     assert_eq!(
         cells.take(n_returns).map(|cell| cell.unwrap()).collect_vec(),
@@ -112,7 +118,7 @@ fn test_runner(function: CasmContext, n_returns: usize, expected: &[i128]) {
 
 #[test]
 fn test_allocate_segment() {
-    let (memory, ap) = run_function(
+    let (memory, ap, _) = run_function(
         casm! {
             [ap] = 1337, ap++;
             %{ memory[ap] = segments.add() %}
@@ -123,6 +129,7 @@ fn test_allocate_segment() {
         .iter(),
         vec![],
         |_| Ok(()),
+        &mut StarknetState::default(),
     )
     .expect("Running code failed.");
     let ptr = memory[ap]
@@ -132,3 +139,17 @@ fn test_allocate_segment() {
         .expect("Number not in index range.");
     assert_eq!(memory[ptr], Some(Felt::from(1337)));
 }
+
+#[test]
+fn test_calculate_contract_address() {
+    // Matches `get_contract_address` of the `starknet-core` crate.
+    assert_eq!(
+        calculate_contract_address(
+            &felt_str!("18a7a329d1d85b621350f2b5fc9c64b2e57dfe708525f0aff2c90de1e5b9c8", 16),
+            &felt_str!("750cd490a7cd1572411169eaa8be292325990d33c5d4733655fe6b926985062", 16),
+            &[Felt::from(1)],
+            &Felt::from(0),
+        ),
+        felt_str!("da27ef7c3869c3a6cc6a0f7bf07a51c3e590825adba8a51cae27d815839eec", 16)
+    );
+}
@@ -1,31 +1,63 @@
 //! Compiles and runs a Cairo program.
 
+use std::collections::HashMap;
+use std::io::Write;
 use std::path::Path;
 
-use anyhow::{Context, Ok};
+use anyhow::{bail, Context, Ok};
+use cairo_felt::Felt;
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, MessageFormat};
 use cairo_lang_compiler::project::setup_project;
+use cairo_lang_defs::ids::{FreeFunctionId, ModuleItemId, TopLevelLanguageElementId};
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_runner::SierraCasmRunner;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_runner::debugger::{
+    get_statements_source_locations, Breakpoint, Debugger, SourceLocation, StopReason,
+};
+use cairo_lang_runner::short_string::as_cairo_short_string;
+use cairo_lang_runner::typed_value::decode_value;
+use cairo_lang_runner::{Arg, SierraCasmRunner};
+use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
 #[derive(Parser, Debug)]
 #[clap(version, verbatim_doc_comment)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// The file to compile and run.
     #[arg(short, long)]
     path: String,
+    /// The path of the function to run, or a suffix of it.
+    #[arg(long, default_value = "main")]
+    function: String,
+    /// The arguments to pass to the function, separated by whitespace.
+    /// Each argument is a number, a short string (e.g. 'abc'), or an array of those
+    /// (e.g. [1 2 'abc']).
+    #[arg(long, value_parser = parse_function_args, default_value = "", allow_hyphen_values = true)]
+    args: FunctionArgs,
     /// In cases where gas is available, the amount of provided gas.
     #[arg(long)]
     available_gas: Option<usize>,
     /// Whether to print the memory.
     #[arg(long, default_value_t = false)]
     print_full_memory: bool,
+    /// The format in which diagnostics are reported.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+}
+
+/// The modes of running the function, other than running it to completion.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs the function, and replays the run in an interactive debugger before printing its
+    /// result.
+    Debug,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -35,12 +67,20 @@ fn main() -> anyhow::Result<()> {
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
 
-    if DiagnosticsReporter::stderr().check(db) {
+    if DiagnosticsReporter::stderr().with_message_format(args.message_format).check(db) {
         anyhow::bail!("failed to compile: {}", args.path);
     }
 
+    let function_id = find_function(db, &main_crate_ids, &args.function)
+        .with_context(|| format!("Function `{}` not found.", args.function))?;
+    let function_path = function_id.full_path(db);
+    let return_type = db
+        .free_function_signature(function_id)
+        .to_option()
+        .with_context(|| "Failed getting the function signature.")?
+        .return_type;
     let sierra_program = db
-        .get_sierra_program(main_crate_ids)
+        .get_sierra_program(main_crate_ids.clone())
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
     let runner = SierraCasmRunner::new(
@@ -49,14 +89,32 @@ fn main() -> anyhow::Result<()> {
     )
     .with_context(|| "Failed setting up runner.")?;
     let result = runner
-        .run_function("::main", &[], args.available_gas)
+        .run_function(&function_path, &args.args.0, args.available_gas)
         .with_context(|| "Failed to run the function.")?;
+    if let Some(Command::Debug) = args.command {
+        let locations = db
+            .get_sierra_program_statement_locations(main_crate_ids)
+            .to_option()
+            .with_context(|| "Failed getting the source locations of the program.")?;
+        let locations = get_statements_source_locations(db, &locations);
+        run_debugger_repl(Debugger::new(&runner, &result, locations))?;
+    }
     match result.value {
         cairo_lang_runner::RunResultValue::Success(values) => {
-            println!("Run completed successfully, returning {values:?}")
+            let value = decode_value(db, return_type, &values, &result.memory)
+                .to_option()
+                .with_context(|| "Failed decoding the return value.")?;
+            println!("Run completed successfully, returning {value}")
         }
         cairo_lang_runner::RunResultValue::Panic(values) => {
-            println!("Run panicked with err values: {values:?}")
+            print!("Run panicked with err values: [");
+            for value in &values {
+                match as_cairo_short_string(value) {
+                    Some(as_string) => print!("{value} ('{as_string}'), "),
+                    None => print!("{value}, "),
+                }
+            }
+            println!("]")
         }
     }
     if let Some(gas) = result.gas_counter {
@@ -74,3 +132,249 @@ fn main() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+/// The commands of the debugger.
+const DEBUGGER_HELP: &str = "\
+step (s)              Continue to the next line, stepping into function calls.
+next (n)              Continue to the next line of the current function.
+finish (f)            Continue until the current function returns.
+continue (c)          Continue until a breakpoint is hit.
+restart (r)           Restart the replay from the beginning of the run.
+break (b) [FILE:]LINE Add a breakpoint, in the current file if none is given.
+delete (d) ID         Delete a breakpoint.
+breakpoints           List the breakpoints.
+backtrace (bt)        Show the call stack.
+locals [FRAME]        Show the Sierra variables alive in a frame of the call stack.
+list (l)              Show the source code around the current line.
+quit (q)              Quit the debugger and print the result of the run.";
+
+/// Runs the debugger, reading commands from the standard input until it is closed or the user
+/// quits.
+fn run_debugger_repl(mut debugger: Debugger<'_>) -> anyhow::Result<()> {
+    println!("Debugging the run. Type `help` for the list of commands.");
+    let mut sources = SourceFiles::default();
+    print_debugger_stop(&debugger, StopReason::Step, &mut sources);
+    let stdin = std::io::stdin();
+    loop {
+        print!("(cairo-debug) ");
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            println!();
+            return Ok(());
+        }
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else { continue };
+        let argument = words.next();
+        let reason = match command {
+            "s" | "step" => debugger.step_into(),
+            "n" | "next" => debugger.step_over(),
+            "f" | "finish" => debugger.step_out(),
+            "c" | "continue" => debugger.continue_run(),
+            "r" | "restart" => {
+                debugger.restart();
+                StopReason::Step
+            }
+            "b" | "break" => {
+                match argument.and_then(|argument| parse_breakpoint(argument, debugger.location()))
+                {
+                    Some(breakpoint) => {
+                        let (file, line) = (breakpoint.file.clone(), breakpoint.line + 1);
+                        let id = debugger.add_breakpoint(breakpoint);
+                        println!("Breakpoint {id} at {file}:{line}.");
+                    }
+                    None => println!("Usage: break [FILE:]LINE"),
+                }
+                continue;
+            }
+            "d" | "delete" => {
+                match argument.and_then(|argument| argument.parse().ok()) {
+                    Some(id) if debugger.remove_breakpoint(id).is_some() => {
+                        println!("Deleted breakpoint {id}.")
+                    }
+                    _ => println!("Usage: delete ID, where ID is the id of a breakpoint."),
+                }
+                continue;
+            }
+            "breakpoints" => {
+                for (id, breakpoint) in debugger.breakpoints() {
+                    println!("{id}: {}:{}", breakpoint.file, breakpoint.line + 1);
+                }
+                continue;
+            }
+            "bt" | "backtrace" => {
+                for (i, frame) in debugger.stack_frames().iter().enumerate() {
+                    match &frame.location {
+                        Some(location) => println!("#{i} {} at {location}", frame.function),
+                        None => {
+                            println!("#{i} {} at statement {}", frame.function, frame.statement_idx)
+                        }
+                    }
+                }
+                continue;
+            }
+            "locals" => {
+                let frame = argument.and_then(|argument| argument.parse().ok()).unwrap_or(0);
+                for variable in debugger.variables(frame) {
+                    println!("{}: {} = {}", variable.name, variable.ty, variable.value);
+                }
+                continue;
+            }
+            "l" | "list" => {
+                match debugger.location() {
+                    Some(location) => sources.print_lines(
+                        location,
+                        location.line.saturating_sub(5),
+                        location.line + 6,
+                    ),
+                    None => println!("The current location is unknown."),
+                }
+                continue;
+            }
+            "h" | "help" => {
+                println!("{DEBUGGER_HELP}");
+                continue;
+            }
+            "q" | "quit" => return Ok(()),
+            _ => {
+                println!("Unknown command `{command}`. Type `help` for the list of commands.");
+                continue;
+            }
+        };
+        print_debugger_stop(&debugger, reason, &mut sources);
+    }
+}
+
+/// Prints where the debugger stopped.
+fn print_debugger_stop(debugger: &Debugger<'_>, reason: StopReason, sources: &mut SourceFiles) {
+    if debugger.is_finished() {
+        println!("The run ended. Use `restart` to replay it again.");
+        return;
+    }
+    if let StopReason::Breakpoint(id) = reason {
+        println!("Hit breakpoint {id}.");
+    }
+    let frames = debugger.stack_frames();
+    let Some(location) = debugger.location() else { return };
+    println!("{} at {location}", frames[0].function);
+    sources.print_lines(location, location.line, location.line + 1);
+}
+
+/// Parses a breakpoint given as `[FILE:]LINE`, where the file defaults to the file of `location`.
+fn parse_breakpoint(argument: &str, location: Option<&SourceLocation>) -> Option<Breakpoint> {
+    let (file, line) = match argument.rsplit_once(':') {
+        Some((file, line)) => (file.to_string(), line),
+        None => (location?.file_path.clone(), argument),
+    };
+    let line = line.parse::<usize>().ok()?.checked_sub(1)?;
+    Some(Breakpoint { file, line })
+}
+
+/// The lines of the source files shown by the debugger, read on first use.
+#[derive(Default)]
+struct SourceFiles {
+    files: HashMap<String, Option<Vec<String>>>,
+}
+impl SourceFiles {
+    /// Prints the lines in the range `[start, end)` of the file of `location`, marking its line.
+    fn print_lines(&mut self, location: &SourceLocation, start: usize, end: usize) {
+        let lines = self.files.entry(location.file_path.clone()).or_insert_with(|| {
+            let content = std::fs::read_to_string(&location.file_path).ok()?;
+            Some(content.lines().map(str::to_string).collect())
+        });
+        let Some(lines) = lines else { return };
+        for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+            let marker = if i == location.line { "->" } else { "  " };
+            println!("{marker}{:>5} {line}", i + 1);
+        }
+    }
+}
+
+/// The arguments to pass to the ran function.
+#[derive(Clone, Debug)]
+struct FunctionArgs(Vec<Arg>);
+
+/// Parses the arguments to pass to the ran function, see [Args::args].
+fn parse_function_args(input: &str) -> anyhow::Result<FunctionArgs> {
+    let mut args = vec![];
+    // The values of the array currently being parsed, if any.
+    let mut array: Option<Vec<Felt>> = None;
+    let mut chars = input.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let value = match chars.peek() {
+            None => break,
+            Some('[') => {
+                chars.next();
+                if array.replace(vec![]).is_some() {
+                    bail!("Nested arrays are not supported.");
+                }
+                continue;
+            }
+            Some(']') => {
+                chars.next();
+                args.push(Arg::Array(array.take().context("Unexpected `]`.")?));
+                continue;
+            }
+            Some('\'') => {
+                chars.next();
+                let text: String = chars.by_ref().take_while(|c| *c != '\'').collect();
+                if !text.is_ascii() || text.len() > 31 {
+                    bail!("Invalid short string `'{text}'`.");
+                }
+                Felt::from_bytes_be(text.as_bytes())
+            }
+            Some(_) => {
+                let mut text = String::new();
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != ']') {
+                    text.push(c);
+                }
+                parse_felt(&text).with_context(|| format!("Invalid number `{text}`."))?
+            }
+        };
+        match &mut array {
+            Some(values) => values.push(value),
+            None => args.push(Arg::Value(value)),
+        }
+    }
+    if array.is_some() {
+        bail!("Missing `]`.");
+    }
+    Ok(FunctionArgs(args))
+}
+
+/// Parses a decimal or hexadecimal (`0x` prefixed) number, possibly negative, into a felt.
+fn parse_felt(text: &str) -> Option<Felt> {
+    let (is_negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let value = match text.strip_prefix("0x") {
+        Some(digits) => Felt::parse_bytes(digits.as_bytes(), 16),
+        None => Felt::parse_bytes(text.as_bytes(), 10),
+    }?;
+    Some(if is_negative { -value } else { value })
+}
+
+/// Finds the free function in the main crates whose path is `path` or ends with it.
+fn find_function(
+    db: &dyn SemanticGroup,
+    main_crates: &[CrateId],
+    path: &str,
+) -> Option<FreeFunctionId> {
+    let path_suffix = format!("::{path}");
+    main_crates
+        .iter()
+        .flat_map(|crate_id| db.crate_modules(*crate_id).iter().copied().collect::<Vec<_>>())
+        .flat_map(|module_id| {
+            db.module_items(module_id).ok().unwrap_or_default().iter().copied().collect::<Vec<_>>()
+        })
+        .find_map(|item| match item {
+            ModuleItemId::FreeFunction(function_id) => {
+                let function_path = function_id.full_path(db);
+                (function_path == path || function_path.ends_with(&path_suffix))
+                    .then_some(function_id)
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,523 @@
+//! Source level debugging of runs of Sierra programs.
+//!
+//! A run is completed first, and the debugger then replays its trace: each step is mapped to the
+//! Sierra statement it executes, and from it to the Cairo source code the statement was generated
+//! from.
+
+use std::fmt;
+use std::iter::zip;
+use std::path::Path;
+
+use cairo_felt::Felt;
+use cairo_lang_casm::cell_expression::{CellExpression, CellOperator};
+use cairo_lang_casm::operand::{CellRef, DerefOrImmediate, Register};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_sierra::extensions::array::ArrayType;
+use cairo_lang_sierra::extensions::enm::EnumType;
+use cairo_lang_sierra::extensions::structure::StructType;
+use cairo_lang_sierra::extensions::{ConcreteType, NamedType};
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{GenericArg, StatementIdx};
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_to_casm::invocations::variant_index_from_selector;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::{RunResult, SierraCasmRunner};
+
+/// A location in the source code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceLocation {
+    /// The path of the file, or the name of a virtual file generated by the compiler.
+    pub file_path: String,
+    /// The line in the file, starting from 0.
+    pub line: usize,
+    /// The column in the line, starting from 0.
+    pub col: usize,
+}
+impl SourceLocation {
+    /// Returns the source location of the beginning of `location`.
+    pub fn from_stable_location(db: &dyn SierraGenGroup, location: StableLocation) -> Option<Self> {
+        let defs_db: &dyn DefsGroup = db.upcast();
+        let location = location.diagnostic_location(defs_db);
+        let files_db: &dyn FilesGroup = defs_db.upcast();
+        let position = location.span.start.position_in_file(files_db, location.file_id)?;
+        let file_path = match db.lookup_intern_file(location.file_id) {
+            FileLongId::OnDisk(path) => path.to_string_lossy().to_string(),
+            FileLongId::Virtual(file) => file.name.to_string(),
+        };
+        Some(Self { file_path, line: position.line, col: position.col })
+    }
+
+    /// Returns whether the location is in `file`, given by its path or by a suffix of it.
+    pub fn is_in_file(&self, file: &str) -> bool {
+        Path::new(&self.file_path).ends_with(file) || Path::new(file).ends_with(&self.file_path)
+    }
+}
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file_path, self.line + 1, self.col + 1)
+    }
+}
+
+/// Returns the source locations of the statements of a Sierra program, given the locations
+/// returned by [SierraGenGroup::get_sierra_program_statement_locations].
+pub fn get_statements_source_locations(
+    db: &dyn SierraGenGroup,
+    locations: &[Option<StableLocation>],
+) -> Vec<Option<SourceLocation>> {
+    locations
+        .iter()
+        .map(|location| SourceLocation::from_stable_location(db, (*location)?))
+        .collect()
+}
+
+/// A breakpoint on a line of the source code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breakpoint {
+    /// The file of the breakpoint, given by its path or by a suffix of it.
+    pub file: String,
+    /// The line of the breakpoint, starting from 0.
+    pub line: usize,
+}
+
+impl Breakpoint {
+    /// Returns whether `location` is on the line of the breakpoint.
+    pub fn matches(&self, location: &SourceLocation) -> bool {
+        self.line == location.line && location.is_in_file(&self.file)
+    }
+}
+
+/// The reason the replay of a run stopped.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StopReason {
+    /// The requested step was completed.
+    Step,
+    /// The breakpoint with the given id was hit.
+    Breakpoint(usize),
+    /// The end of the run was reached.
+    End,
+}
+
+/// A frame of the call stack.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StackFrame {
+    /// The name of the function of the frame.
+    pub function: String,
+    /// The Sierra statement being executed in the frame.
+    pub statement_idx: StatementIdx,
+    /// The source location of the statement, if known.
+    pub location: Option<SourceLocation>,
+}
+
+/// A Sierra variable alive in a frame, decoded according to its type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Variable {
+    /// The name of the variable.
+    pub name: String,
+    /// The name of the type of the variable.
+    pub ty: String,
+    /// The value of the variable. Structs are shown with their members in order, enums with the
+    /// index of their variant, and unknown cells as `?`.
+    pub value: String,
+}
+
+/// The beginning of the execution of a Sierra statement in the trace of the run.
+#[derive(Clone, Debug)]
+struct Stop {
+    statement_idx: StatementIdx,
+    /// The value of `ap` at the beginning of the statement.
+    ap: usize,
+    /// The value of `fp` at the beginning of the statement.
+    fp: usize,
+    /// The number of frames in the call stack.
+    depth: usize,
+    /// The stop of the statement which called the function of this stop, if any.
+    caller: Option<usize>,
+}
+
+/// A debugger replaying a run of a [SierraCasmRunner].
+pub struct Debugger<'a> {
+    runner: &'a SierraCasmRunner,
+    /// The memory at the end of the run.
+    memory: &'a [Option<Felt>],
+    /// The source location of each Sierra statement, if known.
+    locations: Vec<Option<SourceLocation>>,
+    /// The entry points of the functions of the program, with their names, in order.
+    functions: Vec<(usize, String)>,
+    stops: Vec<Stop>,
+    /// The index of the current stop, which is `stops.len()` once the replay reached the end of
+    /// the run.
+    position: usize,
+    breakpoints: OrderedHashMap<usize, Breakpoint>,
+    next_breakpoint_id: usize,
+}
+impl<'a> Debugger<'a> {
+    /// Creates a debugger replaying `result`, which is the result of a run of `runner`, given the
+    /// source locations of the statements of the program. The replay starts at the first statement
+    /// with a known location.
+    pub fn new(
+        runner: &'a SierraCasmRunner,
+        result: &'a RunResult,
+        locations: Vec<Option<SourceLocation>>,
+    ) -> Self {
+        let statement_info = &runner.casm_program.debug_info.sierra_statement_info;
+        let mut stops = vec![];
+        // The `fp` of each frame of the call stack, with the stop which called it.
+        let mut frames: Vec<(usize, Option<usize>)> = vec![];
+        for entry in &result.trace {
+            while matches!(frames.last(), Some((fp, _)) if entry.fp < *fp) {
+                frames.pop();
+            }
+            if !matches!(frames.last(), Some((fp, _)) if entry.fp == *fp) {
+                frames.push((entry.fp, stops.len().checked_sub(1)));
+            }
+            let Some(code_offset) = entry.code_offset else { continue };
+            // Statements without code share their offset with the statement following them, which
+            // is the one executed.
+            let statement_idx =
+                statement_info.partition_point(|info| info.code_offset <= code_offset) - 1;
+            if statement_info[statement_idx].code_offset == code_offset {
+                stops.push(Stop {
+                    statement_idx: StatementIdx(statement_idx),
+                    ap: entry.ap,
+                    fp: entry.fp,
+                    depth: frames.len(),
+                    caller: frames.last().unwrap().1,
+                });
+            }
+        }
+        let functions = runner
+            .sierra_program
+            .funcs
+            .iter()
+            .map(|function| (function.entry_point.0, function.id.to_string()))
+            .sorted()
+            .collect();
+        let mut debugger = Self {
+            runner,
+            memory: &result.memory,
+            locations,
+            functions,
+            stops,
+            position: 0,
+            breakpoints: OrderedHashMap::default(),
+            next_breakpoint_id: 1,
+        };
+        debugger.restart();
+        debugger
+    }
+
+    /// Restarts the replay from the first statement with a known location.
+    pub fn restart(&mut self) {
+        self.position = (0..self.stops.len())
+            .find(|position| self.stop_location(*position).is_some())
+            .unwrap_or(self.stops.len());
+    }
+
+    /// Returns whether the replay reached the end of the run.
+    pub fn is_finished(&self) -> bool {
+        self.position == self.stops.len()
+    }
+
+    /// Returns the source location of the current statement, if known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.stop_location(self.position)
+    }
+
+    /// Adds a breakpoint, and returns its id.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> usize {
+        let id = self.next_breakpoint_id;
+        self.next_breakpoint_id += 1;
+        self.breakpoints.insert(id, breakpoint);
+        id
+    }
+
+    /// Removes the breakpoint with the given id, and returns it if it existed.
+    pub fn remove_breakpoint(&mut self, id: usize) -> Option<Breakpoint> {
+        self.breakpoints.swap_remove(&id)
+    }
+
+    /// Returns the breakpoints with their ids, in the order they were added.
+    pub fn breakpoints(&self) -> impl Iterator<Item = (usize, &Breakpoint)> {
+        self.breakpoints.iter().map(|(id, breakpoint)| (*id, breakpoint))
+    }
+
+    /// Returns whether some statement of the program is located on the line of `breakpoint`, so
+    /// the breakpoint may be hit.
+    pub fn has_code_at(&self, breakpoint: &Breakpoint) -> bool {
+        let statement_info = &self.runner.casm_program.debug_info.sierra_statement_info;
+        // Statements without code are never stopped at.
+        zip(statement_info.iter().tuple_windows(), &self.locations).any(
+            |((info, next_info), location)| {
+                next_info.code_offset > info.code_offset
+                    && matches!(location, Some(location) if breakpoint.matches(location))
+            },
+        )
+    }
+
+    /// Returns the id of the first breakpoint on the current line, if any.
+    pub fn current_breakpoint(&self) -> Option<usize> {
+        self.breakpoint_at(self.location()?)
+    }
+
+    /// Continues to the next source line executed, possibly in a called function.
+    pub fn step_into(&mut self) -> StopReason {
+        self.run_until(|_, _| true)
+    }
+
+    /// Continues to the next source line of the current function, or of its caller if it
+    /// returns.
+    pub fn step_over(&mut self) -> StopReason {
+        let depth = self.current_depth();
+        let start = self.location().cloned();
+        self.run_until(|stop_depth, location| {
+            stop_depth < depth
+                || (stop_depth == depth
+                    && !matches!(&start, Some(start)
+                        if location.line == start.line && location.file_path == start.file_path))
+        })
+    }
+
+    /// Continues until the current function returns to its caller.
+    pub fn step_out(&mut self) -> StopReason {
+        let depth = self.current_depth();
+        self.run_until(|stop_depth, _| stop_depth < depth)
+    }
+
+    /// Continues until a breakpoint is hit.
+    pub fn continue_run(&mut self) -> StopReason {
+        self.run_until(|_, _| false)
+    }
+
+    /// Returns the frames of the call stack, innermost first.
+    pub fn stack_frames(&self) -> Vec<StackFrame> {
+        let mut frames = vec![];
+        let mut position = (!self.is_finished()).then_some(self.position);
+        while let Some(current) = position {
+            let stop = &self.stops[current];
+            frames.push(StackFrame {
+                function: self.function_name(stop.statement_idx),
+                statement_idx: stop.statement_idx,
+                location: self.stop_location(current).cloned(),
+            });
+            position = stop.caller;
+        }
+        frames
+    }
+
+    /// Returns the Sierra variables alive in the given frame of the call stack, where 0 is the
+    /// innermost frame.
+    pub fn variables(&self, frame: usize) -> Vec<Variable> {
+        let mut position = (!self.is_finished()).then_some(self.position);
+        for _ in 0..frame {
+            position = position.and_then(|current| self.stops[current].caller);
+        }
+        let Some(position) = position else { return vec![] };
+        let stop = &self.stops[position];
+        let statement_info =
+            &self.runner.casm_program.debug_info.sierra_statement_info[stop.statement_idx.0];
+        statement_info
+            .references
+            .iter()
+            .map(|(var_id, reference)| {
+                let cells = reference
+                    .expression
+                    .cells
+                    .iter()
+                    .map(|cell| self.evaluate_cell(cell, stop))
+                    .collect_vec();
+                Variable {
+                    name: var_id.to_string(),
+                    ty: reference.ty.to_string(),
+                    value: self.format_value(&reference.ty, &cells),
+                }
+            })
+            .collect()
+    }
+
+    /// Moves to the first stop after the current one that starts a new source line and either
+    /// hits a breakpoint or satisfies `should_stop`, given the stop's depth and location.
+    fn run_until(&mut self, should_stop: impl Fn(usize, &SourceLocation) -> bool) -> StopReason {
+        let mut previous_line = self.line_of(self.position);
+        for position in self.position + 1..self.stops.len() {
+            let Some(line) = self.line_of(position) else { continue };
+            if Some(line) == previous_line {
+                continue;
+            }
+            previous_line = Some(line);
+            let location = self.stop_location(position).unwrap();
+            if let Some(id) = self.breakpoint_at(location) {
+                self.position = position;
+                return StopReason::Breakpoint(id);
+            }
+            if should_stop(self.stops[position].depth, location) {
+                self.position = position;
+                return StopReason::Step;
+            }
+        }
+        self.position = self.stops.len();
+        StopReason::End
+    }
+
+    /// Returns the source line of a stop, with its depth, if its location is known.
+    fn line_of(&self, position: usize) -> Option<(&str, usize, usize)> {
+        let location = self.stop_location(position)?;
+        Some((&location.file_path, location.line, self.stops[position].depth))
+    }
+
+    /// Returns the id of the first breakpoint on the line of `location`, if any.
+    fn breakpoint_at(&self, location: &SourceLocation) -> Option<usize> {
+        self.breakpoints
+            .iter()
+            .find_map(|(id, breakpoint)| breakpoint.matches(location).then_some(*id))
+    }
+
+    fn stop_location(&self, position: usize) -> Option<&SourceLocation> {
+        self.locations.get(self.stops.get(position)?.statement_idx.0)?.as_ref()
+    }
+
+    fn current_depth(&self) -> usize {
+        self.stops.get(self.position).map_or(0, |stop| stop.depth)
+    }
+
+    /// Returns the name of the function containing the given statement.
+    fn function_name(&self, statement_idx: StatementIdx) -> String {
+        let index =
+            self.functions.partition_point(|(entry_point, _)| *entry_point <= statement_idx.0);
+        index.checked_sub(1).map_or_else(|| "<unknown>".into(), |i| self.functions[i].1.clone())
+    }
+
+    /// Evaluates a cell expression at the beginning of the statement of `stop`. Returns `None` if
+    /// the value is unknown.
+    fn evaluate_cell(&self, cell: &CellExpression, stop: &Stop) -> Option<Felt> {
+        Some(match cell {
+            CellExpression::Deref(cell_ref) => self.read(cell_ref, stop)?,
+            CellExpression::DoubleDeref(cell_ref, offset) => {
+                let address = self.read(cell_ref, stop)?.to_i64()? + *offset as i64;
+                self.memory.get(usize::try_from(address).ok()?)?.clone()?
+            }
+            CellExpression::Immediate(value) => Felt::from(value.clone()),
+            CellExpression::BinOp { op, a, b } => {
+                let a = self.read(a, stop)?;
+                let b = match b {
+                    DerefOrImmediate::Deref(cell_ref) => self.read(cell_ref, stop)?,
+                    DerefOrImmediate::Immediate(value) => Felt::from(value.clone()),
+                };
+                match op {
+                    CellOperator::Add => a + b,
+                    CellOperator::Sub => a - b,
+                    CellOperator::Mul => a * b,
+                    CellOperator::Div if b.is_zero() => return None,
+                    CellOperator::Div => a / b,
+                }
+            }
+        })
+    }
+
+    /// Reads the memory cell `cell_ref` at the beginning of the statement of `stop`.
+    fn read(&self, cell_ref: &CellRef, stop: &Stop) -> Option<Felt> {
+        let base = match cell_ref.register {
+            Register::AP => stop.ap,
+            Register::FP => stop.fp,
+        };
+        let address = usize::try_from(base as i64 + cell_ref.offset as i64).ok()?;
+        self.memory.get(address)?.clone()
+    }
+
+    /// Formats a value of type `ty` given its cells.
+    fn format_value(&self, ty: &ConcreteTypeId, cells: &[Option<Felt>]) -> String {
+        let Ok(concrete_type) = self.runner.sierra_program_registry.get_type(ty) else {
+            return format_cells(cells);
+        };
+        let long_id = &concrete_type.info().long_id;
+        match (&long_id.generic_id, &long_id.generic_args[..]) {
+            (generic_id, [GenericArg::UserType(user_type), members @ ..])
+                if *generic_id == StructType::ID =>
+            {
+                let mut offset = 0;
+                let values = members
+                    .iter()
+                    .map(|member| {
+                        let GenericArg::Type(member_ty) = member else { return "?".into() };
+                        let size = self.type_size(member_ty);
+                        let value = self.format_value(
+                            member_ty,
+                            cells.get(offset..offset + size).unwrap_or_default(),
+                        );
+                        offset += size;
+                        value
+                    })
+                    .join(", ");
+                let name = user_type.to_string();
+                if name == "Tuple" {
+                    format!("({values})")
+                } else {
+                    format!("{}({values})", short_type_name(&name))
+                }
+            }
+            (generic_id, [GenericArg::UserType(user_type), variants @ ..])
+                if *generic_id == EnumType::ID =>
+            {
+                let Some(index) = cells.first().cloned().flatten().and_then(|selector| {
+                    variant_index_from_selector(selector.to_usize()?, variants.len())
+                }) else {
+                    return format_cells(cells);
+                };
+                let Some(GenericArg::Type(variant_ty)) = variants.get(index) else {
+                    return format_cells(cells);
+                };
+                let name = user_type.to_string();
+                if name == "core::bool" {
+                    return (index == 1).to_string();
+                }
+                let size = self.type_size(variant_ty);
+                let value =
+                    self.format_value(variant_ty, cells.get(1..1 + size).unwrap_or_default());
+                format!("{}::{index}({value})", short_type_name(&name))
+            }
+            (generic_id, [GenericArg::Type(element_ty)]) if *generic_id == ArrayType::ID => {
+                let (Some(Some(start)), Some(Some(end))) = (cells.first(), cells.get(1)) else {
+                    return format_cells(cells);
+                };
+                let (Some(start), Some(end)) = (start.to_usize(), end.to_usize()) else {
+                    return format_cells(cells);
+                };
+                let size = self.type_size(element_ty);
+                if size == 0 || end < start || end > self.memory.len() {
+                    return format_cells(cells);
+                }
+                let elements = self.memory[start..end]
+                    .chunks(size)
+                    .map(|element| self.format_value(element_ty, element))
+                    .join(", ");
+                format!("[{elements}]")
+            }
+            _ => format_cells(cells),
+        }
+    }
+
+    /// Returns the number of cells a value of type `ty` takes.
+    fn type_size(&self, ty: &ConcreteTypeId) -> usize {
+        self.runner
+            .sierra_program_registry
+            .get_type(ty)
+            .map_or(0, |concrete_type| concrete_type.info().size as usize)
+    }
+}
+
+/// Formats the cells of a value of a type which is not decoded.
+fn format_cells(cells: &[Option<Felt>]) -> String {
+    let cells = cells.iter().map(|cell| cell.as_ref().map_or("?".into(), Felt::to_string));
+    if cells.len() == 1 { cells.collect() } else { format!("<{}>", cells.format(", ")) }
+}
+
+/// Returns the name of a user type without its module path and generic arguments.
+fn short_type_name(name: &str) -> &str {
+    let path = name.split("::<").next().unwrap();
+    path.rsplit("::").next().unwrap()
+}
@@ -23,12 +23,23 @@ use cairo_lang_sierra_to_casm::compiler::{CairoProgram, CompilationError};
 use cairo_lang_sierra_to_casm::metadata::{calc_metadata, Metadata, MetadataError};
 use cairo_lang_utils::extract_matches;
 use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+use casm_run::RunFunctionContext;
 use itertools::chain;
 use num_traits::ToPrimitive;
 use thiserror::Error;
 
 mod casm_run;
+pub mod debugger;
 pub mod short_string;
+pub mod typed_value;
+
+#[cfg(test)]
+mod test;
+
+pub use casm_run::{
+    DeployedContract, Event, L2ToL1Message, StarknetState, SyscallHandler, SyscallResult, TxInfo,
+};
 
 #[derive(Debug, Error)]
 pub enum RunnerError {
@@ -42,6 +53,8 @@ pub enum RunnerError {
     MissingFunction { suffix: String },
     #[error("Function expects arguments of size {expected} and received {actual} instead.")]
     ArgumentsSizeMismatch { expected: usize, actual: usize },
+    #[error("Array argument {arg_index} is not aligned with the parameters of the function.")]
+    ArgumentUnaligned { arg_index: usize },
     #[error(transparent)]
     ProgramRegistryError(#[from] Box<ProgramRegistryError>),
     #[error(transparent)]
@@ -52,11 +65,48 @@ pub enum RunnerError {
     VirtualMachineError(#[from] Box<VirtualMachineError>),
 }
 
+/// An argument to a function run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Arg {
+    /// A single cell value.
+    Value(Felt),
+    /// An array of values, passed as the pointers to its start and end.
+    Array(Vec<Felt>),
+}
+impl Arg {
+    /// Returns the number of cells the argument takes as a function parameter.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Value(_) => 1,
+            Self::Array(_) => 2,
+        }
+    }
+}
+impl From<Felt> for Arg {
+    fn from(value: Felt) -> Self {
+        Self::Value(value)
+    }
+}
+
 /// The full result of a run.
 pub struct RunResult {
     pub gas_counter: Option<Felt>,
     pub memory: Vec<Option<Felt>>,
     pub value: RunResultValue,
+    /// The executed steps, in order.
+    pub trace: Vec<TraceEntry>,
+}
+
+/// The registers before the execution of a single step of a run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    /// The offset of the executed instruction within the code of the casm program, or `None` for
+    /// the instructions added by the runner around it.
+    pub code_offset: Option<usize>,
+    /// The address of the `ap` register in the memory of the run.
+    pub ap: usize,
+    /// The address of the `fp` register in the memory of the run.
+    pub fp: usize,
 }
 
 /// The ran function return value.
@@ -100,31 +150,36 @@ impl SierraCasmRunner {
     pub fn run_function(
         &self,
         name_suffix: &str,
-        args: &[Felt],
+        args: &[Arg],
         available_gas: Option<usize>,
+    ) -> Result<RunResult, RunnerError> {
+        self.run_function_with_syscall_handler(
+            name_suffix,
+            args,
+            available_gas,
+            &mut StarknetState::default(),
+        )
+    }
+
+    /// Same as [Self::run_function], but handles the system calls and cheatcodes of the run with
+    /// `syscall_handler`. For example, a [StarknetState] allows the function to call the contracts
+    /// deployed in it, and can be inspected after the run.
+    pub fn run_function_with_syscall_handler(
+        &self,
+        name_suffix: &str,
+        args: &[Arg],
+        available_gas: Option<usize>,
+        syscall_handler: &mut dyn SyscallHandler,
     ) -> Result<RunResult, RunnerError> {
         let func = self.find_function(name_suffix)?;
         let initial_gas = self.get_initial_gas(func, available_gas)?;
         let (entry_code, builtins) = self.create_entry_code(func, args, initial_gas)?;
         let footer = self.create_code_footer();
-        let (cells, ap) = casm_run::run_function(
+        let (cells, ap, trace) = casm_run::run_function(
             chain!(entry_code.iter(), self.casm_program.instructions.iter(), footer.iter()),
             builtins,
-            |context| {
-                let vm = context.vm;
-                // Create the builtin cost segment, with dummy values.
-                let builtin_cost_segment = vm.add_memory_segment();
-                for token_type in CostTokenType::iter_precost() {
-                    vm.insert_value(
-                        &(builtin_cost_segment + (token_type.offset_in_builtin_costs() as usize)),
-                        Felt::from(DUMMY_BUILTIN_GAS_COST),
-                    )?;
-                }
-                // Put a pointer to the builtin cost segment at the end of the program (after the
-                // additional `ret` statement).
-                vm.insert_value(&(vm.get_pc() + context.data_len), builtin_cost_segment)?;
-                Ok(())
-            },
+            initialize_vm,
+            syscall_handler,
         )?;
         let mut results_data = self.get_results_data(func, &cells, ap)?;
         // Handling implicits.
@@ -153,7 +208,32 @@ impl SierraCasmRunner {
             let [(ty, values)] = <[_; 1]>::try_from(results_data).ok().unwrap();
             self.handle_main_return_value(ty, values, &cells)?
         };
-        Ok(RunResult { gas_counter, memory: cells, value })
+        let trace = self.locate_trace(&entry_code, trace);
+        Ok(RunResult { gas_counter, memory: cells, value, trace })
+    }
+
+    /// Converts the trace of a run starting with `entry_code` to a trace of [TraceEntry].
+    fn locate_trace(
+        &self,
+        entry_code: &[Instruction],
+        trace: Vec<RelocatedTraceEntry>,
+    ) -> Vec<TraceEntry> {
+        // The program segment is relocated to the beginning of the memory, after the unused
+        // address 0.
+        let code_start = 1 + entry_code.iter().map(|inst| inst.body.op_size()).sum::<usize>();
+        let code_size =
+            self.casm_program.debug_info.sierra_statement_info.last().unwrap().code_offset;
+        trace
+            .into_iter()
+            .map(|entry| TraceEntry {
+                code_offset: entry
+                    .pc
+                    .checked_sub(code_start)
+                    .filter(|code_offset| *code_offset < code_size),
+                ap: entry.ap,
+                fp: entry.fp,
+            })
+            .collect()
     }
 
     /// Handling the main return value to create a `RunResultValue`.
@@ -238,12 +318,32 @@ impl SierraCasmRunner {
     fn create_entry_code(
         &self,
         func: &Function,
-        args: &[Felt],
+        args: &[Arg],
         initial_gas: usize,
     ) -> Result<(Vec<Instruction>, Vec<String>), RunnerError> {
-        let mut arg_iter = args.iter();
+        let mut arg_iter = args.iter().enumerate();
         let mut expected_arguments_size = 0;
         let mut ctx = casm! {};
+        // The offsets [fp + i] of the start pointers of the array arguments.
+        let mut array_start_offsets = vec![];
+        let mut ap_offset: i16 = 0;
+        for arg in args {
+            let Arg::Array(values) = arg else { continue };
+            array_start_offsets.push(ap_offset);
+            casm_extend! {ctx,
+                %{ memory[ap + 0] = segments.add() %}
+                ap += 1;
+            }
+            for (i, value) in values.iter().enumerate() {
+                let i = i as i16;
+                casm_extend! {ctx,
+                    [ap + 0] = (value.to_bigint()), ap++;
+                    [ap - 1] = [[fp + ap_offset] + i];
+                }
+            }
+            ap_offset += 1 + values.len() as i16;
+        }
+        let mut array_start_offset_iter = array_start_offsets.into_iter();
         // The builtins in the formatting expected by the runner.
         let builtins: Vec<_> = ["pedersen", "range_check", "bitwise", "ec_op"]
             .map(&str::to_string)
@@ -297,21 +397,38 @@ impl SierraCasmRunner {
                     [ap + 0] = [ap + offset], ap++;
                 }
             } else {
-                let arg_size = info.size;
-                expected_arguments_size += arg_size as usize;
-                for _ in 0..arg_size {
-                    if let Some(value) = arg_iter.next() {
-                        casm_extend! {ctx,
-                            [ap + 0] = (value.to_bigint()), ap++;
+                let arg_size = info.size as usize;
+                expected_arguments_size += arg_size;
+                let mut taken_size = 0;
+                while taken_size < arg_size {
+                    let Some((arg_index, arg)) = arg_iter.next() else { break };
+                    match arg {
+                        Arg::Value(value) => {
+                            casm_extend! {ctx,
+                                [ap + 0] = (value.to_bigint()), ap++;
+                            }
+                        }
+                        Arg::Array(_) if taken_size + 2 > arg_size => {
+                            return Err(RunnerError::ArgumentUnaligned { arg_index });
+                        }
+                        Arg::Array(values) => {
+                            let offset = array_start_offset_iter.next().unwrap();
+                            let len = values.len();
+                            casm_extend! {ctx,
+                                [ap + 0] = [fp + offset], ap++;
+                                [ap + 0] = [fp + offset] + (len), ap++;
+                            }
                         }
                     }
+                    taken_size += arg.size();
                 }
             }
         }
-        if expected_arguments_size != args.len() {
+        let actual_arguments_size = args.iter().map(Arg::size).sum();
+        if expected_arguments_size != actual_arguments_size {
             return Err(RunnerError::ArgumentsSizeMismatch {
                 expected: expected_arguments_size,
-                actual: args.len(),
+                actual: actual_arguments_size,
             });
         }
         let before_final_call = ctx.current_code_offset;
@@ -356,6 +473,24 @@ impl SierraCasmRunner {
     }
 }
 
+/// Initializes the vm before running a program, by creating the builtin cost segment and putting
+/// a pointer to it at the end of the program.
+fn initialize_vm(context: RunFunctionContext<'_>) -> Result<(), Box<VirtualMachineError>> {
+    let vm = context.vm;
+    // Create the builtin cost segment, with dummy values.
+    let builtin_cost_segment = vm.add_memory_segment();
+    for token_type in CostTokenType::iter_precost() {
+        vm.insert_value(
+            &(builtin_cost_segment + (token_type.offset_in_builtin_costs() as usize)),
+            Felt::from(DUMMY_BUILTIN_GAS_COST),
+        )?;
+    }
+    // Put a pointer to the builtin cost segment at the end of the program (after the additional
+    // `ret` statement).
+    vm.insert_value(&(vm.get_pc() + context.data_len), builtin_cost_segment)?;
+    Ok(())
+}
+
 /// Creates the metadata required for a Sierra program lowering to casm.
 fn create_metadata(
     sierra_program: &cairo_lang_sierra::program::Program,
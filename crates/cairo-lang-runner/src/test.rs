@@ -0,0 +1,288 @@
+use std::path::{Path, PathBuf};
+
+use assert_matches::assert_matches;
+use cairo_felt::Felt;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::project::{enable_crates_cfg, setup_project};
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::corelib::core_crate;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_starknet::contract::starknet_keccak;
+use cairo_lang_starknet::contract_class::compile_path;
+use cairo_lang_starknet::db::StarknetRootDatabaseBuilderEx;
+use pretty_assertions::assert_eq;
+
+use crate::debugger::{get_statements_source_locations, Breakpoint, Debugger, StopReason};
+use crate::short_string::as_cairo_short_string;
+use crate::typed_value::decode_value;
+use crate::{Arg, Event, RunResultValue, RunnerError, SierraCasmRunner, StarknetState};
+
+const CONTRACT_ADDRESS: usize = 1000;
+
+/// Returns a database with the given file in the runner test data set up as the main crate, and
+/// the id of that crate.
+fn setup_db(file_name: &str) -> (RootDatabase, CrateId) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "test_data", file_name].into_iter().collect();
+    let mut db = RootDatabase::builder().detect_corelib().with_starknet().build().unwrap();
+    let main_crate_ids = setup_project(&mut db, &path).unwrap();
+    // The test data uses the testing utilities of the core crate, such as `starknet::testing`.
+    let core_crate_id = core_crate(&db);
+    enable_crates_cfg(&mut db, &[core_crate_id], "testing");
+    (db, main_crate_ids[0])
+}
+
+/// Returns a runner for the given file in the runner test data.
+fn get_runner(file_name: &str) -> SierraCasmRunner {
+    let (db, crate_id) = setup_db(file_name);
+    let sierra_program = db.get_sierra_program(vec![crate_id]).to_option().unwrap();
+    SierraCasmRunner::new(replace_sierra_ids_in_program(&db, &sierra_program), true).unwrap()
+}
+
+/// Returns a StarkNet state with the `HelloStarknet` example contract deployed at
+/// `CONTRACT_ADDRESS`.
+fn get_starknet_state() -> StarknetState {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../cairo-lang-starknet/test_data/hello_starknet.cairo");
+    let contract_class = compile_path(&path, Default::default()).unwrap();
+    let mut starknet_state = StarknetState::default();
+    starknet_state.deploy_contract(Felt::from(CONTRACT_ADDRESS), contract_class).unwrap();
+    starknet_state
+}
+
+#[test]
+fn test_call_contract() {
+    let mut starknet_state = get_starknet_state();
+    let result = get_runner("call_contract.cairo")
+        .run_function_with_syscall_handler(
+            "::increase_and_get_balance",
+            &[Felt::from(CONTRACT_ADDRESS), Felt::from(5)].map(Arg::Value),
+            Some(usize::MAX),
+            &mut starknet_state,
+        )
+        .unwrap();
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt::from(10)]));
+    let contract = starknet_state.deployed_contract(&Felt::from(CONTRACT_ADDRESS)).unwrap();
+    assert_eq!(contract.storage.values().collect::<Vec<_>>(), vec![&Felt::from(10)]);
+}
+
+#[test]
+fn test_call_failing_contract() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/failing_contract.cairo");
+    let contract_class = compile_path(&path, Default::default()).unwrap();
+    let mut starknet_state = StarknetState::default();
+    starknet_state.deploy_contract(Felt::from(CONTRACT_ADDRESS), contract_class).unwrap();
+    let selector = Felt::from(starknet_keccak(b"set_value_and_fail"));
+    let result = get_runner("call_contract.cairo")
+        .run_function_with_syscall_handler(
+            "::emit_and_call_failing_contract",
+            &[Felt::from(CONTRACT_ADDRESS), selector].map(Arg::Value),
+            Some(usize::MAX),
+            &mut starknet_state,
+        )
+        .unwrap();
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt::from_bytes_be(b"FAILED")]));
+    // Only the changes made by the caller remain.
+    let contract = starknet_state.deployed_contract(&Felt::from(CONTRACT_ADDRESS)).unwrap();
+    assert!(contract.storage.is_empty());
+    assert_eq!(
+        starknet_state.events(),
+        [Event {
+            from_address: Felt::from(0),
+            keys: vec![Felt::from(1)],
+            data: vec![Felt::from(10)]
+        }]
+    );
+}
+
+#[test]
+fn test_deploy_in_failing_call() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/failing_contract.cairo");
+    let contract_class = compile_path(&path, Default::default()).unwrap();
+    let mut starknet_state = StarknetState::default();
+    starknet_state.deploy_contract(Felt::from(CONTRACT_ADDRESS), contract_class).unwrap();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../cairo-lang-starknet/test_data/hello_starknet.cairo");
+    let class_hash = Felt::from(1234);
+    let contract_class = compile_path(&path, Default::default()).unwrap();
+    starknet_state.declare_class(class_hash.clone(), contract_class).unwrap();
+    let selector = Felt::from(starknet_keccak(b"deploy_and_fail"));
+    let result = get_runner("call_contract.cairo")
+        .run_function_with_syscall_handler(
+            "::call_deploying_contract",
+            &[Felt::from(CONTRACT_ADDRESS), selector, class_hash].map(Arg::Value),
+            Some(usize::MAX),
+            &mut starknet_state,
+        )
+        .unwrap();
+    let RunResultValue::Success(values) = result.value else {
+        panic!("Expected a successful run.");
+    };
+    // Only the contract deployed by the caller remains.
+    assert!(starknet_state.deployed_contract(&values[0]).is_some());
+}
+
+#[test]
+fn test_call_missing_contract() {
+    let result = get_runner("call_contract.cairo")
+        .run_function_with_syscall_handler(
+            "::call_missing_contract",
+            &[Arg::Value(Felt::from(CONTRACT_ADDRESS + 1))],
+            Some(usize::MAX),
+            &mut get_starknet_state(),
+        )
+        .unwrap();
+    let RunResultValue::Success(values) = result.value else {
+        panic!("Expected a successful run.");
+    };
+    assert_eq!(
+        values.iter().map(|value| as_cairo_short_string(value).unwrap()).collect::<Vec<_>>(),
+        vec!["CONTRACT_NOT_DEPLOYED"]
+    );
+}
+
+#[test]
+fn test_cheatcodes() {
+    let mut starknet_state = StarknetState::default();
+    let result = get_runner("syscalls.cairo")
+        .run_function_with_syscall_handler(
+            "::read_with_cheatcodes",
+            &[Arg::Value(Felt::from(CONTRACT_ADDRESS))],
+            Some(usize::MAX),
+            &mut starknet_state,
+        )
+        .unwrap();
+    assert_eq!(
+        result.value,
+        RunResultValue::Success([CONTRACT_ADDRESS, 7, 1234, 55].map(Felt::from).to_vec())
+    );
+}
+
+#[test]
+fn test_emitted_events() {
+    let mut starknet_state = StarknetState::default();
+    let result = get_runner("syscalls.cairo")
+        .run_function_with_syscall_handler(
+            "::emit_and_pop_event",
+            &[],
+            Some(usize::MAX),
+            &mut starknet_state,
+        )
+        .unwrap();
+    assert_eq!(result.value, RunResultValue::Success(vec![Felt::from(1), Felt::from(10)]));
+    assert_eq!(
+        starknet_state.events(),
+        [Event {
+            from_address: Felt::from(0),
+            keys: vec![Felt::from(2)],
+            data: vec![Felt::from(20)]
+        }]
+    );
+}
+
+#[test]
+fn test_array_args_and_typed_return_value() {
+    let (db, crate_id) = setup_db("typed_values.cairo");
+    let sierra_program = db.get_sierra_program(vec![crate_id]).to_option().unwrap();
+    let runner =
+        SierraCasmRunner::new(replace_sierra_ids_in_program(&db, &sierra_program), true).unwrap();
+    let result = runner
+        .run_function(
+            "::sum_and_wrap",
+            &[
+                Arg::Value(Felt::from(1)),
+                Arg::Array(vec![Felt::from(10), Felt::from(20)]),
+                Arg::Value(Felt::from(100)),
+            ],
+            Some(usize::MAX),
+        )
+        .unwrap();
+    let RunResultValue::Success(values) = result.value else {
+        panic!("Expected a successful run.");
+    };
+    // The file is a submodule of the crate's root module.
+    let module_id = db.crate_modules(crate_id)[1];
+    let Some(ModuleItemId::FreeFunction(function_id)) =
+        db.module_item_by_name(module_id, "sum_and_wrap".into()).unwrap()
+    else {
+        panic!("Function not found.");
+    };
+    let return_type = db.free_function_signature(function_id).unwrap().return_type;
+    assert_eq!(
+        decode_value(&db, return_type, &values, &result.memory).unwrap().to_string(),
+        "(131, Option::Some(Point { x: 131, y: 3 }), false, [1, 2])"
+    );
+}
+
+#[test]
+fn test_unaligned_array_arg() {
+    let result = get_runner("typed_values.cairo").run_function(
+        "::sum_and_wrap",
+        &[Arg::Array(vec![]), Arg::Array(vec![])],
+        Some(usize::MAX),
+    );
+    assert_matches!(result.err(), Some(RunnerError::ArgumentUnaligned { arg_index: 0 }));
+}
+
+#[test]
+fn test_debugger() {
+    let (db, crate_id) = setup_db("debugger.cairo");
+    let sierra_program = db.get_sierra_program(vec![crate_id]).to_option().unwrap();
+    let runner =
+        SierraCasmRunner::new(replace_sierra_ids_in_program(&db, &sierra_program), true).unwrap();
+    let result = runner.run_function("::main", &[], Some(usize::MAX)).unwrap();
+    let locations = db.get_sierra_program_statement_locations(vec![crate_id]).unwrap();
+    let mut debugger =
+        Debugger::new(&runner, &result, get_statements_source_locations(&db, &locations));
+    // Returns the functions of the call stack, with their lines.
+    let stack = |debugger: &Debugger<'_>| {
+        debugger
+            .stack_frames()
+            .into_iter()
+            .map(|frame| format!("{}:{}", frame.function, frame.location.unwrap().line + 1))
+            .collect::<Vec<_>>()
+    };
+    // Returns the values of the variables of a frame.
+    let values = |debugger: &Debugger<'_>, frame: usize| {
+        debugger
+            .variables(frame)
+            .into_iter()
+            .map(|variable| format!("{}: {} = {}", variable.name, variable.ty, variable.value))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(stack(&debugger), vec!["debugger::debugger::main:14"]);
+    assert_eq!(debugger.step_into(), StopReason::Step);
+    assert_eq!(
+        stack(&debugger),
+        vec!["debugger::debugger::scale:10", "debugger::debugger::main:14"]
+    );
+    assert_eq!(values(&debugger, 0), vec!["[10]: debugger::debugger::Point = Point(3, 6)"]);
+    assert_eq!(
+        values(&debugger, 1),
+        vec!["[5]: debugger::debugger::Point = Point(1, 2)", "[6]: felt = 3"]
+    );
+    assert_eq!(debugger.step_over(), StopReason::Step);
+    assert_eq!(stack(&debugger), vec!["debugger::debugger::main:15"]);
+    // The multiplications of `scale` are only computed when its result is stored, so the line of
+    // the breakpoint is the only line of `scale` with code.
+    assert!(!debugger.has_code_at(&Breakpoint { file: "debugger.cairo".into(), line: 8 }));
+    let breakpoint = Breakpoint { file: "debugger.cairo".into(), line: 9 };
+    assert!(debugger.has_code_at(&breakpoint));
+    let breakpoint = debugger.add_breakpoint(breakpoint);
+    assert_eq!(debugger.continue_run(), StopReason::Breakpoint(breakpoint));
+    assert_eq!(debugger.current_breakpoint(), Some(breakpoint));
+    assert_eq!(
+        stack(&debugger),
+        vec!["debugger::debugger::scale:10", "debugger::debugger::main:15"]
+    );
+    assert_eq!(values(&debugger, 0), vec!["[10]: debugger::debugger::Point = Point(30, 60)"]);
+    assert_eq!(debugger.step_out(), StopReason::Step);
+    assert_eq!(stack(&debugger), vec!["debugger::debugger::main:16"]);
+    assert_eq!(debugger.continue_run(), StopReason::End);
+    assert!(debugger.is_finished());
+}
@@ -0,0 +1,153 @@
+//! Decoding of run results according to their Cairo types.
+
+use std::fmt;
+
+use cairo_felt::Felt;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic::corelib::core_bool_ty;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::{ConcreteTypeId, GenericArgumentId, TypeId, TypeLongId};
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_to_casm::invocations::variant_index_from_selector;
+use itertools::Itertools;
+use num_traits::{ToPrimitive, Zero};
+
+/// A value decoded according to its Cairo type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypedValue {
+    /// A value of a single cell type, such as `felt` or an integer.
+    Felt(Felt),
+    Bool(bool),
+    Array(Vec<TypedValue>),
+    Tuple(Vec<TypedValue>),
+    Struct {
+        name: String,
+        members: Vec<(String, TypedValue)>,
+    },
+    Enum {
+        name: String,
+        variant: String,
+        value: Box<TypedValue>,
+    },
+    /// The cells of a value of a type that is not decoded.
+    Raw(Vec<Felt>),
+}
+
+impl fmt::Display for TypedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedValue::Felt(value) => write!(f, "{value}"),
+            TypedValue::Bool(value) => write!(f, "{value}"),
+            TypedValue::Array(values) => write!(f, "[{}]", values.iter().join(", ")),
+            TypedValue::Tuple(values) if values.len() == 1 => write!(f, "({},)", values[0]),
+            TypedValue::Tuple(values) => write!(f, "({})", values.iter().join(", ")),
+            TypedValue::Struct { name, members } => write!(
+                f,
+                "{name} {{ {} }}",
+                members.iter().map(|(member, value)| format!("{member}: {value}")).join(", ")
+            ),
+            TypedValue::Enum { name, variant, value } => write!(f, "{name}::{variant}({value})"),
+            TypedValue::Raw(values) => write!(f, "<{}>", values.iter().join(", ")),
+        }
+    }
+}
+
+/// Decodes `values`, the cells of a value of type `ty`, into a typed value.
+/// The elements of arrays are read from `memory`.
+pub fn decode_value(
+    db: &dyn SierraGenGroup,
+    ty: TypeId,
+    values: &[Felt],
+    memory: &[Option<Felt>],
+) -> Maybe<TypedValue> {
+    let semantic_db: &dyn SemanticGroup = db.upcast();
+    let defs_db: &dyn DefsGroup = db.upcast();
+    if ty == core_bool_ty(semantic_db) {
+        return Ok(TypedValue::Bool(!values[0].is_zero()));
+    }
+    Ok(match db.lookup_intern_type(ty) {
+        TypeLongId::Tuple(types) => {
+            TypedValue::Tuple(decode_sequence(db, types.into_iter(), values, memory)?)
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            let members = db.concrete_struct_members(concrete_struct_id)?;
+            let names = members.keys().map(|name| name.to_string()).collect_vec();
+            let values = decode_sequence(
+                db,
+                members.into_iter().map(|(_, member)| member.ty),
+                values,
+                memory,
+            )?;
+            TypedValue::Struct {
+                name: concrete_struct_id.struct_id(semantic_db).name(defs_db).into(),
+                members: names.into_iter().zip(values).collect(),
+            }
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            let variants = db.concrete_enum_variants(concrete_enum_id)?;
+            let selector = values[0].to_usize().unwrap();
+            let index = variant_index_from_selector(selector, variants.len()).unwrap();
+            let variant = &variants[index];
+            let variant_size = type_size(db, variant.ty)?;
+            TypedValue::Enum {
+                name: concrete_enum_id.enum_id(semantic_db).name(defs_db).into(),
+                variant: variant.id.name(defs_db).into(),
+                value: Box::new(decode_value(
+                    db,
+                    variant.ty,
+                    &values[1..1 + variant_size],
+                    memory,
+                )?),
+            }
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Extern(concrete_extern_type_id)) => {
+            let long_id = db.lookup_intern_concrete_extern_type(concrete_extern_type_id);
+            match (long_id.extern_type_id.name(defs_db).as_str(), &long_id.generic_args[..]) {
+                ("Array", [GenericArgumentId::Type(element_ty)]) => {
+                    let start = values[0].to_usize().unwrap();
+                    let end = values[1].to_usize().unwrap();
+                    let elements: Vec<Felt> =
+                        memory[start..end].iter().map(|cell| cell.clone().unwrap()).collect();
+                    let element_size = type_size(db, *element_ty)?;
+                    TypedValue::Array(
+                        elements
+                            .chunks(element_size)
+                            .map(|element| decode_value(db, *element_ty, element, memory))
+                            .collect::<Maybe<_>>()?,
+                    )
+                }
+                _ if values.len() == 1 => TypedValue::Felt(values[0].clone()),
+                _ => TypedValue::Raw(values.to_vec()),
+            }
+        }
+        TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
+            TypedValue::Raw(values.to_vec())
+        }
+    })
+}
+
+/// Decodes the consecutive values of the given types from `values`.
+fn decode_sequence(
+    db: &dyn SierraGenGroup,
+    types: impl Iterator<Item = TypeId>,
+    values: &[Felt],
+    memory: &[Option<Felt>],
+) -> Maybe<Vec<TypedValue>> {
+    let mut offset = 0;
+    types
+        .map(|ty| {
+            let size = type_size(db, ty)?;
+            let value = decode_value(db, ty, &values[offset..offset + size], memory)?;
+            offset += size;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Returns the number of cells a value of type `ty` takes.
+fn type_size(db: &dyn SierraGenGroup, ty: TypeId) -> Maybe<usize> {
+    Ok(db.get_type_info(db.get_concrete_type_id(ty)?)?.size as usize)
+}
@@ -0,0 +1,60 @@
+use array::ArrayTrait;
+use option::OptionTrait;
+use result::ResultTrait;
+use starknet::SyscallResultTrait;
+use starknet::contract_address_try_from_felt;
+
+#[abi]
+trait IHelloStarknet {
+    fn increase_balance(amount: felt);
+    fn get_balance() -> felt;
+}
+
+fn increase_and_get_balance(address: felt, amount: felt) -> felt {
+    let contract_address = contract_address_try_from_felt(address).unwrap();
+    IHelloStarknetDispatcher::increase_balance(contract_address, amount);
+    IHelloStarknetDispatcher::increase_balance(contract_address, amount);
+    IHelloStarknetDispatcher::get_balance(contract_address)
+}
+
+// Emits an event, then calls an entry point that fails after changing the state of its contract.
+// Returns the revert reason of the call.
+fn emit_and_call_failing_contract(address: felt, selector: felt) -> felt {
+    let mut keys = ArrayTrait::new();
+    keys.append(1);
+    let mut data = ArrayTrait::new();
+    data.append(10);
+    starknet::emit_event_syscall(keys, data).unwrap_syscall();
+    let contract_address = contract_address_try_from_felt(address).unwrap();
+    let mut calldata = ArrayTrait::new();
+    calldata.append(5);
+    match starknet::call_contract_syscall(contract_address, selector, calldata) {
+        Result::Ok(_) => 0,
+        Result::Err(mut revert_reason) => revert_reason.pop_front().unwrap(),
+    }
+}
+
+// Calls an entry point that deploys an instance of the class `class_hash` and fails, then deploys
+// the same instance, which requires the first deployment to be rolled back. Returns the address of
+// the deployed contract.
+fn call_deploying_contract(address: felt, selector: felt, class_hash: felt) -> felt {
+    let contract_address = contract_address_try_from_felt(address).unwrap();
+    let mut calldata = ArrayTrait::new();
+    calldata.append(class_hash);
+    let mut revert_reason = starknet::call_contract_syscall(
+        contract_address, selector, calldata
+    ).unwrap_err();
+    assert(revert_reason.pop_front().unwrap() == 'FAILED', 'Unexpected revert reason');
+    let (deployed_address, _) = starknet::deploy_syscall(
+        class_hash, 0, ArrayTrait::new(), true
+    ).unwrap_syscall();
+    starknet::contract_address_to_felt(deployed_address)
+}
+
+fn call_missing_contract(address: felt) -> felt {
+    let contract_address = contract_address_try_from_felt(address).unwrap();
+    match starknet::call_contract_syscall(contract_address, 0, ArrayTrait::new()) {
+        Result::Ok(_) => 0,
+        Result::Err(mut revert_reason) => revert_reason.pop_front().unwrap(),
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Copy, Drop)]
+struct Point {
+    x: felt,
+    y: felt,
+}
+
+fn scale(p: Point, factor: felt) -> Point {
+    let x = p.x * factor;
+    let y = p.y * factor;
+    Point { x, y }
+}
+
+fn main() -> felt {
+    let p = scale(Point { x: 1, y: 2 }, 3);
+    let q = scale(p, 10);
+    q.x + q.y
+}
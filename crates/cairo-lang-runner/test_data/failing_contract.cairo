@@ -0,0 +1,26 @@
+#[contract]
+mod FailingContract {
+    use array::ArrayTrait;
+
+    struct Storage {
+        value: felt, 
+    }
+
+    #[event]
+    fn ValueSet(value: felt) {}
+
+    // Sets the value and emits an event, then fails.
+    #[external]
+    fn set_value_and_fail(value: felt) {
+        value::write(value);
+        ValueSet(value);
+        assert(false, 'FAILED');
+    }
+
+    // Deploys an instance of the class `class_hash`, then fails.
+    #[external]
+    fn deploy_and_fail(class_hash: felt) {
+        starknet::deploy_syscall(class_hash, 0, ArrayTrait::new(), true).unwrap_syscall();
+        assert(false, 'FAILED');
+    }
+}
@@ -0,0 +1,37 @@
+use array::ArrayTrait;
+use option::OptionTrait;
+use starknet::SyscallResultTrait;
+use starknet::contract_address_try_from_felt;
+use starknet::testing;
+
+fn read_with_cheatcodes(address: felt) -> (felt, felt, u64, felt) {
+    let contract_address = contract_address_try_from_felt(address).unwrap();
+    testing::set_contract_address(contract_address);
+    testing::set_caller_address(7);
+    testing::set_block_timestamp(1234_u64);
+    testing::set_storage(contract_address, 5, 55);
+    let storage_address = starknet::storage_address_from_base(
+        starknet::storage_base_address_const::<5>()
+    );
+    (
+        starknet::contract_address_to_felt(starknet::get_contract_address()),
+        starknet::get_caller_address(),
+        starknet::get_block_timestamp(),
+        starknet::storage_read_syscall(0, storage_address).unwrap_syscall()
+    )
+}
+
+fn emit(key: felt, value: felt) {
+    let mut keys = ArrayTrait::new();
+    keys.append(key);
+    let mut data = ArrayTrait::new();
+    data.append(value);
+    starknet::emit_event_syscall(keys, data).unwrap_syscall();
+}
+
+fn emit_and_pop_event() -> (felt, felt) {
+    emit(1, 10);
+    emit(2, 20);
+    let (mut keys, mut data) = testing::pop_event(starknet::get_contract_address()).unwrap();
+    (keys.pop_front().unwrap(), data.pop_front().unwrap())
+}
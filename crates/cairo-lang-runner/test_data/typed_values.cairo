@@ -0,0 +1,17 @@
+use array::ArrayTrait;
+use option::OptionTrait;
+
+struct Point {
+    x: felt,
+    y: u128,
+}
+
+fn sum_and_wrap(
+    a: felt, mut arr: Array::<felt>, b: felt
+) -> (felt, Option::<Point>, bool, Array::<u8>) {
+    let sum = a + arr.pop_front().unwrap() + arr.pop_front().unwrap() + b;
+    let mut out = ArrayTrait::new();
+    out.append(1_u8);
+    out.append(2_u8);
+    (sum, Option::Some(Point { x: sum, y: 3_u128 }), false, out)
+}
@@ -12,15 +12,16 @@ use cairo_lang_defs::ids::{
 use cairo_lang_defs::plugin::MacroPlugin;
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup};
-use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::ids::{CrateId, FileId, FileLongId};
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::stable_ptr::SyntaxStablePtr;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use cairo_lang_utils::Upcast;
 use smol_str::SmolStr;
 
-use crate::diagnostic::SemanticDiagnosticKind;
+use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics};
 use crate::items::attribute::Attribute;
 use crate::items::constant::Constant;
 use crate::items::function_with_body::FunctionBody;
@@ -28,6 +29,7 @@ use crate::items::generics::GenericParam;
 use crate::items::imp::{ImplId, ImplLookupContext};
 use crate::items::module::ModuleSemanticData;
 use crate::items::trt::ConcreteTraitId;
+use crate::lint::Lint;
 use crate::plugin::{DynPluginAuxData, SemanticPlugin};
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, ResolvedLookback};
 use crate::{
@@ -63,6 +65,11 @@ pub trait SemanticGroup:
         id: items::functions::ConcreteFunctionWithBody,
     ) -> semantic::ConcreteFunctionWithBodyId;
     #[salsa::interned]
+    fn intern_loop_function(
+        &self,
+        id: items::functions::LoopFunctionLongId,
+    ) -> items::functions::LoopFunctionId;
+    #[salsa::interned]
     fn intern_concrete_struct(&self, id: types::ConcreteStructLongId) -> types::ConcreteStructId;
     #[salsa::interned]
     fn intern_concrete_enum(&self, id: types::ConcreteEnumLongId) -> types::ConcreteEnumId;
@@ -674,6 +681,8 @@ pub trait SemanticGroup:
 
     /// Aggregates file level semantic diagnostics.
     fn file_semantic_diagnostics(&self, file_id: FileId) -> Maybe<Diagnostics<SemanticDiagnostic>>;
+    /// Returns the `use` items of a crate which are resolved through by its items.
+    fn priv_crate_used_uses(&self, crate_id: CrateId) -> Arc<UnorderedHashSet<UseId>>;
 
     // Corelib.
     // ========
@@ -686,6 +695,9 @@ pub trait SemanticGroup:
     // ========
     #[salsa::input]
     fn semantic_plugins(&self) -> Vec<Arc<dyn SemanticPlugin>>;
+    /// The plugins which crates can enable by name in their settings, e.g. "starknet".
+    #[salsa::input]
+    fn named_semantic_plugins(&self) -> Arc<OrderedHashMap<SmolStr, Arc<dyn SemanticPlugin>>>;
 }
 
 impl<T: Upcast<dyn SemanticGroup + 'static>> Elongate for T {
@@ -698,6 +710,7 @@ impl<T: Upcast<dyn SemanticGroup + 'static>> Elongate for T {
 pub fn init_semantic_group(db: &mut (dyn SemanticGroup + 'static)) {
     // Initialize inputs.
     db.set_semantic_plugins(Vec::new());
+    db.set_named_semantic_plugins(Arc::new(OrderedHashMap::default()));
 }
 
 pub trait SemanticGroupEx: Upcast<dyn SemanticGroup> {
@@ -786,6 +799,29 @@ fn module_semantic_diagnostics(
         }
     }
 
+    // Uses in the core crate may only re-export items. Uses of impls are used implicitly, when
+    // looking for impls of traits.
+    let crate_id = module_id.owning_crate(db.upcast());
+    if crate_id != db.core_module().owning_crate(db.upcast()) {
+        let used_uses = db.priv_crate_used_uses(crate_id);
+        for use_id in db.module_uses_ids(module_id)? {
+            if used_uses.contains(&use_id)
+                || matches!(db.use_resolved_item(use_id), Ok(ResolvedGenericItem::Impl(_)) | Err(_))
+            {
+                continue;
+            }
+            let mut semantic_diagnostics =
+                SemanticDiagnostics::new(use_id.module_file_id(db.upcast()));
+            semantic_diagnostics.report_lint(
+                db,
+                use_id.stable_ptr(db.upcast()).untyped(),
+                SemanticDiagnosticKind::UnusedImport { name: use_id.name(db.upcast()) },
+                Lint::UnusedImports,
+            );
+            diagnostics.extend(semantic_diagnostics.build());
+        }
+    }
+
     Ok(map_diagnostics(
         db.elongate(),
         module_id,
@@ -850,6 +886,37 @@ fn map_diagnostics(
     (has_change, diagnostics.build())
 }
 
+fn priv_crate_used_uses(db: &dyn SemanticGroup, crate_id: CrateId) -> Arc<UnorderedHashSet<UseId>> {
+    let mut used_uses = UnorderedHashSet::default();
+    for module_id in db.crate_modules(crate_id).iter() {
+        let Ok(module_items) = db.module_items(*module_id) else { continue; };
+        for item in module_items.iter() {
+            let mut lookbacks = get_resolver_lookbacks(LookupItemId::ModuleItem(*item), db);
+            // Trait and impl functions are not module items.
+            match item {
+                ModuleItemId::Trait(trait_id) => {
+                    let trait_functions = db.trait_functions(*trait_id).unwrap_or_default();
+                    for trait_function in trait_functions.values() {
+                        lookbacks.extend(db.trait_function_resolved_lookback(*trait_function));
+                    }
+                }
+                ModuleItemId::Impl(impl_def_id) => {
+                    let impl_functions = db.impl_functions(*impl_def_id).unwrap_or_default();
+                    for impl_function in impl_functions.values() {
+                        lookbacks.extend(db.impl_function_resolved_lookback(*impl_function));
+                        lookbacks.extend(db.impl_function_body_resolved_lookback(*impl_function));
+                    }
+                }
+                _ => {}
+            }
+            for lookback in lookbacks {
+                used_uses.extend_unordered(lookback.uses.clone());
+            }
+        }
+    }
+    Arc::new(used_uses)
+}
+
 fn file_semantic_diagnostics(
     db: &dyn SemanticGroup,
     file_id: FileId,
@@ -11,7 +11,8 @@ use cairo_lang_defs::ids::{
 };
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder,
+    variant_name, DiagnosticAdded, DiagnosticEntry, DiagnosticFix, DiagnosticLocation, Diagnostics,
+    DiagnosticsBuilder, Severity,
 };
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::TypedSyntaxNode;
@@ -19,9 +20,10 @@ use itertools::Itertools;
 use smol_str::SmolStr;
 
 use crate::db::SemanticGroup;
+use crate::lint::{lint_level, Lint};
 use crate::plugin::PluginMappedDiagnostic;
 use crate::resolve_path::ResolvedConcreteItem;
-use crate::{semantic, ConcreteTraitId};
+use crate::{fixes, semantic, ConcreteTraitId};
 
 pub struct SemanticDiagnostics {
     pub diagnostics: DiagnosticsBuilder<SemanticDiagnostic>,
@@ -64,6 +66,24 @@ impl SemanticDiagnostics {
             kind,
         ))
     }
+    /// Report a lint diagnostic in the given location, with the severity set for the lint there.
+    /// Nothing is reported if the lint is allowed.
+    pub fn report_lint(
+        &mut self,
+        db: &dyn SemanticGroup,
+        stable_ptr: SyntaxStablePtrId,
+        kind: SemanticDiagnosticKind,
+        lint: Lint,
+    ) -> Option<DiagnosticAdded> {
+        let stable_location = StableLocation::new(self.module_file_id, stable_ptr);
+        let severity = lint_level(db, &stable_location, lint).severity()?;
+        Some(
+            self.diagnostics.add(SemanticDiagnostic {
+                severity,
+                ..SemanticDiagnostic::new(stable_location, kind)
+            }),
+        )
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -73,20 +93,29 @@ pub struct SemanticDiagnostic {
     /// true if the diagnostic should be reported *after* the given location. Normally false, in
     /// which case the diagnostic points to the given location (as-is).
     pub after: bool,
+    pub severity: Severity,
 }
 impl SemanticDiagnostic {
-    /// Create a diagnostic in the given location.
+    /// Create an error diagnostic in the given location.
     pub fn new(stable_location: StableLocation, kind: SemanticDiagnosticKind) -> Self {
-        SemanticDiagnostic { stable_location, kind, after: false }
+        SemanticDiagnostic { stable_location, kind, after: false, severity: Severity::Error }
     }
-    /// Create a diagnostic in the location after the given location (with width 0).
+    /// Create an error diagnostic in the location after the given location (with width 0).
     pub fn new_after(stable_location: StableLocation, kind: SemanticDiagnosticKind) -> Self {
-        SemanticDiagnostic { stable_location, kind, after: true }
+        SemanticDiagnostic { stable_location, kind, after: true, severity: Severity::Error }
     }
 }
 impl DiagnosticEntry for SemanticDiagnostic {
     type DbType = dyn SemanticGroup;
 
+    fn code(&self) -> Option<String> {
+        Some(variant_name(&self.kind))
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
     fn format(&self, db: &Self::DbType) -> String {
         match &self.kind {
             SemanticDiagnosticKind::ModuleFileNotFound { path } => {
@@ -360,6 +389,11 @@ impl DiagnosticEntry for SemanticDiagnostic {
                 match_ty.format(db),
                 arm_ty.format(db)
             ),
+            SemanticDiagnosticKind::MissingMatchArms { missing_patterns } => format!(
+                "Match is not exhaustive. Missing patterns: {}.",
+                missing_patterns.iter().map(|pattern| format!("`{pattern}`")).join(", ")
+            ),
+            SemanticDiagnosticKind::UnreachableMatchArm => "Unreachable match arm.".into(),
             SemanticDiagnosticKind::IncompatibleIfBlockTypes { block_if_ty, block_else_ty } => {
                 format!(
                     r#"If blocks have incompatible types: "{}" and "{}""#,
@@ -367,6 +401,28 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     block_else_ty.format(db),
                 )
             }
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { current_ty, break_ty } => {
+                format!(
+                    r#"Loop has incompatible return types: "{}" and "{}""#,
+                    current_ty.format(db),
+                    break_ty.format(db),
+                )
+            }
+            SemanticDiagnosticKind::BreakOnlyAllowedInsideALoop => {
+                "'break' is only allowed inside a loop.".into()
+            }
+            SemanticDiagnosticKind::BreakWithValueOnlyAllowedInsideALoop => {
+                "Can only break with a value inside a 'loop'.".into()
+            }
+            SemanticDiagnosticKind::ContinueOnlyAllowedInsideALoop => {
+                "'continue' is only allowed inside a loop.".into()
+            }
+            SemanticDiagnosticKind::ReturnNotAllowedInsideALoop => {
+                "'return' is not allowed inside a loop.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideALoop => {
+                "The '?' operator is not allowed inside a loop.".into()
+            }
             SemanticDiagnosticKind::TypeHasNoMembers { ty, member_name: _ } => {
                 format!(r#"Type "{}" has no members."#, ty.format(db))
             }
@@ -421,8 +477,11 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::SuperUsedInRootModule => {
                 "'super' cannot be used for the crate's root module.".into()
             }
+            SemanticDiagnosticKind::CrateNotADependency { crate_name } => {
+                format!(r#"Crate "{crate_name}" is not declared as a dependency."#)
+            }
             SemanticDiagnosticKind::UnexpectedLiteralPattern { ty } => format!(
-                r#"Unexpected type for literal pattern. Expected: felt. Got: "{}""#,
+                r#"Unexpected type for literal pattern. Expected: felt or an unsigned integer. Got: "{}""#,
                 ty.format(db),
             ),
             SemanticDiagnosticKind::UnexpectedEnumPattern { ty } => {
@@ -526,6 +585,10 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::TraitMismatch => {
                 "Supplied impl does not match the required trait".into()
             }
+            SemanticDiagnosticKind::UnusedVariable => {
+                "Unused variable. Consider ignoring by prefixing with `_`.".into()
+            }
+            SemanticDiagnosticKind::UnusedImport { name } => format!("Unused import: `{name}`."),
         }
     }
 
@@ -541,6 +604,26 @@ impl DiagnosticEntry for SemanticDiagnostic {
             _ => location,
         }
     }
+
+    fn fixes(&self, db: &Self::DbType) -> Vec<DiagnosticFix> {
+        match &self.kind {
+            SemanticDiagnosticKind::PathNotFound(_) => {
+                fixes::missing_use_fixes(db, &self.stable_location)
+            }
+            SemanticDiagnosticKind::RefArgNotExplicit => {
+                fixes::ref_arg_fix(db, &self.stable_location).into_iter().collect()
+            }
+            SemanticDiagnosticKind::MissingMatchArms { missing_patterns } => {
+                fixes::missing_match_arms_fix(db, &self.stable_location, missing_patterns)
+                    .into_iter()
+                    .collect()
+            }
+            SemanticDiagnosticKind::UnusedVariable => {
+                vec![fixes::underscore_prefix_fix(db, &self.stable_location)]
+            }
+            _ => vec![],
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -683,10 +766,23 @@ pub enum SemanticDiagnosticKind {
         match_ty: semantic::TypeId,
         arm_ty: semantic::TypeId,
     },
+    MissingMatchArms {
+        missing_patterns: Vec<String>,
+    },
+    UnreachableMatchArm,
     IncompatibleIfBlockTypes {
         block_if_ty: semantic::TypeId,
         block_else_ty: semantic::TypeId,
     },
+    IncompatibleLoopBreakTypes {
+        current_ty: semantic::TypeId,
+        break_ty: semantic::TypeId,
+    },
+    BreakOnlyAllowedInsideALoop,
+    BreakWithValueOnlyAllowedInsideALoop,
+    ContinueOnlyAllowedInsideALoop,
+    ReturnNotAllowedInsideALoop,
+    ErrorPropagateNotAllowedInsideALoop,
     TypeHasNoMembers {
         ty: semantic::TypeId,
         member_name: SmolStr,
@@ -717,6 +813,9 @@ pub enum SemanticDiagnosticKind {
     InvalidPath,
     PathNotFound(NotFoundItemType),
     SuperUsedInRootModule,
+    CrateNotADependency {
+        crate_name: SmolStr,
+    },
     RedundantModifier {
         current_modifier: SmolStr,
         previous_modifier: SmolStr,
@@ -775,6 +874,10 @@ pub enum SemanticDiagnosticKind {
     ExternItemWithImplGenericsNotSupported,
     MissingSemicolon,
     TraitMismatch,
+    UnusedVariable,
+    UnusedImport {
+        name: SmolStr,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
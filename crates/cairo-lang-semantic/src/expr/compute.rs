@@ -5,7 +5,10 @@
 use std::collections::HashMap;
 
 use ast::{BinaryOperator, PathSegment};
-use cairo_lang_defs::ids::{FunctionSignatureId, LocalVarLongId, MemberId, TraitId};
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{
+    FunctionSignatureId, LocalVarLongId, MemberId, ModuleId, TraitFunctionId, TraitId,
+};
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe, ToOption};
 use cairo_lang_syntax::node::ast::{BlockOrIf, PatternStructParam};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -17,7 +20,7 @@ use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper};
 use id_arena::Arena;
-use itertools::{chain, zip_eq};
+use itertools::{chain, zip_eq, Itertools};
 use num_bigint::{BigInt, Sign};
 use smol_str::SmolStr;
 use unescaper::unescape;
@@ -27,10 +30,11 @@ use super::objects::*;
 use super::pattern::{
     Pattern, PatternEnumVariant, PatternLiteral, PatternOtherwise, PatternTuple, PatternVariable,
 };
+use super::usefulness::check_match;
 use crate::corelib::{
-    core_binary_operator, core_felt_ty, core_unary_operator, false_literal_expr, never_ty,
-    true_literal_expr, try_get_const_libfunc_name_by_type, try_get_core_ty_by_name, unit_ty,
-    unwrap_error_propagation_type,
+    core_binary_operator, core_bool_ty, core_felt_ty, core_unary_operator, false_literal_expr,
+    never_ty, true_literal_expr, try_get_const_libfunc_name_by_type, try_get_core_ty_by_name,
+    unit_ty, unwrap_error_propagation_type,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
@@ -43,6 +47,7 @@ use crate::items::imp::find_impls_at_context;
 use crate::items::modifiers::compute_mutability;
 use crate::items::structure::SemanticStructEx;
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
+use crate::lint::Lint;
 use crate::literals::LiteralLongId;
 use crate::resolve_path::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
 use crate::semantic::{self, FunctionId, LocalVariable, TypeId, TypeLongId, Variable};
@@ -60,6 +65,8 @@ pub struct ComputationContext<'ctx> {
     pub statements: Arena<semantic::Statement>,
     /// Definitions of semantic variables.
     pub semantic_defs: UnorderedHashMap<semantic::VarId, semantic::Variable>,
+    /// The innermost loop the current expression is in, if any.
+    loop_ctx: Option<LoopContext>,
 }
 impl<'ctx> ComputationContext<'ctx> {
     pub fn new(
@@ -80,6 +87,7 @@ impl<'ctx> ComputationContext<'ctx> {
             exprs: Arena::default(),
             statements: Arena::default(),
             semantic_defs,
+            loop_ctx: None,
         }
     }
 
@@ -122,6 +130,27 @@ impl<'ctx> ComputationContext<'ctx> {
     fn reduce_ty(&mut self, ty: TypeId) -> TypeId {
         self.resolver.inference.reduce_ty(ty)
     }
+
+    /// Runs a function with a modified context, inside the body of a new loop.
+    /// Returns the result of the function, and the context of the loop after it ran.
+    fn run_in_loop<T, F>(&mut self, is_while: bool, f: F) -> (T, LoopContext)
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let old_loop_ctx = self.loop_ctx.replace(LoopContext { is_while, break_ty: None });
+        let res = f(self);
+        let loop_ctx = std::mem::replace(&mut self.loop_ctx, old_loop_ctx).unwrap();
+        (res, loop_ctx)
+    }
+}
+
+/// Context of a loop whose body is being computed.
+struct LoopContext {
+    /// Whether the loop is a `while` loop, which can not break with a value.
+    is_while: bool,
+    /// The type of the values the loop breaks with, merged over the `break` statements computed so
+    /// far.
+    break_ty: Option<TypeId>,
 }
 
 // TODO(ilya): Change value to VarId.
@@ -210,6 +239,8 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::Block(block_syntax) => compute_expr_block_semantic(ctx, block_syntax),
         ast::Expr::Match(expr_match) => compute_expr_match_semantic(ctx, expr_match),
         ast::Expr::If(expr_if) => compute_expr_if_semantic(ctx, expr_if),
+        ast::Expr::Loop(expr_loop) => compute_expr_loop_semantic(ctx, expr_loop),
+        ast::Expr::While(expr_while) => compute_expr_while_semantic(ctx, expr_while),
         ast::Expr::ErrorPropagate(expr) => compute_expr_error_propagate_semantic(ctx, expr),
         ast::Expr::Missing(_) | ast::Expr::FieldInitShorthand(_) => {
             Err(ctx.diagnostics.report(syntax, Unsupported))
@@ -522,6 +553,8 @@ pub fn compute_root_expr(
                 }
             }
             Expr::If(expr) => expr.ty = ctx.resolver.inference.reduce_ty(expr.ty),
+            Expr::Loop(expr) => expr.ty = ctx.resolver.inference.reduce_ty(expr.ty),
+            Expr::While(expr) => expr.ty = ctx.resolver.inference.reduce_ty(expr.ty),
             Expr::Var(expr) => expr.ty = ctx.resolver.inference.reduce_ty(expr.ty),
             Expr::Literal(_) => {
                 // TODO(spapini): Support literal inference. Perhaps using Numeric trait.
@@ -545,7 +578,10 @@ pub fn compute_root_expr(
     for (_id, stmt) in ctx.statements.iter_mut() {
         match stmt {
             Statement::Let(stmt) => ctx.resolver.inference.reduce_pattern(&mut stmt.pattern),
-            Statement::Expr(_) | Statement::Return(_) => {}
+            Statement::Expr(_)
+            | Statement::Return(_)
+            | Statement::Break(_)
+            | Statement::Continue(_) => {}
         }
     }
 
@@ -555,8 +591,54 @@ pub fn compute_root_expr(
         return Ok(res);
     }
 
+    report_unused_variables(ctx);
     Ok(res)
 }
+
+/// Reports the local variables that are never used, in the order of their definitions. Assigning
+/// to a variable is not a use of it, and variables whose name starts with `_` are intentionally
+/// unused. Nothing is reported if there are errors, as the expressions using a variable may be
+/// missing.
+fn report_unused_variables(ctx: &mut ComputationContext<'_>) {
+    if ctx.diagnostics.diagnostics.has_errors() {
+        return;
+    }
+    let mut used_vars = UnorderedHashSet::default();
+    for (_id, expr) in ctx.exprs.iter() {
+        match expr {
+            Expr::Var(expr) => {
+                used_vars.insert(expr.var);
+            }
+            Expr::FunctionCall(expr) => used_vars.extend(expr.ref_args.iter().copied()),
+            _ => {}
+        }
+    }
+    // Local variables are defined by the patterns of let statements and match arms.
+    let let_patterns = ctx.statements.iter().filter_map(|(_id, stmt)| match stmt {
+        Statement::Let(stmt) => Some(&stmt.pattern),
+        _ => None,
+    });
+    let arm_patterns = ctx.exprs.iter().flat_map(|(_id, expr)| match expr {
+        Expr::Match(expr) => expr.arms.iter().map(|arm| &arm.pattern).collect_vec(),
+        _ => vec![],
+    });
+    let unused_vars = chain!(let_patterns, arm_patterns)
+        .flat_map(|pattern| pattern.variables())
+        .filter(|pattern_var| {
+            !pattern_var.name.starts_with('_')
+                && !used_vars.contains(&semantic::VarId::Local(pattern_var.var.id))
+        })
+        .map(|pattern_var| {
+            let stable_ptr = pattern_var.var.id.stable_ptr(ctx.db.upcast()).untyped();
+            let location = StableLocation::new(ctx.diagnostics.module_file_id, stable_ptr);
+            (location.syntax_node(ctx.db.upcast()).1.offset(), stable_ptr)
+        })
+        .sorted_by_key(|(offset, _)| *offset)
+        .collect_vec();
+    for (_, stable_ptr) in unused_vars {
+        ctx.diagnostics.report_lint(ctx.db, stable_ptr, UnusedVariable, Lint::UnusedVariables);
+    }
+}
 /// Computes the semantic model of an expression of type [ast::ExprBlock].
 pub fn compute_expr_block_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -587,7 +669,9 @@ pub fn compute_expr_block_semantic(
         let ty = if let Some(t) = &tail_semantic_expr {
             t.ty()
         } else if let Some(statement) = statements_semantic.last() {
-            if let Statement::Return(_) = &new_ctx.statements[*statement] {
+            if let Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) =
+                &new_ctx.statements[*statement]
+            {
                 never_ty(new_ctx.db)
             } else {
                 unit_ty(db)
@@ -645,7 +729,6 @@ fn compute_expr_match_semantic(
     ctx: &mut ComputationContext<'_>,
     syntax: &ast::ExprMatch,
 ) -> Maybe<Expr> {
-    // TODO(yuval): verify exhaustiveness.
     let db = ctx.db;
     let syntax_db = db.upcast();
 
@@ -688,6 +771,8 @@ fn compute_expr_match_semantic(
     }
     // Compute semantic representation of the match arms.
     let pattern_and_exprs: Vec<_> = pattern_and_expr_options.into_iter().collect::<Maybe<_>>()?;
+    let ty = helper.get_final_type();
+    check_match_arms(ctx, expr.ty(), &pattern_and_exprs, syntax)?;
     let semantic_arms = pattern_and_exprs
         .into_iter()
         .map(|(pattern, arm_expr)| MatchArm { pattern, expression: ctx.exprs.alloc(arm_expr) })
@@ -695,11 +780,38 @@ fn compute_expr_match_semantic(
     Ok(Expr::Match(ExprMatch {
         matched_expr: ctx.exprs.alloc(expr),
         arms: semantic_arms,
-        ty: helper.get_final_type(),
+        ty,
         stable_ptr: syntax.stable_ptr().into(),
     }))
 }
 
+/// Reports the arms of a match that can never be reached, and the patterns of the matched type not
+/// covered by any arm.
+fn check_match_arms(
+    ctx: &mut ComputationContext<'_>,
+    matched_ty: TypeId,
+    pattern_and_exprs: &[(Pattern, Expr)],
+    syntax: &ast::ExprMatch,
+) -> Maybe<()> {
+    let matched_ty = ctx.resolver.inference.reduce_ty(matched_ty);
+    if matched_ty.is_missing(ctx.db) {
+        return Ok(());
+    }
+    let patterns = pattern_and_exprs.iter().map(|(pattern, _)| pattern).collect_vec();
+    let result = check_match(ctx.db, matched_ty, &patterns)?;
+    for arm_index in result.unreachable_arms {
+        ctx.diagnostics
+            .report_by_ptr(patterns[arm_index].stable_ptr().untyped(), UnreachableMatchArm);
+    }
+    if !result.missing_patterns.is_empty() {
+        ctx.diagnostics.report_by_ptr(
+            syntax.expr(ctx.db.upcast()).stable_ptr().untyped(),
+            MissingMatchArms { missing_patterns: result.missing_patterns },
+        );
+    }
+    Ok(())
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprIf].
 fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::ExprIf) -> Maybe<Expr> {
     let syntax_db = ctx.db.upcast();
@@ -739,6 +851,67 @@ fn compute_expr_if_semantic(ctx: &mut ComputationContext<'_>, syntax: &ast::Expr
     }))
 }
 
+/// Computes the semantic model of an expression of type [ast::ExprLoop].
+fn compute_expr_loop_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprLoop,
+) -> Maybe<Expr> {
+    let syntax_db = ctx.db.upcast();
+
+    let (body, loop_ctx) = ctx
+        .run_in_loop(false, |new_ctx| compute_loop_body_semantic(new_ctx, &syntax.body(syntax_db)));
+    Ok(Expr::Loop(ExprLoop {
+        body: body?,
+        ty: loop_ctx.break_ty.unwrap_or_else(|| never_ty(ctx.db)),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprWhile].
+fn compute_expr_while_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprWhile,
+) -> Maybe<Expr> {
+    let syntax_db = ctx.db.upcast();
+
+    let condition_syntax = syntax.condition(syntax_db);
+    let condition = compute_expr_semantic(ctx, &condition_syntax);
+    let condition_ty = ctx.reduce_ty(condition.ty());
+    let bool_ty = core_bool_ty(ctx.db);
+    if !condition_ty.is_missing(ctx.db)
+        && ctx.resolver.inference.conform_ty(condition_ty, bool_ty).is_err()
+    {
+        ctx.diagnostics
+            .report(&condition_syntax, WrongType { expected_ty: bool_ty, actual_ty: condition_ty });
+    }
+    let (body, _) = ctx
+        .run_in_loop(true, |new_ctx| compute_loop_body_semantic(new_ctx, &syntax.body(syntax_db)));
+    Ok(Expr::While(ExprWhile {
+        condition: ctx.exprs.alloc(condition),
+        body: body?,
+        ty: unit_ty(ctx.db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of the body of a loop, checking that it does not evaluate to a
+/// value.
+fn compute_loop_body_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprBlock,
+) -> Maybe<ExprId> {
+    let body = compute_expr_block_semantic(ctx, syntax)?;
+    let body_ty = ctx.reduce_ty(body.ty());
+    let unit_ty = unit_ty(ctx.db);
+    if !body_ty.is_missing(ctx.db)
+        && body_ty != never_ty(ctx.db)
+        && ctx.resolver.inference.conform_ty(body_ty, unit_ty).is_err()
+    {
+        ctx.diagnostics.report(syntax, WrongType { expected_ty: unit_ty, actual_ty: body_ty });
+    }
+    Ok(ctx.exprs.alloc(body))
+}
+
 /// Computes the semantic model of an expression of type [ast::ExprErrorPropagate].
 fn compute_expr_error_propagate_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -752,6 +925,9 @@ fn compute_expr_error_propagate_semantic(
         unwrap_error_propagation_type(ctx.db, inner_ty).ok_or_else(|| {
             ctx.diagnostics.report(syntax, ErrorPropagateOnNonErrorType { ty: inner_ty })
         })?;
+    if ctx.loop_ctx.is_some() {
+        return Err(ctx.diagnostics.report(syntax, ErrorPropagateNotAllowedInsideALoop));
+    }
     let func_signature = ctx.get_signature(
         syntax.stable_ptr().untyped(),
         UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate,
@@ -803,7 +979,7 @@ fn compute_pattern_semantic(
         }
         ast::Pattern::Literal(literal_pattern) => {
             let literal = literal_to_semantic(ctx, &literal_pattern)?;
-            if ctx.resolver.inference.conform_ty(ty, core_felt_ty(ctx.db)).is_err() {
+            if !is_literal_pattern_ty(ctx, ty) {
                 return Err(ctx
                     .diagnostics
                     .report(&literal_pattern, UnexpectedLiteralPattern { ty }));
@@ -816,7 +992,7 @@ fn compute_pattern_semantic(
         }
         ast::Pattern::ShortString(short_string_pattern) => {
             let literal = short_string_to_semantic(ctx, &short_string_pattern)?;
-            if ctx.resolver.inference.conform_ty(ty, core_felt_ty(ctx.db)).is_err() {
+            if !is_literal_pattern_ty(ctx, ty) {
                 return Err(ctx
                     .diagnostics
                     .report(&short_string_pattern, UnexpectedLiteralPattern { ty }));
@@ -1002,6 +1178,13 @@ fn compute_pattern_semantic(
     })
 }
 
+/// Returns whether values of type `ty` can be matched against literal patterns, that is, whether it
+/// is felt or an unsigned integer type. Infers the type to be felt if it is not yet known.
+fn is_literal_pattern_ty(ctx: &mut ComputationContext<'_>, ty: TypeId) -> bool {
+    try_get_const_libfunc_name_by_type(ctx.db, ty).is_ok()
+        || ctx.resolver.inference.conform_ty(ty, core_felt_ty(ctx.db)).is_ok()
+}
+
 /// Creates a local variable pattern.
 fn create_variable_pattern(
     ctx: &mut ComputationContext<'_>,
@@ -1216,21 +1399,73 @@ fn dot_expr(
     match rhs_syntax {
         ast::Expr::Path(expr) => member_access_expr(ctx, lexpr, expr, stable_ptr),
         ast::Expr::FunctionCall(expr) => method_call_expr(ctx, lexpr, expr, stable_ptr),
-        _ => Err(ctx.diagnostics.report(&rhs_syntax, InvalidMemberExpression)),
+        _ => {
+            // Keep the receiver in the semantic model, as it can still be looked up.
+            ctx.exprs.alloc(lexpr);
+            Err(ctx.diagnostics.report(&rhs_syntax, InvalidMemberExpression))
+        }
     }
 }
 
-/// Finds all the trait ids usable in the current context.
-fn all_module_trait_ids(ctx: &mut ComputationContext<'_>) -> Maybe<Vec<TraitId>> {
-    let mut module_traits = ctx.db.module_traits_ids(ctx.resolver.module_file_id.0)?;
-    for use_id in ctx.db.module_uses_ids(ctx.resolver.module_file_id.0)? {
-        if let Ok(ResolvedGenericItem::Trait(trait_id)) = ctx.db.use_resolved_item(use_id) {
+/// Finds all the trait ids usable in a module.
+fn all_module_trait_ids(db: &dyn SemanticGroup, module_id: ModuleId) -> Maybe<Vec<TraitId>> {
+    let mut module_traits = db.module_traits_ids(module_id)?;
+    for use_id in db.module_uses_ids(module_id)? {
+        if let Ok(ResolvedGenericItem::Trait(trait_id)) = db.use_resolved_item(use_id) {
             module_traits.push(trait_id);
         }
     }
     Ok(module_traits)
 }
 
+/// Finds the trait functions usable in the context of `resolver` which can be called as methods of
+/// a value of type `ty` - functions with a matching `self` param, of traits with at least one
+/// candidate impl.
+pub fn find_methods_for_type(
+    db: &dyn SemanticGroup,
+    resolver: &mut Resolver<'_>,
+    ty: TypeId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Maybe<Vec<TraitFunctionId>> {
+    let mut methods = vec![];
+    for trait_id in all_module_trait_ids(db, resolver.module_file_id.0)? {
+        for (_, trait_function) in db.trait_functions(trait_id)? {
+            if is_method_of_type(db, resolver, trait_id, trait_function, ty, stable_ptr) {
+                methods.push(trait_function);
+            }
+        }
+    }
+    Ok(methods)
+}
+
+/// Returns whether `trait_function` can be called as a method of a value of type `ty`, i.e. its
+/// first param fits the type, and its trait has at least one candidate impl.
+fn is_method_of_type(
+    db: &dyn SemanticGroup,
+    resolver: &mut Resolver<'_>,
+    trait_id: TraitId,
+    trait_function: TraitFunctionId,
+    ty: TypeId,
+    stable_ptr: SyntaxStablePtrId,
+) -> bool {
+    // Check if trait function signature's first param can fit our expr type.
+    let mut inference = resolver.inference.clone();
+    let Some(concrete_trait_id) = inference.infer_concrete_trait_by_self(
+        trait_function, ty, stable_ptr
+    ) else {
+        return false;
+    };
+
+    // Find impls for it.
+    let lookup_context = resolver.impl_lookup_context(trait_id);
+    let Ok(impls) = find_impls_at_context(
+        db, &inference, &lookup_context, concrete_trait_id, stable_ptr
+    ) else {
+        return false;
+    };
+    !impls.is_empty()
+}
+
 /// Computes the semantic model of a method call expression (e.g. "expr.method(..)").
 /// Finds all traits with at least one candidate impl with a matching `self` param.
 /// If more/less than 1 such trait exists, fails.
@@ -1241,40 +1476,26 @@ fn method_call_expr(
     stable_ptr: ast::ExprPtr,
 ) -> Maybe<Expr> {
     // TODO(spapini): Add ctx.module_id.
-    // TODO(spapini): Look also in uses.
     let syntax_db = ctx.db.upcast();
     let path = expr.path(syntax_db);
     let segment = path.elements(syntax_db).last().unwrap().clone();
     let func_name = segment.identifier(syntax_db);
     let generic_args_syntax = segment.generic_args(syntax_db);
     let mut candidates = vec![];
-    for trait_id in all_module_trait_ids(ctx)? {
+    for trait_id in all_module_trait_ids(ctx.db, ctx.resolver.module_file_id.0)? {
         for (name, trait_function) in ctx.db.trait_functions(trait_id)? {
-            if name != func_name {
-                continue;
-            }
-
-            // Check if trait function signature's first param can fit our expr type.
-            let mut inference = ctx.resolver.inference.clone();
-            let Some(concrete_trait_id) = inference.infer_concrete_trait_by_self(
-                trait_function, lexpr.ty(), stable_ptr.untyped()
-            ) else {
-                continue;
-            };
-
-            // Find impls for it.
-            let lookup_context = ctx.resolver.impl_lookup_context(trait_id);
-            let Ok(impls) = find_impls_at_context(
-                ctx.db, &inference, &lookup_context, concrete_trait_id, stable_ptr.untyped()
-            ) else {
-                continue;
-            };
-
-            if impls.is_empty() {
-                continue;
+            if name == func_name
+                && is_method_of_type(
+                    ctx.db,
+                    &mut ctx.resolver,
+                    trait_id,
+                    trait_function,
+                    lexpr.ty(),
+                    stable_ptr.untyped(),
+                )
+            {
+                candidates.push(trait_function);
             }
-
-            candidates.push(trait_function);
         }
     }
 
@@ -1292,6 +1513,13 @@ fn method_call_expr(
             ));
         }
     };
+    // The uses importing the trait of the method are used by the call.
+    let trait_id = trait_function.trait_id(ctx.db.upcast());
+    for use_id in ctx.db.module_uses_ids(ctx.resolver.module_file_id.0)? {
+        if ctx.db.use_resolved_item(use_id) == Ok(ResolvedGenericItem::Trait(trait_id)) {
+            ctx.resolver.lookback.uses.insert(use_id);
+        }
+    }
 
     let concrete_trait_id = ctx
         .resolver
@@ -1341,10 +1569,14 @@ fn member_access_expr(
     stable_ptr: ast::ExprPtr,
 ) -> Maybe<Expr> {
     let syntax_db = ctx.db.upcast();
+    let ty = lexpr.ty();
+    // The receiver is kept in the semantic model even when the access fails, as it can still be
+    // looked up (e.g. for completing an incomplete member access).
+    let lexpr_id = ctx.exprs.alloc(lexpr);
 
     // Find MemberId.
     let member_name = expr_as_identifier(ctx, &rhs_syntax, syntax_db)?;
-    match ctx.db.lookup_intern_type(lexpr.ty()) {
+    match ctx.db.lookup_intern_type(ty) {
         TypeLongId::Concrete(concrete) => match concrete {
             ConcreteTypeId::Struct(concrete_struct_id) => {
                 // TODO(lior): Add a diagnostic test when accessing a member of a missing type.
@@ -1358,7 +1590,6 @@ fn member_access_expr(
                         },
                     )
                 })?;
-                let lexpr_id = ctx.exprs.alloc(lexpr);
                 Ok(Expr::MemberAccess(ExprMemberAccess {
                     expr: lexpr_id,
                     struct_id: concrete_struct_id.struct_id(ctx.db),
@@ -1367,17 +1598,15 @@ fn member_access_expr(
                     stable_ptr,
                 }))
             }
-            _ => Err(ctx
-                .diagnostics
-                .report(&rhs_syntax, TypeHasNoMembers { ty: lexpr.ty(), member_name })),
+            _ => Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name })),
         },
         TypeLongId::Tuple(_) => {
             // TODO(spapini): Handle .0, .1, ...;
             Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
         }
-        TypeLongId::GenericParameter(_) => Err(ctx
-            .diagnostics
-            .report(&rhs_syntax, TypeHasNoMembers { ty: lexpr.ty(), member_name })),
+        TypeLongId::GenericParameter(_) => {
+            Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name }))
+        }
         TypeLongId::Var(_) => Err(ctx.diagnostics.report(&rhs_syntax, TypeYetUnknown)),
         TypeLongId::Missing(diag_added) => Err(diag_added),
     }
@@ -1628,7 +1857,11 @@ pub fn compute_statement_semantic(
                 ast::OptionTerminalSemicolon::Empty(_)
             ) && !matches!(
                 expr_syntax,
-                ast::Expr::Block(_) | ast::Expr::If(_) | ast::Expr::Match(_)
+                ast::Expr::Block(_)
+                    | ast::Expr::If(_)
+                    | ast::Expr::Match(_)
+                    | ast::Expr::Loop(_)
+                    | ast::Expr::While(_)
             ) {
                 // Point to after the expression, where the semicolon is missing.
                 ctx.diagnostics.report_after(&expr_syntax, MissingSemicolon);
@@ -1655,11 +1888,60 @@ pub fn compute_statement_semantic(
                 ctx.diagnostics
                     .report(&expr_syntax, WrongReturnType { expected_ty, actual_ty: expr_ty });
             }
+            if ctx.loop_ctx.is_some() {
+                ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideALoop);
+            }
             semantic::Statement::Return(semantic::StatementReturn {
                 expr: ctx.exprs.alloc(expr),
                 stable_ptr: syntax.stable_ptr(),
             })
         }
+        ast::Statement::Break(break_syntax) => {
+            let expr_option = match break_syntax.expr_clause(syntax_db) {
+                ast::OptionExprClause::Empty(_) => None,
+                ast::OptionExprClause::ExprClause(expr_clause) => {
+                    Some(compute_expr_semantic(ctx, &expr_clause.expr(syntax_db)))
+                }
+            };
+            let ty = expr_option.as_ref().map(|expr| expr.ty()).unwrap_or_else(|| unit_ty(db));
+            match &mut ctx.loop_ctx {
+                None => {
+                    ctx.diagnostics.report(break_syntax, BreakOnlyAllowedInsideALoop);
+                }
+                Some(LoopContext { is_while: true, .. }) => {
+                    if expr_option.is_some() {
+                        ctx.diagnostics.report(break_syntax, BreakWithValueOnlyAllowedInsideALoop);
+                    }
+                }
+                Some(LoopContext { is_while: false, break_ty }) => {
+                    if ty != never_ty(db) && !ty.is_missing(db) {
+                        match *break_ty {
+                            Some(current_ty) => {
+                                if ctx.resolver.inference.conform_ty(ty, current_ty).is_err() {
+                                    ctx.diagnostics.report(
+                                        break_syntax,
+                                        IncompatibleLoopBreakTypes { current_ty, break_ty: ty },
+                                    );
+                                }
+                            }
+                            None => *break_ty = Some(ty),
+                        }
+                    }
+                }
+            }
+            semantic::Statement::Break(semantic::StatementBreak {
+                expr_option: expr_option.map(|expr| ctx.exprs.alloc(expr)),
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
+        ast::Statement::Continue(continue_syntax) => {
+            if ctx.loop_ctx.is_none() {
+                ctx.diagnostics.report(continue_syntax, ContinueOnlyAllowedInsideALoop);
+            }
+            semantic::Statement::Continue(semantic::StatementContinue {
+                stable_ptr: syntax.stable_ptr(),
+            })
+        }
         ast::Statement::Missing(_) => todo!(),
     };
     Ok(ctx.statements.alloc(statement))
@@ -7,3 +7,4 @@ pub mod fmt;
 pub mod inference;
 pub mod objects;
 pub mod pattern;
+pub mod usefulness;
@@ -41,6 +41,8 @@ pub enum Statement {
     Expr(StatementExpr),
     Let(StatementLet),
     Return(StatementReturn),
+    Break(StatementBreak),
+    Continue(StatementContinue),
 }
 impl Statement {
     pub fn stable_ptr(&self) -> ast::StatementPtr {
@@ -48,6 +50,8 @@ impl Statement {
             Statement::Expr(stmt) => stmt.stable_ptr,
             Statement::Let(stmt) => stmt.stable_ptr,
             Statement::Return(stmt) => stmt.stable_ptr,
+            Statement::Break(stmt) => stmt.stable_ptr,
+            Statement::Continue(stmt) => stmt.stable_ptr,
         }
     }
 }
@@ -77,6 +81,22 @@ pub struct StatementReturn {
     pub stable_ptr: ast::StatementPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementBreak {
+    /// The value the loop evaluates to, if given.
+    pub expr_option: Option<ExprId>,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::StatementPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct StatementContinue {
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::StatementPtr,
+}
+
 // Expressions.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
@@ -87,6 +107,8 @@ pub enum Expr {
     FunctionCall(ExprFunctionCall),
     Match(ExprMatch),
     If(ExprIf),
+    Loop(ExprLoop),
+    While(ExprWhile),
     Var(ExprVar),
     Literal(ExprLiteral),
     MemberAccess(ExprMemberAccess),
@@ -105,6 +127,8 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.ty,
             Expr::Match(expr) => expr.ty,
             Expr::If(expr) => expr.ty,
+            Expr::Loop(expr) => expr.ty,
+            Expr::While(expr) => expr.ty,
             Expr::Var(expr) => expr.ty,
             Expr::Literal(expr) => expr.ty,
             Expr::MemberAccess(expr) => expr.ty,
@@ -123,6 +147,8 @@ impl Expr {
             Expr::FunctionCall(expr) => expr.stable_ptr,
             Expr::Match(expr) => expr.stable_ptr,
             Expr::If(expr) => expr.stable_ptr,
+            Expr::Loop(expr) => expr.stable_ptr,
+            Expr::While(expr) => expr.stable_ptr,
             Expr::Var(expr) => expr.stable_ptr,
             Expr::Literal(expr) => expr.stable_ptr,
             Expr::MemberAccess(expr) => expr.stable_ptr,
@@ -190,6 +216,27 @@ pub struct ExprIf {
     pub stable_ptr: ast::ExprPtr,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprLoop {
+    pub body: ExprId,
+    /// The type of the values the loop breaks with, or never if the loop never breaks.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprWhile {
+    pub condition: ExprId,
+    pub body: ExprId,
+    /// The type of the loop, which is always the unit type.
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct MatchArm {
@@ -30,7 +30,9 @@ cairo_lang_test_utils::test_file_test!(
         inference: "inference",
         let_statement: "let_statement",
         literal: "literal",
+        loop_: "loop",
         match_: "match",
+        match_exhaustiveness: "match_exhaustiveness",
         method: "method",
         operators: "operators",
         pattern: "pattern",
@@ -0,0 +1,398 @@
+//! Exhaustiveness and reachability checking of match arms.
+//!
+//! Implements the usefulness algorithm described in "Warnings for pattern matching" by Luc
+//! Maranget: a pattern is useful with respect to a list of previous patterns if there is a value
+//! matched by it and by none of the previous patterns. A match arm is unreachable if its pattern is
+//! not useful with respect to the patterns of the arms before it, and a match is exhaustive if a
+//! wildcard is not useful with respect to the patterns of all its arms.
+
+use cairo_lang_diagnostics::Maybe;
+use itertools::Itertools;
+use num_bigint::BigInt;
+
+use super::pattern::Pattern;
+use crate::db::SemanticGroup;
+use crate::items::enm::SemanticEnumEx;
+use crate::items::structure::SemanticStructEx;
+use crate::semantic::{TypeId, TypeLongId};
+use crate::types::ConcreteTypeId;
+
+/// The maximal number of missing patterns collected for a non-exhaustive match.
+const MAX_MISSING_PATTERNS: usize = 3;
+
+/// A constructor of values of a type.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum Constructor {
+    /// The only constructor of a struct or a tuple.
+    Single,
+    /// The variant of an enum with the given index.
+    Variant(usize),
+    /// A literal value, one of the infinitely many constructors of a felt.
+    Literal(BigInt),
+}
+
+/// A pattern reduced to the structure relevant for usefulness checking.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DeconstructedPattern {
+    /// A pattern matching any value: a variable or `_`.
+    Wildcard,
+    /// A pattern matching the values built by the constructor, with the given patterns for its
+    /// fields.
+    Constructor(Constructor, Vec<DeconstructedPattern>),
+}
+
+/// The result of checking the arms of a match.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MatchCheckResult {
+    /// The indices of the arms that can never be reached.
+    pub unreachable_arms: Vec<usize>,
+    /// Examples of the values not matched by any arm, formatted as patterns. Empty if the match
+    /// is exhaustive.
+    pub missing_patterns: Vec<String>,
+}
+
+/// Checks the reachability of the arms of a match on a value of type `ty`, given the patterns of
+/// its arms, and whether the arms cover all the values of the type.
+pub fn check_match(
+    db: &dyn SemanticGroup,
+    ty: TypeId,
+    patterns: &[&Pattern],
+) -> Maybe<MatchCheckResult> {
+    let checker = UsefulnessChecker { db };
+    let mut matrix: Vec<Vec<DeconstructedPattern>> = vec![];
+    let mut unreachable_arms = vec![];
+    for (i, pattern) in patterns.iter().enumerate() {
+        let row = vec![checker.deconstruct(pattern)?];
+        if !checker.is_useful(&matrix, &row, &[ty])? {
+            unreachable_arms.push(i);
+        }
+        matrix.push(row);
+    }
+    let missing_patterns = checker
+        .missing_patterns(&matrix, &[ty])?
+        .into_iter()
+        .map(|mut witness| checker.format_pattern(ty, &witness.remove(0)))
+        .collect::<Maybe<_>>()?;
+    Ok(MatchCheckResult { unreachable_arms, missing_patterns })
+}
+
+struct UsefulnessChecker<'a> {
+    db: &'a dyn SemanticGroup,
+}
+impl<'a> UsefulnessChecker<'a> {
+    /// Converts a semantic pattern to a deconstructed pattern.
+    fn deconstruct(&self, pattern: &Pattern) -> Maybe<DeconstructedPattern> {
+        Ok(match pattern {
+            Pattern::Variable(_) | Pattern::Otherwise(_) => DeconstructedPattern::Wildcard,
+            Pattern::Literal(pattern) => DeconstructedPattern::Constructor(
+                Constructor::Literal(pattern.literal.value.clone()),
+                vec![],
+            ),
+            Pattern::EnumVariant(pattern) => DeconstructedPattern::Constructor(
+                Constructor::Variant(pattern.variant.idx),
+                vec![self.deconstruct(&pattern.inner_pattern)?],
+            ),
+            Pattern::Struct(pattern) => {
+                let concrete_struct_id = match self.db.lookup_intern_type(pattern.ty) {
+                    TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                        concrete_struct_id
+                    }
+                    _ => return Ok(DeconstructedPattern::Wildcard),
+                };
+                // Members missing from the pattern (with `..`) match any value.
+                let fields = self
+                    .db
+                    .concrete_struct_members(concrete_struct_id)?
+                    .values()
+                    .map(|member| {
+                        match pattern.field_patterns.iter().find(|(m, _)| m.id == member.id) {
+                            Some((_, field_pattern)) => self.deconstruct(field_pattern),
+                            None => Ok(DeconstructedPattern::Wildcard),
+                        }
+                    })
+                    .collect::<Maybe<_>>()?;
+                DeconstructedPattern::Constructor(Constructor::Single, fields)
+            }
+            Pattern::Tuple(pattern) => DeconstructedPattern::Constructor(
+                Constructor::Single,
+                pattern
+                    .field_patterns
+                    .iter()
+                    .map(|field_pattern| self.deconstruct(field_pattern))
+                    .collect::<Maybe<_>>()?,
+            ),
+        })
+    }
+
+    /// Returns all the constructors of values of type `ty`, or `None` if there are infinitely
+    /// many of them, or they are unknown.
+    fn all_constructors(&self, ty: TypeId) -> Maybe<Option<Vec<Constructor>>> {
+        Ok(match self.db.lookup_intern_type(ty) {
+            TypeLongId::Tuple(_) | TypeLongId::Concrete(ConcreteTypeId::Struct(_)) => {
+                Some(vec![Constructor::Single])
+            }
+            TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => Some(
+                (0..self.db.concrete_enum_variants(concrete_enum_id)?.len())
+                    .map(Constructor::Variant)
+                    .collect(),
+            ),
+            _ => None,
+        })
+    }
+
+    /// Returns the types of the fields of the values of type `ty` built by `constructor`.
+    fn field_types(&self, ty: TypeId, constructor: &Constructor) -> Maybe<Vec<TypeId>> {
+        Ok(match (self.db.lookup_intern_type(ty), constructor) {
+            (TypeLongId::Tuple(types), Constructor::Single) => types,
+            (
+                TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)),
+                Constructor::Single,
+            ) => self
+                .db
+                .concrete_struct_members(concrete_struct_id)?
+                .values()
+                .map(|member| member.ty)
+                .collect(),
+            (
+                TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)),
+                Constructor::Variant(idx),
+            ) => vec![self.db.concrete_enum_variants(concrete_enum_id)?[*idx].ty],
+            _ => vec![],
+        })
+    }
+
+    /// Returns the constructors of the patterns at the head of the rows of `matrix`.
+    fn head_constructors<'m>(
+        &self,
+        matrix: &'m [Vec<DeconstructedPattern>],
+    ) -> Vec<&'m Constructor> {
+        matrix
+            .iter()
+            .filter_map(|row| match &row[0] {
+                DeconstructedPattern::Constructor(constructor, _) => Some(constructor),
+                DeconstructedPattern::Wildcard => None,
+            })
+            .unique()
+            .collect()
+    }
+
+    /// Returns the constructors of `ty` missing from `head_constructors`, or `None` if there are
+    /// infinitely many constructors of `ty`, in which case some are always missing.
+    fn missing_constructors(
+        &self,
+        ty: TypeId,
+        head_constructors: &[&Constructor],
+    ) -> Maybe<Option<Vec<Constructor>>> {
+        Ok(self.all_constructors(ty)?.map(|all_constructors| {
+            all_constructors
+                .into_iter()
+                .filter(|constructor| !head_constructors.contains(&constructor))
+                .collect()
+        }))
+    }
+
+    /// Returns the rows of `matrix` matching values built by `constructor`, with their head
+    /// pattern replaced by the patterns of the `arity` fields of the constructor.
+    fn specialize(
+        &self,
+        matrix: &[Vec<DeconstructedPattern>],
+        constructor: &Constructor,
+        arity: usize,
+    ) -> Vec<Vec<DeconstructedPattern>> {
+        matrix.iter().filter_map(|row| specialize_row(row, constructor, arity)).collect()
+    }
+
+    /// Returns the rows of `matrix` with a wildcard head pattern, without it.
+    fn default_matrix(
+        &self,
+        matrix: &[Vec<DeconstructedPattern>],
+    ) -> Vec<Vec<DeconstructedPattern>> {
+        matrix
+            .iter()
+            .filter(|row| row[0] == DeconstructedPattern::Wildcard)
+            .map(|row| row[1..].to_vec())
+            .collect()
+    }
+
+    /// Returns whether there is a value of types `types` matched by `row` and by none of the rows
+    /// of `matrix`.
+    fn is_useful(
+        &self,
+        matrix: &[Vec<DeconstructedPattern>],
+        row: &[DeconstructedPattern],
+        types: &[TypeId],
+    ) -> Maybe<bool> {
+        let Some((ty, rest_types)) = types.split_first() else {
+            return Ok(matrix.is_empty());
+        };
+        match &row[0] {
+            DeconstructedPattern::Constructor(constructor, _) => {
+                let field_types = self.field_types(*ty, constructor)?;
+                let specialized_row = specialize_row(row, constructor, field_types.len()).unwrap();
+                self.is_useful(
+                    &self.specialize(matrix, constructor, field_types.len()),
+                    &specialized_row,
+                    &[field_types, rest_types.to_vec()].concat(),
+                )
+            }
+            DeconstructedPattern::Wildcard => {
+                let head_constructors = self.head_constructors(matrix);
+                match self.missing_constructors(*ty, &head_constructors)? {
+                    // All the constructors appear in the matrix, the wildcard is useful if it is
+                    // useful for one of them.
+                    Some(missing_constructors) if missing_constructors.is_empty() => {
+                        for constructor in self.all_constructors(*ty)?.unwrap() {
+                            let field_types = self.field_types(*ty, &constructor)?;
+                            let specialized_row =
+                                specialize_row(row, &constructor, field_types.len()).unwrap();
+                            if self.is_useful(
+                                &self.specialize(matrix, &constructor, field_types.len()),
+                                &specialized_row,
+                                &[field_types, rest_types.to_vec()].concat(),
+                            )? {
+                                return Ok(true);
+                            }
+                        }
+                        Ok(false)
+                    }
+                    // The wildcard matches a missing constructor, which is only matched by the
+                    // rows with a wildcard head.
+                    _ => self.is_useful(&self.default_matrix(matrix), &row[1..], rest_types),
+                }
+            }
+        }
+    }
+
+    /// Returns rows of patterns of types `types` matching values not matched by any of the rows
+    /// of `matrix`, up to [MAX_MISSING_PATTERNS] of them.
+    fn missing_patterns(
+        &self,
+        matrix: &[Vec<DeconstructedPattern>],
+        types: &[TypeId],
+    ) -> Maybe<Vec<Vec<DeconstructedPattern>>> {
+        let Some((ty, rest_types)) = types.split_first() else {
+            return Ok(if matrix.is_empty() { vec![vec![]] } else { vec![] });
+        };
+        let head_constructors = self.head_constructors(matrix);
+        let mut witnesses = vec![];
+        match self.missing_constructors(*ty, &head_constructors)? {
+            Some(missing_constructors) if missing_constructors.is_empty() => {
+                for constructor in self.all_constructors(*ty)?.unwrap() {
+                    let field_types = self.field_types(*ty, &constructor)?;
+                    let arity = field_types.len();
+                    let specialized_witnesses = self.missing_patterns(
+                        &self.specialize(matrix, &constructor, arity),
+                        &[field_types, rest_types.to_vec()].concat(),
+                    )?;
+                    for mut witness in specialized_witnesses {
+                        let rest = witness.split_off(arity);
+                        witnesses.push(
+                            [
+                                vec![DeconstructedPattern::Constructor(
+                                    constructor.clone(),
+                                    witness,
+                                )],
+                                rest,
+                            ]
+                            .concat(),
+                        );
+                    }
+                    if witnesses.len() >= MAX_MISSING_PATTERNS {
+                        break;
+                    }
+                }
+            }
+            missing_constructors => {
+                let default_witnesses =
+                    self.missing_patterns(&self.default_matrix(matrix), rest_types)?;
+                // If no constructor appears in the matrix, a wildcard is a more concise example
+                // than listing all the constructors.
+                let heads = match missing_constructors {
+                    Some(missing_constructors) if !head_constructors.is_empty() => {
+                        missing_constructors
+                            .into_iter()
+                            .map(|constructor| {
+                                let arity = self.field_types(*ty, &constructor)?.len();
+                                Ok(DeconstructedPattern::Constructor(
+                                    constructor,
+                                    vec![DeconstructedPattern::Wildcard; arity],
+                                ))
+                            })
+                            .collect::<Maybe<Vec<_>>>()?
+                    }
+                    _ => vec![DeconstructedPattern::Wildcard],
+                };
+                for witness in default_witnesses {
+                    for head in &heads {
+                        witnesses.push([vec![head.clone()], witness.clone()].concat());
+                    }
+                }
+            }
+        }
+        witnesses.truncate(MAX_MISSING_PATTERNS);
+        Ok(witnesses)
+    }
+
+    /// Formats a deconstructed pattern of type `ty` the way it would be written in Cairo.
+    fn format_pattern(&self, ty: TypeId, pattern: &DeconstructedPattern) -> Maybe<String> {
+        let DeconstructedPattern::Constructor(constructor, fields) = pattern else {
+            return Ok("_".into());
+        };
+        let field_types = self.field_types(ty, constructor)?;
+        let fields: Vec<String> = fields
+            .iter()
+            .zip(field_types)
+            .map(|(field, field_ty)| self.format_pattern(field_ty, field))
+            .collect::<Maybe<_>>()?;
+        let defs_db = self.db.upcast();
+        Ok(match (self.db.lookup_intern_type(ty), constructor) {
+            (_, Constructor::Literal(value)) => value.to_string(),
+            (TypeLongId::Tuple(_), _) if fields.len() == 1 => format!("({},)", fields[0]),
+            (TypeLongId::Tuple(_), _) => format!("({})", fields.join(", ")),
+            (TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)), _) => {
+                let members = self.db.concrete_struct_members(concrete_struct_id)?;
+                format!(
+                    "{} {{ {} }}",
+                    concrete_struct_id.struct_id(self.db).name(defs_db),
+                    members
+                        .keys()
+                        .zip(fields)
+                        .map(|(name, field)| format!("{name}: {field}"))
+                        .join(", ")
+                )
+            }
+            (
+                TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)),
+                Constructor::Variant(idx),
+            ) => {
+                let variant = &self.db.concrete_enum_variants(concrete_enum_id)?[*idx];
+                format!(
+                    "{}::{}({})",
+                    concrete_enum_id.enum_id(self.db).name(defs_db),
+                    variant.id.name(defs_db),
+                    fields.join(", ")
+                )
+            }
+            _ => "_".into(),
+        })
+    }
+}
+
+/// Returns `row` with its head pattern replaced by the patterns of the `arity` fields of
+/// `constructor`, or `None` if the head pattern does not match values built by `constructor`.
+fn specialize_row(
+    row: &[DeconstructedPattern],
+    constructor: &Constructor,
+    arity: usize,
+) -> Option<Vec<DeconstructedPattern>> {
+    let head = match &row[0] {
+        DeconstructedPattern::Wildcard => vec![DeconstructedPattern::Wildcard; arity],
+        DeconstructedPattern::Constructor(head_constructor, fields) => {
+            if head_constructor != constructor {
+                return None;
+            }
+            fields.clone()
+        }
+    };
+    Some([head, row[1..].to_vec()].concat())
+}
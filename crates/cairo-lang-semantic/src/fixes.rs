@@ -0,0 +1,303 @@
+//! Machine-applicable fixes for diagnostics.
+
+#[cfg(test)]
+#[path = "fixes_test.rs"]
+mod test;
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{FileIndex, LanguageElementId, ModuleFileId, ModuleId, ModuleItemId};
+use cairo_lang_diagnostics::{DiagnosticEdit, DiagnosticFix};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use itertools::Itertools;
+
+use crate::corelib::core_crate;
+use crate::db::SemanticGroup;
+use crate::{ConcreteTypeId, TypeId, TypeLongId};
+
+/// Returns the fixes adding a `use` of each item named like the identifier at `location`, when the
+/// identifier is the first segment of a path that was not found.
+pub fn missing_use_fixes(db: &dyn SemanticGroup, location: &StableLocation) -> Vec<DiagnosticFix> {
+    let syntax_db = db.upcast();
+    let (_, node) = location.syntax_node(db.upcast());
+    if node.kind(syntax_db) != SyntaxKind::TerminalIdentifier {
+        return vec![];
+    }
+    let Some(segment) = node.parent() else {
+        return vec![];
+    };
+    let Some(path) = segment.parent() else {
+        return vec![];
+    };
+    if path.kind(syntax_db) != SyntaxKind::ExprPath
+        || matches!(path.parent(), Some(parent) if parent.kind(syntax_db) == SyntaxKind::ItemUse)
+    {
+        return vec![];
+    }
+    let first_segment = ast::ExprPath::from_syntax_node(syntax_db, path).elements(syntax_db)[0]
+        .as_syntax_node()
+        .stable_ptr();
+    if first_segment != segment.stable_ptr() {
+        return vec![];
+    }
+    let Some((file_id, offset, indent)) = use_insertion_point(db, location.module_file_id) else {
+        return vec![];
+    };
+
+    let name = ast::TerminalIdentifier::from_syntax_node(syntax_db, node).text(syntax_db);
+    let current_module_id = location.module_file_id.0;
+    let mut fixes = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            if *module_id == current_module_id {
+                continue;
+            }
+            match db.module_item_by_name(*module_id, name.clone()) {
+                // Items brought into scope by `use` are suggested through their own module.
+                Ok(Some(ModuleItemId::Use(_))) | Ok(None) | Err(_) => continue,
+                Ok(Some(_)) => {}
+            }
+            let path =
+                format!("{}::{name}", relative_module_path(db, current_module_id, *module_id));
+            fixes.push(DiagnosticFix {
+                description: format!("Add `use {path};`."),
+                edits: vec![DiagnosticEdit::insert(
+                    file_id,
+                    offset,
+                    match &indent {
+                        Some(indent) => format!("\n{indent}use {path};"),
+                        None => format!("use {path};\n"),
+                    },
+                )],
+            });
+        }
+    }
+    fixes
+}
+
+/// Returns the path of `module_id` as seen from `from_module_id`: relative to it if both are in the
+/// same crate, and starting with the crate's name otherwise.
+fn relative_module_path(
+    db: &dyn SemanticGroup,
+    from_module_id: ModuleId,
+    module_id: ModuleId,
+) -> String {
+    let defs_db = db.upcast();
+    if from_module_id.owning_crate(defs_db) != module_id.owning_crate(defs_db) {
+        return module_id.full_path(defs_db);
+    }
+    // The modules from the given one up to the crate root.
+    let ancestors = |mut module_id| {
+        let mut res = vec![module_id];
+        while let ModuleId::Submodule(submodule_id) = module_id {
+            module_id = submodule_id.parent_module(defs_db);
+            res.push(module_id);
+        }
+        res
+    };
+    let module_ancestors = ancestors(module_id);
+    let (n_supers, common_index) = ancestors(from_module_id)
+        .into_iter()
+        .enumerate()
+        .find_map(|(n_supers, ancestor)| {
+            Some((n_supers, module_ancestors.iter().position(|module_id| *module_id == ancestor)?))
+        })
+        .expect("Modules of the same crate have a common ancestor.");
+    let names = module_ancestors[..common_index].iter().rev().map(|module_id| match module_id {
+        ModuleId::Submodule(submodule_id) => submodule_id.name(defs_db),
+        ModuleId::CrateRoot(_) => unreachable!("The crate root is an ancestor of all modules."),
+    });
+    (0..n_supers).map(|_| "super".into()).chain(names).join("::")
+}
+
+/// Returns where a `use` item should be inserted in a module: the start of the file for modules
+/// with their own file, or right after the opening brace of an inline module, along with the
+/// indentation of its items. Returns None for plugin generated files.
+fn use_insertion_point(
+    db: &dyn SemanticGroup,
+    module_file_id: ModuleFileId,
+) -> Option<(FileId, TextOffset, Option<String>)> {
+    if module_file_id.1 != FileIndex(0) {
+        return None;
+    }
+    let file_id = db.module_file(module_file_id).ok()?;
+    let ModuleId::Submodule(submodule_id) = module_file_id.0 else {
+        return Some((file_id, TextOffset::default(), None));
+    };
+    let syntax_db = db.upcast();
+    // The submodule is declared in the file of its parent module.
+    let parent_file_id = db.module_file(submodule_id.module_file_id(db.upcast())).ok()?;
+    let submodule_ptr = submodule_id.stable_ptr(db.upcast()).untyped();
+    let module_ast = ast::ItemModule::from_syntax_node(
+        syntax_db,
+        lookup_node(db, parent_file_id, submodule_ptr)?,
+    );
+    match module_ast.body(syntax_db) {
+        ast::MaybeModuleBody::Some(body) => {
+            let module_start = module_ast.as_syntax_node().span_start_without_trivia(syntax_db);
+            let indent = format!("{}    ", line_indentation(db, file_id, module_start)?);
+            let offset = body.lbrace(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db);
+            Some((file_id, offset, Some(indent)))
+        }
+        ast::MaybeModuleBody::None(_) => Some((file_id, TextOffset::default(), None)),
+    }
+}
+
+/// Returns the fix adding `#[derive(<trait_name>)]` to the declaration of `ty`, if it is a struct
+/// or an enum outside of the core library that doesn't derive the trait already.
+pub fn derive_fix(db: &dyn SemanticGroup, ty: TypeId, trait_name: &str) -> Option<DiagnosticFix> {
+    let defs_db = db.upcast();
+    let syntax_db = db.upcast();
+    let (module_file_id, stable_ptr) = match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            let struct_id = concrete_struct_id.struct_id(db);
+            (struct_id.module_file_id(defs_db), struct_id.stable_ptr(defs_db).untyped())
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            let enum_id = concrete_enum_id.enum_id(db);
+            (enum_id.module_file_id(defs_db), enum_id.stable_ptr(defs_db).untyped())
+        }
+        _ => return None,
+    };
+    if module_file_id.0.owning_crate(defs_db) == core_crate(db) {
+        return None;
+    }
+    let file_id = db.module_file(module_file_id).ok()?;
+    let item = lookup_node(db, file_id, stable_ptr)?;
+    let attributes = match item.kind(syntax_db) {
+        SyntaxKind::ItemStruct => {
+            ast::ItemStruct::from_syntax_node(syntax_db, item.clone()).attributes(syntax_db)
+        }
+        _ => ast::ItemEnum::from_syntax_node(syntax_db, item.clone()).attributes(syntax_db),
+    };
+    if derived_traits(syntax_db, attributes).any(|derived| derived == trait_name) {
+        return None;
+    }
+    let offset = item.span_start_without_trivia(syntax_db);
+    let indent = line_indentation(db, file_id, offset)?;
+    Some(DiagnosticFix {
+        description: format!("Add `#[derive({trait_name})]`."),
+        edits: vec![DiagnosticEdit::insert(
+            file_id,
+            offset,
+            format!("#[derive({trait_name})]\n{indent}"),
+        )],
+    })
+}
+
+/// Returns the names of the traits derived by `#[derive(...)]` attributes.
+fn derived_traits(
+    db: &dyn SyntaxGroup,
+    attributes: ast::AttributeList,
+) -> impl Iterator<Item = String> + '_ {
+    attributes
+        .elements(db)
+        .into_iter()
+        .filter(|attr| attr.attr(db).text(db) == "derive")
+        .flat_map(|attr| match attr.args(db) {
+            ast::OptionAttributeArgs::AttributeArgs(args) => args.arg_list(db).elements(db),
+            ast::OptionAttributeArgs::Empty(_) => vec![],
+        })
+        .map(|arg| arg.as_syntax_node().get_text_without_trivia(db))
+}
+
+/// Returns the fix adding `ref` before the argument at `location`, if it has no other modifiers.
+pub fn ref_arg_fix(db: &dyn SemanticGroup, location: &StableLocation) -> Option<DiagnosticFix> {
+    let syntax_db = db.upcast();
+    let (file_id, mut node) = location.syntax_node(db.upcast());
+    while node.kind(syntax_db) != SyntaxKind::Arg {
+        node = node.parent()?;
+    }
+    let arg = ast::Arg::from_syntax_node(syntax_db, node);
+    if !arg.modifiers(syntax_db).elements(syntax_db).is_empty() {
+        return None;
+    }
+    Some(DiagnosticFix {
+        description: "Add `ref`.".into(),
+        edits: vec![DiagnosticEdit::insert(
+            file_id,
+            arg.as_syntax_node().span_start_without_trivia(syntax_db),
+            "ref ".into(),
+        )],
+    })
+}
+
+/// Returns the fix prefixing the identifier at `location` with `_`, marking it as intentionally
+/// unused.
+pub fn underscore_prefix_fix(db: &dyn SemanticGroup, location: &StableLocation) -> DiagnosticFix {
+    let (file_id, node) = location.syntax_node(db.upcast());
+    DiagnosticFix {
+        description: "Prefix with `_`.".into(),
+        edits: vec![DiagnosticEdit::insert(
+            file_id,
+            node.span_start_without_trivia(db.upcast()),
+            "_".into(),
+        )],
+    }
+}
+
+/// Returns the fix adding arms for the missing patterns of the match whose matched expression is at
+/// `location`. The added arms panic.
+pub fn missing_match_arms_fix(
+    db: &dyn SemanticGroup,
+    location: &StableLocation,
+    missing_patterns: &[String],
+) -> Option<DiagnosticFix> {
+    let syntax_db = db.upcast();
+    let (file_id, node) = location.syntax_node(db.upcast());
+    let match_node = node.parent()?;
+    if match_node.kind(syntax_db) != SyntaxKind::ExprMatch {
+        return None;
+    }
+    let match_ast = ast::ExprMatch::from_syntax_node(syntax_db, match_node);
+    let arms = match_ast.arms(syntax_db);
+    let arm_elements = arms.elements(syntax_db);
+    let (offset, indent, separator) = match arm_elements.last() {
+        Some(last_arm) => {
+            let last_arm_start = last_arm.as_syntax_node().span_start_without_trivia(syntax_db);
+            (
+                arms.as_syntax_node().span_end_without_trivia(syntax_db),
+                line_indentation(db, file_id, last_arm_start)?,
+                if arms.has_tail(syntax_db) { "," } else { "" },
+            )
+        }
+        None => {
+            let match_start = match_ast.as_syntax_node().span_start_without_trivia(syntax_db);
+            (
+                match_ast.lbrace(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db),
+                format!("{}    ", line_indentation(db, file_id, match_start)?),
+                "",
+            )
+        }
+    };
+    let new_arms = missing_patterns
+        .iter()
+        .map(|pattern| format!("\n{indent}{pattern} => {{ panic(array_new()) }},"))
+        .join("");
+    Some(DiagnosticFix {
+        description: "Add the missing match arms.".into(),
+        edits: vec![DiagnosticEdit::insert(file_id, offset, format!("{separator}{new_arms}"))],
+    })
+}
+
+/// Returns the node pointed by `stable_ptr` in the syntax of `file_id`.
+fn lookup_node(
+    db: &dyn SemanticGroup,
+    file_id: FileId,
+    stable_ptr: SyntaxStablePtrId,
+) -> Option<SyntaxNode> {
+    Some(db.file_syntax(file_id).ok()?.as_syntax_node().lookup_ptr(db.upcast(), stable_ptr))
+}
+
+/// Returns the leading whitespace of the line containing `offset`.
+fn line_indentation(db: &dyn SemanticGroup, file_id: FileId, offset: TextOffset) -> Option<String> {
+    let line_number = offset.get_line_number(db.upcast(), file_id)?;
+    let line_start = db.file_summary(file_id)?.line_offsets[line_number];
+    let content = db.file_content(file_id)?;
+    let line = TextSpan { start: line_start, end: offset }.take(&content);
+    Some(line.chars().take_while(|c| *c == ' ' || *c == '\t').collect())
+}
@@ -0,0 +1,124 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{apply_edits, DiagnosticEntry};
+use cairo_lang_filesystem::db::FilesGroup;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use crate::test_utils::{
+    get_crate_semantic_diagnostics, setup_test_crate, SemanticDatabaseForTesting,
+};
+
+/// Sets up a crate with the given content, and returns the content after applying the first fix
+/// of each of its diagnostics.
+fn apply_first_fixes(content: &str) -> String {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let crate_id = setup_test_crate(db, content);
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    let edits = get_crate_semantic_diagnostics(db, crate_id)
+        .get_all()
+        .into_iter()
+        .filter_map(|diagnostic| diagnostic.fixes(db).into_iter().next())
+        .flat_map(|fix| fix.edits)
+        .collect::<Vec<_>>();
+    assert!(edits.iter().all(|edit| edit.file_id == file_id));
+    apply_edits(&db.file_content(file_id).unwrap(), &edits).0
+}
+
+#[test]
+fn test_missing_use_fix() {
+    assert_eq!(
+        apply_first_fixes(indoc! {"
+            mod a {
+                fn foo() -> felt {
+                    1
+                }
+                mod b {
+                    fn bar() -> felt {
+                        foo()
+                    }
+                }
+            }
+            fn baz() -> felt {
+                foo()
+            }
+        "}),
+        indoc! {"
+            use a::foo;
+            mod a {
+                fn foo() -> felt {
+                    1
+                }
+                mod b {
+                    use super::foo;
+                    fn bar() -> felt {
+                        foo()
+                    }
+                }
+            }
+            fn baz() -> felt {
+                foo()
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_ref_arg_fix() {
+    assert_eq!(
+        apply_first_fixes(indoc! {"
+            fn foo(ref a: felt) {}
+            fn bar() {
+                let mut x = 1;
+                foo(x);
+            }
+        "}),
+        indoc! {"
+            fn foo(ref a: felt) {}
+            fn bar() {
+                let mut x = 1;
+                foo(ref x);
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_missing_match_arms_fix() {
+    assert_eq!(
+        apply_first_fixes(indoc! {"
+            enum E {
+                A: felt,
+                B: felt,
+            }
+            fn foo(e: E) -> felt {
+                match e {
+                    E::A(a) => a
+                }
+            }
+            fn bar(e: E) -> felt {
+                match e {
+                }
+            }
+        "}),
+        indoc! {"
+            enum E {
+                A: felt,
+                B: felt,
+            }
+            fn foo(e: E) -> felt {
+                match e {
+                    E::A(a) => a,
+                    E::B(_) => { panic(array_new()) },
+                }
+            }
+            fn bar(e: E) -> felt {
+                match e {
+                    _ => { panic(array_new()) },
+                }
+            }
+        "}
+    );
+}
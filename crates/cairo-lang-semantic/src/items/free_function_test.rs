@@ -91,3 +91,45 @@ fn test_expr_lookup() {
         "[Attribute { id: \"external\" }, Attribute { id: \"my_attr\" }]"
     );
 }
+
+#[test]
+fn test_expr_lookup_failed_member_access() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+    let (test_module, diagnostics) = setup_test_module(
+        db,
+        indoc::indoc! {"
+            struct A {
+                x: felt,
+            }
+            fn foo(a: A) -> felt {
+                a.y
+            }
+        "},
+    )
+    .split();
+    assert!(diagnostics.contains("Struct \"test::A\" has no member \"y\""), "{diagnostics}");
+
+    let function_id = FunctionWithBodyId::Free(extract_matches!(
+        db.module_item_by_name(test_module.module_id, "foo".into()).unwrap().unwrap(),
+        ModuleItemId::FreeFunction
+    ));
+    let expr_formatter = ExprFormatter { db, function_id };
+    // The receiver of the member access is kept, even though the access failed.
+    let expr_debugs: Vec<_> = db
+        .function_body(function_id)
+        .unwrap()
+        .exprs
+        .iter()
+        .map(|(_, expr)| format!("{:?}", expr.debug(&expr_formatter)))
+        .collect();
+    assert_eq!(
+        expr_debugs,
+        [
+            "Var(ExprVar { var: ParamId(test::a), ty: test::A })",
+            "Missing(ExprMissing { ty: <missing> })",
+            "Block(ExprBlock { statements: [], tail: Some(Missing(ExprMissing { ty: <missing> \
+             })), ty: <missing> })",
+        ]
+    );
+}
@@ -5,7 +5,7 @@ use cairo_lang_defs::ids::{
     ExternFunctionId, FreeFunctionId, FunctionSignatureId, FunctionWithBodyId, ImplFunctionId,
     ModuleItemId, ParamLongId, TopLevelLanguageElementId,
 };
-use cairo_lang_diagnostics::{Diagnostics, Maybe};
+use cairo_lang_diagnostics::{skip_diagnostic, Diagnostics, Maybe};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax as syntax;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
@@ -21,7 +21,10 @@ use crate::diagnostic::SemanticDiagnostics;
 use crate::expr::compute::Environment;
 use crate::resolve_path::{ResolvedLookback, Resolver};
 use crate::types::{resolve_type, substitute_ty, GenericSubstitution};
-use crate::{semantic, ConcreteImplId, GenericArgumentId, Parameter, SemanticDiagnostic};
+use crate::{
+    semantic, ConcreteImplId, GenericArgumentId, GenericParam, Parameter, SemanticDiagnostic,
+    TypeLongId,
+};
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ConcreteImplGenericFunctionId {
@@ -40,6 +43,8 @@ pub enum GenericFunctionId {
     Impl(ConcreteImplGenericFunctionId),
     // TODO(spapini): Remove when we separate semantic representations.
     Trait(ConcreteTraitGenericFunctionId),
+    /// The helper function of a loop expression.
+    Loop(LoopFunctionId),
 }
 impl GenericFunctionId {
     pub fn generic_args_apply<F: FnOnce(&mut Vec<GenericArgumentId>)>(
@@ -84,16 +89,19 @@ impl GenericFunctionId {
                     id.function_id(db).name(defs_db)
                 )
             }
+            GenericFunctionId::Loop(id) => id.full_path(db),
         }
     }
-    /// Gets the FunctionSignatureId of the generic function.
-    pub fn signature(&self, db: &dyn SemanticGroup) -> FunctionSignatureId {
-        match *self {
+    /// Gets the FunctionSignatureId of the generic function. Fails for loop functions, as their
+    /// parameters are only known after lowering.
+    pub fn signature(&self, db: &dyn SemanticGroup) -> Maybe<FunctionSignatureId> {
+        Ok(match *self {
             GenericFunctionId::Free(id) => FunctionSignatureId::Free(id),
             GenericFunctionId::Extern(id) => FunctionSignatureId::Extern(id),
             GenericFunctionId::Impl(id) => FunctionSignatureId::Impl(id.function),
             GenericFunctionId::Trait(id) => FunctionSignatureId::Trait(id.function_id(db)),
-        }
+            GenericFunctionId::Loop(_) => return Err(skip_diagnostic()),
+        })
     }
 }
 /// Conversion from ModuleItemId to GenericFunctionId.
@@ -157,23 +165,82 @@ impl FunctionId {
             GenericFunctionId::Impl(impl_function_id) => {
                 Some(FunctionWithBodyId::Impl(impl_function_id.function))
             }
+            GenericFunctionId::Loop(loop_function_id) => Some(loop_function_id.parent(db)),
             GenericFunctionId::Trait(_) => None,
             GenericFunctionId::Extern(_) => None,
         }
     }
 }
 
+/// The long ID of the helper function of a loop expression. Loops are lowered into recursive
+/// helper functions, taking the variables captured by the loop as parameters.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LoopFunctionLongId {
+    /// The function containing the loop.
+    pub parent: FunctionWithBodyId,
+    /// The loop expression, in the body of the parent function.
+    pub expr_id: semantic::ExprId,
+}
+
+define_short_id!(LoopFunctionId, LoopFunctionLongId, SemanticGroup, lookup_intern_loop_function);
+impl LoopFunctionId {
+    pub fn parent(&self, db: &dyn SemanticGroup) -> FunctionWithBodyId {
+        db.lookup_intern_loop_function(*self).parent
+    }
+    pub fn expr_id(&self, db: &dyn SemanticGroup) -> semantic::ExprId {
+        db.lookup_intern_loop_function(*self).expr_id
+    }
+    pub fn full_path(&self, db: &dyn SemanticGroup) -> String {
+        let LoopFunctionLongId { parent, expr_id } = db.lookup_intern_loop_function(*self);
+        format!("{}[expr{}]", parent.full_path(db.upcast()), expr_id.index())
+    }
+    /// Returns the generic params of the loop function: the type generic params of the parent
+    /// function, followed by those of its impl, if any.
+    pub fn generic_params(&self, db: &dyn SemanticGroup) -> Maybe<Vec<semantic::GenericParam>> {
+        let params = match self.parent(db) {
+            FunctionWithBodyId::Free(free_function_id) => {
+                db.free_function_generic_params(free_function_id)?
+            }
+            FunctionWithBodyId::Impl(impl_function_id) => chain!(
+                db.impl_function_generic_params(impl_function_id)?,
+                db.impl_def_generic_params(impl_function_id.impl_def_id(db.upcast()))?
+            )
+            .collect(),
+        };
+        Ok(params.into_iter().filter(|param| matches!(param, GenericParam::Type(_))).collect())
+    }
+    /// Returns the loop function, instantiated with the generic params of the parent function, as
+    /// called from inside the parent function.
+    pub fn function_id(&self, db: &dyn SemanticGroup) -> Maybe<FunctionId> {
+        let generic_args = self
+            .generic_params(db)?
+            .into_iter()
+            .map(|param| {
+                GenericArgumentId::Type(db.intern_type(TypeLongId::GenericParameter(param.id())))
+            })
+            .collect();
+        Ok(db.intern_function(FunctionLongId {
+            function: ConcreteFunction {
+                generic_function: GenericFunctionId::Loop(*self),
+                generic_args,
+            },
+        }))
+    }
+}
+
 /// The ID of a generic function with body that can be concretized.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum GenericFunctionWithBodyId {
     Free(FreeFunctionId),
     Impl(ConcreteImplGenericFunctionId),
+    Loop(LoopFunctionId),
 }
 impl From<GenericFunctionWithBodyId> for GenericFunctionId {
     fn from(val: GenericFunctionWithBodyId) -> Self {
         match val {
             GenericFunctionWithBodyId::Free(id) => GenericFunctionId::Free(id),
             GenericFunctionWithBodyId::Impl(id) => GenericFunctionId::Impl(id),
+            GenericFunctionWithBodyId::Loop(id) => GenericFunctionId::Loop(id),
         }
     }
 }
@@ -182,6 +249,7 @@ impl OptionFrom<GenericFunctionId> for GenericFunctionWithBodyId {
         Some(match other {
             GenericFunctionId::Free(id) => GenericFunctionWithBodyId::Free(id),
             GenericFunctionId::Impl(id) => GenericFunctionWithBodyId::Impl(id),
+            GenericFunctionId::Loop(id) => GenericFunctionWithBodyId::Loop(id),
             _ => return None,
         })
     }
@@ -194,10 +262,13 @@ pub struct ConcreteFunctionWithBody {
     pub generic_args: Vec<semantic::GenericArgumentId>,
 }
 impl ConcreteFunctionWithBody {
-    pub fn function_with_body_id(&self) -> FunctionWithBodyId {
+    /// Returns the function with body this function is defined in. For a loop function, this is
+    /// the function containing the loop.
+    pub fn function_with_body_id(&self, db: &dyn SemanticGroup) -> FunctionWithBodyId {
         match self.generic_function {
             GenericFunctionWithBodyId::Free(id) => FunctionWithBodyId::Free(id),
             GenericFunctionWithBodyId::Impl(id) => FunctionWithBodyId::Impl(id.function),
+            GenericFunctionWithBodyId::Loop(id) => id.parent(db),
         }
     }
     pub fn substitution(&self, db: &dyn SemanticGroup) -> Maybe<GenericSubstitution> {
@@ -220,6 +291,9 @@ impl ConcreteFunctionWithBody {
                     .collect_vec(),
                 )
             }
+            GenericFunctionWithBodyId::Loop(id) => {
+                GenericSubstitution::new(&id.generic_params(db)?, &self.generic_args)
+            }
         })
     }
     pub fn from_no_generics_free(
@@ -256,7 +330,7 @@ impl ConcreteFunctionWithBodyId {
         db.lookup_intern_concrete_function_with_body(*self)
     }
     pub fn function_with_body_id(&self, db: &dyn SemanticGroup) -> FunctionWithBodyId {
-        self.get(db).function_with_body_id()
+        self.get(db).function_with_body_id(db)
     }
     pub fn substitution(&self, db: &dyn SemanticGroup) -> Maybe<GenericSubstitution> {
         self.get(db).substitution(db)
@@ -456,7 +530,8 @@ pub fn concrete_function_signature(
 ) -> Maybe<Signature> {
     let ConcreteFunction { generic_function, generic_args, .. } =
         db.lookup_intern_function(function_id).function;
-    let generic_params = db.function_signature_generic_params(generic_function.signature(db))?;
+    let signature_id = generic_function.signature(db)?;
+    let generic_params = db.function_signature_generic_params(signature_id)?;
     // TODO(spapini): When trait generics are supported, they need to be substituted
     //   one by one, not together.
     // Panic shouldn't occur since ConcreteFunction is assumed to be constructed correctly.
@@ -475,8 +550,9 @@ pub fn concrete_function_signature(
             let generic_args = long_concrete_trait.generic_args;
             function_substitution.concat(GenericSubstitution::new(&generic_params, &generic_args))
         }
+        GenericFunctionId::Loop(_) => return Err(skip_diagnostic()),
     };
-    let generic_signature = db.function_signature_signature(generic_function.signature(db))?;
+    let generic_signature = db.function_signature_signature(signature_id)?;
     Ok(substitute_signature(db, substitution, generic_signature))
 }
 
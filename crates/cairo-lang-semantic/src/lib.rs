@@ -6,7 +6,9 @@ pub mod corelib;
 pub mod db;
 pub mod diagnostic;
 pub mod expr;
+pub mod fixes;
 pub mod items;
+pub mod lint;
 pub mod literals;
 pub mod patcher;
 pub mod plugin;
@@ -0,0 +1,118 @@
+//! Lints: warnings that can be allowed, or turned into errors, by attributes.
+
+#[cfg(test)]
+#[path = "lint_test.rs"]
+mod test;
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+
+use crate::db::SemanticGroup;
+
+/// A kind of warning, named in `#[allow(...)]`, `#[warn(...)]` and `#[deny(...)]` attributes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Lint {
+    UnusedVariables,
+    UnusedImports,
+    UnreachableCode,
+}
+impl Lint {
+    /// The name of the lint in attributes.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused_variables",
+            Lint::UnusedImports => "unused_imports",
+            Lint::UnreachableCode => "unreachable_code",
+        }
+    }
+}
+
+/// How a lint is reported at some location.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LintLevel {
+    /// Not reported.
+    Allow,
+    /// Reported as a warning. This is the default.
+    Warn,
+    /// Reported as an error.
+    Deny,
+}
+impl LintLevel {
+    /// Returns the level set by an attribute with the given name, if it is a lint attribute.
+    fn from_attribute_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+
+    /// Returns the severity the lint is reported with, or None if it is not reported.
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            LintLevel::Allow => None,
+            LintLevel::Warn => Some(Severity::Warning),
+            LintLevel::Deny => Some(Severity::Error),
+        }
+    }
+}
+
+/// Returns the level of `lint` at `location`: the level set by the innermost item around it with a
+/// lint attribute naming the lint, where the declarations of the module and its ancestors are
+/// checked last. Defaults to [LintLevel::Warn].
+pub fn lint_level(db: &dyn SemanticGroup, location: &StableLocation, lint: Lint) -> LintLevel {
+    let syntax_db = db.upcast();
+    let (_, node) = location.syntax_node(db.upcast());
+    let mut maybe_node = Some(node);
+    while let Some(node) = maybe_node {
+        if let Some(level) = attributes_lint_level(syntax_db, &node, lint) {
+            return level;
+        }
+        maybe_node = node.parent();
+    }
+
+    let defs_db = db.upcast();
+    let mut module_id = location.module_file_id.0;
+    while let ModuleId::Submodule(submodule_id) = module_id {
+        let parent_module_file_id = submodule_id.module_file_id(defs_db);
+        if let Ok(file_id) = db.module_file(parent_module_file_id) {
+            if let Ok(syntax) = db.file_syntax(file_id) {
+                let declaration = syntax
+                    .as_syntax_node()
+                    .lookup_ptr(syntax_db, submodule_id.stable_ptr(defs_db).untyped());
+                if let Some(level) = attributes_lint_level(syntax_db, &declaration, lint) {
+                    return level;
+                }
+            }
+        }
+        module_id = parent_module_file_id.0;
+    }
+    LintLevel::Warn
+}
+
+/// Returns the level of `lint` set by the attributes of `node`, if it is an item with attributes
+/// and one of them names the lint. The last such attribute wins.
+fn attributes_lint_level(db: &dyn SyntaxGroup, node: &SyntaxNode, lint: Lint) -> Option<LintLevel> {
+    let attributes = node.children(db).find(|child| child.kind(db) == SyntaxKind::AttributeList)?;
+    let attributes = ast::AttributeList::from_syntax_node(db, attributes);
+    attributes
+        .elements(db)
+        .into_iter()
+        .filter_map(|attribute| {
+            let level = LintLevel::from_attribute_name(&attribute.attr(db).text(db))?;
+            let ast::OptionAttributeArgs::AttributeArgs(args) = attribute.args(db) else {
+                return None;
+            };
+            args.arg_list(db)
+                .elements(db)
+                .iter()
+                .any(|arg| arg.as_syntax_node().get_text_without_trivia(db) == lint.name())
+                .then_some(level)
+        })
+        .last()
+}
@@ -0,0 +1,29 @@
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use crate::test_utils::{
+    get_crate_semantic_diagnostics, setup_test_crate, SemanticDatabaseForTesting,
+};
+
+cairo_lang_test_utils::test_file_test!(
+    lint,
+    "src/lint_test_data",
+    {
+        unused_variables: "unused_variables",
+        unused_imports: "unused_imports",
+        lint_levels: "lint_levels",
+    },
+    test_lint_diagnostics
+);
+
+/// Returns the diagnostics of all the modules of a crate with the given code, including the
+/// inline submodules.
+fn test_lint_diagnostics(
+    inputs: &OrderedHashMap<String, String>,
+) -> OrderedHashMap<String, String> {
+    let db = &mut SemanticDatabaseForTesting::default();
+    let crate_id = setup_test_crate(db, inputs["crate_code"].as_str());
+    OrderedHashMap::from([(
+        "expected_diagnostics".into(),
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+    )])
+}
@@ -132,6 +132,18 @@ impl Patches {
         }
         None
     }
+
+    /// Translates a span of the original code to the span of the generated code it was copied to,
+    /// if any.
+    pub fn translate_origin(&self, _db: &dyn DefsGroup, span: TextSpan) -> Option<TextSpan> {
+        for Patch { span: patch_span, origin_span } in &self.patches {
+            if origin_span.contains(span) {
+                let start = patch_span.start.add_width(span.start - origin_span.start);
+                return Some(TextSpan { start, end: start.add_width(span.end - span.start) });
+            }
+        }
+        None
+    }
 }
 
 pub struct PatchBuilder<'a> {
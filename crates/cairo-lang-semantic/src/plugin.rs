@@ -27,6 +27,22 @@ pub trait PluginAuxData:
         db: &(dyn SemanticGroup + 'static),
         diag: &dyn Any,
     ) -> Option<PluginMappedDiagnostic>;
+
+    /// Maps a span of the generated file to the span of the original code it was copied from, if
+    /// any.
+    fn map_span(&self, _db: &(dyn SemanticGroup + 'static), _span: TextSpan) -> Option<TextSpan> {
+        None
+    }
+
+    /// Maps a span of the original code to the span of the generated file it was copied to, if
+    /// any.
+    fn map_origin_span(
+        &self,
+        _db: &(dyn SemanticGroup + 'static),
+        _span: TextSpan,
+    ) -> Option<TextSpan> {
+        None
+    }
 }
 pub trait AsDynGeneratedFileAuxData {
     fn as_dyn_macro_token(&self) -> &(dyn GeneratedFileAuxData + 'static);
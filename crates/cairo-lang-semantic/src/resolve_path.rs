@@ -6,10 +6,10 @@ use std::iter::Peekable;
 
 use cairo_lang_defs::ids::{
     ConstantId, GenericKind, GenericTypeId, ImplDefId, LanguageElementId, ModuleFileId, ModuleId,
-    ModuleItemId, TraitFunctionId, TraitId, TypeAliasId,
+    ModuleItemId, TraitFunctionId, TraitId, TypeAliasId, UseId,
 };
 use cairo_lang_diagnostics::Maybe;
-use cairo_lang_filesystem::ids::CrateLongId;
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax as syntax;
 use cairo_lang_syntax::node::helpers::PathSegmentEx;
@@ -18,6 +18,7 @@ use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use itertools::Itertools;
 use smol_str::SmolStr;
 
@@ -115,6 +116,9 @@ impl ResolvedConcreteItem {
 pub struct ResolvedLookback {
     pub concrete: UnorderedHashMap<ast::TerminalIdentifierPtr, ResolvedConcreteItem>,
     pub generic: UnorderedHashMap<ast::TerminalIdentifierPtr, ResolvedGenericItem>,
+    /// The `use` items resolved through, used to report unused imports.
+    #[hide_field_debug_with_db]
+    pub uses: UnorderedHashSet<UseId>,
 }
 impl ResolvedLookback {
     // Relates a path segment to a ResolvedConcreteItem, and adds to a lookback map. This will be
@@ -237,7 +241,7 @@ impl<'db> Resolver<'db> {
             syntax::node::ast::PathSegment::WithGenericArgs(generic_segment) => {
                 let identifier = generic_segment.ident(syntax_db);
                 // Identifier with generic args cannot be a local item.
-                if let Some(module_id) = self.determine_base_module(&identifier) {
+                if let Some(module_id) = self.determine_base_module(diagnostics, &identifier)? {
                     ResolvedConcreteItem::Module(module_id)
                 } else {
                     // Crates do not have generics.
@@ -249,7 +253,9 @@ impl<'db> Resolver<'db> {
                 let identifier = simple_segment.ident(syntax_db);
                 if let Some(local_item) = self.determine_base_item_in_local_scope(&identifier) {
                     self.lookback.mark_concrete(self.db, segments.next().unwrap(), local_item)
-                } else if let Some(module_id) = self.determine_base_module(&identifier) {
+                } else if let Some(module_id) =
+                    self.determine_base_module(diagnostics, &identifier)?
+                {
                     // This item lies inside a module.
                     ResolvedConcreteItem::Module(module_id)
                 } else {
@@ -320,7 +326,7 @@ impl<'db> Resolver<'db> {
             }
             syntax::node::ast::PathSegment::Simple(simple_segment) => {
                 let identifier = simple_segment.ident(syntax_db);
-                if let Some(module_id) = self.determine_base_module(&identifier) {
+                if let Some(module_id) = self.determine_base_module(diagnostics, &identifier)? {
                     // This item lies inside a module.
                     ResolvedGenericItem::Module(module_id)
                 } else {
@@ -631,6 +637,7 @@ impl<'db> Resolver<'db> {
                 // directly for cycle handling. Do we need to handle cycle both on
                 // it and on the selector use_resolved_item() now?
                 diagnostics.diagnostics.extend(self.db.use_semantic_diagnostics(id));
+                self.lookback.uses.insert(id);
                 self.db.use_resolved_item(id)?
             }
             ModuleItemId::FreeFunction(id) => {
@@ -674,25 +681,49 @@ impl<'db> Resolver<'db> {
     /// current module, or crates).
     /// Returns Some(module) if the identifier is an item in a module. Otherwise, the path is fully
     /// qualified, which means the identifier is a crate. In this case, returns None.
-    fn determine_base_module(&mut self, identifier: &ast::TerminalIdentifier) -> Option<ModuleId> {
+    /// Fails if the identifier is a crate which is not a dependency of the current crate.
+    fn determine_base_module(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        identifier: &ast::TerminalIdentifier,
+    ) -> Maybe<Option<ModuleId>> {
         let syntax_db = self.db.upcast();
         let ident = identifier.text(syntax_db);
 
         // If an item with this name is found inside the current module, use the current module.
         if let Ok(Some(_)) = self.db.module_item_by_name(self.module_file_id.0, ident.clone()) {
-            return Some(self.module_file_id.0);
+            return Ok(Some(self.module_file_id.0));
         }
 
         // If the first segment is a name of a crate, use the crate's root module as the base
         // module.
-        let crate_id = self.db.intern_crate(CrateLongId(ident));
+        let crate_id = self.db.intern_crate(CrateLongId(ident.clone()));
         // TODO(spapini): Use a better interface to check if the crate exists (not using `dir`).
         if self.db.crate_root_dir(crate_id).is_some() {
-            return None;
+            if !self.is_crate_visible(crate_id) {
+                return Err(
+                    diagnostics.report(identifier, CrateNotADependency { crate_name: ident })
+                );
+            }
+            return Ok(None);
         }
 
         // Last resort, use the `core` crate root module as the base module.
-        Some(core_module(self.db))
+        Ok(Some(core_module(self.db)))
+    }
+
+    /// Returns whether the current crate can refer to the given crate by name. A crate can always
+    /// refer to itself and to the core crate, and to other crates only if they are its dependencies
+    /// (or if its dependencies are not declared).
+    fn is_crate_visible(&self, crate_id: CrateId) -> bool {
+        let current_crate_id = self.module_file_id.0.owning_crate(self.db.upcast());
+        if crate_id == current_crate_id || ModuleId::CrateRoot(crate_id) == core_module(self.db) {
+            return true;
+        }
+        match self.db.crate_settings(current_crate_id).and_then(|settings| settings.dependencies) {
+            Some(dependencies) => dependencies.contains(&crate_id),
+            None => true,
+        }
     }
 
     /// Specializes a trait.
@@ -748,9 +779,9 @@ impl<'db> Resolver<'db> {
         generic_args: Vec<ast::Expr>,
     ) -> Maybe<FunctionId> {
         // TODO(lior): Should we report diagnostic if `impl_def_generic_params` failed?
-        let generic_params: Vec<_> = self
-            .db
-            .function_signature_generic_params(generic_function.signature(self.db))
+        let generic_params: Vec<_> = generic_function
+            .signature(self.db)
+            .and_then(|signature| self.db.function_signature_generic_params(signature))
             .map_err(|_| diagnostics.report_by_ptr(stable_ptr, UnknownFunction))?;
 
         let generic_args =
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_filesystem::db::{AsFilesGroupMut, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::db::{AsFilesGroupMut, CrateSettings, FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
 use cairo_lang_utils::extract_matches;
 use indoc::indoc;
@@ -145,3 +145,45 @@ fn test_resolve_path_trait_impl() {
          }))"
     );
 }
+
+#[test]
+fn test_resolve_path_crate_dependencies() {
+    let mut db_val = SemanticDatabaseForTesting::default();
+    let db = &mut db_val;
+
+    let main_crate_id = db.intern_crate(CrateLongId("main_crate".into()));
+    db.set_crate_root(main_crate_id, Some(Directory("main_src".into())));
+    let dependency_id = db.intern_crate(CrateLongId("dependency".into()));
+    db.set_crate_root(dependency_id, Some(Directory("dependency_src".into())));
+    let other_crate_id = db.intern_crate(CrateLongId("other_crate".into()));
+    db.set_crate_root(other_crate_id, Some(Directory("other_src".into())));
+    db.set_crate_settings(
+        main_crate_id,
+        Some(CrateSettings { dependencies: Some(vec![dependency_id]), ..Default::default() }),
+    );
+
+    set_file_content(
+        db,
+        "main_src/lib.cairo",
+        indoc! {"
+            struct MainStruct {
+                a: dependency::DependencyStruct,
+                b: other_crate::OtherStruct,
+            }
+        "},
+    );
+    set_file_content(db, "dependency_src/lib.cairo", "struct DependencyStruct {}");
+    set_file_content(db, "other_src/lib.cairo", "struct OtherStruct {}");
+
+    let diagnostics = db.module_semantic_diagnostics(ModuleId::CrateRoot(main_crate_id)).unwrap();
+    assert_eq!(
+        diagnostics.format(db),
+        indoc! {r#"
+            error: Crate "other_crate" is not declared as a dependency.
+             --> lib.cairo:3:8
+                b: other_crate::OtherStruct,
+                   ^*********^
+
+        "#}
+    );
+}
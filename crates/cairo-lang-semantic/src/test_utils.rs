@@ -13,7 +13,6 @@ use cairo_lang_parser::db::ParserDatabase;
 use cairo_lang_syntax::node::db::{SyntaxDatabase, SyntaxGroup};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::{extract_matches, OptionFrom, Upcast};
-use pretty_assertions::assert_eq;
 
 use crate::db::{SemanticDatabase, SemanticGroup, SemanticGroupEx};
 use crate::items::functions::GenericFunctionId;
@@ -68,11 +67,13 @@ impl HasMacroPlugins for SemanticDatabaseForTesting {
 pub struct WithStringDiagnostics<T> {
     value: T,
     diagnostics: String,
+    has_errors: bool,
 }
 impl<T> WithStringDiagnostics<T> {
-    /// Verifies that there are no diagnostics (fails otherwise), and returns the inner value.
+    /// Verifies that there are no errors (fails otherwise), and returns the inner value. Warnings
+    /// are allowed.
     pub fn unwrap(self) -> T {
-        assert_eq!(self.diagnostics, "");
+        assert!(!self.has_errors, "{}", self.diagnostics);
         self.value
     }
 
@@ -112,12 +113,17 @@ pub fn setup_test_module(
     let module_id = ModuleId::CrateRoot(crate_id);
     let file_id = db.module_main_file(module_id).unwrap();
 
-    let syntax_diagnostics = db.file_syntax_diagnostics(file_id).format(Upcast::upcast(db));
-    let semantic_diagnostics = db.module_semantic_diagnostics(module_id).unwrap().format(db);
+    let syntax_diagnostics = db.file_syntax_diagnostics(file_id);
+    let semantic_diagnostics = db.module_semantic_diagnostics(module_id).unwrap();
 
     WithStringDiagnostics {
         value: TestModule { crate_id, module_id },
-        diagnostics: format!("{syntax_diagnostics}{semantic_diagnostics}"),
+        diagnostics: format!(
+            "{}{}",
+            syntax_diagnostics.format(Upcast::upcast(db)),
+            semantic_diagnostics.format(db)
+        ),
+        has_errors: syntax_diagnostics.has_errors() || semantic_diagnostics.has_errors(),
     }
 }
 
@@ -144,7 +150,8 @@ pub fn setup_test_function(
     } else {
         format!("{module_code}\n{function_code}")
     };
-    let (test_module, diagnostics) = setup_test_module(db, &content).split();
+    let WithStringDiagnostics { value: test_module, diagnostics, has_errors } =
+        setup_test_module(db, &content);
     let generic_function_id = db
         .module_item_by_name(test_module.module_id, function_name.into())
         .expect("Failed to load module")
@@ -165,6 +172,7 @@ pub fn setup_test_function(
             body: db.function_body_expr(function_id).unwrap(),
         },
         diagnostics,
+        has_errors,
     }
 }
 
@@ -187,8 +195,8 @@ pub fn setup_test_expr(
     function_body: &str,
 ) -> WithStringDiagnostics<TestExpr> {
     let function_code = format!("fn test_func() {{ {function_body} {{\n{expr_code}\n}}; }}");
-    let (test_function, diagnostics) =
-        setup_test_function(db, &function_code, "test_func", module_code).split();
+    let WithStringDiagnostics { value: test_function, diagnostics, has_errors } =
+        setup_test_function(db, &function_code, "test_func", module_code);
     let semantic::ExprBlock { statements, .. } = extract_matches!(
         db.expr_semantic(test_function.function_id, test_function.body),
         semantic::Expr::Block
@@ -214,6 +222,7 @@ pub fn setup_test_expr(
             expr_id: tail.unwrap(),
         },
         diagnostics,
+        has_errors,
     }
 }
 
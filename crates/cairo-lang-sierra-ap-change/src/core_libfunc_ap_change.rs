@@ -231,6 +231,7 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             StarkNetConcreteLibfunc::ContractAddressTryFromFelt(_) => {
                 vec![ApChange::Known(5), ApChange::Known(6)]
             }
+            StarkNetConcreteLibfunc::ContractAddressToFelt(_) => vec![ApChange::Known(0)],
             StarkNetConcreteLibfunc::CallContract(_) => {
                 vec![ApChange::Known(2), ApChange::Known(2)]
             }
@@ -243,9 +244,19 @@ pub fn core_libfunc_ap_change<InfoProvider: InvocationApChangeInfoProvider>(
             StarkNetConcreteLibfunc::StorageAddressFromBase(_) => vec![ApChange::Known(0)],
             StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => vec![ApChange::Known(0)],
             StarkNetConcreteLibfunc::EmitEvent(_) => vec![ApChange::Known(2), ApChange::Known(2)],
-            StarkNetConcreteLibfunc::GetCallerAddress(_) => {
+            StarkNetConcreteLibfunc::GetCallerAddress(_)
+            | StarkNetConcreteLibfunc::GetContractAddress(_)
+            | StarkNetConcreteLibfunc::GetBlockNumber(_)
+            | StarkNetConcreteLibfunc::GetBlockTimestamp(_)
+            | StarkNetConcreteLibfunc::GetSequencerAddress(_)
+            | StarkNetConcreteLibfunc::GetTxInfo(_)
+            | StarkNetConcreteLibfunc::LibraryCall(_)
+            | StarkNetConcreteLibfunc::Deploy(_)
+            | StarkNetConcreteLibfunc::SendMessageToL1(_)
+            | StarkNetConcreteLibfunc::ReplaceClass(_) => {
                 vec![ApChange::Known(2), ApChange::Known(2)]
             }
+            StarkNetConcreteLibfunc::Cheatcode(_) => vec![ApChange::Known(2)],
         },
         CoreConcreteLibfunc::Nullable(libfunc) => match libfunc {
             NullableConcreteLibfunc::Null(_) => vec![ApChange::Known(0)],
@@ -13,7 +13,7 @@ pub fn starknet_libfunc_cost_base<Ops: CostOperations>(
     libfunc: &StarkNetConcreteLibfunc,
 ) -> Vec<Ops::CostType> {
     match libfunc {
-        StarkNetConcreteLibfunc::CallContract(_) => syscall_cost(ops, 8, 8),
+        StarkNetConcreteLibfunc::CallContract(_) => syscall_cost(ops, 9, 9),
         StarkNetConcreteLibfunc::ContractAddressConst(_) => vec![ops.steps(0)],
         StarkNetConcreteLibfunc::ContractAddressTryFromFelt(_) => {
             vec![
@@ -21,6 +21,7 @@ pub fn starknet_libfunc_cost_base<Ops: CostOperations>(
                 ops.const_cost(ConstCost { steps: 9, holes: 0, range_checks: 3 }),
             ]
         }
+        StarkNetConcreteLibfunc::ContractAddressToFelt(_) => vec![ops.steps(0)],
         StarkNetConcreteLibfunc::StorageRead(_) => syscall_cost(ops, 7, 7),
         StarkNetConcreteLibfunc::StorageWrite(_) => syscall_cost(ops, 8, 8),
         StarkNetConcreteLibfunc::StorageBaseAddressConst(_) => vec![ops.steps(0)],
@@ -30,7 +31,17 @@ pub fn starknet_libfunc_cost_base<Ops: CostOperations>(
         StarkNetConcreteLibfunc::StorageAddressFromBase(_) => vec![ops.steps(0)],
         StarkNetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => vec![ops.steps(0)],
         StarkNetConcreteLibfunc::EmitEvent(_) => syscall_cost(ops, 9, 9),
-        StarkNetConcreteLibfunc::GetCallerAddress(_) => syscall_cost(ops, 5, 5),
+        StarkNetConcreteLibfunc::GetCallerAddress(_)
+        | StarkNetConcreteLibfunc::GetContractAddress(_)
+        | StarkNetConcreteLibfunc::GetBlockNumber(_)
+        | StarkNetConcreteLibfunc::GetBlockTimestamp(_)
+        | StarkNetConcreteLibfunc::GetSequencerAddress(_)
+        | StarkNetConcreteLibfunc::GetTxInfo(_) => syscall_cost(ops, 5, 5),
+        StarkNetConcreteLibfunc::LibraryCall(_) => syscall_cost(ops, 9, 9),
+        StarkNetConcreteLibfunc::Deploy(_) => syscall_cost(ops, 10, 10),
+        StarkNetConcreteLibfunc::SendMessageToL1(_) => syscall_cost(ops, 8, 8),
+        StarkNetConcreteLibfunc::ReplaceClass(_) => syscall_cost(ops, 6, 6),
+        StarkNetConcreteLibfunc::Cheatcode(_) => vec![ops.steps(1)],
     }
 }
 
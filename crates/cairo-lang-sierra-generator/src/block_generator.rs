@@ -39,6 +39,11 @@ pub fn generate_block_body_code(
     // Process the statements.
     for (i, statement) in block.statements.iter().enumerate() {
         let statement_location = (block_id, i);
+        // The location of a statement is the location of the variables it defines, or of the
+        // variables it uses if it defines none (e.g. a match).
+        if let Some(var) = chain!(statement.outputs(), statement.inputs()).next() {
+            statements.push(pre_sierra::Statement::Location(context.get_var_location(var)));
+        }
         statements.extend(generate_statement_code(context, statement, &statement_location)?);
         let drop_location = &DropLocation::PostStatement(statement_location);
         add_drop_statements(context, drops, drop_location, &mut statements)?;
@@ -270,7 +275,7 @@ fn generate_statement_call_code(
         get_concrete_libfunc_id(context.get_db(), statement.function);
 
     match function_long_id.generic_function {
-        GenericFunctionId::Free(_) | GenericFunctionId::Impl(_) => {
+        GenericFunctionId::Free(_) | GenericFunctionId::Impl(_) | GenericFunctionId::Loop(_) => {
             // Create [pre_sierra::PushValue] instances for the arguments.
             let mut args_on_stack: Vec<sierra::ids::VarId> = vec![];
             let mut push_values_vec: Vec<pre_sierra::PushValue> = vec![];
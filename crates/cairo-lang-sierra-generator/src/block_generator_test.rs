@@ -6,6 +6,7 @@ use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use crate::block_generator::generate_block_body_code;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::lifetime::find_variable_lifetime;
+use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -59,6 +60,7 @@ fn block_generator_test(inputs: &OrderedHashMap<String, String>) -> OrderedHashM
     let expected_sierra_code = statements_opt.map_or("None".into(), |statements| {
         statements
             .iter()
+            .filter(|x| !matches!(x, pre_sierra::Statement::Location(_)))
             .map(|x| replace_sierra_ids(db, x).to_string())
             .collect::<Vec<String>>()
             .join("\n")
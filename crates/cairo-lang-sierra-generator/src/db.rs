@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib::get_core_ty_by_name;
-use cairo_lang_semantic::{ConcreteFunctionWithBodyId, GenericArgumentId, Mutability};
+use cairo_lang_semantic::{ConcreteFunctionWithBodyId, GenericArgumentId};
 use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::{ConcreteType, GenericTypeEx};
 use cairo_lang_sierra::ids::ConcreteTypeId;
@@ -13,6 +14,7 @@ use cairo_lang_utils::Upcast;
 
 use crate::program_generator::{self};
 use crate::specialization_context::SierraSignatureSpecializationContext;
+use crate::utils::get_function_explicit_types;
 use crate::{ap_change, function_generator, pre_sierra};
 
 #[salsa::query_group(SierraGenDatabase)]
@@ -99,6 +101,22 @@ pub trait SierraGenGroup: LoweringGroup + Upcast<dyn LoweringGroup> {
         &self,
         requested_crate_ids: Vec<CrateId>,
     ) -> Maybe<Arc<cairo_lang_sierra::program::Program>>;
+
+    /// Returns the source location of each statement of the program returned by
+    /// [SierraGenGroup::get_sierra_program_for_functions], if known.
+    #[salsa::invoke(program_generator::get_sierra_program_statement_locations_for_functions)]
+    fn get_sierra_program_statement_locations_for_functions(
+        &self,
+        requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+    ) -> Maybe<Arc<Vec<Option<StableLocation>>>>;
+
+    /// Returns the source location of each statement of the program returned by
+    /// [SierraGenGroup::get_sierra_program], if known.
+    #[salsa::invoke(program_generator::get_sierra_program_statement_locations)]
+    fn get_sierra_program_statement_locations(
+        &self,
+        requested_crate_ids: Vec<CrateId>,
+    ) -> Maybe<Arc<Vec<Option<StableLocation>>>>;
 }
 
 fn get_function_signature(
@@ -111,7 +129,7 @@ fn get_function_signature(
     // it in the end of program_generator::get_sierra_program instead of calling this function from
     // there.
     let semantic_function_id = db.lookup_intern_sierra_function(function_id);
-    let signature = db.concrete_function_signature(semantic_function_id)?;
+    let (params, return_type) = get_function_explicit_types(db, semantic_function_id)?;
     let may_panic = db.function_may_panic(semantic_function_id)?;
 
     let implicits = db
@@ -124,16 +142,16 @@ fn get_function_signature(
     let mut ret_types = implicits.clone();
     let mut all_params = implicits;
 
-    for param in signature.params {
-        let concrete_type_id = db.get_concrete_type_id(param.ty)?;
+    for (ty, is_ref) in params {
+        let concrete_type_id = db.get_concrete_type_id(ty)?;
         all_params.push(concrete_type_id.clone());
-        if param.mutability == Mutability::Reference {
+        if is_ref {
             ret_types.push(concrete_type_id);
         }
     }
 
     // TODO(ilya): Handle tuple and struct types.
-    let mut return_type = signature.return_type;
+    let mut return_type = return_type;
     if may_panic {
         return_type = get_core_ty_by_name(
             db.upcast(),
@@ -1,3 +1,4 @@
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_lowering as lowering;
 use cairo_lang_semantic::{ConcreteFunctionWithBodyId, TypeId};
@@ -145,6 +146,11 @@ impl<'a> ExprGeneratorContext<'a> {
     pub fn get_var_type(&self, var_id: VariableId) -> TypeId {
         self.lowered.variables[var_id].ty
     }
+
+    /// Returns the location of the variable given by `var_id`.
+    pub fn get_var_location(&self, var_id: VariableId) -> StableLocation {
+        self.lowered.variables[var_id].location
+    }
 }
 
 /// A variant of ExprGeneratorContext::alloc_label_id that allows the caller to avoid
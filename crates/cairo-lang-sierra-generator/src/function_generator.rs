@@ -19,7 +19,8 @@ use crate::local_variables::find_local_variables;
 use crate::pre_sierra::{self, Statement};
 use crate::store_variables::{add_store_statements, LibfuncInfo, LocalVariables};
 use crate::utils::{
-    alloc_local_libfunc_id, finalize_locals_libfunc_id, get_libfunc_signature, simple_statement,
+    alloc_local_libfunc_id, finalize_locals_libfunc_id, get_function_explicit_types,
+    get_libfunc_signature, simple_statement,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -48,7 +49,7 @@ fn get_function_code(
     db: &dyn SierraGenGroup,
     function_id: ConcreteFunctionWithBodyId,
 ) -> Maybe<Arc<pre_sierra::Function>> {
-    let signature = db.concrete_function_signature(function_id.function_id(db.upcast()))?;
+    let (_, return_type) = get_function_explicit_types(db, function_id.function_id(db.upcast()))?;
     let lowered_function = &*db.concrete_function_with_body_lowered(function_id)?;
     let block_id = lowered_function.root?;
     let block = &lowered_function.blocks[block_id];
@@ -75,7 +76,7 @@ fn get_function_code(
         })
     }
 
-    let ret_types = vec![db.get_concrete_type_id(signature.return_type)?];
+    let ret_types = vec![db.get_concrete_type_id(return_type)?];
 
     let mut statements: Vec<pre_sierra::Statement> = vec![label];
 
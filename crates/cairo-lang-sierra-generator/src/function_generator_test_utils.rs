@@ -3,6 +3,7 @@ use cairo_lang_semantic::test_utils::setup_test_function;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::db::SierraGenGroup;
+use crate::pre_sierra;
 use crate::replace_ids::replace_sierra_ids;
 use crate::test_utils::SierraGenDatabaseForTesting;
 
@@ -28,6 +29,7 @@ pub fn test_function_generator(
     let sierra_code: String = function.map_or("None".into(), |func| {
         func.body
             .iter()
+            .filter(|x| !matches!(x, pre_sierra::Statement::Location(_)))
             .map(|x| replace_sierra_ids(db, x).to_string())
             .collect::<Vec<String>>()
             .join("\n")
@@ -158,6 +158,10 @@ fn inner_find_variable_lifetime(
         }
         lowering::FlatBlockEnd::Goto(target_block_id, remapping) => {
             *state = context.block_state[*target_block_id].clone();
+            // The destination variables of the remapping are defined by the jump.
+            for var_id in remapping.keys() {
+                state.used_variables.swap_remove(&SierraGenVar::LoweringVar(*var_id));
+            }
             let vars = remapping.values().copied().collect_vec();
 
             state.use_variables(context, &vars, (block_id, block.statements.len()));
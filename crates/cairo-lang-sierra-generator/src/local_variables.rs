@@ -15,8 +15,9 @@ use lowering::FlatLowered;
 use crate::db::SierraGenGroup;
 use crate::replace_ids::{DebugReplacer, SierraIdReplacer};
 use crate::utils::{
-    enum_init_libfunc_id, get_concrete_libfunc_id, get_libfunc_signature, match_enum_libfunc_id,
-    statement_outputs, struct_construct_libfunc_id, struct_deconstruct_libfunc_id,
+    arm_callsite_remapping, enum_init_libfunc_id, get_concrete_libfunc_id, get_libfunc_signature,
+    match_enum_libfunc_id, statement_outputs, struct_construct_libfunc_id,
+    struct_deconstruct_libfunc_id,
 };
 
 /// Given the lowering of a function, returns the set of variables which should be stored as local
@@ -238,7 +239,7 @@ fn handle_match(
         let inner_known_ap_change = inner_find_local_variables(ctx, *block_id, state_clone, res)?;
 
         // Update reachable_branches and reachable_branches_known_ap_change.
-        if let lowering::FlatBlockEnd::Callsite(_) = ctx.lowered_function.blocks[*block_id].end {
+        if arm_callsite_remapping(ctx.lowered_function, *block_id).is_some() {
             reachable_branches += 1;
             if !inner_known_ap_change {
                 reachable_branches_known_ap_change = false;
@@ -1,3 +1,4 @@
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra as sierra;
 use cairo_lang_sierra::ids::ConcreteTypeId;
@@ -54,6 +55,9 @@ pub enum Statement {
     /// Note that push values does not guarantee that new copies of the values will be pushed.
     /// If a prefix of the values is already on the stack, they will not be re-pushed.
     PushValues(Vec<PushValue>),
+    /// Marks the beginning of the code generated for a lowering statement, at the given source
+    /// location. Only used as debug information, and removed when labels are resolved.
+    Location(StableLocation),
 }
 impl std::fmt::Display for Statement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -75,6 +79,7 @@ impl std::fmt::Display for Statement {
                 )?;
                 write!(f, ")")
             }
+            Statement::Location(_) => write!(f, "Location"),
         }
     }
 }
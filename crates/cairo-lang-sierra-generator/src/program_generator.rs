@@ -1,6 +1,7 @@
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::{skip_diagnostic, Maybe, ToMaybe};
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
@@ -50,7 +51,8 @@ fn collect_used_libfuncs(
                 Some(invocation.libfunc_id.clone())
             }
             pre_sierra::Statement::Sierra(program::GenStatement::Return(_))
-            | pre_sierra::Statement::Label(_) => None,
+            | pre_sierra::Statement::Label(_)
+            | pre_sierra::Statement::Location(_) => None,
             pre_sierra::Statement::PushValues(_) => {
                 panic!("Unexpected pre_sierra::Statement::PushValues in collect_used_libfuncs().")
             }
@@ -121,10 +123,12 @@ fn collect_used_types(
         .collect()
 }
 
-pub fn get_sierra_program_for_functions(
+/// Collects the code of the requested functions and the functions they call, before label
+/// resolution. Returns the functions and the concatenation of their statements.
+fn collect_functions_code(
     db: &dyn SierraGenGroup,
     requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
-) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+) -> Maybe<(Vec<Arc<pre_sierra::Function>>, Vec<pre_sierra::Statement>)> {
     let mut functions: Vec<Arc<pre_sierra::Function>> = vec![];
     let mut statements: Vec<pre_sierra::Statement> = vec![];
     let mut processed_function_ids = UnorderedHashSet::<ConcreteFunctionWithBodyId>::default();
@@ -141,7 +145,10 @@ pub fn get_sierra_program_for_functions(
             // If AP change is unknown for the function, adding a revoke so that AP balancing would
             // not occur.
             let revoke_statement = simple_statement(revoke_ap_tracking_libfunc_id(db), &[], &[]);
-            if function.body.get(function.prolog_size) != Some(&revoke_statement) {
+            let first_body_statement = function.body[function.prolog_size..]
+                .iter()
+                .find(|statement| !matches!(statement, pre_sierra::Statement::Location(_)));
+            if first_body_statement != Some(&revoke_statement) {
                 statements.push(revoke_statement);
             }
         }
@@ -152,7 +159,14 @@ pub fn get_sierra_program_for_functions(
             }
         }
     }
+    Ok((functions, statements))
+}
 
+pub fn get_sierra_program_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    let (functions, statements) = collect_functions_code(db, requested_function_ids)?;
     let libfunc_declarations =
         generate_libfunc_declarations(db, collect_used_libfuncs(&statements).iter());
     let type_declarations =
@@ -209,10 +223,71 @@ fn try_get_function_with_body_id(
     function.get_body(db.upcast()).ok_or_else(skip_diagnostic)
 }
 
+/// Query implementation of
+/// [SierraGenGroup::get_sierra_program_statement_locations_for_functions].
+pub fn get_sierra_program_statement_locations_for_functions(
+    db: &dyn SierraGenGroup,
+    requested_function_ids: Vec<ConcreteFunctionWithBodyId>,
+) -> Maybe<Arc<Vec<Option<StableLocation>>>> {
+    let (functions, statements) = collect_functions_code(db, requested_function_ids)?;
+    // The labels which are reached by jumps, rather than only by falling through to them.
+    let mut jump_targets: UnorderedHashSet<pre_sierra::LabelId> =
+        functions.iter().map(|function| function.entry_point).collect();
+    for statement in &statements {
+        if let pre_sierra::Statement::Sierra(program::GenStatement::Invocation(invocation)) =
+            statement
+        {
+            for branch in &invocation.branches {
+                if let program::GenBranchTarget::Statement(label_id) = branch.target {
+                    jump_targets.insert(label_id);
+                }
+            }
+        }
+    }
+    let mut locations = vec![];
+    let mut current_location = None;
+    for statement in statements {
+        match statement {
+            pre_sierra::Statement::Sierra(_) => locations.push(current_location),
+            pre_sierra::Statement::Location(location) => current_location = Some(location),
+            pre_sierra::Statement::Label(pre_sierra::Label { id }) => {
+                // The code after a jump target may be reached from other locations.
+                if jump_targets.contains(&id) {
+                    current_location = None;
+                }
+            }
+            pre_sierra::Statement::PushValues(_) => panic!(
+                "Unexpected pre_sierra::Statement::PushValues in \
+                 get_sierra_program_statement_locations_for_functions()."
+            ),
+        }
+    }
+    Ok(Arc::new(locations))
+}
+
 pub fn get_sierra_program(
     db: &dyn SierraGenGroup,
     requested_crate_ids: Vec<CrateId>,
 ) -> Maybe<Arc<cairo_lang_sierra::program::Program>> {
+    db.get_sierra_program_for_functions(crates_functions(db, requested_crate_ids)?)
+}
+
+/// Query implementation of [SierraGenGroup::get_sierra_program_statement_locations].
+pub fn get_sierra_program_statement_locations(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Arc<Vec<Option<StableLocation>>>> {
+    db.get_sierra_program_statement_locations_for_functions(crates_functions(
+        db,
+        requested_crate_ids,
+    )?)
+}
+
+/// Returns the free functions of the given crates that have no generic parameters.
+fn crates_functions(
+    db: &dyn SierraGenGroup,
+    requested_crate_ids: Vec<CrateId>,
+) -> Maybe<Vec<ConcreteFunctionWithBodyId>> {
     let mut requested_function_ids = vec![];
     for crate_id in requested_crate_ids {
         for module_id in db.crate_modules(crate_id).iter() {
@@ -226,5 +301,5 @@ pub fn get_sierra_program(
             }
         }
     }
-    db.get_sierra_program_for_functions(requested_function_ids)
+    Ok(requested_function_ids)
 }
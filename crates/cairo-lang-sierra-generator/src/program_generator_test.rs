@@ -1,10 +1,11 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleItemId;
+use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::ConcreteFunctionWithBodyId;
 use cairo_lang_utils::try_extract_matches;
 use indoc::indoc;
-use itertools::Itertools;
+use itertools::{zip_eq, Itertools};
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 use test_log::test;
@@ -143,3 +144,46 @@ fn test_only_include_dependecies(func_name: &str, sierra_used_funcs: &[&str]) {
         sierra_used_funcs
     );
 }
+
+#[test]
+fn test_statement_locations() {
+    let (db, crate_id) = setup_db_and_get_crate_id(indoc! {"
+                fn foo(a: felt) -> felt {
+                    bar(5)
+                }
+
+                fn bar(a: felt) -> felt {
+                    felt_add(felt_add(a, a), a)
+                }
+            "});
+    let program = db.get_sierra_program(vec![crate_id]).unwrap();
+    let locations = db.get_sierra_program_statement_locations(vec![crate_id]).unwrap();
+
+    // Each statement is followed by the code at its location.
+    assert_eq!(
+        zip_eq(&replace_sierra_ids_in_program(&db, &program).statements, locations.iter())
+            .map(|(statement, location)| match location {
+                Some(location) => {
+                    let location = location.diagnostic_location(&db);
+                    let content = db.file_content(location.file_id).unwrap();
+                    format!("{statement} // {}", location.span.take(&content))
+                }
+                None => statement.to_string(),
+            })
+            .join("\n"),
+        indoc! {"
+            drop<felt>([0]) -> ()
+            felt_const<5>() -> ([1]) // 5
+            store_temp<felt>([1]) -> ([3]) // bar(5)
+            function_call<user@test::bar>([3]) -> ([2]) // bar(5)
+            rename<felt>([2]) -> ([4]) // bar(5)
+            return([4]) // bar(5)
+            dup<felt>([0]) -> ([0], [2]) // felt_add(a, a)
+            dup<felt>([0]) -> ([0], [3]) // felt_add(a, a)
+            felt_add([2], [3]) -> ([1]) // felt_add(a, a)
+            store_temp<felt>([1]) -> ([1]) // felt_add(felt_add(a, a), a)
+            felt_add([1], [0]) -> ([4]) // felt_add(felt_add(a, a), a)
+            store_temp<felt>([4]) -> ([5]) // felt_add(felt_add(a, a), a)
+            return([5]) // felt_add(felt_add(a, a), a)"}
+    );
+}
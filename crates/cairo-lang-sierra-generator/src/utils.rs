@@ -1,4 +1,7 @@
+use cairo_lang_diagnostics::Maybe;
 use cairo_lang_semantic::corelib::get_const_libfunc_name_by_type;
+use cairo_lang_semantic::types::substitute_ty;
+use cairo_lang_semantic::Mutability;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
 use cairo_lang_sierra::extensions::lib_func::LibfuncSignature;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
@@ -239,7 +242,7 @@ pub fn get_concrete_libfunc_id(
     // Check if this is a user-defined function or a libfunc.
     let concrete_function = db.lookup_intern_function(function).function;
     match concrete_function.generic_function {
-        GenericFunctionId::Free(_) | GenericFunctionId::Impl(_) => {
+        GenericFunctionId::Free(_) | GenericFunctionId::Impl(_) | GenericFunctionId::Loop(_) => {
             (concrete_function, function_call_libfunc_id(db, function))
         }
         GenericFunctionId::Extern(extern_id) => {
@@ -269,6 +272,37 @@ pub fn get_concrete_libfunc_id(
     }
 }
 
+/// Returns the types of the explicit parameters of a user-defined function, each with whether it is
+/// a reference parameter, and its return type.
+/// Unlike the semantic signature, this also supports the helper functions of loops.
+pub fn get_function_explicit_types(
+    db: &dyn SierraGenGroup,
+    function: semantic::FunctionId,
+) -> Maybe<(Vec<(semantic::TypeId, bool)>, semantic::TypeId)> {
+    let concrete_function = db.lookup_intern_function(function).function;
+    let loop_function = match concrete_function.generic_function {
+        GenericFunctionId::Loop(loop_function) => loop_function,
+        _ => {
+            let signature = db.concrete_function_signature(function)?;
+            let params = signature
+                .params
+                .iter()
+                .map(|param| (param.ty, param.mutability == Mutability::Reference))
+                .collect();
+            return Ok((params, signature.return_type));
+        }
+    };
+    // The loop signature is in terms of the generic parameters of the function containing the
+    // loop.
+    let loop_signature = db.loop_signature(loop_function)?;
+    let substitution =
+        concrete_function.get_body(db.upcast()).unwrap().substitution(db.upcast())?;
+    let substitute = |ty| substitute_ty(db.upcast(), &substitution, ty);
+    let ref_params = loop_signature.ref_params.iter().map(|var| (substitute(var.ty()), true));
+    let params = loop_signature.params.iter().map(|var| (substitute(var.ty()), false));
+    Ok((ref_params.chain(params).collect(), substitute(loop_signature.return_type)))
+}
+
 /// Gets the output variables from a statement, including branching statements.
 pub fn statement_outputs(
     statement: &lowering::Statement,
@@ -290,10 +324,30 @@ fn collect_outputs(
     blocks: &[lowering::BlockId],
 ) -> Vec<id_arena::Id<lowering::Variable>> {
     for block in blocks {
-        if let lowering::FlatBlockEnd::Callsite(remapping) = &lowered_function.blocks[*block].end {
+        if let Some(remapping) = arm_callsite_remapping(lowered_function, *block) {
             // It is guaranteed by lowering phase that all of the variables mapped to are the same.
             return remapping.keys().copied().collect();
         }
     }
     vec![]
 }
+
+/// Returns the remapping of the callsite reached at the end of a match arm, or None if the arm does
+/// not reach the callsite.
+/// An arm into which a function was inlined falls through to the rest of the arm, so fallthroughs
+/// are followed to the end of the arm. Gotos are early returns of inlined functions, which leave
+/// the match without reaching its callsite.
+pub fn arm_callsite_remapping(
+    lowered_function: &lowering::FlatLowered,
+    mut block: lowering::BlockId,
+) -> Option<&lowering::VarRemapping> {
+    loop {
+        match &lowered_function.blocks[block].end {
+            lowering::FlatBlockEnd::Callsite(remapping) => return Some(remapping),
+            lowering::FlatBlockEnd::Fallthrough(target, _) => block = *target,
+            lowering::FlatBlockEnd::Goto(..)
+            | lowering::FlatBlockEnd::Return(_)
+            | lowering::FlatBlockEnd::Unreachable => return None,
+        }
+    }
+}
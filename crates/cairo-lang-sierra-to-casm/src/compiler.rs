@@ -7,7 +7,7 @@ use cairo_lang_sierra::extensions::ConcreteLibfunc;
 use cairo_lang_sierra::ids::VarId;
 use cairo_lang_sierra::program::{BranchTarget, Invocation, Program, Statement, StatementIdx};
 use cairo_lang_sierra::program_registry::{ProgramRegistry, ProgramRegistryError};
-use itertools::zip_eq;
+use itertools::{chain, zip_eq};
 use thiserror::Error;
 
 use crate::annotations::{AnnotationError, ProgramAnnotations, StatementAnnotations};
@@ -15,7 +15,7 @@ use crate::invocations::{
     check_references_on_stack, compile_invocation, InvocationError, ProgramInfo,
 };
 use crate::metadata::Metadata;
-use crate::references::{check_types_match, ReferencesError};
+use crate::references::{check_types_match, ReferenceValue, ReferencesError, StatementRefs};
 use crate::relocations::{relocate_instructions, RelocationEntry};
 use crate::type_sizes::get_type_size_map;
 
@@ -69,6 +69,9 @@ impl Display for CairoProgram {
 pub struct SierraStatementDebugInfo {
     /// The offset of the sierra statement within the bytecode.
     pub code_offset: usize,
+    /// The references to the variables alive at the beginning of the statement, ordered by
+    /// variable id.
+    pub references: Vec<(VarId, ReferenceValue)>,
 }
 
 /// The debug information of a compilation from Sierra to casm.
@@ -113,6 +116,8 @@ pub fn compile(
     // Maps statement_idx to program_offset. The last value (for statement_idx=number-of-statements)
     // contains the final offset (the size of the program code segment).
     let mut statement_offsets = Vec::with_capacity(program.statements.len());
+    // The references alive at the beginning of each statement.
+    let mut statement_references = Vec::with_capacity(program.statements.len());
 
     let registry = ProgramRegistry::<CoreType, CoreLibfunc>::with_ap_change(
         program,
@@ -140,6 +145,11 @@ pub fn compile(
                 let (annotations, return_refs) = program_annotations
                     .get_annotations_after_take_args(statement_idx, ref_ids.iter())
                     .map_err(|err| Box::new(err.into()))?;
+                statement_references.push(alive_references(
+                    &annotations.refs,
+                    ref_ids,
+                    &return_refs,
+                ));
 
                 if let Some(var_id) = annotations.refs.keys().next() {
                     return Err(Box::new(CompilationError::DanglingReferences {
@@ -172,6 +182,11 @@ pub fn compile(
                 let (annotations, invoke_refs) = program_annotations
                     .get_annotations_after_take_args(statement_idx, invocation.args.iter())
                     .map_err(|err| Box::new(err.into()))?;
+                statement_references.push(alive_references(
+                    &annotations.refs,
+                    &invocation.args,
+                    &invoke_refs,
+                ));
 
                 let libfunc = registry
                     .get_libfunc(&invocation.libfunc_id)
@@ -248,20 +263,39 @@ pub fn compile(
 
     // Push the final offset at the end of `statement_offsets`.
     statement_offsets.push(program_offset);
+    statement_references.push(vec![]);
 
     relocate_instructions(&relocations, &statement_offsets, &mut instructions);
 
     Ok(CairoProgram {
         instructions,
         debug_info: CairoProgramDebugInfo {
-            sierra_statement_info: statement_offsets
-                .into_iter()
-                .map(|code_offset| SierraStatementDebugInfo { code_offset })
+            sierra_statement_info: zip_eq(statement_offsets, statement_references)
+                .map(|(code_offset, references)| SierraStatementDebugInfo {
+                    code_offset,
+                    references,
+                })
                 .collect(),
         },
     })
 }
 
+/// Returns the references alive at the beginning of a statement, given the references remaining
+/// after taking the statement arguments, and the taken references.
+fn alive_references(
+    remaining_refs: &StatementRefs,
+    args: &[VarId],
+    taken_refs: &[ReferenceValue],
+) -> Vec<(VarId, ReferenceValue)> {
+    let mut references: Vec<_> = chain!(
+        remaining_refs.iter().map(|(var_id, value)| (var_id.clone(), value.clone())),
+        zip_eq(args.iter().cloned(), taken_refs.iter().cloned())
+    )
+    .collect();
+    references.sort_by_key(|(var_id, _)| var_id.id);
+    references
+}
+
 /// Returns true if `statement` is an invocation of the branch_align libfunc.
 fn is_branch_align(
     registry: &ProgramRegistry<CoreType, CoreLibfunc>,
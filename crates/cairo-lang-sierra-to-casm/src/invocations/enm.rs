@@ -103,6 +103,22 @@ fn build_enum_init(
     Ok(builder.build_only_reference_changes(output_expressions))
 }
 
+/// Returns the index of the variant whose variant selector, as set by `enum_init` for an enum with
+/// `num_variants` variants, is `variant_selector`, or None if there is no such variant.
+///
+/// The variant selector is the index of the variant for enums with up to 2 variants, and the
+/// relative jump `2 * index + 1` of the `enum_match` jump table otherwise. See `build_enum_init`.
+pub fn variant_index_from_selector(variant_selector: usize, num_variants: usize) -> Option<usize> {
+    let index = if num_variants <= 2 {
+        variant_selector
+    } else if variant_selector % 2 == 1 {
+        variant_selector / 2
+    } else {
+        return None;
+    };
+    (index < num_variants).then_some(index)
+}
+
 /// Handles statement for matching an enum.
 fn build_enum_match(
     builder: CompiledInvocationBuilder<'_>,
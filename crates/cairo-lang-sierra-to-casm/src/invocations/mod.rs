@@ -21,6 +21,7 @@ use itertools::{zip_eq, Itertools};
 use thiserror::Error;
 use {cairo_lang_casm, cairo_lang_sierra};
 
+pub use self::enm::variant_index_from_selector;
 use crate::environment::frame_state::{FrameState, FrameStateError};
 use crate::environment::Environment;
 use crate::metadata::Metadata;
@@ -17,21 +17,40 @@ use crate::references::ReferenceExpression;
 /// Builds instructions for StarkNet call contract system call.
 pub fn build_call_contract(
     builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    build_call(builder, "CallContract")
+}
+
+/// Builds instructions for StarkNet library call system call.
+pub fn build_library_call(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    build_call(builder, "LibraryCall")
+}
+
+/// Builds instructions for a system call invoking an entry point of a contract or a class, given
+/// the target, the entry point selector and the call data.
+fn build_call(
+    builder: CompiledInvocationBuilder<'_>,
+    selector: &str,
 ) -> Result<CompiledInvocation, InvocationError> {
     let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
-    let selector_imm = BigInt::from_bytes_be(num_bigint::Sign::Plus, "CallContract".as_bytes());
+    let selector_imm = BigInt::from_bytes_be(num_bigint::Sign::Plus, selector.as_bytes());
 
-    let [expr_gas_builtin, expr_system, expr_address, expr_arr] = builder.try_get_refs()?;
+    let [expr_gas_builtin, expr_system, expr_target, expr_entry_point_selector, expr_arr] =
+        builder.try_get_refs()?;
     let gas_builtin = expr_gas_builtin.try_unpack_single()?;
     let system = expr_system.try_unpack_single()?;
-    let contract_address = expr_address.try_unpack_single()?;
+    let target = expr_target.try_unpack_single()?;
+    let entry_point_selector = expr_entry_point_selector.try_unpack_single()?;
     let [call_data_start, call_data_end] = expr_arr.try_unpack()?;
 
     let mut casm_builder = CasmBuilder::default();
     add_input_variables! {casm_builder,
-        buffer(8) system;
+        buffer(9) system;
         deref gas_builtin;
-        deref contract_address;
+        deref target;
+        deref entry_point_selector;
         deref call_data_start;
         deref call_data_end;
     };
@@ -41,7 +60,8 @@ pub fn build_call_contract(
         let original_system = system;
         assert selector = *(system++);
         assert gas_builtin = *(system++);
-        assert contract_address = *(system++);
+        assert target = *(system++);
+        assert entry_point_selector = *(system++);
         assert call_data_start = *(system++);
         assert call_data_end = *(system++);
         hint SystemCall { system: original_system };
@@ -70,6 +90,130 @@ pub fn build_call_contract(
     ))
 }
 
+/// Builds instructions for StarkNet deploy system call.
+pub fn build_deploy(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let selector_imm = BigInt::from_bytes_be(num_bigint::Sign::Plus, "Deploy".as_bytes());
+
+    let [
+        expr_gas_builtin,
+        expr_system,
+        expr_class_hash,
+        expr_contract_address_salt,
+        expr_arr,
+        expr_deploy_from_zero,
+    ] = builder.try_get_refs()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let system = expr_system.try_unpack_single()?;
+    let class_hash = expr_class_hash.try_unpack_single()?;
+    let contract_address_salt = expr_contract_address_salt.try_unpack_single()?;
+    let [call_data_start, call_data_end] = expr_arr.try_unpack()?;
+    let deploy_from_zero = expr_deploy_from_zero.try_unpack_single()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(11) system;
+        deref gas_builtin;
+        deref class_hash;
+        deref contract_address_salt;
+        deref call_data_start;
+        deref call_data_end;
+        deref deploy_from_zero;
+    };
+    casm_build_extend! {casm_builder,
+        const selector_imm = selector_imm;
+        tempvar selector = selector_imm;
+        let original_system = system;
+        assert selector = *(system++);
+        assert gas_builtin = *(system++);
+        assert class_hash = *(system++);
+        assert contract_address_salt = *(system++);
+        assert call_data_start = *(system++);
+        assert call_data_end = *(system++);
+        assert deploy_from_zero = *(system++);
+        hint SystemCall { system: original_system };
+
+        let updated_gas_builtin = *(system++);
+        // `failure_flag` is 0 on success, nonzero on failure/revert.
+        tempvar failure_flag = *(system++);
+        // The response in the failure case is smaller than in the success case.
+        let response_0 = *(system++);
+        let response_1 = *(system++);
+        let failure_final_system = system;
+        let response_2 = *(system++);
+        jump Failure if failure_flag != 0;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            (
+                "Fallthrough",
+                &[&[updated_gas_builtin], &[system], &[response_0], &[response_1, response_2]],
+                None,
+            ),
+            (
+                "Failure",
+                &[&[updated_gas_builtin], &[failure_final_system], &[response_0, response_1]],
+                Some(failure_handle_statement_id),
+            ),
+        ],
+        CostValidationInfo {
+            range_check_info: None,
+            extra_costs: Some([SYSTEM_CALL_COST, SYSTEM_CALL_COST]),
+        },
+    ))
+}
+
+/// Builds instructions for StarkNet replace class system call.
+pub fn build_replace_class(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let selector_imm = BigInt::from_bytes_be(num_bigint::Sign::Plus, "ReplaceClass".as_bytes());
+
+    let [gas_builtin, system, class_hash] = builder.try_get_single_cells()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(6) system;
+        deref gas_builtin;
+        deref class_hash;
+    };
+    casm_build_extend! {casm_builder,
+        let original_system = system;
+        const selector_imm = selector_imm;
+        tempvar selector = selector_imm;
+        assert selector = *(system++);
+        assert gas_builtin = *(system++);
+        assert class_hash = *(system++);
+        hint SystemCall { system: original_system };
+        let updated_gas_builtin = *(system++);
+        tempvar failure_flag = *(system++);
+        // The response in the success case is smaller than in the failure case.
+        let success_final_system = system;
+        let revert_reason_start = *(system++);
+        let revert_reason_end = *(system++);
+        jump Failure if failure_flag != 0;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[updated_gas_builtin], &[success_final_system]], None),
+            (
+                "Failure",
+                &[&[updated_gas_builtin], &[system], &[revert_reason_start, revert_reason_end]],
+                Some(failure_handle_statement_id),
+            ),
+        ],
+        CostValidationInfo {
+            range_check_info: None,
+            extra_costs: Some([SYSTEM_CALL_COST, SYSTEM_CALL_COST]),
+        },
+    ))
+}
+
 /// Handles the contract_address_const libfunc.
 pub fn build_contract_address_const(
     builder: CompiledInvocationBuilder<'_>,
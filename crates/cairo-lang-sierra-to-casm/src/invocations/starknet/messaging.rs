@@ -0,0 +1,65 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_sierra_gas::core_libfunc_cost::SYSTEM_CALL_COST;
+use num_bigint::BigInt;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::{
+    add_input_variables, get_non_fallthrough_statement_id, CostValidationInfo,
+};
+
+/// Builds instructions for StarkNet send message to L1 system call.
+pub fn build_send_message_to_l1(
+    builder: CompiledInvocationBuilder<'_>,
+) -> Result<CompiledInvocation, InvocationError> {
+    let failure_handle_statement_id = get_non_fallthrough_statement_id(&builder);
+    let selector_imm = BigInt::from_bytes_be(num_bigint::Sign::Plus, "SendMessageToL1".as_bytes());
+
+    let [expr_gas_builtin, expr_system, expr_to_address, expr_payload] = builder.try_get_refs()?;
+    let gas_builtin = expr_gas_builtin.try_unpack_single()?;
+    let system = expr_system.try_unpack_single()?;
+    let to_address = expr_to_address.try_unpack_single()?;
+    let [payload_start, payload_end] = expr_payload.try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        buffer(8) system;
+        deref gas_builtin;
+        deref to_address;
+        deref payload_start;
+        deref payload_end;
+    };
+    casm_build_extend! {casm_builder,
+        let original_system = system;
+        const selector_imm = selector_imm;
+        tempvar selector = selector_imm;
+        assert selector = *(system++);
+        assert gas_builtin = *(system++);
+        assert to_address = *(system++);
+        assert payload_start = *(system++);
+        assert payload_end = *(system++);
+        hint SystemCall { system: original_system };
+        let updated_gas_builtin = *(system++);
+        tempvar failure_flag = *(system++);
+        // The response in the success case is smaller than in the failure case.
+        let success_final_system = system;
+        let revert_reason_start = *(system++);
+        let revert_reason_end = *(system++);
+        jump Failure if failure_flag != 0;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [
+            ("Fallthrough", &[&[updated_gas_builtin], &[success_final_system]], None),
+            (
+                "Failure",
+                &[&[updated_gas_builtin], &[system], &[revert_reason_start, revert_reason_end]],
+                Some(failure_handle_statement_id),
+            ),
+        ],
+        CostValidationInfo {
+            range_check_info: None,
+            extra_costs: Some([SYSTEM_CALL_COST, SYSTEM_CALL_COST]),
+        },
+    ))
+}
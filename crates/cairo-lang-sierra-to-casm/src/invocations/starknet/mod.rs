@@ -3,7 +3,9 @@ use cairo_lang_sierra::extensions::starknet::StarkNetConcreteLibfunc;
 use self::getter::build_getter;
 use self::interoperability::{
     build_call_contract, build_contract_address_const, build_contract_address_try_from_felt,
+    build_deploy, build_library_call, build_replace_class,
 };
+use self::messaging::build_send_message_to_l1;
 use self::storage::{
     build_storage_address_from_base_and_offset, build_storage_base_address_const,
     build_storage_base_address_from_felt,
@@ -21,6 +23,11 @@ mod interoperability;
 mod emit_event;
 use emit_event::build_emit_event;
 
+mod messaging;
+
+mod testing;
+use testing::build_cheatcode;
+
 /// Builds instructions for Sierra array operations.
 pub fn build(
     libfunc: &StarkNetConcreteLibfunc,
@@ -34,6 +41,7 @@ pub fn build(
         StarkNetConcreteLibfunc::ContractAddressTryFromFelt(_) => {
             build_contract_address_try_from_felt(builder)
         }
+        StarkNetConcreteLibfunc::ContractAddressToFelt(_) => misc::build_identity(builder),
         StarkNetConcreteLibfunc::StorageRead(_) => build_storage_read(builder),
         StarkNetConcreteLibfunc::StorageWrite(_) => build_storage_write(builder),
         StarkNetConcreteLibfunc::StorageBaseAddressConst(libfunc) => {
@@ -48,5 +56,19 @@ pub fn build(
         }
         StarkNetConcreteLibfunc::EmitEvent(_) => build_emit_event(builder),
         StarkNetConcreteLibfunc::GetCallerAddress(_) => build_getter(builder, "GetCallerAddress"),
+        StarkNetConcreteLibfunc::GetContractAddress(_) => {
+            build_getter(builder, "GetContractAddress")
+        }
+        StarkNetConcreteLibfunc::GetBlockNumber(_) => build_getter(builder, "GetBlockNumber"),
+        StarkNetConcreteLibfunc::GetBlockTimestamp(_) => build_getter(builder, "GetBlockTimestamp"),
+        StarkNetConcreteLibfunc::GetSequencerAddress(_) => {
+            build_getter(builder, "GetSequencerAddress")
+        }
+        StarkNetConcreteLibfunc::GetTxInfo(_) => build_getter(builder, "GetTxInfo"),
+        StarkNetConcreteLibfunc::LibraryCall(_) => build_library_call(builder),
+        StarkNetConcreteLibfunc::Deploy(_) => build_deploy(builder),
+        StarkNetConcreteLibfunc::SendMessageToL1(_) => build_send_message_to_l1(builder),
+        StarkNetConcreteLibfunc::ReplaceClass(_) => build_replace_class(builder),
+        StarkNetConcreteLibfunc::Cheatcode(libfunc) => build_cheatcode(builder, libfunc),
     }
 }
@@ -0,0 +1,46 @@
+use cairo_lang_casm::builder::CasmBuilder;
+use cairo_lang_casm::casm_build_extend;
+use cairo_lang_casm::hints::Hint;
+use cairo_lang_sierra::extensions::consts::SignatureAndConstConcreteLibfunc;
+
+use super::{CompiledInvocation, CompiledInvocationBuilder, InvocationError};
+use crate::invocations::add_input_variables;
+
+/// Builds instructions for running a testing cheatcode.
+pub fn build_cheatcode(
+    builder: CompiledInvocationBuilder<'_>,
+    libfunc: &SignatureAndConstConcreteLibfunc,
+) -> Result<CompiledInvocation, InvocationError> {
+    let [expr_input] = builder.try_get_refs()?;
+    let [input_start, input_end] = expr_input.try_unpack()?;
+
+    let mut casm_builder = CasmBuilder::default();
+    add_input_variables! {casm_builder,
+        deref input_start;
+        deref input_end;
+    };
+    casm_build_extend! {casm_builder,
+        tempvar output_start;
+        tempvar output_end;
+    };
+    let selector = libfunc.c.clone();
+    casm_builder.add_hint(
+        |[input_start, input_end], [output_start, output_end]| Hint::Cheatcode {
+            selector,
+            input_start,
+            input_end,
+            output_start,
+            output_end,
+        },
+        [input_start, input_end],
+        [output_start, output_end],
+    );
+    casm_build_extend! {casm_builder,
+        ap += 2;
+    };
+    Ok(builder.build_from_casm_builder(
+        casm_builder,
+        [("Fallthrough", &[&[output_start, output_end]], None)],
+        Default::default(),
+    ))
+}
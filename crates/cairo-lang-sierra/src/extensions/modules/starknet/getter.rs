@@ -1,17 +1,24 @@
 use std::marker::PhantomData;
 
+use super::interoperability::ContractAddressType;
 use super::syscalls::SystemType;
 use crate::extensions::array::ArrayType;
+use crate::extensions::boxing::BoxType;
 use crate::extensions::felt::FeltType;
 use crate::extensions::gas::GasBuiltinType;
 use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
     SierraApChange, SignatureSpecializationContext,
 };
+use crate::extensions::structure::StructType;
+use crate::extensions::uint::Uint64Type;
+use crate::extensions::uint128::Uint128Type;
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
     SpecializationError,
 };
+use crate::ids::{ConcreteTypeId, UserTypeId};
+use crate::program::GenericArg;
 
 /// Trait for implementing getters.
 pub trait GetterTraits: Default {
@@ -21,20 +28,41 @@ pub trait GetterTraits: Default {
     type InfoType: NoGenericArgsGenericType;
 }
 
+/// Same as GetterTraits, but with a function to return the concrete TypeId.
+pub trait GetterTraitsEx: Default {
+    /// The generic libfunc id for the getter libfunc.
+    const STR_ID: &'static str;
+    /// The concrete type returned by the getter.
+    fn info_type_id(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<ConcreteTypeId, SpecializationError>;
+}
+
+impl<TGetterTraits: GetterTraits> GetterTraitsEx for TGetterTraits {
+    const STR_ID: &'static str = TGetterTraits::STR_ID;
+    fn info_type_id(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<ConcreteTypeId, SpecializationError> {
+        context.get_concrete_type(TGetterTraits::InfoType::id(), &[])
+    }
+}
+
 /// Libfunc for a getter system call.
 #[derive(Default)]
-pub struct GetterLibfunc<TGetterTraits: GetterTraits> {
-    _phantom: PhantomData<TGetterTraits>,
+pub struct GetterLibfunc<TGetterTraitsEx: GetterTraitsEx> {
+    _phantom: PhantomData<TGetterTraitsEx>,
 }
-impl<TGetterTraits: GetterTraits> NoGenericArgsGenericLibfunc for GetterLibfunc<TGetterTraits> {
-    const STR_ID: &'static str = TGetterTraits::STR_ID;
+impl<TGetterTraitsEx: GetterTraitsEx> NoGenericArgsGenericLibfunc
+    for GetterLibfunc<TGetterTraitsEx>
+{
+    const STR_ID: &'static str = TGetterTraitsEx::STR_ID;
 
     fn specialize_signature(
         &self,
         context: &dyn SignatureSpecializationContext,
     ) -> Result<LibfuncSignature, SpecializationError> {
         let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
-        let info_ty = context.get_concrete_type(TGetterTraits::InfoType::id(), &[])?;
+        let info_ty = TGetterTraitsEx::info_type_id(context)?;
         let gas_builtin_ty = context.get_concrete_type(GasBuiltinType::id(), &[])?;
         let system_ty = context.get_concrete_type(SystemType::id(), &[])?;
         let felt_array_ty = context.get_wrapped_concrete_type(ArrayType::id(), felt_ty)?;
@@ -109,3 +137,74 @@ impl GetterTraits for GetCallerAddressTrait {
     const STR_ID: &'static str = "get_caller_address_syscall";
     type InfoType = FeltType;
 }
+
+#[derive(Default)]
+pub struct GetContractAddressTrait {}
+impl GetterTraits for GetContractAddressTrait {
+    const STR_ID: &'static str = "get_contract_address_syscall";
+    type InfoType = ContractAddressType;
+}
+
+#[derive(Default)]
+pub struct GetBlockNumberTrait {}
+impl GetterTraits for GetBlockNumberTrait {
+    const STR_ID: &'static str = "get_block_number_syscall";
+    type InfoType = Uint64Type;
+}
+
+#[derive(Default)]
+pub struct GetBlockTimestampTrait {}
+impl GetterTraits for GetBlockTimestampTrait {
+    const STR_ID: &'static str = "get_block_timestamp_syscall";
+    type InfoType = Uint64Type;
+}
+
+#[derive(Default)]
+pub struct GetSequencerAddressTrait {}
+impl GetterTraits for GetSequencerAddressTrait {
+    const STR_ID: &'static str = "get_sequencer_address_syscall";
+    type InfoType = ContractAddressType;
+}
+
+#[derive(Default)]
+pub struct GetTxInfoTrait {}
+impl GetterTraitsEx for GetTxInfoTrait {
+    const STR_ID: &'static str = "get_tx_info_syscall";
+
+    fn info_type_id(
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<ConcreteTypeId, SpecializationError> {
+        let tx_info_ty = get_tx_info_type(context)?;
+        context.get_wrapped_concrete_type(BoxType::id(), tx_info_ty)
+    }
+}
+
+/// Helper for getting the `core::starknet::TxInfo` type, matching its definition in the corelib.
+fn get_tx_info_type(
+    context: &dyn SignatureSpecializationContext,
+) -> Result<ConcreteTypeId, SpecializationError> {
+    let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+    let contract_address_ty = context.get_concrete_type(ContractAddressType::id(), &[])?;
+    let u128_ty = context.get_concrete_type(Uint128Type::id(), &[])?;
+    let felt_array_ty = context.get_wrapped_concrete_type(ArrayType::id(), felt_ty.clone())?;
+    context.get_concrete_type(
+        StructType::id(),
+        &[
+            GenericArg::UserType(UserTypeId::from_string("core::starknet::TxInfo")),
+            // version
+            GenericArg::Type(felt_ty.clone()),
+            // account_contract_address
+            GenericArg::Type(contract_address_ty),
+            // max_fee
+            GenericArg::Type(u128_ty),
+            // signature
+            GenericArg::Type(felt_array_ty),
+            // transaction_hash
+            GenericArg::Type(felt_ty.clone()),
+            // chain_id
+            GenericArg::Type(felt_ty.clone()),
+            // nonce
+            GenericArg::Type(felt_ty),
+        ],
+    )
+}
@@ -7,12 +7,13 @@ use crate::extensions::lib_func::{
     BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
     SierraApChange, SignatureSpecializationContext,
 };
+use crate::extensions::modules::get_bool_type;
 use crate::extensions::range_check::RangeCheckType;
 use crate::extensions::{
     NamedType, NoGenericArgsGenericLibfunc, NoGenericArgsGenericType, OutputVarReferenceInfo,
     SpecializationError,
 };
-use crate::ids::GenericTypeId;
+use crate::ids::{ConcreteTypeId, GenericTypeId};
 use crate::program::GenericArg;
 
 /// Type for StarkNet storage address, a value in the range [0, 2 ** 250).
@@ -88,12 +89,128 @@ impl NoGenericArgsGenericLibfunc for ContractAddressTryFromFeltLibfunc {
     }
 }
 
-/// Libfunc for a storage call contract system call.
+/// Libfunc for converting a contract address into a felt.
+#[derive(Default)]
+pub struct ContractAddressToFeltLibfunc {}
+impl NoGenericArgsGenericLibfunc for ContractAddressToFeltLibfunc {
+    const STR_ID: &'static str = "contract_address_to_felt";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        Ok(LibfuncSignature::new_non_branch_ex(
+            vec![ParamSignature {
+                ty: context.get_concrete_type(ContractAddressType::id(), &[])?,
+                allow_deferred: true,
+                allow_add_const: true,
+                allow_const: true,
+            }],
+            vec![OutputVarInfo {
+                ty: context.get_concrete_type(FeltType::id(), &[])?,
+                ref_info: OutputVarReferenceInfo::SameAsParam { param_idx: 0 },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+}
+
+/// Libfunc for a call contract system call.
 #[derive(Default)]
 pub struct CallContractLibfunc {}
 impl NoGenericArgsGenericLibfunc for CallContractLibfunc {
     const STR_ID: &'static str = "call_contract_syscall";
 
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let addr_ty = context.get_concrete_type(ContractAddressType::id(), &[])?;
+        call_signature(context, addr_ty)
+    }
+}
+
+/// Libfunc for a library call system call.
+#[derive(Default)]
+pub struct LibraryCallLibfunc {}
+impl NoGenericArgsGenericLibfunc for LibraryCallLibfunc {
+    const STR_ID: &'static str = "library_call_syscall";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        // The class hash of the called library.
+        let class_hash_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        call_signature(context, class_hash_ty)
+    }
+}
+
+/// Returns the signature of a system call invoking an entry point of `target_ty` (a contract
+/// address or a class hash) with an entry point selector and call data, returning the call result.
+fn call_signature(
+    context: &dyn SignatureSpecializationContext,
+    target_ty: ConcreteTypeId,
+) -> Result<LibfuncSignature, SpecializationError> {
+    let gas_builtin_ty = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+    let system_ty = context.get_concrete_type(SystemType::id(), &[])?;
+    let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+    let felt_array_ty =
+        context.get_concrete_type(ArrayType::id(), &[GenericArg::Type(felt_ty.clone())])?;
+    Ok(LibfuncSignature {
+        param_signatures: vec![
+            // Gas builtin
+            ParamSignature::new(gas_builtin_ty.clone()),
+            // System
+            ParamSignature {
+                ty: system_ty.clone(),
+                allow_deferred: false,
+                allow_add_const: true,
+                allow_const: false,
+            },
+            // Target
+            ParamSignature::new(target_ty),
+            // Entry point selector.
+            ParamSignature::new(felt_ty),
+            // Call data
+            ParamSignature::new(felt_array_ty.clone()),
+        ],
+        branch_signatures: vec![
+            // Success branch
+            BranchSignature {
+                vars: vec![
+                    // Gas builtin
+                    OutputVarInfo {
+                        ty: gas_builtin_ty.clone(),
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                    },
+                    // System
+                    OutputVarInfo {
+                        ty: system_ty.clone(),
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                            param_idx: 1,
+                        }),
+                    },
+                    // result
+                    OutputVarInfo {
+                        ty: felt_array_ty.clone(),
+                        ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                    },
+                ],
+                ap_change: SierraApChange::Known { new_vars_only: false },
+            },
+            failure_branch_signature(gas_builtin_ty, system_ty, felt_array_ty),
+        ],
+        fallthrough: Some(0),
+    })
+}
+
+/// Libfunc for a deploy system call.
+#[derive(Default)]
+pub struct DeployLibfunc {}
+impl NoGenericArgsGenericLibfunc for DeployLibfunc {
+    const STR_ID: &'static str = "deploy_syscall";
+
     fn specialize_signature(
         &self,
         context: &dyn SignatureSpecializationContext,
@@ -103,7 +220,7 @@ impl NoGenericArgsGenericLibfunc for CallContractLibfunc {
         let addr_ty = context.get_concrete_type(ContractAddressType::id(), &[])?;
         let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
         let felt_array_ty =
-            context.get_concrete_type(ArrayType::id(), &[GenericArg::Type(felt_ty)])?;
+            context.get_concrete_type(ArrayType::id(), &[GenericArg::Type(felt_ty.clone())])?;
         Ok(LibfuncSignature {
             param_signatures: vec![
                 // Gas builtin
@@ -115,10 +232,14 @@ impl NoGenericArgsGenericLibfunc for CallContractLibfunc {
                     allow_add_const: true,
                     allow_const: false,
                 },
-                // Address
-                ParamSignature::new(addr_ty),
-                // Call data
+                // Class hash
+                ParamSignature::new(felt_ty.clone()),
+                // Contract address salt
+                ParamSignature::new(felt_ty),
+                // Constructor call data
                 ParamSignature::new(felt_array_ty.clone()),
+                // Deploy from zero
+                ParamSignature::new(get_bool_type(context)?),
             ],
             branch_signatures: vec![
                 // Success branch
@@ -136,7 +257,12 @@ impl NoGenericArgsGenericLibfunc for CallContractLibfunc {
                                 DeferredOutputKind::AddConst { param_idx: 1 },
                             ),
                         },
-                        // result
+                        // Deployed contract address
+                        OutputVarInfo {
+                            ty: addr_ty,
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                        // Constructor result
                         OutputVarInfo {
                             ty: felt_array_ty.clone(),
                             ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
@@ -144,30 +270,95 @@ impl NoGenericArgsGenericLibfunc for CallContractLibfunc {
                     ],
                     ap_change: SierraApChange::Known { new_vars_only: false },
                 },
+                failure_branch_signature(gas_builtin_ty, system_ty, felt_array_ty),
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
+
+/// Libfunc for a replace class system call.
+#[derive(Default)]
+pub struct ReplaceClassLibfunc {}
+impl NoGenericArgsGenericLibfunc for ReplaceClassLibfunc {
+    const STR_ID: &'static str = "replace_class_syscall";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let gas_builtin_ty = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let system_ty = context.get_concrete_type(SystemType::id(), &[])?;
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        let felt_array_ty =
+            context.get_concrete_type(ArrayType::id(), &[GenericArg::Type(felt_ty.clone())])?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                // Gas builtin
+                ParamSignature::new(gas_builtin_ty.clone()),
+                // System
+                ParamSignature {
+                    ty: system_ty.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                // Class hash
+                ParamSignature::new(felt_ty),
+            ],
+            branch_signatures: vec![
+                // Success branch
                 BranchSignature {
                     vars: vec![
                         // Gas builtin
                         OutputVarInfo {
-                            ty: gas_builtin_ty,
+                            ty: gas_builtin_ty.clone(),
                             ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
                         },
                         // System
                         OutputVarInfo {
-                            ty: system_ty,
+                            ty: system_ty.clone(),
                             ref_info: OutputVarReferenceInfo::Deferred(
                                 DeferredOutputKind::AddConst { param_idx: 1 },
                             ),
                         },
-                        // Revert reason
-                        OutputVarInfo {
-                            ty: felt_array_ty,
-                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
-                        },
                     ],
                     ap_change: SierraApChange::Known { new_vars_only: false },
                 },
+                failure_branch_signature(gas_builtin_ty, system_ty, felt_array_ty),
             ],
             fallthrough: Some(0),
         })
     }
 }
+
+/// Returns the signature of the failure branch of a system call, which returns the updated gas
+/// builtin and system, and the revert reason.
+pub(super) fn failure_branch_signature(
+    gas_builtin_ty: ConcreteTypeId,
+    system_ty: ConcreteTypeId,
+    felt_array_ty: ConcreteTypeId,
+) -> BranchSignature {
+    BranchSignature {
+        vars: vec![
+            // Gas builtin
+            OutputVarInfo {
+                ty: gas_builtin_ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            },
+            // System
+            OutputVarInfo {
+                ty: system_ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::AddConst {
+                    param_idx: 1,
+                }),
+            },
+            // Revert reason
+            OutputVarInfo {
+                ty: felt_array_ty,
+                ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+            },
+        ],
+        ap_change: SierraApChange::Known { new_vars_only: false },
+    }
+}
@@ -0,0 +1,69 @@
+use super::interoperability::failure_branch_signature;
+use super::syscalls::SystemType;
+use crate::extensions::array::ArrayType;
+use crate::extensions::felt::FeltType;
+use crate::extensions::gas::GasBuiltinType;
+use crate::extensions::lib_func::{
+    BranchSignature, DeferredOutputKind, LibfuncSignature, OutputVarInfo, ParamSignature,
+    SierraApChange, SignatureSpecializationContext,
+};
+use crate::extensions::{
+    NamedType, NoGenericArgsGenericLibfunc, OutputVarReferenceInfo, SpecializationError,
+};
+
+/// Libfunc for a send message to L1 system call.
+#[derive(Default)]
+pub struct SendMessageToL1Libfunc {}
+impl NoGenericArgsGenericLibfunc for SendMessageToL1Libfunc {
+    const STR_ID: &'static str = "send_message_to_l1_syscall";
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        let felt_array_ty = context.get_wrapped_concrete_type(ArrayType::id(), felt_ty.clone())?;
+        let gas_builtin_ty = context.get_concrete_type(GasBuiltinType::id(), &[])?;
+        let system_ty = context.get_concrete_type(SystemType::id(), &[])?;
+        Ok(LibfuncSignature {
+            param_signatures: vec![
+                // Gas builtin
+                ParamSignature::new(gas_builtin_ty.clone()),
+                // System
+                ParamSignature {
+                    ty: system_ty.clone(),
+                    allow_deferred: false,
+                    allow_add_const: true,
+                    allow_const: false,
+                },
+                // L1 address
+                ParamSignature::new(felt_ty),
+                // Payload
+                ParamSignature::new(felt_array_ty.clone()),
+            ],
+            branch_signatures: vec![
+                // Success branch.
+                BranchSignature {
+                    vars: vec![
+                        // Gas builtin
+                        OutputVarInfo {
+                            ty: gas_builtin_ty.clone(),
+                            ref_info: OutputVarReferenceInfo::Deferred(DeferredOutputKind::Generic),
+                        },
+                        // System
+                        OutputVarInfo {
+                            ty: system_ty.clone(),
+                            ref_info: OutputVarReferenceInfo::Deferred(
+                                DeferredOutputKind::AddConst { param_idx: 1 },
+                            ),
+                        },
+                    ],
+                    ap_change: SierraApChange::Known { new_vars_only: false },
+                },
+                // Failure branch.
+                failure_branch_signature(gas_builtin_ty, system_ty, felt_array_ty),
+            ],
+            fallthrough: Some(0),
+        })
+    }
+}
@@ -14,9 +14,21 @@ pub mod emit_event;
 use emit_event::EmitEventLibfunc;
 
 pub mod interoperability;
-use interoperability::{CallContractLibfunc, ContractAddressConstLibfunc, ContractAddressType};
+use interoperability::{
+    CallContractLibfunc, ContractAddressConstLibfunc, ContractAddressToFeltLibfunc,
+    ContractAddressType, DeployLibfunc, LibraryCallLibfunc, ReplaceClassLibfunc,
+};
+
+pub mod messaging;
+use messaging::SendMessageToL1Libfunc;
 
-use self::getter::{GetCallerAddressTrait, GetterLibfunc};
+pub mod testing;
+use testing::CheatcodeLibfunc;
+
+use self::getter::{
+    GetBlockNumberTrait, GetBlockTimestampTrait, GetCallerAddressTrait, GetContractAddressTrait,
+    GetSequencerAddressTrait, GetTxInfoTrait, GetterLibfunc,
+};
 use self::interoperability::ContractAddressTryFromFeltLibfunc;
 use self::storage::{
     StorageAddressFromBaseAndOffsetLibfunc, StorageAddressFromBaseLibfunc, StorageAddressType,
@@ -37,6 +49,7 @@ define_libfunc_hierarchy! {
          CallContract(CallContractLibfunc),
          ContractAddressConst(ContractAddressConstLibfunc),
          ContractAddressTryFromFelt(ContractAddressTryFromFeltLibfunc),
+         ContractAddressToFelt(ContractAddressToFeltLibfunc),
          StorageRead(StorageReadLibfunc),
          StorageWrite(StorageWriteLibfunc),
          StorageBaseAddressConst(StorageBaseAddressConstLibfunc),
@@ -45,5 +58,15 @@ define_libfunc_hierarchy! {
          StorageAddressFromBaseAndOffset(StorageAddressFromBaseAndOffsetLibfunc),
          EmitEvent(EmitEventLibfunc),
          GetCallerAddress(GetterLibfunc<GetCallerAddressTrait>),
+         GetContractAddress(GetterLibfunc<GetContractAddressTrait>),
+         GetBlockNumber(GetterLibfunc<GetBlockNumberTrait>),
+         GetBlockTimestamp(GetterLibfunc<GetBlockTimestampTrait>),
+         GetSequencerAddress(GetterLibfunc<GetSequencerAddressTrait>),
+         GetTxInfo(GetterLibfunc<GetTxInfoTrait>),
+         LibraryCall(LibraryCallLibfunc),
+         Deploy(DeployLibfunc),
+         SendMessageToL1(SendMessageToL1Libfunc),
+         ReplaceClass(ReplaceClassLibfunc),
+         Cheatcode(CheatcodeLibfunc),
     }, StarkNetConcreteLibfunc
 }
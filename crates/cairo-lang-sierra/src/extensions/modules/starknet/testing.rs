@@ -0,0 +1,53 @@
+use crate::extensions::array::ArrayType;
+use crate::extensions::consts::SignatureAndConstConcreteLibfunc;
+use crate::extensions::felt::FeltType;
+use crate::extensions::lib_func::{
+    LibfuncSignature, OutputVarInfo, SierraApChange, SignatureSpecializationContext,
+    SpecializationContext,
+};
+use crate::extensions::{NamedLibfunc, NamedType, OutputVarReferenceInfo, SpecializationError};
+use crate::program::GenericArg;
+
+/// Libfunc for running a cheatcode, which manipulates the simulated StarkNet state when running
+/// tests.
+/// The cheatcode is identified by the generic arg, and gets and returns an array of felts.
+#[derive(Default)]
+pub struct CheatcodeLibfunc {}
+impl NamedLibfunc for CheatcodeLibfunc {
+    const STR_ID: &'static str = "cheatcode";
+    type Concrete = SignatureAndConstConcreteLibfunc;
+
+    fn specialize_signature(
+        &self,
+        context: &dyn SignatureSpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<LibfuncSignature, SpecializationError> {
+        if !matches!(args, [GenericArg::Value(_)]) {
+            return Err(SpecializationError::UnsupportedGenericArg);
+        }
+        let felt_ty = context.get_concrete_type(FeltType::id(), &[])?;
+        let felt_array_ty = context.get_wrapped_concrete_type(ArrayType::id(), felt_ty)?;
+        Ok(LibfuncSignature::new_non_branch(
+            vec![felt_array_ty.clone()],
+            vec![OutputVarInfo {
+                ty: felt_array_ty,
+                ref_info: OutputVarReferenceInfo::NewTempVar { idx: Some(0) },
+            }],
+            SierraApChange::Known { new_vars_only: true },
+        ))
+    }
+
+    fn specialize(
+        &self,
+        context: &dyn SpecializationContext,
+        args: &[GenericArg],
+    ) -> Result<Self::Concrete, SpecializationError> {
+        match args {
+            [GenericArg::Value(c)] => Ok(SignatureAndConstConcreteLibfunc {
+                c: c.clone(),
+                signature: self.specialize_signature(context.upcast(), args)?,
+            }),
+            _ => Err(SpecializationError::UnsupportedGenericArg),
+        }
+    }
+}
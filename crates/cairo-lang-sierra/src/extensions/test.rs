@@ -221,6 +221,9 @@ fn find_type_specialization(
 #[test_case("contract_address_const", vec![value_arg(8)] => Ok(()); "contract_address_const<8>")]
 #[test_case("contract_address_const", vec![] => Err(UnsupportedGenericArg);
 "contract_address_const")]
+#[test_case("contract_address_to_felt", vec![] => Ok(()); "contract_address_to_felt")]
+#[test_case("cheatcode", vec![value_arg(8)] => Ok(()); "cheatcode<8>")]
+#[test_case("cheatcode", vec![] => Err(UnsupportedGenericArg); "cheatcode")]
 #[test_case("drop", vec![type_arg("u128")] => Ok(()); "drop<u128>")]
 #[test_case("drop", vec![] => Err(WrongNumberOfGenericArgs); "drop<>")]
 #[test_case("drop", vec![type_arg("GasBuiltin")] => Err(UnsupportedGenericArg);
@@ -286,6 +289,11 @@ Ok(());"enum_init<Option,1>")]
 #[test_case("storage_write_syscall", vec![] => Ok(()); "storage_write_syscall")]
 #[test_case("call_contract_syscall", vec![] => Ok(()); "call_contract_syscall")]
 #[test_case("emit_event_syscall", vec![] => Ok(()); "emit_event_syscall")]
+#[test_case("library_call_syscall", vec![] => Ok(()); "library_call_syscall")]
+#[test_case("replace_class_syscall", vec![] => Ok(()); "replace_class_syscall")]
+#[test_case("send_message_to_l1_syscall", vec![] => Ok(()); "send_message_to_l1_syscall")]
+#[test_case("get_contract_address_syscall", vec![] => Ok(()); "get_contract_address_syscall")]
+#[test_case("get_block_number_syscall", vec![] => Ok(()); "get_block_number_syscall")]
 fn find_libfunc_specialization(
     id: &str,
     generic_args: Vec<GenericArg>,
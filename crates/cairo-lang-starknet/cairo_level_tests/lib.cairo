@@ -1,13 +1,35 @@
 use array::ArrayTrait;
 
+#[derive(Copy, Drop, PartialEq, Serde, StorageAccess)]
+struct Position {
+    x: u128,
+    y: u256,
+    visible: bool,
+}
+
+#[derive(Copy, Drop, PartialEq, Serde, StorageAccess)]
+enum Status {
+    Idle: (),
+    Moving: Position,
+    Stopped: u128,
+}
+
 #[contract]
 mod TestContract {
     use array::ArrayTrait;
+    use super::Position;
+    use super::PositionStorageAccess;
+    use super::Status;
+    use super::StatusSerde;
+    use super::StatusStorageAccess;
 
     struct Storage {
         value: felt,
         mapping: LegacyMap::<u128, bool>,
         large_mapping: LegacyMap::<u256, u256>,
+        positions: Map::<felt, Position>,
+        allowances: Map::<felt, Map::<u128, felt>>,
+        statuses: Map::<felt, Status>,
     }
 
     #[view]
@@ -58,6 +80,34 @@ mod TestContract {
     fn get_large(key: u256) -> u256 {
         large_mapping::read(key)
     }
+
+    fn set_position(key: felt, position: Position) {
+        positions::write(key, position)
+    }
+
+    fn get_position(key: felt) -> Position {
+        positions::read(key)
+    }
+
+    #[external]
+    fn set_allowance(owner: felt, spender: u128, amount: felt) {
+        allowances::write(owner, spender, amount)
+    }
+
+    #[view]
+    fn get_allowance(owner: felt, spender: u128) -> felt {
+        allowances::read(owner, spender)
+    }
+
+    #[external]
+    fn set_status(key: felt, status: Status) {
+        statuses::write(key, status)
+    }
+
+    #[view]
+    fn get_status(key: felt) -> Status {
+        statuses::read(key)
+    }
 }
 
 #[test]
@@ -215,3 +265,46 @@ fn write_read_large_value() {
     assert(value.low == 3_u128, 'bad low');
     assert(value.high == 4_u128, 'bad high');
 }
+
+#[test]
+#[available_gas(300000)]
+fn write_read_struct_in_map() {
+    let position = Position { x: 1_u128, y: u256 { low: 2_u128, high: 3_u128 }, visible: true };
+    TestContract::set_position(5, position);
+    let read_position = TestContract::get_position(5);
+    assert(read_position.x == 1_u128, 'Wrong x');
+    assert(read_position.y.low == 2_u128, 'Wrong y.low');
+    assert(read_position.y.high == 3_u128, 'Wrong y.high');
+    assert(read_position.visible, 'Wrong visible');
+    let empty_position = TestContract::get_position(6);
+    assert(empty_position.x == 0_u128, 'Wrong empty x');
+    assert(!empty_position.visible, 'Wrong empty visible');
+}
+
+#[test]
+#[available_gas(300000)]
+fn write_read_nested_map() {
+    let mut calldata = ArrayTrait::new();
+    calldata.append(1);
+    calldata.append(2);
+    calldata.append(3);
+    assert_empty(TestContract::__external::set_allowance(calldata));
+    assert(TestContract::get_allowance(1, 2_u128) == 3, 'Wrong allowance');
+    assert(TestContract::get_allowance(1, 3_u128) == 0, 'Wrong other spender');
+    assert(TestContract::get_allowance(2, 2_u128) == 0, 'Wrong other owner');
+}
+
+#[test]
+#[available_gas(2000000)]
+fn write_read_enum_in_map() {
+    assert(TestContract::get_status(1) == Status::Idle(()), 'Wrong empty status');
+    let moving = Status::Moving(
+        Position { x: 1_u128, y: u256 { low: 2_u128, high: 3_u128 }, visible: true }
+    );
+    TestContract::set_status(1, moving);
+    TestContract::set_status(2, Status::Stopped(4_u128));
+    assert(TestContract::get_status(1) == moving, 'Wrong moving status');
+    assert(TestContract::get_status(2) == Status::Stopped(4_u128), 'Wrong stopped status');
+    TestContract::set_status(1, Status::Idle(()));
+    assert(TestContract::get_status(1) == Status::Idle(()), 'Wrong idle status');
+}
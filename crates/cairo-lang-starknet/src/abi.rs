@@ -1,9 +1,14 @@
 use cairo_lang_defs::ids::{TraitFunctionId, TraitId};
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::{ConcreteTypeId, GenericArgumentId, TypeId, TypeLongId};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::plugin::{EVENT_ATTR, VIEW_ATTR};
+use crate::casm_contract_class::BigIntAsHex;
+use crate::contract::starknet_keccak;
+use crate::plugin::{EVENT_ATTR, L1_HANDLER_ATTR, VIEW_ATTR};
 
 #[cfg(test)]
 #[path = "abi_test.rs"]
@@ -28,6 +33,8 @@ impl Contract {
         for trait_function_id in db.trait_functions(trait_id).unwrap_or_default().values() {
             if trait_function_has_attr(db, *trait_function_id, EVENT_ATTR)? {
                 contract.add_event(db, *trait_function_id)?;
+            } else if trait_function_has_attr(db, *trait_function_id, L1_HANDLER_ATTR)? {
+                contract.add_l1_handler(db, *trait_function_id)?;
             } else {
                 contract.add_function(db, *trait_function_id)?;
             }
@@ -42,30 +49,22 @@ impl Contract {
         db: &dyn SemanticGroup,
         trait_function_id: TraitFunctionId,
     ) -> Result<(), ABIError> {
-        let state_mutability = if trait_function_has_attr(db, trait_function_id, VIEW_ATTR)? {
-            StateMutability::View
-        } else {
-            StateMutability::External
-        };
-        let defs_db = db.upcast();
-        let name = trait_function_id.name(defs_db).into();
-        let signature = db
-            .trait_function_signature(trait_function_id)
-            .map_err(|_| ABIError::CompilationError)?;
-        self.items.push(Item::Function(Function {
-            name,
-            inputs: signature
-                .params
-                .into_iter()
-                .map(|param| Input {
-                    name: param.id.name(db.upcast()).into(),
-                    ty: param.ty.format(db),
-                })
-                .collect(),
-            // TODO(spapini): output refs?
-            output_ty: signature.return_type.format(db),
-            state_mutability,
-        }));
+        let function = function_abi(db, trait_function_id)?;
+        self.add_signature_types(db, trait_function_id)?;
+        self.items.push(Item::Function(function));
+
+        Ok(())
+    }
+
+    /// Adds an L1 handler to the ABI from a TraitFunctionId.
+    fn add_l1_handler(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let function = function_abi(db, trait_function_id)?;
+        self.add_signature_types(db, trait_function_id)?;
+        self.items.push(Item::L1Handler(function));
 
         Ok(())
     }
@@ -77,13 +76,15 @@ impl Contract {
         trait_function_id: TraitFunctionId,
     ) -> Result<(), ABIError> {
         let defs_db = db.upcast();
-        let name = trait_function_id.name(defs_db).into();
+        let name: String = trait_function_id.name(defs_db).into();
         let signature = db
             .trait_function_signature(trait_function_id)
             .map_err(|_| ABIError::CompilationError)?;
+        self.add_signature_types(db, trait_function_id)?;
         self.items.push(Item::Event(Event {
+            keys: vec![BigIntAsHex { value: starknet_keccak(name.as_bytes()) }],
             name,
-            inputs: signature
+            data: signature
                 .params
                 .into_iter()
                 .map(|param| Input {
@@ -96,11 +97,132 @@ impl Contract {
         Ok(())
     }
 
+    /// Adds the definitions of the types used in the signature of a TraitFunctionId to the ABI.
+    fn add_signature_types(
+        &mut self,
+        db: &dyn SemanticGroup,
+        trait_function_id: TraitFunctionId,
+    ) -> Result<(), ABIError> {
+        let signature = db
+            .trait_function_signature(trait_function_id)
+            .map_err(|_| ABIError::CompilationError)?;
+        for param in signature.params {
+            self.add_type(db, param.ty)?;
+        }
+        self.add_type(db, signature.return_type)
+    }
+
+    /// Adds the definitions of a type and of the types it is built of to the ABI. Each struct and
+    /// enum is added once, after the types of its members or variants.
+    fn add_type(&mut self, db: &dyn SemanticGroup, ty: TypeId) -> Result<(), ABIError> {
+        match db.lookup_intern_type(ty) {
+            TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+                let name = ty.format(db);
+                if self.has_type_definition(&name) {
+                    return Ok(());
+                }
+                let members = db
+                    .concrete_struct_members(concrete_struct_id)
+                    .map_err(|_| ABIError::CompilationError)?;
+                for member in members.values() {
+                    self.add_type(db, member.ty)?;
+                }
+                self.items.push(Item::Struct(Struct {
+                    name,
+                    members: members
+                        .into_iter()
+                        .map(|(name, member)| StructMember {
+                            name: name.into(),
+                            ty: member.ty.format(db),
+                        })
+                        .collect(),
+                }));
+            }
+            TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+                let name = ty.format(db);
+                if self.has_type_definition(&name) {
+                    return Ok(());
+                }
+                let variants = db
+                    .concrete_enum_variants(concrete_enum_id)
+                    .map_err(|_| ABIError::CompilationError)?;
+                for variant in &variants {
+                    self.add_type(db, variant.ty)?;
+                }
+                self.items.push(Item::Enum(Enum {
+                    name,
+                    variants: variants
+                        .into_iter()
+                        .map(|variant| EnumVariant {
+                            name: variant.id.name(db.upcast()).into(),
+                            index: variant.idx,
+                            ty: variant.ty.format(db),
+                        })
+                        .collect(),
+                }));
+            }
+            TypeLongId::Concrete(ConcreteTypeId::Extern(concrete_extern_type_id)) => {
+                for generic_arg in ConcreteTypeId::Extern(concrete_extern_type_id).generic_args(db)
+                {
+                    if let GenericArgumentId::Type(generic_arg_ty) = generic_arg {
+                        self.add_type(db, generic_arg_ty)?;
+                    }
+                }
+            }
+            TypeLongId::Tuple(tys) => {
+                for ty in tys {
+                    self.add_type(db, ty)?;
+                }
+            }
+            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
+                return Err(ABIError::CompilationError);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether the ABI already contains the definition of the struct or enum with the given
+    /// name.
+    fn has_type_definition(&self, name: &str) -> bool {
+        self.items.iter().any(|item| match item {
+            Item::Struct(item) => item.name == name,
+            Item::Enum(item) => item.name == name,
+            Item::Function(_) | Item::Event(_) | Item::L1Handler(_) => false,
+        })
+    }
+
     pub fn json(&self) -> String {
         serde_json::to_string_pretty(&self).unwrap()
     }
 }
 
+/// Returns the ABI of a function from a TraitFunctionId.
+fn function_abi(
+    db: &dyn SemanticGroup,
+    trait_function_id: TraitFunctionId,
+) -> Result<Function, ABIError> {
+    let state_mutability = if trait_function_has_attr(db, trait_function_id, VIEW_ATTR)? {
+        StateMutability::View
+    } else {
+        StateMutability::External
+    };
+    let defs_db = db.upcast();
+    let name = trait_function_id.name(defs_db).into();
+    let signature =
+        db.trait_function_signature(trait_function_id).map_err(|_| ABIError::CompilationError)?;
+    Ok(Function {
+        name,
+        inputs: signature
+            .params
+            .into_iter()
+            .map(|param| Input { name: param.id.name(db.upcast()).into(), ty: param.ty.format(db) })
+            .collect(),
+        // TODO(spapini): output refs?
+        output_ty: signature.return_type.format(db),
+        state_mutability,
+    })
+}
+
 /// Checks whether the trait function has the given attribute.
 fn trait_function_has_attr(
     db: &dyn SemanticGroup,
@@ -130,6 +252,12 @@ pub enum Item {
     Function(Function),
     #[serde(rename = "event")]
     Event(Event),
+    #[serde(rename = "l1_handler")]
+    L1Handler(Function),
+    #[serde(rename = "struct")]
+    Struct(Struct),
+    #[serde(rename = "enum")]
+    Enum(Enum),
 }
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -153,7 +281,10 @@ pub struct Function {
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Event {
     pub name: String,
-    pub inputs: Vec<Input>,
+    /// The keys the event is emitted with.
+    pub keys: Vec<BigIntAsHex>,
+    /// The members of the event, serialized in order into its data.
+    pub data: Vec<Input>,
 }
 
 /// Function input ABI.
@@ -162,3 +293,33 @@ pub struct Input {
     pub name: String,
     pub ty: String,
 }
+
+/// Struct ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+    pub name: String,
+    pub members: Vec<StructMember>,
+}
+
+/// Struct member ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructMember {
+    pub name: String,
+    pub ty: String,
+}
+
+/// Enum ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enum {
+    pub name: String,
+    pub variants: Vec<EnumVariant>,
+}
+
+/// Enum variant ABI.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub name: String,
+    /// The index of the variant within the enum.
+    pub index: usize,
+    pub ty: String,
+}
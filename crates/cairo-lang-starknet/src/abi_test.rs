@@ -13,6 +13,16 @@ fn test_abi() {
     let module_id = setup_test_module(
         &mut db_val,
         indoc! {"
+            struct MyStruct<T> {
+                a: T,
+                b: felt,
+            }
+
+            enum MyEnum<S> {
+                a: u128,
+                b: MyStruct::<S>,
+            }
+
             trait MyAbi {
                 fn foo(a: felt, b: u128) -> Option::<()>;
 
@@ -22,6 +32,9 @@ fn test_abi() {
                 #[view]
                 fn foo_view(a: felt, b: u128) -> Option::<()>;
 
+                #[view]
+                fn foo_types(a: MyEnum::<u128>, b: Array::<MyStruct::<felt>>) -> (MyStruct::<u128>, u256);
+
                 #[event]
                 fn foo_event(a: felt, b: u128);
             }
@@ -41,6 +54,22 @@ fn test_abi() {
         actual_serialization,
         indoc! {
         r#"[
+            {
+              "type": "enum",
+              "name": "core::option::Option::<()>",
+              "variants": [
+                {
+                  "name": "Some",
+                  "index": 0,
+                  "ty": "()"
+                },
+                {
+                  "name": "None",
+                  "index": 1,
+                  "ty": "()"
+                }
+              ]
+            },
             {
               "type": "function",
               "name": "foo",
@@ -89,10 +118,87 @@ fn test_abi() {
               "output_ty": "core::option::Option::<()>",
               "state_mutability": "view"
             },
+            {
+              "type": "struct",
+              "name": "test::MyStruct::<core::integer::u128>",
+              "members": [
+                {
+                  "name": "a",
+                  "ty": "core::integer::u128"
+                },
+                {
+                  "name": "b",
+                  "ty": "core::felt"
+                }
+              ]
+            },
+            {
+              "type": "enum",
+              "name": "test::MyEnum::<core::integer::u128>",
+              "variants": [
+                {
+                  "name": "a",
+                  "index": 0,
+                  "ty": "core::integer::u128"
+                },
+                {
+                  "name": "b",
+                  "index": 1,
+                  "ty": "test::MyStruct::<core::integer::u128>"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "test::MyStruct::<core::felt>",
+              "members": [
+                {
+                  "name": "a",
+                  "ty": "core::felt"
+                },
+                {
+                  "name": "b",
+                  "ty": "core::felt"
+                }
+              ]
+            },
+            {
+              "type": "struct",
+              "name": "core::integer::u256",
+              "members": [
+                {
+                  "name": "low",
+                  "ty": "core::integer::u128"
+                },
+                {
+                  "name": "high",
+                  "ty": "core::integer::u128"
+                }
+              ]
+            },
+            {
+              "type": "function",
+              "name": "foo_types",
+              "inputs": [
+                {
+                  "name": "a",
+                  "ty": "test::MyEnum::<core::integer::u128>"
+                },
+                {
+                  "name": "b",
+                  "ty": "core::array::Array::<test::MyStruct::<core::felt>>"
+                }
+              ],
+              "output_ty": "(test::MyStruct::<core::integer::u128>, core::integer::u256)",
+              "state_mutability": "view"
+            },
             {
               "type": "event",
               "name": "foo_event",
-              "inputs": [
+              "keys": [
+                "0x76e2ab58546df51500046d5b205434be8bf57c86f047572a137b2008b2f472"
+              ],
+              "data": [
                 {
                   "name": "a",
                   "ty": "core::felt"
@@ -2,6 +2,7 @@
 #[path = "casm_contract_class_test.rs"]
 mod test;
 
+use cairo_lang_casm::hints::Hint;
 use cairo_lang_sierra::extensions::builtin_cost::CostTokenType;
 use cairo_lang_sierra::extensions::gas::GasBuiltinType;
 use cairo_lang_sierra::extensions::pedersen::PedersenType;
@@ -27,11 +28,15 @@ use thiserror::Error;
 
 use crate::contract_class::{ContractClass, ContractEntryPoint};
 use crate::felt_serde::{sierra_from_felts, FeltSerdeError};
+use crate::poseidon::poseidon_hash_many;
 use crate::sierra_version::SierraVersionError;
 
 /// The expected gas cost of an entrypoint that begins with get_gas() immediately.
 pub const ENTRY_POINT_COST: i32 = 10000;
 
+/// The version of the compiled class hash computation, hashed as a short string.
+const COMPILED_CLASS_VERSION: &str = "COMPILED_CLASS_V1";
+
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum StarknetSierraCompilationError {
     #[error(transparent)]
@@ -50,8 +55,11 @@ pub enum StarknetSierraCompilationError {
     InvalidEntryPointSignature,
 }
 
+/// The hints of a compiled program, keyed by the bytecode offset of their instruction.
+pub type HintsByOffset = Vec<(usize, Vec<Hint>)>;
+
 /// Represents a contract in the StarkNet network.
-#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CasmContractClass {
     #[serde(serialize_with = "serialize_big_uint", deserialize_with = "deserialize_big_uint")]
     pub prime: BigUint,
@@ -67,6 +75,15 @@ impl CasmContractClass {
     pub fn from_contract_class(
         contract_class: ContractClass,
     ) -> Result<Self, StarknetSierraCompilationError> {
+        Ok(Self::from_contract_class_with_hints(contract_class)?.0)
+    }
+
+    /// Same as [Self::from_contract_class], but also returns the hints of the compiled program,
+    /// keyed by the bytecode offset of their instruction (matching the offsets of `hints`).
+    #[allow(clippy::result_large_err)]
+    pub fn from_contract_class_with_hints(
+        contract_class: ContractClass,
+    ) -> Result<(Self, HintsByOffset), StarknetSierraCompilationError> {
         contract_class.verify_compatible_sierra_version()?;
         let prime = BigUint::from_str_radix(
             "800000000000011000000000000000000000000000000000000000000000001",
@@ -95,12 +112,14 @@ impl CasmContractClass {
 
         let mut bytecode = vec![];
         let mut hints = vec![];
+        let mut hint_objects = vec![];
         for instruction in cairo_program.instructions {
             if !instruction.hints.is_empty() {
                 hints.push((
                     bytecode.len(),
                     instruction.hints.iter().map(|hint| hint.to_string()).collect(),
-                ))
+                ));
+                hint_objects.push((bytecode.len(), instruction.hints.clone()));
             }
             bytecode.extend(instruction.assemble().encode().iter().map(|big_int| {
                 let (_q, reminder) = big_int.magnitude().div_rem(&prime);
@@ -182,21 +201,61 @@ impl CasmContractClass {
             Ok::<Vec<CasmContractEntryPoint>, StarknetSierraCompilationError>(entry_points)
         };
 
-        Ok(Self {
-            prime,
-            compiler_version: "1.0.0".to_string(),
-            bytecode,
-            hints,
-            entry_points_by_type: CasmContractEntryPoints {
-                external: as_casm_entry_points(contract_class.entry_points_by_type.external)?,
-                l1_handler: as_casm_entry_points(contract_class.entry_points_by_type.l1_handler)?,
-                constructor: as_casm_entry_points(contract_class.entry_points_by_type.constructor)?,
+        Ok((
+            Self {
+                prime,
+                compiler_version: "1.0.0".to_string(),
+                bytecode,
+                hints,
+                entry_points_by_type: CasmContractEntryPoints {
+                    external: as_casm_entry_points(contract_class.entry_points_by_type.external)?,
+                    l1_handler: as_casm_entry_points(
+                        contract_class.entry_points_by_type.l1_handler,
+                    )?,
+                    constructor: as_casm_entry_points(
+                        contract_class.entry_points_by_type.constructor,
+                    )?,
+                },
             },
-        })
+            hint_objects,
+        ))
+    }
+
+    /// Returns the compiled class hash, which is the Poseidon hash of its version, its entry points
+    /// by type, and its bytecode.
+    pub fn compiled_class_hash(&self) -> BigUint {
+        poseidon_hash_many(&[
+            BigUint::from_bytes_be(COMPILED_CLASS_VERSION.as_bytes()),
+            entry_points_hash(&self.entry_points_by_type.external),
+            entry_points_hash(&self.entry_points_by_type.l1_handler),
+            entry_points_hash(&self.entry_points_by_type.constructor),
+            poseidon_hash_many(
+                &self.bytecode.iter().map(|felt| felt.value.clone()).collect::<Vec<_>>(),
+            ),
+        ])
+    }
+}
+
+/// Returns the hash of the entry points of a single type, for
+/// [CasmContractClass::compiled_class_hash].
+fn entry_points_hash(entry_points: &[CasmContractEntryPoint]) -> BigUint {
+    let mut elements = vec![];
+    for entry_point in entry_points {
+        let builtins = entry_point
+            .builtins
+            .iter()
+            .map(|builtin| BigUint::from_bytes_be(builtin.as_bytes()))
+            .collect::<Vec<_>>();
+        elements.extend([
+            entry_point.selector.clone(),
+            BigUint::from(entry_point.offset),
+            poseidon_hash_many(&builtins),
+        ]);
     }
+    poseidon_hash_many(&elements)
 }
 
-#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CasmContractEntryPoint {
     /// A field element that encodes the signature of the called function.
     #[serde(serialize_with = "serialize_big_uint", deserialize_with = "deserialize_big_uint")]
@@ -207,7 +266,7 @@ pub struct CasmContractEntryPoint {
     pub builtins: Vec<String>,
 }
 
-#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CasmContractEntryPoints {
     #[serde(rename = "EXTERNAL")]
     pub external: Vec<CasmContractEntryPoint>,
@@ -237,7 +296,7 @@ where
 }
 
 // A wrapper for BigUint that serializes as hex.
-#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct BigIntAsHex {
     /// A field element that encodes the signature of the called function.
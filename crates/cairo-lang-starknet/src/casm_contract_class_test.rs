@@ -1,4 +1,8 @@
+use std::fs;
+
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
+use num_bigint::BigUint;
+use num_traits::Num;
 use test_case::test_case;
 
 use crate::casm_contract_class::CasmContractClass;
@@ -16,3 +20,19 @@ fn test_casm_contract_from_contract_class(example_file_name: &str) {
         serde_json::to_string_pretty(&casm_contract).unwrap() + "\n",
     );
 }
+
+// The expected hashes are computed by the `starknet-core` crate (v0.6.1), whose compiled class
+// hashes are tested against the ones of `cairo-lang`.
+#[test_case("test_contract", "4c5ca10d3453939a6045795555f185b6b2ff397a078a7229d315165282b5fb3")]
+#[test_case("hello_starknet", "5fe7d01f79835273eddff2d7cad5405e2733a969db3b0dd216cad82d21325f2")]
+#[test_case("erc20", "2ad50725e97aaab88f574e2c3eea38bef06ad5ab4c4dfb9ee4963f46e4877f1")]
+fn test_compiled_class_hash(example_file_name: &str, expected_hash: &str) {
+    let path = get_example_file_path(format!("{example_file_name}.casm").as_str());
+    let casm_contract: CasmContractClass =
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+    assert_eq!(
+        casm_contract.compiled_class_hash(),
+        BigUint::from_str_radix(expected_hash, 16).unwrap()
+    );
+}
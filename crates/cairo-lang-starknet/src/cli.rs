@@ -2,6 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, MessageFormat};
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_starknet::contract_class::compile_path;
 use clap::Parser;
@@ -18,19 +19,33 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// The format in which diagnostics are reported.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+    /// Prints the class hash of the contract to stdout, instead of the contract class.
+    #[arg(long, default_value_t = false)]
+    class_hash: bool,
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
     let contract = compile_path(
         &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+        CompilerConfig {
+            diagnostics_reporter: DiagnosticsReporter::stderr()
+                .with_message_format(args.message_format),
+            replace_ids: args.replace_ids,
+        },
     )?;
     let res = serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?;
     match args.output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
+        None if args.class_hash => {}
         None => println!("{res}"),
     }
+    if args.class_hash {
+        println!("{:#x}", contract.class_hash());
+    }
 
     Ok(())
 }
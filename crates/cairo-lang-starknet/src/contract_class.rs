@@ -1,3 +1,4 @@
+use std::io;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -25,13 +26,17 @@ use crate::contract::{
 };
 use crate::db::StarknetRootDatabaseBuilderEx;
 use crate::felt_serde::{sierra_from_felts, sierra_to_felts};
-use crate::plugin::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE};
+use crate::plugin::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
+use crate::poseidon::poseidon_hash_many;
 use crate::sierra_version::{self, lookup_sierra_version, SierraVersionError};
 
 #[cfg(test)]
 #[path = "contract_class_test.rs"]
 mod test;
 
+/// The version of the class hash computation, hashed as a short string.
+const CONTRACT_CLASS_VERSION: &str = "CONTRACT_CLASS_V0.1.0";
+
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum StarknetCompilationError {
     #[error("Invalid entry point.")]
@@ -69,6 +74,64 @@ impl ContractClass {
         }
         Ok(())
     }
+
+    /// Returns the hash of the class, which is the Poseidon hash of its version, its entry points
+    /// by type, the StarkNet keccak of its JSON ABI, and its Sierra program.
+    pub fn class_hash(&self) -> BigUint {
+        let mut abi_json = vec![];
+        if let Some(abi) = &self.abi {
+            let mut serializer =
+                serde_json::Serializer::with_formatter(&mut abi_json, PythonicJsonFormatter);
+            abi.serialize(&mut serializer).expect("ABI serialization failed.");
+        }
+        poseidon_hash_many(&[
+            BigUint::from_bytes_be(CONTRACT_CLASS_VERSION.as_bytes()),
+            entry_points_hash(&self.entry_points_by_type.external),
+            entry_points_hash(&self.entry_points_by_type.l1_handler),
+            entry_points_hash(&self.entry_points_by_type.constructor),
+            starknet_keccak(&abi_json),
+            poseidon_hash_many(
+                &self.sierra_program.iter().map(|felt| felt.value.clone()).collect_vec(),
+            ),
+        ])
+    }
+}
+
+/// Formats JSON with the separators of Python's `json.dumps`, which StarkNet uses to serialize the
+/// ABI when computing the class hash.
+struct PythonicJsonFormatter;
+impl serde_json::ser::Formatter for PythonicJsonFormatter {
+    fn begin_array_value<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first { Ok(()) } else { writer.write_all(b", ") }
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> io::Result<()> {
+        if first { Ok(()) } else { writer.write_all(b", ") }
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b": ")
+    }
+}
+
+/// Returns the hash of the entry points of a single type, for [ContractClass::class_hash].
+fn entry_points_hash(entry_points: &[ContractEntryPoint]) -> BigUint {
+    poseidon_hash_many(
+        &entry_points
+            .iter()
+            .flat_map(|entry_point| {
+                [entry_point.selector.clone(), BigUint::from(entry_point.function_idx)]
+            })
+            .collect_vec(),
+    )
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -163,9 +226,15 @@ fn compile_contract_with_prepared_and_checked_db(
         .into_iter()
         .flat_map(|f| ConcreteFunctionWithBodyId::from_no_generics_free(db, f))
         .collect();
+    let l1_handler_functions: Vec<_> = get_module_functions(db, contract, L1_HANDLER_MODULE)?
+        .into_iter()
+        .flat_map(|f| ConcreteFunctionWithBodyId::from_no_generics_free(db, f))
+        .collect();
     let mut sierra_program = db
         .get_sierra_program_for_functions(
-            chain!(&external_functions, &constructor_functions).cloned().collect(),
+            chain!(&external_functions, &l1_handler_functions, &constructor_functions)
+                .cloned()
+                .collect(),
         )
         .to_option()
         .with_context(|| "Compilation failed without any diagnostics.")?;
@@ -178,7 +247,7 @@ fn compile_contract_with_prepared_and_checked_db(
 
     let entry_points_by_type = ContractEntryPoints {
         external: get_entry_points(db, &external_functions, &replacer)?,
-        l1_handler: vec![],
+        l1_handler: get_entry_points(db, &l1_handler_functions, &replacer)?,
         /// TODO(orizi): Validate there is at most one constructor.
         constructor: get_entry_points(db, &constructor_functions, &replacer)?,
     };
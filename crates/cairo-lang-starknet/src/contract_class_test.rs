@@ -1,6 +1,9 @@
+use std::fs;
+
 use cairo_lang_test_utils::compare_contents_or_fix_with_path;
 use indoc::indoc;
 use num_bigint::BigUint;
+use num_traits::Num;
 use pretty_assertions::assert_eq;
 use test_case::test_case;
 
@@ -52,6 +55,18 @@ fn test_serialization() {
     assert_eq!(contract, serde_json::from_str(&serialized).unwrap())
 }
 
+// The expected hashes are computed by `FlattenedSierraClass::class_hash` of the `starknet-core`
+// crate (v0.6.1), with the ABI serialized by `to_string_pythonic`, as in `SierraClass::flatten`.
+#[test_case("test_contract", "2b0aea72ff9131093201d41727e390a30e97ce00453c94a9e2af5a8480f53c6")]
+#[test_case("hello_starknet", "49f2ef37c4c2b63a4e57a9eaddf9fc4b36986542b32f0c6a33e4c7ecf0dbb5a")]
+#[test_case("erc20", "380e32c97a473e139ddd80051cc36ac6d6f8e50ac4f4dea3e53eaf043fe91eb")]
+fn test_class_hash(example_file_name: &str, expected_hash: &str) {
+    let path = get_example_file_path(format!("{example_file_name}.json").as_str());
+    let contract: ContractClass = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+    assert_eq!(contract.class_hash(), BigUint::from_str_radix(expected_hash, 16).unwrap());
+}
+
 #[test_case("test_contract")]
 #[test_case("hello_starknet")]
 #[test_case("erc20")]
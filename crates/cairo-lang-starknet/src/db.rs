@@ -5,9 +5,14 @@ use cairo_lang_plugins::get_default_plugins;
 
 use crate::plugin::StarkNetPlugin;
 
+/// The name crates enable the StarkNet plugin by, in their project settings.
+pub const STARKNET_PLUGIN_NAME: &str = "starknet";
+
 pub trait StarknetRootDatabaseBuilderEx {
     /// Tunes a compiler database to StarkNet (e.g. StarkNet plugin).
     fn with_starknet(&mut self) -> &mut Self;
+    /// Makes the StarkNet plugin available to the crates enabling it in their project settings.
+    fn with_named_starknet_plugin(&mut self) -> &mut Self;
 }
 
 impl StarknetRootDatabaseBuilderEx for RootDatabaseBuilder {
@@ -18,6 +23,12 @@ impl StarknetRootDatabaseBuilderEx for RootDatabaseBuilder {
         let mut plugins = get_default_plugins();
         plugins.push(Arc::new(StarkNetPlugin {}));
 
-        self.with_implicit_precedence(&precedence).with_plugins(plugins)
+        self.with_implicit_precedence(&precedence)
+            .with_plugins(plugins)
+            .with_named_starknet_plugin()
+    }
+
+    fn with_named_starknet_plugin(&mut self) -> &mut Self {
+        self.with_named_plugin(STARKNET_PLUGIN_NAME, Arc::new(StarkNetPlugin {}))
     }
 }
@@ -11,6 +11,7 @@ pub mod contract_class;
 pub mod db;
 mod felt_serde;
 pub mod plugin;
+pub mod poseidon;
 mod sierra_version;
 
 #[cfg(test)]
@@ -7,6 +7,8 @@ use cairo_lang_defs::plugin::{
     PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_plugins::derive::derived_traits;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::patcher::{ModifiedNode, PatchBuilder, Patches, RewriteNode};
 use cairo_lang_semantic::plugin::{
@@ -23,19 +25,26 @@ use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::try_extract_matches;
 use indoc::formatdoc;
+use itertools::Itertools;
+use smol_str::SmolStr;
 
 use crate::contract::starknet_keccak;
 
 const ABI_ATTR: &str = "abi";
-const CONTRACT_ATTR: &str = "contract";
-const EXTERNAL_ATTR: &str = "external";
-const CONSTRUCTOR_ATTR: &str = "constructor";
+pub const CONTRACT_ATTR: &str = "contract";
+pub const EXTERNAL_ATTR: &str = "external";
+pub const CONSTRUCTOR_ATTR: &str = "constructor";
+pub const L1_HANDLER_ATTR: &str = "l1_handler";
 pub const VIEW_ATTR: &str = "view";
 pub const EVENT_ATTR: &str = "event";
 pub const ABI_TRAIT: &str = "__abi";
 pub const STORAGE_STRUCT_NAME: &str = "Storage";
 pub const EXTERNAL_MODULE: &str = "__external";
 pub const CONSTRUCTOR_MODULE: &str = "__constructor";
+pub const L1_HANDLER_MODULE: &str = "__l1_handler";
+/// The name of the first parameter of L1 handlers, the address of the L1 contract sending the
+/// message.
+pub const L1_HANDLER_FIRST_PARAM_NAME: &str = "from_address";
 
 // TODO(ilya): Move AuxData logic to a separate file.
 
@@ -73,6 +82,18 @@ impl PluginAuxData for StarkNetContractAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
+
+    fn map_origin_span(
+        &self,
+        db: &(dyn SemanticGroup + 'static),
+        span: TextSpan,
+    ) -> Option<TextSpan> {
+        self.patches.translate_origin(db.upcast(), span)
+    }
 }
 
 /// Contract related auxiliary data of the Starknet plugin.
@@ -106,6 +127,18 @@ impl PluginAuxData for StarkNetABIAuxData {
             .translate(db.upcast(), diag.stable_location.diagnostic_location(db.upcast()).span)?;
         Some(PluginMappedDiagnostic { span, message: diag.format(db) })
     }
+
+    fn map_span(&self, db: &(dyn SemanticGroup + 'static), span: TextSpan) -> Option<TextSpan> {
+        self.patches.translate(db.upcast(), span)
+    }
+
+    fn map_origin_span(
+        &self,
+        db: &(dyn SemanticGroup + 'static),
+        span: TextSpan,
+    ) -> Option<TextSpan> {
+        self.patches.translate_origin(db.upcast(), span)
+    }
 }
 
 #[cfg(test)]
@@ -160,8 +193,8 @@ fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult
     for item_ast in body.items(db).elements(db) {
         match item_ast {
             ast::TraitItem::Function(func) => {
-                // Ignore events.
-                if func.has_attr(db, EVENT_ATTR) {
+                // Ignore events and L1 handlers, which cannot be called by other contracts.
+                if func.has_attr(db, EVENT_ATTR) || func.has_attr(db, L1_HANDLER_ATTR) {
                     continue;
                 }
 
@@ -201,19 +234,25 @@ fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult
                     continue;
                 }
 
-                let ret_decode = match signature.ret_ty(db) {
-                    OptionReturnTypeClause::Empty(_) => "".to_string(),
+                // The returned data is only bound when it is decoded.
+                let (ret_data_binding, ret_decode) = match signature.ret_ty(db) {
+                    OptionReturnTypeClause::Empty(_) => ("", "".to_string()),
                     OptionReturnTypeClause::ReturnTypeClause(ty) => {
                         let ret_type_ast = ty.ty(db);
                         let type_name = ret_type_ast.as_syntax_node().get_text(db);
-                        format!(
-                            "
+                        (
+                            "let mut ret_data = ",
+                            format!(
+                                "
         serde::Serde::<{type_name}>::deserialize(ref ret_data).expect(
             'Returned data too short')"
+                            ),
                         )
                     }
                 };
 
+                let entry_point_selector =
+                    format!("0x{:x}", starknet_keccak(declaration.name(db).text(db).as_bytes()));
                 let mut func_declaration = RewriteNode::from_ast(&declaration);
                 func_declaration
                     .modify_child(db, ast::FunctionDeclaration::INDEX_SIGNATURE)
@@ -232,8 +271,9 @@ fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginResult
                     "$func_decl$ {
         let mut calldata = array_new();
 $serialization_code$
-        let mut ret_data = starknet::call_contract_syscall(
+        $ret_data_binding$starknet::call_contract_syscall(
             contract_address,
+            $entry_point_selector$,
             calldata,
         ).unwrap_syscall();
 $deserialization_code$
@@ -241,10 +281,18 @@ $deserialization_code$
 ",
                     HashMap::from([
                         ("func_decl".to_string(), func_declaration),
+                        (
+                            "entry_point_selector".to_string(),
+                            RewriteNode::Text(entry_point_selector),
+                        ),
                         (
                             "serialization_code".to_string(),
                             RewriteNode::Modified(ModifiedNode { children: serialization_code }),
                         ),
+                        (
+                            "ret_data_binding".to_string(),
+                            RewriteNode::Text(ret_data_binding.to_string()),
+                        ),
                         ("deserialization_code".to_string(), RewriteNode::Text(ret_decode)),
                     ]),
                 ));
@@ -316,7 +364,7 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
             continue;
         }
         kept_original_items.push(RewriteNode::Copied(item.as_syntax_node()));
-        if let Some(ident) = match item {
+        if let Some(ident) = match &item {
             ast::Item::Constant(item) => Some(item.name(db)),
             ast::Item::Module(item) => Some(item.name(db)),
             ast::Item::Use(item) => {
@@ -339,12 +387,27 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
             | ast::Item::Trait(_)
             | ast::Item::FreeFunction(_) => None,
         } {
-            extra_uses.push(RewriteNode::Text(format!("\n        use super::{};", ident.text(db))));
+            // The items of the contract module are imported whether or not they are used.
+            extra_uses.push(RewriteNode::Text(format!(
+                "\n        #[allow(unused_imports)]\n        use super::{};",
+                ident.text(db)
+            )));
+        }
+        // The impls derived for the types of the contract module are imported as well, as they
+        // are not found by the impl lookup of the generated inner modules otherwise.
+        let derived_impls = match &item {
+            ast::Item::Struct(item) => derived_impl_names(db, item.name(db), item.attributes(db)),
+            ast::Item::Enum(item) => derived_impl_names(db, item.name(db), item.attributes(db)),
+            _ => vec![],
+        };
+        for impl_name in derived_impls {
+            extra_uses.push(RewriteNode::Text(format!("\n        use super::{impl_name};")));
         }
     }
     let extra_uses_node = RewriteNode::Modified(ModifiedNode { children: extra_uses });
     let mut generated_external_functions = Vec::new();
     let mut generated_constructor_functions = Vec::new();
+    let mut generated_l1_handler_functions = Vec::new();
 
     let mut storage_code = RewriteNode::Text("".to_string());
     let mut abi_functions = Vec::new();
@@ -355,14 +418,17 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
             ast::Item::FreeFunction(item_function)
                 if item_function.has_attr(db, EXTERNAL_ATTR)
                     || item_function.has_attr(db, VIEW_ATTR)
-                    || item_function.has_attr(db, CONSTRUCTOR_ATTR) =>
+                    || item_function.has_attr(db, CONSTRUCTOR_ATTR)
+                    || item_function.has_attr(db, L1_HANDLER_ATTR) =>
             {
                 let attr = if item_function.has_attr(db, EXTERNAL_ATTR) {
                     EXTERNAL_ATTR
                 } else if item_function.has_attr(db, VIEW_ATTR) {
                     VIEW_ATTR
-                } else {
+                } else if item_function.has_attr(db, CONSTRUCTOR_ATTR) {
                     CONSTRUCTOR_ATTR
+                } else {
+                    L1_HANDLER_ATTR
                 };
 
                 let declaration = item_function.declaration(db);
@@ -374,6 +440,9 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
                         stable_ptr: generic_params.stable_ptr().untyped(),
                     })
                 }
+                if attr == L1_HANDLER_ATTR {
+                    diagnostics.extend(validate_l1_handler_signature(db, &declaration));
+                }
                 abi_functions.push(RewriteNode::Modified(ModifiedNode {
                     children: vec![
                         RewriteNode::Text(format!("#[{attr}]\n        ")),
@@ -384,10 +453,10 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
 
                 match generate_entry_point_wrapper(db, item_function) {
                     Ok(generated_function) => {
-                        let generated = if item_function.has_attr(db, CONSTRUCTOR_ATTR) {
-                            &mut generated_constructor_functions
-                        } else {
-                            &mut generated_external_functions
+                        let generated = match attr {
+                            CONSTRUCTOR_ATTR => &mut generated_constructor_functions,
+                            L1_HANDLER_ATTR => &mut generated_l1_handler_functions,
+                            _ => &mut generated_external_functions,
                         };
                         generated.push(generated_function);
                         generated.push(RewriteNode::Text("\n        ".to_string()));
@@ -401,7 +470,6 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
                 let (rewrite_nodes, event_diagnostics) = handle_event(db, item_function.clone());
                 if let Some((event_function_rewrite, abi_event_rewrite)) = rewrite_nodes {
                     event_functions.push(event_function_rewrite);
-                    // TODO(yuval): keep track in the ABI that these are events.
                     abi_events.push(abi_event_rewrite);
                 }
                 diagnostics.extend(event_diagnostics);
@@ -423,6 +491,7 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
         formatdoc!(
             "
             mod $contract_name$ {{
+                #[allow(unused_imports)]
                 use starknet::SyscallResultTrait;
                 use starknet::SyscallResultTraitImpl;
 
@@ -443,6 +512,10 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
                 mod {CONSTRUCTOR_MODULE} {{$extra_uses$
                     $generated_constructor_functions$
                 }}
+
+                mod {L1_HANDLER_MODULE} {{$extra_uses$
+                    $generated_l1_handler_functions$
+                }}
             }}
         "
         )
@@ -475,6 +548,10 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
                 "generated_constructor_functions".to_string(),
                 RewriteNode::Modified(ModifiedNode { children: generated_constructor_functions }),
             ),
+            (
+                "generated_l1_handler_functions".to_string(),
+                RewriteNode::Modified(ModifiedNode { children: generated_l1_handler_functions }),
+            ),
         ]),
     );
 
@@ -496,6 +573,36 @@ fn handle_mod(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -> PluginResult
     }
 }
 
+/// Validates the signature of an L1 handler: its first parameter must be `from_address: felt`.
+fn validate_l1_handler_signature(
+    db: &dyn SyntaxGroup,
+    declaration: &ast::FunctionDeclaration,
+) -> Option<PluginDiagnostic> {
+    let parameters = declaration.signature(db).parameters(db);
+    let stable_ptr = match parameters.elements(db).into_iter().next() {
+        Some(first_param)
+            if first_param.name(db).text(db) == L1_HANDLER_FIRST_PARAM_NAME
+                && first_param.modifiers(db).elements(db).is_empty()
+                && first_param
+                    .type_clause(db)
+                    .ty(db)
+                    .as_syntax_node()
+                    .get_text_without_trivia(db)
+                    == "felt" =>
+        {
+            return None;
+        }
+        Some(first_param) => first_param.stable_ptr().untyped(),
+        None => parameters.stable_ptr().untyped(),
+    };
+    Some(PluginDiagnostic {
+        message: format!(
+            "The first parameter of an L1 handler must be `{L1_HANDLER_FIRST_PARAM_NAME}: felt`."
+        ),
+        stable_ptr,
+    })
+}
+
 /// Generates a function to emit an event and the corresponding ABI item.
 /// On success, returns a RewriteNode for the event function and a RewriteNode for the ABI
 /// declaration. On failure returns None. In addition, returns diagnostics.
@@ -619,14 +726,16 @@ fn handle_storage_struct(
     extra_uses_node: &RewriteNode,
 ) -> (RewriteNode, Vec<PluginDiagnostic>) {
     let mut members_code = Vec::new();
-    let mut diagnostics = vec![];
 
     for member in struct_ast.members(db).elements(db) {
         let name = member.name(db).text(db);
         let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
         let type_ast = member.type_clause(db).ty(db);
         match try_extract_mapping_types(db, &type_ast) {
-            Some((key_type_ast, value_type_ast, MappingType::Legacy)) => {
+            Some((key_types_ast, value_type_ast, MappingType::Legacy)) => {
+                let [key_type_ast] = &key_types_ast[..] else {
+                    unreachable!("A `LegacyMap` has a single key.");
+                };
                 members_code.push(RewriteNode::interpolate_patched(
                     handle_legacy_mapping_storage_var(&address).as_str(),
                     HashMap::from([
@@ -646,11 +755,28 @@ fn handle_storage_struct(
                     ]),
                 ));
             }
-            Some((_, _, MappingType::NonLegacy)) => {
-                diagnostics.push(PluginDiagnostic {
-                    message: "Non `LegacyMap` mapping is not yet supported.".to_string(),
-                    stable_ptr: type_ast.stable_ptr().untyped(),
-                });
+            Some((key_types_ast, value_type_ast, MappingType::NonLegacy)) => {
+                let mut patches = HashMap::from([
+                    (
+                        "storage_var_name".to_string(),
+                        RewriteNode::Trimmed(member.name(db).as_syntax_node()),
+                    ),
+                    ("extra_uses".to_string(), extra_uses_node.clone()),
+                    (
+                        "value_type".to_string(),
+                        RewriteNode::Trimmed(value_type_ast.as_syntax_node()),
+                    ),
+                ]);
+                for (i, key_type_ast) in key_types_ast.iter().enumerate() {
+                    patches.insert(
+                        format!("key_type_{i}"),
+                        RewriteNode::Trimmed(key_type_ast.as_syntax_node()),
+                    );
+                }
+                members_code.push(RewriteNode::interpolate_patched(
+                    handle_mapping_storage_var(&address, key_types_ast.len()).as_str(),
+                    patches,
+                ));
             }
             None => {
                 members_code.push(RewriteNode::interpolate_patched(
@@ -667,23 +793,62 @@ fn handle_storage_struct(
             }
         }
     }
-    (RewriteNode::Modified(ModifiedNode { children: members_code }), diagnostics)
+    (RewriteNode::Modified(ModifiedNode { children: members_code }), vec![])
+}
+
+/// Returns the names of the impls the `derive` plugin generates for a type, e.g. `AStorageAccess`
+/// for `#[derive(StorageAccess)] struct A {}`.
+fn derived_impl_names(
+    db: &dyn SyntaxGroup,
+    ident: ast::TerminalIdentifier,
+    attributes: ast::AttributeList,
+) -> Vec<String> {
+    let name = ident.text(db);
+    // Invalid derive arguments are reported by the derive plugin.
+    derived_traits(db, attributes, &mut vec![])
+        .into_iter()
+        .map(|(derived, _)| format!("{name}{derived}"))
+        .collect()
 }
 
 /// The type of the mapping storage variable.
 enum MappingType {
-    /// Pedersen based.
+    /// Pedersen based, with a single key.
     Legacy,
-    /// Poseidon based.
+    /// Based on the `LegacyHash` implementations of the keys. Nested maps add keys.
     NonLegacy,
 }
 
-/// Given a type, if it is of form `Map{Legacy,}::<K, V>`, returns `K` and `V` and the mapping type.
-/// Otherwise, returns None.
+/// Given a type, if it is of form `Map{Legacy,}::<K, V>`, returns the keys, the value type and the
+/// mapping type. Otherwise, returns None.
+/// For nested maps, of the form `Map::<K1, Map::<K2, V>>`, returns all the keys (`K1` and `K2`) and
+/// the innermost value type (`V`).
 fn try_extract_mapping_types(
     db: &dyn SyntaxGroup,
     type_ast: &ast::Expr,
-) -> Option<(ast::Expr, ast::Expr, MappingType)> {
+) -> Option<(Vec<ast::Expr>, ast::Expr, MappingType)> {
+    let (key_ty, value_ty, ty) = try_extract_single_mapping_types(db, type_ast)?;
+    if ty == "LegacyMap" {
+        return Some((vec![key_ty], value_ty, MappingType::Legacy));
+    }
+    let mut key_tys = vec![key_ty];
+    let mut value_ty = value_ty;
+    while let Some((key_ty, inner_value_ty, ty)) = try_extract_single_mapping_types(db, &value_ty) {
+        if ty != "Map" {
+            break;
+        }
+        key_tys.push(key_ty);
+        value_ty = inner_value_ty;
+    }
+    Some((key_tys, value_ty, MappingType::NonLegacy))
+}
+
+/// Given a type, if it is of form `Map{Legacy,}::<K, V>`, returns `K`, `V` and the name of the
+/// mapping type. Otherwise, returns None.
+fn try_extract_single_mapping_types(
+    db: &dyn SyntaxGroup,
+    type_ast: &ast::Expr,
+) -> Option<(ast::Expr, ast::Expr, SmolStr)> {
     let as_path = try_extract_matches!(type_ast, ast::Expr::Path)?;
     let [ast::PathSegment::WithGenericArgs(segment)] = &as_path.elements(db)[..] else {
         return None;
@@ -693,11 +858,7 @@ fn try_extract_mapping_types(
         let [key_ty, value_ty] =
             <[ast::Expr; 2]>::try_from(segment.generic_args(db).generic_args(db).elements(db))
                 .ok()?;
-        Some((
-            key_ty,
-            value_ty,
-            if ty == "LegacyMap" { MappingType::Legacy } else { MappingType::NonLegacy },
-        ))
+        Some((key_ty, value_ty, ty))
     } else {
         None
     }
@@ -768,6 +929,50 @@ fn handle_legacy_mapping_storage_var(address: &str) -> String {
     )
 }
 
+/// Generate getters and setters skeleton for a `Map` member in the storage struct, with the given
+/// number of keys.
+fn handle_mapping_storage_var(address: &str, n_keys: usize) -> String {
+    let key_params = (0..n_keys).map(|i| format!("key{i}: $key_type_{i}$")).join(", ");
+    let key_args = (0..n_keys).map(|i| format!("key{i}")).join(", ");
+    let key_hashes = (0..n_keys)
+        .map(|i| {
+            format!(
+                "
+            let address = hash::LegacyHash::<$key_type_{i}$>::hash(address, key{i});"
+            )
+        })
+        .join("");
+    format!(
+        "
+    mod $storage_var_name$ {{$extra_uses$
+        use starknet::SyscallResultTrait;
+        use starknet::SyscallResultTraitImpl;
+
+        fn address({key_params}) -> starknet::StorageBaseAddress {{
+            let address = {address};{key_hashes}
+            starknet::storage_base_address_from_felt(address)
+        }}
+        fn read({key_params}) -> $value_type$ {{
+            // Only address_domain 0 is currently supported.
+            let address_domain = 0;
+            starknet::StorageAccess::<$value_type$>::read(
+                address_domain,
+                address({key_args}),
+            ).unwrap_syscall()
+        }}
+        fn write({key_params}, value: $value_type$) {{
+            // Only address_domain 0 is currently supported.
+            let address_domain = 0;
+            starknet::StorageAccess::<$value_type$>::write(
+                address_domain,
+                address({key_args}),
+                value,
+            ).unwrap_syscall()
+        }}
+    }}"
+    )
+}
+
 /// Generates Cairo code for an entry point wrapper.
 fn generate_entry_point_wrapper(
     db: &dyn SyntaxGroup,
@@ -0,0 +1,102 @@
+//! The Poseidon hash function over the StarkNet field, as used for computing class hashes.
+//!
+//! The hash is based on the Hades permutation of a state of 3 field elements, with 8 full rounds
+//! and 83 partial rounds.
+
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+use ark_ff::{Field, PrimeField};
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+#[path = "poseidon_test.rs"]
+mod test;
+
+#[derive(MontConfig)]
+#[modulus = "3618502788666131213697322783095070105623107215331596699973092056135872020481"]
+#[generator = "3"]
+struct FeltConfig;
+type Felt = Fp256<MontBackend<FeltConfig, 4>>;
+
+const N_FULL_ROUNDS: usize = 8;
+const N_PARTIAL_ROUNDS: usize = 83;
+const STATE_SIZE: usize = 3;
+
+lazy_static! {
+    /// The constants added to the state at the start of each round - the i-th constant is the
+    /// sha256 of "Hades{i}", reduced into the field.
+    static ref ROUND_CONSTANTS: Vec<[Felt; STATE_SIZE]> = (0..N_FULL_ROUNDS + N_PARTIAL_ROUNDS)
+        .map(|round| {
+            [0, 1, 2].map(|i| {
+                let digest = Sha256::digest(format!("Hades{}", round * STATE_SIZE + i));
+                Felt::from(BigUint::from_bytes_be(&digest))
+            })
+        })
+        .collect();
+}
+
+/// Applies the Hades permutation to `state`.
+pub fn hades_permutation(state: [BigUint; STATE_SIZE]) -> [BigUint; STATE_SIZE] {
+    let mut state = state.map(Felt::from);
+    permute(&mut state);
+    state.map(to_biguint)
+}
+
+/// Returns the Poseidon hash of a single value.
+pub fn poseidon_hash_single(x: &BigUint) -> BigUint {
+    let mut state = [Felt::from(x.clone()), Felt::from(0u32), Felt::from(1u32)];
+    permute(&mut state);
+    to_biguint(state[0])
+}
+
+/// Returns the Poseidon hash of two values.
+pub fn poseidon_hash(x: &BigUint, y: &BigUint) -> BigUint {
+    let mut state = [Felt::from(x.clone()), Felt::from(y.clone()), Felt::from(2u32)];
+    permute(&mut state);
+    to_biguint(state[0])
+}
+
+/// Returns the Poseidon hash of a sequence of values, of any length.
+///
+/// The values are padded with 1, and then with 0 if needed for an even length, and absorbed two at
+/// a time.
+pub fn poseidon_hash_many(values: &[BigUint]) -> BigUint {
+    let mut padded_values: Vec<Felt> = values.iter().cloned().map(Felt::from).collect();
+    padded_values.push(Felt::from(1u32));
+    if padded_values.len() % 2 == 1 {
+        padded_values.push(Felt::from(0u32));
+    }
+    let mut state = [Felt::from(0u32); STATE_SIZE];
+    for chunk in padded_values.chunks(2) {
+        state[0] += chunk[0];
+        state[1] += chunk[1];
+        permute(&mut state);
+    }
+    to_biguint(state[0])
+}
+
+/// Applies the Hades permutation to `state` in place.
+fn permute(state: &mut [Felt; STATE_SIZE]) {
+    for (round, constants) in ROUND_CONSTANTS.iter().enumerate() {
+        for (value, constant) in state.iter_mut().zip(constants) {
+            *value += constant;
+        }
+        let partial_rounds = N_FULL_ROUNDS / 2..N_FULL_ROUNDS / 2 + N_PARTIAL_ROUNDS;
+        if !partial_rounds.contains(&round) {
+            for value in state.iter_mut() {
+                *value *= value.square();
+            }
+        } else {
+            state[2] *= state[2].square();
+        }
+        // Multiplying by the MDS matrix [[3, 1, 1], [1, -1, 1], [1, 1, -2]].
+        let [a, b, c] = *state;
+        let sum = a + b + c;
+        *state = [sum + a.double(), sum - b.double(), sum - c.double() - c];
+    }
+}
+
+fn to_biguint(value: Felt) -> BigUint {
+    value.into_bigint().into()
+}
@@ -0,0 +1,80 @@
+use num_bigint::BigUint;
+use num_traits::Num;
+use test_case::test_case;
+
+use super::{hades_permutation, poseidon_hash, poseidon_hash_many, poseidon_hash_single};
+
+/// Parses a hex string, with an optional `0x` prefix, as a BigUint.
+fn from_hex(value: &str) -> BigUint {
+    BigUint::from_str_radix(value.trim_start_matches("0x"), 16).unwrap()
+}
+
+// Matches `poseidon_permute_comp` of the `starknet-crypto` crate (v0.6.2).
+#[test]
+fn test_hades_permutation() {
+    assert_eq!(
+        hades_permutation([1u32, 2, 3].map(BigUint::from)),
+        [
+            "0xfa8c9b6742b6176139365833d001e30e932a9bf7456d009b1b174f36d558c5",
+            "0x4f04deca4cb7f9f2bd16b1d25b817ca2d16fba2151e4252a2e2111cde08bfe6",
+            "0x58dde0a2a785b395ee2dc7b60b79e9472ab826e9bb5383a8018b59772964892",
+        ]
+        .map(from_hex)
+    );
+}
+
+// The expected hashes below are generated by `cairo-lang` v0.11.0.
+
+#[test_case(
+    "0x9dad5d6f502ccbcb6d34ede04f0337df3b98936aaf782f4cc07d147e3a4fd6",
+    "0x11222854783f17f1c580ff64671bc3868de034c236f956216e8ed4ab7533455";
+    "first"
+)]
+#[test_case(
+    "0x3164a8e2181ff7b83391b4a86bc8967f145c38f10f35fc74e9359a0c78f7b6",
+    "0x79ad7aa7b98d47705446fa01865942119026ac748d67a5840f06948bce2306b";
+    "second"
+)]
+fn test_poseidon_hash_single(x: &str, expected: &str) {
+    assert_eq!(poseidon_hash_single(&from_hex(x)), from_hex(expected));
+}
+
+#[test_case(
+    "0xb662f9017fa7956fd70e26129b1833e10ad000fd37b4d9f4e0ce6884b7bbe",
+    "0x1fe356bf76102cdae1bfbdc173602ead228b12904c00dad9cf16e035468bea",
+    "0x75540825a6ecc5dc7d7c2f5f868164182742227f1367d66c43ee51ec7937a81";
+    "first"
+)]
+#[test_case(
+    "0xf4e01b2032298f86b539e3d3ac05ced20d2ef275273f9325f8827717156529",
+    "0x587bc46f5f58e0511b93c31134652a689d761a9e7f234f0f130c52e4679f3a",
+    "0xbdb3180fdcfd6d6f172beb401af54dd71b6569e6061767234db2b777adf98b";
+    "second"
+)]
+fn test_poseidon_hash(x: &str, y: &str, expected: &str) {
+    assert_eq!(poseidon_hash(&from_hex(x), &from_hex(y)), from_hex(expected));
+}
+
+#[test_case(
+    &[
+        "0x9bf52404586087391c5fbb42538692e7ca2149bac13c145ae4230a51a6fc47",
+        "0x40304159ee9d2d611120fbd7c7fb8020cc8f7a599bfa108e0e085222b862c0",
+        "0x46286e4f3c450761d960d6a151a9c0988f9e16f8a48d4c0a85817c009f806a",
+    ],
+    "0x1ec38b38dc88bac7b0ed6ff6326f975a06a59ac601b417745fd412a5d38e4f7";
+    "odd"
+)]
+#[test_case(
+    &[
+        "0xbdace8883922662601b2fd197bb660b081fcf383ede60725bd080d4b5f2fd3",
+        "0x1eb1daaf3fdad326b959dec70ced23649cdf8786537cee0c5758a1a4229097",
+        "0x869ca04071b779d6f940cdf33e62d51521e19223ab148ef571856ff3a44ff1",
+        "0x533e6df8d7c4b634b1f27035c8676a7439c635e1fea356484de7f0de677930",
+    ],
+    "0x2520b8f910174c3e650725baacad4efafaae7623c69a0b5513d75e500f36624";
+    "even"
+)]
+fn test_poseidon_hash_many(values: &[&str], expected: &str) {
+    let values: Vec<_> = values.iter().copied().map(from_hex).collect();
+    assert_eq!(poseidon_hash_many(&values), from_hex(expected));
+}
@@ -14,6 +14,10 @@ struct Args {
     file: String,
     /// The output file name (default: stdout).
     output: Option<String>,
+    /// Prints the compiled class hash of the contract to stdout, instead of the compiled contract
+    /// class.
+    #[arg(long, default_value_t = false)]
+    compiled_class_hash: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -34,8 +38,12 @@ fn main() -> anyhow::Result<()> {
 
     match args.output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
+        None if args.compiled_class_hash => {}
         None => println!("{res}"),
     }
+    if args.compiled_class_hash {
+        println!("{:#x}", casm_contract.compiled_class_hash());
+    }
 
     Ok(())
 }
@@ -30,4 +30,9 @@ mod TestContract {
         let foo_address = starknet::contract_address_const::<17>();
         super::IAnotherContractDispatcher::foo(foo_address, a)
     }
+
+    #[l1_handler]
+    fn handle_message(from_address: felt, value: felt) {
+        my_storage_var::write(from_address + value);
+    }
 }
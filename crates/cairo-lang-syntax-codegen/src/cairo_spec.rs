@@ -29,6 +29,8 @@ pub fn get_spec() -> Vec<Node> {
             .node("Block")
             .node("Match")
             .node("If")
+            .node("Loop")
+            .node("While")
             .node("ErrorPropagate")
             .node("FieldInitShorthand"),
     )
@@ -159,6 +161,15 @@ pub fn get_spec() -> Vec<Node> {
         .node("else_block_or_if", "BlockOrIf")
     )
     .add_option("ElseClause")
+    .add_struct(StructBuilder::new("ExprLoop")
+        .node("loop_kw", "TerminalLoop")
+        .node("body", "ExprBlock")
+    )
+    .add_struct(StructBuilder::new("ExprWhile")
+        .node("while_kw", "TerminalWhile")
+        .node("condition", "Expr")
+        .node("body", "ExprBlock")
+    )
     .add_struct(StructBuilder::new("ExprErrorPropagate").node("expr", "Expr").node("op", "TerminalQuestionMark"))
     // --- Struct ctor ---
     .add_struct(StructBuilder::new("StructArgExpr")
@@ -247,6 +258,8 @@ pub fn get_spec() -> Vec<Node> {
         .node("Let")
         .node("Expr")
         .node("Return")
+        .node("Break")
+        .node("Continue")
     )
     .add_list("StatementList", "Statement")
     .add_struct(StructBuilder::new("StatementMissing"))
@@ -268,6 +281,17 @@ pub fn get_spec() -> Vec<Node> {
         .node("expr", "Expr")
         .node("semicolon", "TerminalSemicolon")
     )
+    .add_struct(StructBuilder::new("ExprClause").node("expr", "Expr"))
+    .add_option("ExprClause")
+    .add_struct(StructBuilder::new("StatementBreak")
+        .node("break_kw", "TerminalBreak")
+        .node("expr_clause", "OptionExprClause")
+        .node("semicolon", "TerminalSemicolon")
+    )
+    .add_struct(StructBuilder::new("StatementContinue")
+        .node("continue_kw", "TerminalContinue")
+        .node("semicolon", "TerminalSemicolon")
+    )
     // --- Functions ---
     .add_struct(StructBuilder::new("Param")
         .node("modifiers", "ModifierList")
@@ -507,7 +531,9 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("Identifier")
     .add_token_and_terminal("LiteralNumber")
     .add_token_and_terminal("ShortString")
+    .add_keyword_token_and_terminal("Break")
     .add_keyword_token_and_terminal("Const")
+    .add_keyword_token_and_terminal("Continue")
     .add_keyword_token_and_terminal("Else")
     .add_keyword_token_and_terminal("Enum")
     .add_keyword_token_and_terminal("Extern")
@@ -517,6 +543,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("Impl")
     .add_keyword_token_and_terminal("Implicits")
     .add_keyword_token_and_terminal("Let")
+    .add_keyword_token_and_terminal("Loop")
     .add_keyword_token_and_terminal("Match")
     .add_keyword_token_and_terminal("Module")
     .add_keyword_token_and_terminal("Mut")
@@ -529,6 +556,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("True")
     .add_keyword_token_and_terminal("Type")
     .add_keyword_token_and_terminal("Use")
+    .add_keyword_token_and_terminal("While")
     .add_token_and_terminal("And")
     .add_token_and_terminal("AndAnd")
     .add_token_and_terminal("Arrow")
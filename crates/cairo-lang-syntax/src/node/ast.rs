@@ -176,6 +176,8 @@ pub enum Expr {
     Block(ExprBlock),
     Match(ExprMatch),
     If(ExprIf),
+    Loop(ExprLoop),
+    While(ExprWhile),
     ErrorPropagate(ExprErrorPropagate),
     FieldInitShorthand(ExprFieldInitShorthand),
     Missing(ExprMissing),
@@ -257,6 +259,16 @@ impl From<ExprIfPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprLoopPtr> for ExprPtr {
+    fn from(value: ExprLoopPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprWhilePtr> for ExprPtr {
+    fn from(value: ExprWhilePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagatePtr> for ExprPtr {
     fn from(value: ExprErrorPropagatePtr) -> Self {
         Self(value.0)
@@ -342,6 +354,16 @@ impl From<ExprIfGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprLoopGreen> for ExprGreen {
+    fn from(value: ExprLoopGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<ExprWhileGreen> for ExprGreen {
+    fn from(value: ExprWhileGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagateGreen> for ExprGreen {
     fn from(value: ExprErrorPropagateGreen) -> Self {
         Self(value.0)
@@ -393,6 +415,8 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprBlock => Expr::Block(ExprBlock::from_syntax_node(db, node)),
             SyntaxKind::ExprMatch => Expr::Match(ExprMatch::from_syntax_node(db, node)),
             SyntaxKind::ExprIf => Expr::If(ExprIf::from_syntax_node(db, node)),
+            SyntaxKind::ExprLoop => Expr::Loop(ExprLoop::from_syntax_node(db, node)),
+            SyntaxKind::ExprWhile => Expr::While(ExprWhile::from_syntax_node(db, node)),
             SyntaxKind::ExprErrorPropagate => {
                 Expr::ErrorPropagate(ExprErrorPropagate::from_syntax_node(db, node))
             }
@@ -419,6 +443,8 @@ impl TypedSyntaxNode for Expr {
             Expr::Block(x) => x.as_syntax_node(),
             Expr::Match(x) => x.as_syntax_node(),
             Expr::If(x) => x.as_syntax_node(),
+            Expr::Loop(x) => x.as_syntax_node(),
+            Expr::While(x) => x.as_syntax_node(),
             Expr::ErrorPropagate(x) => x.as_syntax_node(),
             Expr::FieldInitShorthand(x) => x.as_syntax_node(),
             Expr::Missing(x) => x.as_syntax_node(),
@@ -3141,6 +3167,161 @@ impl TypedSyntaxNode for OptionElseClauseEmpty {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprLoop {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprLoop {
+    pub const INDEX_LOOP_KW: usize = 0;
+    pub const INDEX_BODY: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        loop_kw: TerminalLoopGreen,
+        body: ExprBlockGreen,
+    ) -> ExprLoopGreen {
+        let children: Vec<GreenId> = vec![loop_kw.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprLoop,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprLoop {
+    pub fn loop_kw(&self, db: &dyn SyntaxGroup) -> TerminalLoop {
+        TerminalLoop::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprLoopPtr(pub SyntaxStablePtrId);
+impl ExprLoopPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprLoopGreen(pub GreenId);
+impl TypedSyntaxNode for ExprLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprLoop);
+    type StablePtr = ExprLoopPtr;
+    type Green = ExprLoopGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprLoop,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalLoop::missing(db).0, ExprBlock::missing(db).0],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprLoop,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprLoop
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprLoopPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprWhile {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl ExprWhile {
+    pub const INDEX_WHILE_KW: usize = 0;
+    pub const INDEX_CONDITION: usize = 1;
+    pub const INDEX_BODY: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        while_kw: TerminalWhileGreen,
+        condition: ExprGreen,
+        body: ExprBlockGreen,
+    ) -> ExprWhileGreen {
+        let children: Vec<GreenId> = vec![while_kw.0, condition.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl ExprWhile {
+    pub fn while_kw(&self, db: &dyn SyntaxGroup) -> TerminalWhile {
+        TerminalWhile::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn condition(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprWhilePtr(pub SyntaxStablePtrId);
+impl ExprWhilePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprWhileGreen(pub GreenId);
+impl TypedSyntaxNode for ExprWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprWhile);
+    type StablePtr = ExprWhilePtr;
+    type Green = ExprWhileGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprWhile,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalWhile::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprWhile,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprWhile
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprWhilePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ExprErrorPropagate {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
@@ -5013,6 +5194,8 @@ pub enum Statement {
     Let(StatementLet),
     Expr(StatementExpr),
     Return(StatementReturn),
+    Break(StatementBreak),
+    Continue(StatementContinue),
     Missing(StatementMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -5037,6 +5220,16 @@ impl From<StatementReturnPtr> for StatementPtr {
         Self(value.0)
     }
 }
+impl From<StatementBreakPtr> for StatementPtr {
+    fn from(value: StatementBreakPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StatementContinuePtr> for StatementPtr {
+    fn from(value: StatementContinuePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<StatementMissingPtr> for StatementPtr {
     fn from(value: StatementMissingPtr) -> Self {
         Self(value.0)
@@ -5057,6 +5250,16 @@ impl From<StatementReturnGreen> for StatementGreen {
         Self(value.0)
     }
 }
+impl From<StatementBreakGreen> for StatementGreen {
+    fn from(value: StatementBreakGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StatementContinueGreen> for StatementGreen {
+    fn from(value: StatementContinueGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<StatementMissingGreen> for StatementGreen {
     fn from(value: StatementMissingGreen) -> Self {
         Self(value.0)
@@ -5079,6 +5282,12 @@ impl TypedSyntaxNode for Statement {
             SyntaxKind::StatementReturn => {
                 Statement::Return(StatementReturn::from_syntax_node(db, node))
             }
+            SyntaxKind::StatementBreak => {
+                Statement::Break(StatementBreak::from_syntax_node(db, node))
+            }
+            SyntaxKind::StatementContinue => {
+                Statement::Continue(StatementContinue::from_syntax_node(db, node))
+            }
             SyntaxKind::StatementMissing => {
                 Statement::Missing(StatementMissing::from_syntax_node(db, node))
             }
@@ -5090,6 +5299,8 @@ impl TypedSyntaxNode for Statement {
             Statement::Let(x) => x.as_syntax_node(),
             Statement::Expr(x) => x.as_syntax_node(),
             Statement::Return(x) => x.as_syntax_node(),
+            Statement::Break(x) => x.as_syntax_node(),
+            Statement::Continue(x) => x.as_syntax_node(),
             Statement::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -5600,69 +5811,44 @@ impl TypedSyntaxNode for StatementReturn {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Param {
+pub struct ExprClause {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Param {
-    pub const INDEX_MODIFIERS: usize = 0;
-    pub const INDEX_NAME: usize = 1;
-    pub const INDEX_TYPE_CLAUSE: usize = 2;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        modifiers: ModifierListGreen,
-        name: TerminalIdentifierGreen,
-        type_clause: TypeClauseGreen,
-    ) -> ParamGreen {
-        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+impl ExprClause {
+    pub const INDEX_EXPR: usize = 0;
+    pub fn new_green(db: &dyn SyntaxGroup, expr: ExprGreen) -> ExprClauseGreen {
+        let children: Vec<GreenId> = vec![expr.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        ParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Param,
+        ExprClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClause,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
 }
-impl Param {
-    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
-        ModifierList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[2].clone())
+impl ExprClause {
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[0].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamPtr(pub SyntaxStablePtrId);
-impl ParamPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct ExprClausePtr(pub SyntaxStablePtrId);
+impl ExprClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ParamGreen(pub GreenId);
-impl TypedSyntaxNode for Param {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Param);
-    type StablePtr = ParamPtr;
-    type Green = ParamGreen;
+pub struct ExprClauseGreen(pub GreenId);
+impl TypedSyntaxNode for ExprClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprClause);
+    type StablePtr = ExprClausePtr;
+    type Green = ExprClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ParamGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::Param,
+        ExprClauseGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ExprClause,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    ModifierList::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    TypeClause::missing(db).0,
-                ],
+                children: vec![Expr::missing(db).0],
                 width: TextWidth::default(),
             },
         }))
@@ -5671,10 +5857,10 @@ impl TypedSyntaxNode for Param {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::Param,
+            SyntaxKind::ExprClause,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::Param
+            SyntaxKind::ExprClause
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -5686,109 +5872,480 @@ impl TypedSyntaxNode for Param {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ParamPtr(self.node.0.stable_ptr)
-    }
-}
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct ModifierList(ElementList<Modifier, 1>);
-impl Deref for ModifierList {
-    type Target = ElementList<Modifier, 1>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-impl ModifierList {
-    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ModifierGreen>) -> ModifierListGreen {
-        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
-        ModifierListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModifierList,
-            details: GreenNodeDetails::Node {
-                children: children.iter().map(|x| x.0).collect(),
-                width,
-            },
-        }))
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierListPtr(pub SyntaxStablePtrId);
-impl ModifierListPtr {
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
-    }
-}
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierListGreen(pub GreenId);
-impl TypedSyntaxNode for ModifierList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModifierList);
-    type StablePtr = ModifierListPtr;
-    type Green = ModifierListGreen;
-    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        ModifierListGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::ModifierList,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
-        }))
-    }
-    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
-    }
-    fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
-    }
-    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
-        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
-    }
-    fn stable_ptr(&self) -> Self::StablePtr {
-        ModifierListPtr(self.node.0.stable_ptr)
+        ExprClausePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum Modifier {
-    Ref(TerminalRef),
-    Mut(TerminalMut),
+pub enum OptionExprClause {
+    Empty(OptionExprClauseEmpty),
+    ExprClause(ExprClause),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierPtr(pub SyntaxStablePtrId);
-impl ModifierPtr {
+pub struct OptionExprClausePtr(pub SyntaxStablePtrId);
+impl OptionExprClausePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
-impl From<TerminalRefPtr> for ModifierPtr {
-    fn from(value: TerminalRefPtr) -> Self {
+impl From<OptionExprClauseEmptyPtr> for OptionExprClausePtr {
+    fn from(value: OptionExprClauseEmptyPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalMutPtr> for ModifierPtr {
-    fn from(value: TerminalMutPtr) -> Self {
+impl From<ExprClausePtr> for OptionExprClausePtr {
+    fn from(value: ExprClausePtr) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalRefGreen> for ModifierGreen {
-    fn from(value: TerminalRefGreen) -> Self {
+impl From<OptionExprClauseEmptyGreen> for OptionExprClauseGreen {
+    fn from(value: OptionExprClauseEmptyGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<TerminalMutGreen> for ModifierGreen {
-    fn from(value: TerminalMutGreen) -> Self {
+impl From<ExprClauseGreen> for OptionExprClauseGreen {
+    fn from(value: ExprClauseGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ModifierGreen(pub GreenId);
-impl TypedSyntaxNode for Modifier {
+pub struct OptionExprClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionExprClause {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = ModifierPtr;
-    type Green = ModifierGreen;
+    type StablePtr = OptionExprClausePtr;
+    type Green = OptionExprClauseGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::TerminalRef => Modifier::Ref(TerminalRef::from_syntax_node(db, node)),
-            SyntaxKind::TerminalMut => Modifier::Mut(TerminalMut::from_syntax_node(db, node)),
-            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Modifier"),
-        }
+            SyntaxKind::OptionExprClauseEmpty => {
+                OptionExprClause::Empty(OptionExprClauseEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::ExprClause => {
+                OptionExprClause::ExprClause(ExprClause::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionExprClause"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionExprClause::Empty(x) => x.as_syntax_node(),
+            OptionExprClause::ExprClause(x) => x.as_syntax_node(),
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionExprClausePtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionExprClauseEmpty {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl OptionExprClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionExprClauseEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionExprClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionExprClauseEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl OptionExprClauseEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionExprClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionExprClauseEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionExprClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionExprClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionExprClauseEmpty);
+    type StablePtr = OptionExprClauseEmptyPtr;
+    type Green = OptionExprClauseEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionExprClauseEmptyGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::OptionExprClauseEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionExprClauseEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionExprClauseEmpty
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionExprClauseEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementBreak {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StatementBreak {
+    pub const INDEX_BREAK_KW: usize = 0;
+    pub const INDEX_EXPR_CLAUSE: usize = 1;
+    pub const INDEX_SEMICOLON: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        break_kw: TerminalBreakGreen,
+        expr_clause: OptionExprClauseGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementBreakGreen {
+        let children: Vec<GreenId> = vec![break_kw.0, expr_clause.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementBreak,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementBreak {
+    pub fn break_kw(&self, db: &dyn SyntaxGroup) -> TerminalBreak {
+        TerminalBreak::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn expr_clause(&self, db: &dyn SyntaxGroup) -> OptionExprClause {
+        OptionExprClause::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementBreakPtr(pub SyntaxStablePtrId);
+impl StatementBreakPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementBreakGreen(pub GreenId);
+impl TypedSyntaxNode for StatementBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementBreak);
+    type StablePtr = StatementBreakPtr;
+    type Green = StatementBreakGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementBreak,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalBreak::missing(db).0,
+                    OptionExprClause::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementBreak,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementBreak
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementBreakPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatementContinue {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl StatementContinue {
+    pub const INDEX_CONTINUE_KW: usize = 0;
+    pub const INDEX_SEMICOLON: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        continue_kw: TerminalContinueGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementContinueGreen {
+        let children: Vec<GreenId> = vec![continue_kw.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementContinue,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl StatementContinue {
+    pub fn continue_kw(&self, db: &dyn SyntaxGroup) -> TerminalContinue {
+        TerminalContinue::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementContinuePtr(pub SyntaxStablePtrId);
+impl StatementContinuePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementContinueGreen(pub GreenId);
+impl TypedSyntaxNode for StatementContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementContinue);
+    type StablePtr = StatementContinuePtr;
+    type Green = StatementContinueGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::StatementContinue,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalContinue::missing(db).0, TerminalSemicolon::missing(db).0],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementContinue,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementContinue
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementContinuePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Param {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Param {
+    pub const INDEX_MODIFIERS: usize = 0;
+    pub const INDEX_NAME: usize = 1;
+    pub const INDEX_TYPE_CLAUSE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        modifiers: ModifierListGreen,
+        name: TerminalIdentifierGreen,
+        type_clause: TypeClauseGreen,
+    ) -> ParamGreen {
+        let children: Vec<GreenId> = vec![modifiers.0, name.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Param,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+}
+impl Param {
+    pub fn modifiers(&self, db: &dyn SyntaxGroup) -> ModifierList {
+        ModifierList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamPtr(pub SyntaxStablePtrId);
+impl ParamPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ParamGreen(pub GreenId);
+impl TypedSyntaxNode for Param {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Param);
+    type StablePtr = ParamPtr;
+    type Green = ParamGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ParamGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::Param,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ModifierList::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TypeClause::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::Param,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::Param
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ParamPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModifierList(ElementList<Modifier, 1>);
+impl Deref for ModifierList {
+    type Target = ElementList<Modifier, 1>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ModifierList {
+    pub fn new_green(db: &dyn SyntaxGroup, children: Vec<ModifierGreen>) -> ModifierListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.0).width()).sum();
+        ModifierListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModifierList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.0).collect(),
+                width,
+            },
+        }))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierListPtr(pub SyntaxStablePtrId);
+impl ModifierListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierListGreen(pub GreenId);
+impl TypedSyntaxNode for ModifierList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ModifierList);
+    type StablePtr = ModifierListPtr;
+    type Green = ModifierListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ModifierListGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::ModifierList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ModifierListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Modifier {
+    Ref(TerminalRef),
+    Mut(TerminalMut),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierPtr(pub SyntaxStablePtrId);
+impl ModifierPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+impl From<TerminalRefPtr> for ModifierPtr {
+    fn from(value: TerminalRefPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMutPtr> for ModifierPtr {
+    fn from(value: TerminalMutPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalRefGreen> for ModifierGreen {
+    fn from(value: TerminalRefGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalMutGreen> for ModifierGreen {
+    fn from(value: TerminalMutGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ModifierGreen(pub GreenId);
+impl TypedSyntaxNode for Modifier {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = ModifierPtr;
+    type Green = ModifierGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalRef => Modifier::Ref(TerminalRef::from_syntax_node(db, node)),
+            SyntaxKind::TerminalMut => Modifier::Mut(TerminalMut::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Modifier"),
+        }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
@@ -10375,12 +10932,294 @@ impl TypedSyntaxNode for TerminalShortString {
     type StablePtr = TerminalShortStringPtr;
     type Green = TerminalShortStringGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalShortStringGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalShortString,
+        TerminalShortStringGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalShortString,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenShortString::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalShortString,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalShortString
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalShortStringPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenBreak {
+    node: SyntaxNode,
+}
+impl Token for TokenBreak {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenBreak,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenBreakPtr(pub SyntaxStablePtrId);
+impl TokenBreakPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenBreakGreen(pub GreenId);
+impl TokenBreakGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenBreak);
+    type StablePtr = TokenBreakPtr;
+    type Green = TokenBreakGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenBreak)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenBreakPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalBreak {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalBreak {
+    const KIND: SyntaxKind = SyntaxKind::TerminalBreak;
+    type TokenType = TokenBreak;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalBreak as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalBreak,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalBreak {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenBreak {
+        TokenBreak::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalBreakPtr(pub SyntaxStablePtrId);
+impl TerminalBreakPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalBreakGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalBreak {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalBreak);
+    type StablePtr = TerminalBreakPtr;
+    type Green = TerminalBreakGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalBreakGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalBreak,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenBreak::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalBreak,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalBreak
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalBreakPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenConst {
+    node: SyntaxNode,
+}
+impl Token for TokenConst {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenConst,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenConstPtr(pub SyntaxStablePtrId);
+impl TokenConstPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenConstGreen(pub GreenId);
+impl TokenConstGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenConst);
+    type StablePtr = TokenConstPtr;
+    type Green = TokenConstGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenConst)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenConstPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalConst {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalConst {
+    const KIND: SyntaxKind = SyntaxKind::TerminalConst;
+    type TokenType = TokenConst;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalConst as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalConst {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenConst {
+        TokenConst::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalConstPtr(pub SyntaxStablePtrId);
+impl TerminalConstPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalConstGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalConst {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalConst);
+    type StablePtr = TerminalConstPtr;
+    type Green = TerminalConstGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalConstGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalConst,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenShortString::missing(db).0,
+                    TokenConst::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -10391,10 +11230,10 @@ impl TypedSyntaxNode for TerminalShortString {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalShortString,
+            SyntaxKind::TerminalConst,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalShortString
+            SyntaxKind::TerminalConst
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10406,17 +11245,17 @@ impl TypedSyntaxNode for TerminalShortString {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalShortStringPtr(self.node.0.stable_ptr)
+        TerminalConstPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TokenConst {
+pub struct TokenContinue {
     node: SyntaxNode,
 }
-impl Token for TokenConst {
+impl Token for TokenContinue {
     fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
-        TokenConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TokenConst,
+        TokenContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenContinue,
             details: GreenNodeDetails::Token(text),
         }))
     }
@@ -10425,25 +11264,25 @@ impl Token for TokenConst {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenConstPtr(pub SyntaxStablePtrId);
-impl TokenConstPtr {
+pub struct TokenContinuePtr(pub SyntaxStablePtrId);
+impl TokenContinuePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TokenConstGreen(pub GreenId);
-impl TokenConstGreen {
+pub struct TokenContinueGreen(pub GreenId);
+impl TokenContinueGreen {
     pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
         extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
     }
 }
-impl TypedSyntaxNode for TokenConst {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenConst);
-    type StablePtr = TokenConstPtr;
-    type Green = TokenConstGreen;
+impl TypedSyntaxNode for TokenContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenContinue);
+    type StablePtr = TokenContinuePtr;
+    type Green = TokenContinueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TokenConstGreen(db.intern_green(GreenNode {
+        TokenContinueGreen(db.intern_green(GreenNode {
             kind: SyntaxKind::TokenMissing,
             details: GreenNodeDetails::Token("".into()),
         }))
@@ -10452,7 +11291,7 @@ impl TypedSyntaxNode for TokenConst {
         match db.lookup_intern_green(node.0.green).details {
             GreenNodeDetails::Token(_) => Self { node },
             GreenNodeDetails::Node { .. } => {
-                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenConst)
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenContinue)
             }
         }
     }
@@ -10463,27 +11302,27 @@ impl TypedSyntaxNode for TokenConst {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TokenConstPtr(self.node.0.stable_ptr)
+        TokenContinuePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct TerminalConst {
+pub struct TerminalContinue {
     node: SyntaxNode,
     children: Vec<SyntaxNode>,
 }
-impl Terminal for TerminalConst {
-    const KIND: SyntaxKind = SyntaxKind::TerminalConst;
-    type TokenType = TokenConst;
+impl Terminal for TerminalContinue {
+    const KIND: SyntaxKind = SyntaxKind::TerminalContinue;
+    type TokenType = TokenContinue;
     fn new_green(
         db: &dyn SyntaxGroup,
         leading_trivia: TriviaGreen,
-        token: <<TerminalConst as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        token: <<TerminalContinue as Terminal>::TokenType as TypedSyntaxNode>::Green,
         trailing_trivia: TriviaGreen,
     ) -> Self::Green {
         let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        TerminalConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalConst,
+        TerminalContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalContinue,
             details: GreenNodeDetails::Node { children, width },
         }))
     }
@@ -10491,37 +11330,37 @@ impl Terminal for TerminalConst {
         self.token(db).text(db)
     }
 }
-impl TerminalConst {
+impl TerminalContinue {
     pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenConst {
-        TokenConst::from_syntax_node(db, self.children[1].clone())
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenContinue {
+        TokenContinue::from_syntax_node(db, self.children[1].clone())
     }
     pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
         Trivia::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalConstPtr(pub SyntaxStablePtrId);
-impl TerminalConstPtr {
+pub struct TerminalContinuePtr(pub SyntaxStablePtrId);
+impl TerminalContinuePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct TerminalConstGreen(pub GreenId);
-impl TypedSyntaxNode for TerminalConst {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalConst);
-    type StablePtr = TerminalConstPtr;
-    type Green = TerminalConstGreen;
+pub struct TerminalContinueGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalContinue {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalContinue);
+    type StablePtr = TerminalContinuePtr;
+    type Green = TerminalContinueGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        TerminalConstGreen(db.intern_green(GreenNode {
-            kind: SyntaxKind::TerminalConst,
+        TerminalContinueGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalContinue,
             details: GreenNodeDetails::Node {
                 children: vec![
                     Trivia::missing(db).0,
-                    TokenConst::missing(db).0,
+                    TokenContinue::missing(db).0,
                     Trivia::missing(db).0,
                 ],
                 width: TextWidth::default(),
@@ -10532,10 +11371,10 @@ impl TypedSyntaxNode for TerminalConst {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::TerminalConst,
+            SyntaxKind::TerminalContinue,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::TerminalConst
+            SyntaxKind::TerminalContinue
         );
         let children = node.children(db).collect();
         Self { node, children }
@@ -10547,7 +11386,7 @@ impl TypedSyntaxNode for TerminalConst {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        TerminalConstPtr(self.node.0.stable_ptr)
+        TerminalContinuePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -11820,6 +12659,147 @@ impl TypedSyntaxNode for TerminalLet {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenLoop {
+    node: SyntaxNode,
+}
+impl Token for TokenLoop {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenLoop,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLoopPtr(pub SyntaxStablePtrId);
+impl TokenLoopPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenLoopGreen(pub GreenId);
+impl TokenLoopGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenLoop);
+    type StablePtr = TokenLoopPtr;
+    type Green = TokenLoopGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenLoop)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenLoopPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalLoop {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalLoop {
+    const KIND: SyntaxKind = SyntaxKind::TerminalLoop;
+    type TokenType = TokenLoop;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalLoop as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalLoop {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenLoop {
+        TokenLoop::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalLoopPtr(pub SyntaxStablePtrId);
+impl TerminalLoopPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalLoopGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalLoop {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalLoop);
+    type StablePtr = TerminalLoopPtr;
+    type Green = TerminalLoopGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalLoopGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalLoop,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenLoop::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalLoop,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalLoop
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalLoopPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenMatch {
     node: SyntaxNode,
 }
@@ -13512,6 +14492,147 @@ impl TypedSyntaxNode for TerminalUse {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenWhile {
+    node: SyntaxNode,
+}
+impl Token for TokenWhile {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenWhile,
+            details: GreenNodeDetails::Token(text),
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.node.0.green).details, GreenNodeDetails::Token)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenWhilePtr(pub SyntaxStablePtrId);
+impl TokenWhilePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenWhileGreen(pub GreenId);
+impl TokenWhileGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(db.lookup_intern_green(self.0).details, GreenNodeDetails::Token)
+    }
+}
+impl TypedSyntaxNode for TokenWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenWhile);
+    type StablePtr = TokenWhilePtr;
+    type Green = TokenWhileGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenWhile)
+            }
+        }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenWhilePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalWhile {
+    node: SyntaxNode,
+    children: Vec<SyntaxNode>,
+}
+impl Terminal for TerminalWhile {
+    const KIND: SyntaxKind = SyntaxKind::TerminalWhile;
+    type TokenType = TokenWhile;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalWhile as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
+            details: GreenNodeDetails::Node { children, width },
+        }))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalWhile {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenWhile {
+        TokenWhile::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalWhilePtr(pub SyntaxStablePtrId);
+impl TerminalWhilePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalWhileGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalWhile {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalWhile);
+    type StablePtr = TerminalWhilePtr;
+    type Green = TerminalWhileGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalWhileGreen(db.intern_green(GreenNode {
+            kind: SyntaxKind::TerminalWhile,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenWhile::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        }))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalWhile,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalWhile
+        );
+        let children = node.children(db).collect();
+        Self { node, children }
+    }
+    fn from_ptr(db: &dyn SyntaxGroup, root: &SyntaxFile, ptr: Self::StablePtr) -> Self {
+        Self::from_syntax_node(db, root.as_syntax_node().lookup_ptr(db, ptr.0))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalWhilePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenAnd {
     node: SyntaxNode,
 }
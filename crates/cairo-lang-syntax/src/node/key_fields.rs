@@ -34,6 +34,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::ExprIf => vec![],
         SyntaxKind::ElseClause => vec![],
         SyntaxKind::OptionElseClauseEmpty => vec![],
+        SyntaxKind::ExprLoop => vec![],
+        SyntaxKind::ExprWhile => vec![],
         SyntaxKind::ExprErrorPropagate => vec![],
         SyntaxKind::StructArgExpr => vec![],
         SyntaxKind::OptionStructArgExprEmpty => vec![],
@@ -58,6 +60,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::OptionTerminalSemicolonEmpty => vec![],
         SyntaxKind::StatementExpr => vec![],
         SyntaxKind::StatementReturn => vec![],
+        SyntaxKind::ExprClause => vec![],
+        SyntaxKind::OptionExprClauseEmpty => vec![],
+        SyntaxKind::StatementBreak => vec![],
+        SyntaxKind::StatementContinue => vec![],
         SyntaxKind::Param => vec![/* name */ children[1]],
         SyntaxKind::ModifierList => vec![],
         SyntaxKind::ParamList => vec![],
@@ -105,8 +111,12 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalLiteralNumber => vec![],
         SyntaxKind::TokenShortString => vec![],
         SyntaxKind::TerminalShortString => vec![],
+        SyntaxKind::TokenBreak => vec![],
+        SyntaxKind::TerminalBreak => vec![],
         SyntaxKind::TokenConst => vec![],
         SyntaxKind::TerminalConst => vec![],
+        SyntaxKind::TokenContinue => vec![],
+        SyntaxKind::TerminalContinue => vec![],
         SyntaxKind::TokenElse => vec![],
         SyntaxKind::TerminalElse => vec![],
         SyntaxKind::TokenEnum => vec![],
@@ -125,6 +135,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalImplicits => vec![],
         SyntaxKind::TokenLet => vec![],
         SyntaxKind::TerminalLet => vec![],
+        SyntaxKind::TokenLoop => vec![],
+        SyntaxKind::TerminalLoop => vec![],
         SyntaxKind::TokenMatch => vec![],
         SyntaxKind::TerminalMatch => vec![],
         SyntaxKind::TokenModule => vec![],
@@ -149,6 +161,8 @@ pub fn get_key_fields(kind: SyntaxKind, children: Vec<GreenId>) -> Vec<GreenId>
         SyntaxKind::TerminalType => vec![],
         SyntaxKind::TokenUse => vec![],
         SyntaxKind::TerminalUse => vec![],
+        SyntaxKind::TokenWhile => vec![],
+        SyntaxKind::TerminalWhile => vec![],
         SyntaxKind::TokenAnd => vec![],
         SyntaxKind::TerminalAnd => vec![],
         SyntaxKind::TokenAndAnd => vec![],
@@ -30,6 +30,8 @@ pub enum SyntaxKind {
     ExprIf,
     ElseClause,
     OptionElseClauseEmpty,
+    ExprLoop,
+    ExprWhile,
     ExprErrorPropagate,
     StructArgExpr,
     OptionStructArgExprEmpty,
@@ -54,6 +56,10 @@ pub enum SyntaxKind {
     OptionTerminalSemicolonEmpty,
     StatementExpr,
     StatementReturn,
+    ExprClause,
+    OptionExprClauseEmpty,
+    StatementBreak,
+    StatementContinue,
     Param,
     ModifierList,
     ParamList,
@@ -101,8 +107,12 @@ pub enum SyntaxKind {
     TerminalLiteralNumber,
     TokenShortString,
     TerminalShortString,
+    TokenBreak,
+    TerminalBreak,
     TokenConst,
     TerminalConst,
+    TokenContinue,
+    TerminalContinue,
     TokenElse,
     TerminalElse,
     TokenEnum,
@@ -121,6 +131,8 @@ pub enum SyntaxKind {
     TerminalImplicits,
     TokenLet,
     TerminalLet,
+    TokenLoop,
+    TerminalLoop,
     TokenMatch,
     TerminalMatch,
     TokenModule,
@@ -145,6 +157,8 @@ pub enum SyntaxKind {
     TerminalType,
     TokenUse,
     TerminalUse,
+    TokenWhile,
+    TerminalWhile,
     TokenAnd,
     TerminalAnd,
     TokenAndAnd,
@@ -233,7 +247,9 @@ impl SyntaxKind {
             SyntaxKind::TokenIdentifier
                 | SyntaxKind::TokenLiteralNumber
                 | SyntaxKind::TokenShortString
+                | SyntaxKind::TokenBreak
                 | SyntaxKind::TokenConst
+                | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenElse
                 | SyntaxKind::TokenEnum
                 | SyntaxKind::TokenExtern
@@ -243,6 +259,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
+                | SyntaxKind::TokenLoop
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenModule
                 | SyntaxKind::TokenMut
@@ -255,6 +272,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenType
                 | SyntaxKind::TokenUse
+                | SyntaxKind::TokenWhile
                 | SyntaxKind::TokenAnd
                 | SyntaxKind::TokenAndAnd
                 | SyntaxKind::TokenArrow
@@ -305,7 +323,9 @@ impl SyntaxKind {
             SyntaxKind::TerminalIdentifier
                 | SyntaxKind::TerminalLiteralNumber
                 | SyntaxKind::TerminalShortString
+                | SyntaxKind::TerminalBreak
                 | SyntaxKind::TerminalConst
+                | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalElse
                 | SyntaxKind::TerminalEnum
                 | SyntaxKind::TerminalExtern
@@ -315,6 +335,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
+                | SyntaxKind::TerminalLoop
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalModule
                 | SyntaxKind::TerminalMut
@@ -327,6 +348,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalType
                 | SyntaxKind::TerminalUse
+                | SyntaxKind::TerminalWhile
                 | SyntaxKind::TerminalAnd
                 | SyntaxKind::TerminalAndAnd
                 | SyntaxKind::TerminalArrow
@@ -369,7 +391,9 @@ impl SyntaxKind {
     pub fn is_keyword_token(&self) -> bool {
         matches!(
             *self,
-            SyntaxKind::TokenConst
+            SyntaxKind::TokenBreak
+                | SyntaxKind::TokenConst
+                | SyntaxKind::TokenContinue
                 | SyntaxKind::TokenElse
                 | SyntaxKind::TokenEnum
                 | SyntaxKind::TokenExtern
@@ -379,6 +403,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
+                | SyntaxKind::TokenLoop
                 | SyntaxKind::TokenMatch
                 | SyntaxKind::TokenModule
                 | SyntaxKind::TokenMut
@@ -391,12 +416,15 @@ impl SyntaxKind {
                 | SyntaxKind::TokenTrue
                 | SyntaxKind::TokenType
                 | SyntaxKind::TokenUse
+                | SyntaxKind::TokenWhile
         )
     }
     pub fn is_keyword_terminal(&self) -> bool {
         matches!(
             *self,
-            SyntaxKind::TerminalConst
+            SyntaxKind::TerminalBreak
+                | SyntaxKind::TerminalConst
+                | SyntaxKind::TerminalContinue
                 | SyntaxKind::TerminalElse
                 | SyntaxKind::TerminalEnum
                 | SyntaxKind::TerminalExtern
@@ -406,6 +434,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
+                | SyntaxKind::TerminalLoop
                 | SyntaxKind::TerminalMatch
                 | SyntaxKind::TerminalModule
                 | SyntaxKind::TerminalMut
@@ -418,6 +447,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalTrue
                 | SyntaxKind::TerminalType
                 | SyntaxKind::TerminalUse
+                | SyntaxKind::TerminalWhile
         )
     }
 }
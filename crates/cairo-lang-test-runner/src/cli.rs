@@ -1,36 +1,37 @@
 //! Compiles and runs a Cairo program.
 
-use std::collections::HashSet;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::sync::Mutex;
 
 use anyhow::{bail, Context};
+use cairo_felt::Felt;
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
-use cairo_lang_compiler::project::setup_project;
+use cairo_lang_compiler::diagnostics::{DiagnosticsReporter, MessageFormat};
+use cairo_lang_compiler::project::{enable_tests_cfg, setup_project};
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{FreeFunctionId, FunctionWithBodyId, ModuleItemId};
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::ids::CrateId;
-use cairo_lang_plugins::config::ConfigPlugin;
-use cairo_lang_plugins::derive::DerivePlugin;
-use cairo_lang_plugins::panicable::PanicablePlugin;
 use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
+use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::functions::GenericFunctionId;
-use cairo_lang_semantic::plugin::SemanticPlugin;
+use cairo_lang_semantic::literals::LiteralLongId;
 use cairo_lang_semantic::{ConcreteFunction, ConcreteFunctionWithBodyId, FunctionLongId};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
-use cairo_lang_starknet::plugin::StarkNetPlugin;
-use cairo_lang_syntax::node::ast::Expr;
+use cairo_lang_starknet::db::StarknetRootDatabaseBuilderEx;
+use cairo_lang_syntax::node::ast::{Expr, UnaryOperator};
 use cairo_lang_syntax::node::Token;
 use clap::Parser;
 use colored::Colorize;
+use fuzzing::{fuzz, FuzzerConfig, ParamKind};
 use itertools::Itertools;
+use num_bigint::{BigInt, Sign};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
+mod fuzzing;
+
 /// Command line args parser.
 /// Exits with 0/1 if the input is formatted correctly/incorrectly.
 #[derive(Parser, Debug)]
@@ -48,38 +49,46 @@ struct Args {
     /// Should we run only the ignored tests.
     #[arg(long, default_value_t = false)]
     ignored: bool,
-    /// Should we add the starknet plugin to run the tests.
-    #[arg(long, default_value_t = false)]
-    starknet: bool,
+    /// The number of random inputs to run each test with parameters with.
+    #[arg(long, default_value_t = 256)]
+    fuzzer_runs: usize,
+    /// The seed for generating the random inputs of tests with parameters.
+    #[arg(long, default_value_t = 0)]
+    fuzzer_seed: u64,
+    /// The format in which diagnostics are reported.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
 }
 
 /// The status of a ran test.
 enum TestStatus {
     Success,
-    Fail(RunResultValue),
+    Fail(TestFailure),
     Ignore,
 }
 
+/// The failure of a ran test.
+struct TestFailure {
+    /// The result of the failing run.
+    run_result: RunResultValue,
+    /// The arguments of the failing run, formatted with their parameter names - `None` for tests
+    /// that were not fuzzed.
+    counterexample: Option<String>,
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
-    // TODO(orizi): Use `get_default_plugins` and just update the config plugin.
-    let mut plugins: Vec<Arc<dyn SemanticPlugin>> = vec![
-        Arc::new(DerivePlugin {}),
-        Arc::new(PanicablePlugin {}),
-        Arc::new(ConfigPlugin { configs: HashSet::from(["test".to_string()]) }),
-    ];
-    if args.starknet {
-        plugins.push(Arc::new(StarkNetPlugin {}));
-    }
-    let db = &mut RootDatabase::builder().with_plugins(plugins).detect_corelib().build()?;
+    // Crates enable plugins, such as the StarkNet plugin, in their project settings.
+    let db = &mut RootDatabase::builder().detect_corelib().with_named_starknet_plugin().build()?;
 
     let main_crate_ids = setup_project(db, Path::new(&args.path))?;
+    enable_tests_cfg(db, &main_crate_ids);
 
-    if DiagnosticsReporter::stderr().check(db) {
+    if DiagnosticsReporter::stderr().with_message_format(args.message_format).check(db) {
         bail!("failed to compile: {}", args.path);
     }
-    let all_tests = find_all_tests(db, main_crate_ids);
+    let all_tests = find_all_tests(db, main_crate_ids)?;
     let sierra_program = db
         .get_sierra_program_for_functions(
             all_tests
@@ -117,8 +126,9 @@ fn main() -> anyhow::Result<()> {
         .filter(|(_, test)| !args.ignored || test.ignored)
         .collect_vec();
     let filtered_out = total_tests_count - named_tests.len();
-    let TestsSummary { passed, failed, ignored, failed_run_results } =
-        run_tests(named_tests, sierra_program)?;
+    let fuzzer_config = FuzzerConfig { runs: args.fuzzer_runs, seed: args.fuzzer_seed };
+    let TestsSummary { passed, failed, ignored, failures } =
+        run_tests(named_tests, sierra_program, &fuzzer_config)?;
     if failed.is_empty() {
         println!(
             "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
@@ -130,9 +140,12 @@ fn main() -> anyhow::Result<()> {
         Ok(())
     } else {
         println!("failures:");
-        for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
-            print!("   {failure} - ");
-            match run_result {
+        for (name, failure) in failed.iter().zip_eq(failures) {
+            print!("   {name} - ");
+            if let Some(counterexample) = failure.counterexample {
+                print!("minimal counterexample ({counterexample}) ");
+            }
+            match failure.run_result {
                 RunResultValue::Success(_) => {
                     println!("expected panic but finished successfully.");
                 }
@@ -164,13 +177,14 @@ struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
-    failed_run_results: Vec<RunResultValue>,
+    failures: Vec<TestFailure>,
 }
 
 /// Runs the tests and process the results for a summary.
 fn run_tests(
     named_tests: Vec<(String, TestConfig)>,
     sierra_program: cairo_lang_sierra::program::Program,
+    fuzzer_config: &FuzzerConfig,
 ) -> anyhow::Result<TestsSummary> {
     let runner =
         SierraCasmRunner::new(sierra_program, true).with_context(|| "Failed setting up runner.")?;
@@ -179,26 +193,72 @@ fn run_tests(
         passed: vec![],
         failed: vec![],
         ignored: vec![],
-        failed_run_results: vec![],
+        failures: vec![],
     }));
     named_tests
         .into_par_iter()
         .map(|(name, test)| -> anyhow::Result<(String, TestStatus)> {
+            let display_name = match &test.inputs {
+                TestInputs::Case(args) => format!(
+                    "{name}({})",
+                    test.params
+                        .iter()
+                        .zip(args)
+                        .map(|((_, kind), arg)| kind.format_value(arg))
+                        .join(", ")
+                ),
+                TestInputs::NoParams | TestInputs::Fuzzed => name.clone(),
+            };
             if test.ignored {
-                return Ok((name, TestStatus::Ignore));
+                return Ok((display_name, TestStatus::Ignore));
             }
-            let result = runner
-                .run_function(name.as_str(), &[], test.available_gas)
-                .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
-            Ok((
-                name,
-                match (&result.value, test.expectation) {
+            let run = |args: &[Felt]| -> anyhow::Result<Option<RunResultValue>> {
+                let result = runner
+                    .run_function(
+                        name.as_str(),
+                        &args.iter().cloned().map(Arg::Value).collect_vec(),
+                        test.available_gas,
+                    )
+                    .with_context(|| format!("Failed to run the function `{}`.", name.as_str()))?;
+                Ok(match (&result.value, &test.expectation) {
                     (RunResultValue::Success(_), TestExpectation::Success)
-                    | (RunResultValue::Panic(_), TestExpectation::Panics) => TestStatus::Success,
+                    | (RunResultValue::Panic(_), TestExpectation::Panics) => None,
                     (RunResultValue::Success(_), TestExpectation::Panics)
-                    | (RunResultValue::Panic(_), TestExpectation::Success) => {
-                        TestStatus::Fail(result.value)
-                    }
+                    | (RunResultValue::Panic(_), TestExpectation::Success) => Some(result.value),
+                })
+            };
+            let failure = match &test.inputs {
+                TestInputs::NoParams => {
+                    run(&[])?.map(|run_result| TestFailure { run_result, counterexample: None })
+                }
+                TestInputs::Case(args) => {
+                    run(args)?.map(|run_result| TestFailure { run_result, counterexample: None })
+                }
+                TestInputs::Fuzzed => {
+                    let kinds = test.params.iter().map(|(_, kind)| *kind).collect_vec();
+                    fuzz(&kinds, fuzzer_config, |args| Ok(run(args)?.is_some()))?
+                        .map(|args| -> anyhow::Result<TestFailure> {
+                            Ok(TestFailure {
+                                run_result: run(&args)?.unwrap(),
+                                counterexample: Some(
+                                    test.params
+                                        .iter()
+                                        .zip(&args)
+                                        .map(|((param_name, kind), arg)| {
+                                            format!("{param_name} = {}", kind.format_value(arg))
+                                        })
+                                        .join(", "),
+                                ),
+                            })
+                        })
+                        .transpose()?
+                }
+            };
+            Ok((
+                display_name,
+                match failure {
+                    None => TestStatus::Success,
+                    Some(failure) => TestStatus::Fail(failure),
                 },
             ))
         })
@@ -217,8 +277,8 @@ fn run_tests(
             let summary = wrapped_summary.as_mut().unwrap();
             let (res_type, status_str) = match status {
                 TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
-                TestStatus::Fail(run_result) => {
-                    summary.failed_run_results.push(run_result);
+                TestStatus::Fail(failure) => {
+                    summary.failures.push(failure);
                     (&mut summary.failed, "fail".bright_red())
                 }
                 TestStatus::Ignore => (&mut summary.ignored, "ignored".bright_yellow()),
@@ -230,6 +290,7 @@ fn run_tests(
 }
 
 /// Expectation for a result of a test.
+#[derive(Clone)]
 enum TestExpectation {
     /// Running the test should not panic.
     Success,
@@ -237,7 +298,19 @@ enum TestExpectation {
     Panics,
 }
 
+/// The inputs to run a test with.
+#[derive(Clone)]
+enum TestInputs {
+    /// The test has no parameters.
+    NoParams,
+    /// The arguments of one of the `#[test_case]` attributes of the test.
+    Case(Vec<Felt>),
+    /// Random arguments, for a test with parameters and no `#[test_case]` attributes.
+    Fuzzed,
+}
+
 /// The configuration for running a single test.
+#[derive(Clone)]
 struct TestConfig {
     /// The function id of the test function.
     func_id: FreeFunctionId,
@@ -247,10 +320,17 @@ struct TestConfig {
     expectation: TestExpectation,
     /// Should the test be ignored.
     ignored: bool,
+    /// The names and value kinds of the parameters of the test function.
+    params: Vec<(String, ParamKind)>,
+    /// The inputs to run the test with.
+    inputs: TestInputs,
 }
 
 /// Finds the tests in the requested crates.
-fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<TestConfig> {
+fn find_all_tests(
+    db: &dyn SemanticGroup,
+    main_crates: Vec<CrateId>,
+) -> anyhow::Result<Vec<TestConfig>> {
     let mut tests = vec![];
     for crate_id in main_crates {
         let modules = db.crate_modules(crate_id);
@@ -268,11 +348,16 @@ fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<Test
                         let mut available_gas = None;
                         let mut ignored = false;
                         let mut should_panic = false;
+                        let mut cases = vec![];
                         for attr in attrs {
                             match attr.id.as_str() {
                                 "test" => {
                                     is_test = true;
                                 }
+                                "test_case" => {
+                                    is_test = true;
+                                    cases.push(attr.args);
+                                }
                                 "available_gas" => {
                                     // TODO(orizi): Provide diagnostics when this does not match.
                                     if let [Expr::Literal(literal)] = &attr.args[..] {
@@ -293,7 +378,9 @@ fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<Test
                             }
                         }
                         if is_test {
-                            tests.push(TestConfig {
+                            let test_name = func_id.name(db.upcast());
+                            let params = get_test_params(db, *func_id)?;
+                            let config = TestConfig {
                                 func_id: *func_id,
                                 available_gas,
                                 expectation: if should_panic {
@@ -302,12 +389,103 @@ fn find_all_tests(db: &dyn SemanticGroup, main_crates: Vec<CrateId>) -> Vec<Test
                                     TestExpectation::Success
                                 },
                                 ignored,
-                            })
+                                inputs: if params.is_empty() {
+                                    TestInputs::NoParams
+                                } else {
+                                    TestInputs::Fuzzed
+                                },
+                                params,
+                            };
+                            if cases.is_empty() {
+                                tests.push(config);
+                                continue;
+                            }
+                            for case in cases {
+                                let inputs = TestInputs::Case(
+                                    get_test_case_args(db, &config.params, &case).with_context(
+                                        || format!("Invalid `test_case` of test `{test_name}`."),
+                                    )?,
+                                );
+                                tests.push(TestConfig { inputs, ..config.clone() });
+                            }
                         }
                     }
                 }
             }
         }
     }
-    tests
+    Ok(tests)
+}
+
+/// Returns the names and value kinds of the parameters of a test function.
+fn get_test_params(
+    db: &dyn SemanticGroup,
+    func_id: FreeFunctionId,
+) -> anyhow::Result<Vec<(String, ParamKind)>> {
+    let test_name = func_id.name(db.upcast());
+    let Ok(signature) = db.free_function_signature(func_id) else {
+        bail!("Failed getting the signature of test `{test_name}`.");
+    };
+    signature
+        .params
+        .into_iter()
+        .map(|param| {
+            let Some(kind) = ParamKind::from_type(db, param.ty) else {
+                bail!(
+                    "Parameter `{}` of test `{test_name}` is of unsupported type `{}`, expected \
+                     `felt`, `bool` or an unsigned integer.",
+                    param.name,
+                    param.ty.format(db)
+                );
+            };
+            Ok((param.name.to_string(), kind))
+        })
+        .collect()
+}
+
+/// Returns the arguments of a `#[test_case]` attribute of a test with the given parameters.
+fn get_test_case_args(
+    db: &dyn SemanticGroup,
+    params: &[(String, ParamKind)],
+    args: &[Expr],
+) -> anyhow::Result<Vec<Felt>> {
+    if args.len() != params.len() {
+        bail!("Expected {} arguments, got {}.", params.len(), args.len());
+    }
+    params
+        .iter()
+        .zip(args)
+        .map(|((param_name, kind), arg)| {
+            literal_value(db, arg).and_then(|value| kind.value_from_literal(&value)).with_context(
+                || format!("Argument for parameter `{param_name}` is not a valid literal."),
+            )
+        })
+        .collect()
+}
+
+/// Returns the value of a literal expression, or `None` if the expression is not a literal.
+fn literal_value(db: &dyn SemanticGroup, expr: &Expr) -> Option<BigInt> {
+    let syntax_db = db.upcast();
+    match expr {
+        Expr::Literal(literal) => {
+            let text = literal.token(syntax_db).text(syntax_db);
+            // Ignoring the type suffix, as the type is determined by the parameter.
+            let text = match text.split_once('_') {
+                Some((value, _)) => value.into(),
+                None => text,
+            };
+            LiteralLongId::try_from(text).ok().map(|literal| literal.value)
+        }
+        Expr::ShortString(short_string) => {
+            let text = short_string.token(syntax_db).text(syntax_db);
+            let (value, _) = text[1..].rsplit_once('\'')?;
+            Some(BigInt::from_bytes_be(Sign::Plus, value.as_bytes()))
+        }
+        Expr::True(_) => Some(BigInt::from(1)),
+        Expr::False(_) => Some(BigInt::from(0)),
+        Expr::Unary(unary) if matches!(unary.op(syntax_db), UnaryOperator::Minus(_)) => {
+            literal_value(db, &unary.expr(syntax_db)).map(|value| -value)
+        }
+        _ => None,
+    }
 }
@@ -0,0 +1,157 @@
+//! Generation and shrinking of the inputs of parametrized tests.
+
+use cairo_felt::{Felt, PRIME_STR};
+use cairo_lang_semantic::corelib::{core_bool_ty, core_felt_ty, get_core_ty_by_name};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::TypeId;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Num, One, Signed, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg(test)]
+#[path = "fuzzing_test.rs"]
+mod test;
+
+/// The unsigned integer types supported as test parameters, with their size in bits.
+const UINT_TYPES: [(&str, usize); 5] =
+    [("u8", 8), ("u16", 16), ("u32", 32), ("u64", 64), ("u128", 128)];
+
+/// The kind of values a test parameter accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamKind {
+    Felt,
+    Bool,
+    /// An unsigned integer of the given size in bits.
+    Uint(usize),
+}
+impl ParamKind {
+    /// Returns the kind of values of the given type, or `None` if the type is not supported as a
+    /// test parameter.
+    pub fn from_type(db: &dyn SemanticGroup, ty: TypeId) -> Option<Self> {
+        if ty == core_felt_ty(db) {
+            return Some(Self::Felt);
+        }
+        if ty == core_bool_ty(db) {
+            return Some(Self::Bool);
+        }
+        UINT_TYPES.into_iter().find_map(|(name, bits)| {
+            (ty == get_core_ty_by_name(db, name.into(), vec![])).then_some(Self::Uint(bits))
+        })
+    }
+
+    /// Returns the maximal value of the kind.
+    fn max_value(&self) -> BigUint {
+        match self {
+            Self::Felt => BigUint::from_str_radix(&PRIME_STR[2..], 16).unwrap() - 1u32,
+            Self::Bool => BigUint::one(),
+            Self::Uint(bits) => (BigUint::one() << bits) - 1u32,
+        }
+    }
+
+    /// Converts a literal value to a value of the kind, if it is in range.
+    /// Negative values are only valid for `felt`, and are taken modulo the prime.
+    pub fn value_from_literal(&self, value: &BigInt) -> Option<Felt> {
+        let magnitude = value.magnitude();
+        match self {
+            Self::Felt if value.is_negative() && *magnitude <= self.max_value() => {
+                Some(-to_felt(magnitude))
+            }
+            _ if value.is_negative() || *magnitude > self.max_value() => None,
+            _ => Some(to_felt(magnitude)),
+        }
+    }
+
+    /// Returns a random value of the kind.
+    /// Edge values are returned more often than others, as they are more likely to find bugs.
+    fn random_value(&self, rng: &mut StdRng) -> Felt {
+        let max_value = self.max_value();
+        if rng.gen_ratio(1, 4) {
+            let edge_values = [BigUint::zero(), BigUint::one(), max_value];
+            return to_felt(&edge_values[rng.gen_range(0..edge_values.len())]);
+        }
+        let mut bytes = [0u8; 32];
+        rng.fill(&mut bytes);
+        to_felt(&(BigUint::from_bytes_be(&bytes) % (max_value + 1u32)))
+    }
+
+    /// Returns the values to try in place of `value` when shrinking a failing case, ordered from
+    /// the smallest. All the candidates are strictly smaller than `value`, which guarantees the
+    /// shrinking terminates.
+    fn shrink_candidates(&self, value: &Felt) -> Vec<Felt> {
+        let value = value.to_biguint();
+        let mut candidates = vec![];
+        // Tries `value - value`, `value - value / 2`, ..., `value - 1`, approaching the minimal
+        // failing value by a binary search.
+        let mut delta = value.clone();
+        while !delta.is_zero() {
+            candidates.push(to_felt(&(&value - &delta)));
+            delta >>= 1;
+        }
+        candidates
+    }
+
+    /// Formats a value of the kind, the way it would be written in Cairo.
+    pub fn format_value(&self, value: &Felt) -> String {
+        match self {
+            Self::Bool if value.is_zero() => "false".into(),
+            Self::Bool => "true".into(),
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// Converts a non-negative number to a felt.
+fn to_felt(value: &BigUint) -> Felt {
+    Felt::from_bytes_be(&value.to_bytes_be())
+}
+
+/// The configuration of the fuzzer.
+pub struct FuzzerConfig {
+    /// The number of random inputs to run each parametrized test with.
+    pub runs: usize,
+    /// The seed of the random inputs generation.
+    pub seed: u64,
+}
+
+/// Runs `is_failing` with random arguments matching `params`, up to the configured number of
+/// runs, stopping at the first failing arguments.
+/// Returns the minimal failing arguments found by shrinking them, or `None` if no run failed.
+pub fn fuzz(
+    params: &[ParamKind],
+    config: &FuzzerConfig,
+    mut is_failing: impl FnMut(&[Felt]) -> anyhow::Result<bool>,
+) -> anyhow::Result<Option<Vec<Felt>>> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    for _ in 0..config.runs {
+        let args: Vec<Felt> = params.iter().map(|param| param.random_value(&mut rng)).collect();
+        if is_failing(&args)? {
+            return Ok(Some(shrink(params, args, is_failing)?));
+        }
+    }
+    Ok(None)
+}
+
+/// Shrinks the failing arguments `args` one at a time, as long as they keep failing.
+fn shrink(
+    params: &[ParamKind],
+    mut args: Vec<Felt>,
+    mut is_failing: impl FnMut(&[Felt]) -> anyhow::Result<bool>,
+) -> anyhow::Result<Vec<Felt>> {
+    let mut shrunk = true;
+    while shrunk {
+        shrunk = false;
+        for (i, param) in params.iter().enumerate() {
+            for candidate in param.shrink_candidates(&args[i]) {
+                let mut candidate_args = args.clone();
+                candidate_args[i] = candidate;
+                if is_failing(&candidate_args)? {
+                    args = candidate_args;
+                    shrunk = true;
+                    break;
+                }
+            }
+        }
+    }
+    Ok(args)
+}
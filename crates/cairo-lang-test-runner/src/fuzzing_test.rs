@@ -0,0 +1,39 @@
+use cairo_felt::Felt;
+use num_bigint::BigInt;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+use super::{fuzz, FuzzerConfig, ParamKind};
+
+#[test_case(ParamKind::Uint(8), 255, Some(Felt::from(255)); "u8 max")]
+#[test_case(ParamKind::Uint(8), 256, None; "u8 overflow")]
+#[test_case(ParamKind::Uint(8), -1, None; "u8 negative")]
+#[test_case(ParamKind::Bool, 1, Some(Felt::from(1)); "bool")]
+#[test_case(ParamKind::Bool, 2, None; "bool overflow")]
+#[test_case(ParamKind::Felt, -1, Some(-Felt::from(1)); "felt negative")]
+fn test_value_from_literal(kind: ParamKind, literal: i64, expected: Option<Felt>) {
+    assert_eq!(kind.value_from_literal(&BigInt::from(literal)), expected);
+}
+
+#[test]
+fn test_fuzz_shrinks_to_minimal_counterexample() {
+    let config = FuzzerConfig { runs: 100, seed: 0 };
+    let params = [ParamKind::Uint(128), ParamKind::Felt, ParamKind::Bool];
+    let counterexample =
+        fuzz(&params, &config, |args| Ok(args[0] > Felt::from(1000) && args[2] == Felt::from(1)))
+            .unwrap();
+    assert_eq!(counterexample, Some(vec![Felt::from(1001), Felt::from(0), Felt::from(1)]));
+}
+
+#[test]
+fn test_fuzz_without_failure() {
+    let config = FuzzerConfig { runs: 100, seed: 0 };
+    let mut runs = 0;
+    let counterexample = fuzz(&[ParamKind::Uint(64)], &config, |_| {
+        runs += 1;
+        Ok(false)
+    })
+    .unwrap();
+    assert_eq!(counterexample, None);
+    assert_eq!(runs, 100);
+}
@@ -1,14 +1,14 @@
 impl MyCopy of Copy::<Option::<(felt, felt)>>;
 
 fn foo(x: Option::<(felt, felt)>) -> Option::<felt> {
-    let y = x;
+    let _y = x;
     match x {
         Option::Some(x) => {
-            let (x, y) = x;
+            let (x, _y) = x;
             Option::<felt>::Some(x)
         },
         // TODO(spapini): Replace with _.
-        Option::None(o) => {
+        Option::None(_o) => {
             return Option::<felt>::None(());
         },
     }
@@ -26,9 +26,9 @@ fn main() -> felt {
     match_long(el1);
     let el2 = MyEnumLong::c(22);
     match_long(el2);
-    let eg1: MyEnumGeneric::<(), felt> = MyEnumGeneric::<(), felt>::a(30);
-    let eg2: MyEnumGeneric::<(), felt> = MyEnumGeneric::<(), felt>::b(());
-    let eg3: MyEnumGeneric::<(), felt> = MyEnumGeneric::<(), felt>::c(32);
+    let _eg1: MyEnumGeneric::<(), felt> = MyEnumGeneric::<(), felt>::a(30);
+    let _eg2: MyEnumGeneric::<(), felt> = MyEnumGeneric::<(), felt>::b(());
+    let _eg3: MyEnumGeneric::<(), felt> = MyEnumGeneric::<(), felt>::c(32);
     300
 }
 
@@ -5,8 +5,8 @@ fn hash_chain(n: felt) -> felt {
     }
 
     match get_gas_all(get_builtin_costs()) {
-        Option::Some(x) => {},
-        Option::None(x) => {
+        Option::Some(_x) => {},
+        Option::None(_x) => {
             let mut data = array_new::<felt>();
             array_append::<felt>(ref data, 'Out of gas');
             panic(data);
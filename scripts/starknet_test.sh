@@ -1,4 +1,4 @@
 #!/bin/bash
 
 cargo +nightly-2022-11-03 run --bin cairo-test -- \
-    --path crates/cairo-lang-starknet/cairo_level_tests/ --starknet
+    --path crates/cairo-lang-starknet/cairo_level_tests/
@@ -37,6 +37,8 @@ cairo_lang_test_utils::test_file_test!(
         interoperability: "interoperability",
         emit_event: "emit_event",
         getters: "getters",
+        messaging: "messaging",
+        testing: "testing",
     },
     run_small_e2e_test
 );
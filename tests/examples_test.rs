@@ -6,7 +6,7 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_filesystem::ids::CrateId;
-use cairo_lang_runner::{RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
+use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner, DUMMY_BUILTIN_GAS_COST};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_lang_sierra_to_casm::test_utils::build_metadata;
@@ -204,7 +204,12 @@ fn run_function_test(
     let runner = SierraCasmRunner::new(checked_compile_to_sierra(name), available_gas.is_some())
         .expect("Failed setting up runner.");
     let result = runner
-        .run_function(/* find first */ "", params, available_gas)
+        .run_function(
+            // find first
+            "",
+            &params.iter().cloned().map(Arg::Value).collect::<Vec<_>>(),
+            available_gas,
+        )
         .expect("Failed running the function.");
     if let Some(expected_cost) = expected_cost {
         assert_eq!(
@@ -9,25 +9,27 @@ import {
     ServerOptions,
 } from 'vscode-languageclient/node';
 
-// Tries to find the development version of the language server executable,
+// Tries to find the development version of the executable `name`,
 // assuming the workspace directory is inside the Cairo repository.
-function findDevLanguageServerAt(path: string, depth: number): string | undefined {
+function findDevExecutableAt(name: string, path: string, depth: number): string | undefined {
     if (depth == 0) {
         return undefined;
     }
-    let candidate = path + "/target/release/cairo-language-server";
+    let candidate = path + "/target/release/" + name;
     if (fs.existsSync(candidate)) {
         return candidate;
     }
-    candidate = path + "/target/debug/cairo-language-server";
+    candidate = path + "/target/debug/" + name;
     if (fs.existsSync(candidate)) {
         return candidate;
     }
-    return findDevLanguageServerAt(path + "/..", depth - 1);
+    return findDevExecutableAt(name, path + "/..", depth - 1);
 }
 
-function findLanguageServerExecutable(
-    config: vscode.WorkspaceConfiguration, context: vscode.ExtensionContext) {
+// Finds the executable `name`, at the path given by the configuration `configKey` if it is set.
+function findExecutable(
+    name: string, configKey: string, config: vscode.WorkspaceConfiguration,
+    context: vscode.ExtensionContext) {
 
     let rootPath = context.extensionPath;
 
@@ -36,18 +38,18 @@ function findLanguageServerExecutable(
         rootPath = workspaceFolders[0].uri.path || rootPath
     }
 
-    var configPath = config.get<string>('cairo1.languageServerPath');
+    var configPath = config.get<string>(configKey);
     if (configPath) {
         // Replace placeholders, if present.
-        let serverPath = configPath.replace(/\${workspaceFolder}/g, rootPath);
-        if (!fs.existsSync(serverPath)) {
+        let executablePath = configPath.replace(/\${workspaceFolder}/g, rootPath);
+        if (!fs.existsSync(executablePath)) {
             return undefined;
         }
-        return serverPath;
+        return executablePath;
     }
 
     // TODO(spapini): Use a bundled language server.
-    return findDevLanguageServerAt(rootPath, 10)
+    return findDevExecutableAt(name, rootPath, 10)
 }
 
 function setupLanguageServer(
@@ -55,7 +57,8 @@ function setupLanguageServer(
 
     let serverOptions: ServerOptions = () => {
         return new Promise((resolve) => {
-            let executable = findLanguageServerExecutable(config, context);
+            let executable = findExecutable(
+                "cairo-language-server", 'cairo1.languageServerPath', config, context);
             if (!executable) {
                 outputChannel.appendLine(
                     "Cairo language server was not found. Make sure cairo-lang-server is " +
@@ -105,6 +108,26 @@ function setupLanguageServer(
     client.start();
 }
 
+function setupDebugAdapter(
+    config: vscode.WorkspaceConfiguration, context: vscode.ExtensionContext, outputChannel: vscode.OutputChannel) {
+
+    const factory = new (class implements vscode.DebugAdapterDescriptorFactory {
+        createDebugAdapterDescriptor(_session: vscode.DebugSession):
+            vscode.ProviderResult<vscode.DebugAdapterDescriptor> {
+            let executable = findExecutable("cairo-dap", 'cairo1.debugAdapterPath', config, context);
+            if (!executable) {
+                outputChannel.appendLine(
+                    "Cairo debug adapter was not found. Make sure cairo-dap is installed and " +
+                    "that the configuration 'cairo1.debugAdapterPath' is correct.");
+                return undefined;
+            }
+            outputChannel.appendLine("Cairo debug adapter running from: " + executable);
+            return new vscode.DebugAdapterExecutable(executable);
+        }
+    })();
+    context.subscriptions.push(vscode.debug.registerDebugAdapterDescriptorFactory("cairo", factory));
+}
+
 export function activate(context: vscode.ExtensionContext) {
     const config = vscode.workspace.getConfiguration();
     let outputChannel = vscode.window.createOutputChannel("Cairo extension");
@@ -116,4 +139,6 @@ export function activate(context: vscode.ExtensionContext) {
         outputChannel.appendLine(
             "Language server is not enabled. Use the cairo1.enableLanguageServer config");
     }
+
+    setupDebugAdapter(config, context, outputChannel);
 }